@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use clap::Parser;
 use futures::stream;
-use kite_sql::db::{DBTransaction, DataBaseBuilder, Database, ResultIter};
+use kite_sql::db::{DBSession, DBTransaction, DataBaseBuilder, Database, ResultIter};
 use kite_sql::errors::DatabaseError;
 use kite_sql::storage::rocksdb::RocksStorage;
 use kite_sql::types::tuple::{Schema, SchemaRef, Tuple};
@@ -80,12 +80,12 @@ impl KiteSQLBackend {
 }
 
 pub struct SessionBackend {
-    inner: Arc<Database<RocksStorage>>,
+    inner: DBSession<RocksStorage>,
     tx: Mutex<Option<TransactionPtr>>,
 }
 
 impl SessionBackend {
-    pub fn new(inner: Arc<Database<RocksStorage>>) -> SessionBackend {
+    pub fn new(inner: DBSession<RocksStorage>) -> SessionBackend {
         SessionBackend {
             inner,
             tx: Mutex::new(None),
@@ -274,6 +274,11 @@ fn encode_tuples<'a>(schema: &SchemaRef, tuples: Vec<Tuple>) -> PgWireResult<Que
                 LogicalType::Decimal(_, _) => {
                     encoder.encode_field(&value.decimal().map(|decimal| decimal.to_string()))
                 }
+                LogicalType::Interval
+                | LogicalType::List(_)
+                | LogicalType::Json
+                | LogicalType::Uuid => encoder.encode_field(&Some(value.to_string())),
+                LogicalType::Bytea => encoder.encode_field(&value.bytea()),
                 _ => unreachable!(),
             }?;
         }
@@ -299,6 +304,11 @@ fn into_pg_type(data_type: &LogicalType) -> PgWireResult<Type> {
         LogicalType::Char(..) => Type::CHAR,
         LogicalType::Time => Type::TIME,
         LogicalType::Decimal(_, _) => Type::NUMERIC,
+        LogicalType::Interval => Type::INTERVAL,
+        LogicalType::Bytea => Type::BYTEA,
+        LogicalType::List(_) => Type::TEXT,
+        LogicalType::Json => Type::JSON,
+        LogicalType::Uuid => Type::UUID,
         _ => {
             return Err(PgWireError::UserError(Box::new(ErrorInfo::new(
                 "ERROR".to_owned(),
@@ -348,14 +358,11 @@ async fn main() {
     );
 
     let backend = KiteSQLBackend::new(args.path).unwrap();
-    let factory = Arc::new(CustomBackendFactory::new(Arc::new(SessionBackend::new(
-        backend.inner,
-    ))));
     let server_addr = format!("{}:{}", args.ip, args.port);
     let listener = TcpListener::bind(server_addr).await.unwrap();
 
     tokio::select! {
-        res = server_run(listener,factory) => {
+        res = server_run(listener, backend.inner) => {
             if let Err(err) = res {
                 error!("[Listener][Failed To Accept]: {}", err);
             }
@@ -366,11 +373,14 @@ async fn main() {
 
 async fn server_run(
     listener: TcpListener,
-    factory_ref: Arc<CustomBackendFactory>,
+    database: Arc<Database<RocksStorage>>,
 ) -> io::Result<()> {
     loop {
         let incoming_socket = listener.accept().await?;
-        let factory_ref = factory_ref.clone();
+        // each connection is a session of its own, so its `SET`s and transaction are not shared
+        let factory_ref = Arc::new(CustomBackendFactory::new(Arc::new(SessionBackend::new(
+            database.new_session(),
+        ))));
 
         tokio::spawn(async move {
             if let Err(err) = process_socket(incoming_socket.0, None, factory_ref).await {
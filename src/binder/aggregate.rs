@@ -1,11 +1,12 @@
-use ahash::RandomState;
 use itertools::Itertools;
-use sqlparser::ast::{Expr, OrderByExpr};
-use std::collections::HashSet;
+use sqlparser::ast::{Expr, OrderByExpr, Value};
+use std::mem;
 
 use super::{Binder, QueryBindStep};
 use crate::errors::DatabaseError;
+use crate::expression::agg::AggKind;
 use crate::expression::function::scala::ScalarFunction;
+use crate::planner::operator::window::WindowCall;
 use crate::planner::LogicalPlan;
 use crate::storage::Transaction;
 use crate::types::value::DataValue;
@@ -14,16 +15,20 @@ use crate::{
     planner::operator::{aggregate::AggregateOperator, sort::SortField},
 };
 
+const MAX_CUBE_SIZE: usize = 12;
+const MAX_GROUPING_SETS: usize = 4096;
+
 impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A> {
     pub fn bind_aggregate(
         &mut self,
         children: LogicalPlan,
         agg_calls: Vec<ScalarExpression>,
         groupby_exprs: Vec<ScalarExpression>,
+        grouping_sets: Vec<Vec<usize>>,
     ) -> LogicalPlan {
         self.context.step(QueryBindStep::Agg);
 
-        AggregateOperator::build(children, agg_calls, groupby_exprs, false)
+        AggregateOperator::build(children, agg_calls, groupby_exprs, grouping_sets, false)
     }
 
     pub fn extract_select_aggregate(
@@ -41,23 +46,98 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         select_list: &mut [ScalarExpression],
         groupby: &[Expr],
     ) -> Result<(), DatabaseError> {
-        let mut group_by_exprs = Vec::with_capacity(groupby.len());
+        let mut group_by_exprs: Vec<ScalarExpression> = Vec::with_capacity(groupby.len());
+        // the grouping sets are the cross product of the sets of each item,
+        // e.g. `GROUP BY a, ROLLUP(b, c)` is `GROUPING SETS ((a, b, c), (a, b), (a))`
+        let mut grouping_sets = vec![vec![]];
+        let mut has_grouping_sets = false;
+
         for expr in groupby.iter() {
-            group_by_exprs.push(self.bind_expr(expr)?);
-        }
+            let item_sets = match expr {
+                Expr::Rollup(exprs) => (0..=exprs.len())
+                    .rev()
+                    .map(|len| exprs[..len].concat())
+                    .collect_vec(),
+                Expr::Cube(exprs) if exprs.len() <= MAX_CUBE_SIZE => (0..1_usize << exprs.len())
+                    .rev()
+                    .map(|mask| {
+                        exprs
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| mask & (1 << i) != 0)
+                            .flat_map(|(_, exprs)| exprs.iter().cloned())
+                            .collect_vec()
+                    })
+                    .collect_vec(),
+                Expr::Cube(_) => {
+                    return Err(DatabaseError::UnsupportedStmt(format!(
+                        "CUBE is limited to {} elements",
+                        MAX_CUBE_SIZE
+                    )))
+                }
+                Expr::GroupingSets(sets) => sets.clone(),
+                expr => vec![vec![expr.clone()]],
+            };
+            has_grouping_sets |= matches!(
+                expr,
+                Expr::Rollup(_) | Expr::Cube(_) | Expr::GroupingSets(_)
+            );
 
-        self.validate_groupby_illegal_column(select_list, &group_by_exprs)?;
+            let mut item_positions = Vec::with_capacity(item_sets.len());
+            for set in item_sets {
+                let mut positions = Vec::with_capacity(set.len());
+                for expr in set.iter() {
+                    let expr = self.bind_expr(expr)?;
+                    let position = match group_by_exprs.iter().position(|bound| bound == &expr) {
+                        Some(position) => position,
+                        None => {
+                            group_by_exprs.push(expr);
+                            group_by_exprs.len() - 1
+                        }
+                    };
+                    positions.push(position);
+                }
+                item_positions.push(positions);
+            }
+            grouping_sets = grouping_sets
+                .iter()
+                .cartesian_product(item_positions.iter())
+                .map(|(set, positions)| {
+                    set.iter()
+                        .chain(positions.iter())
+                        .copied()
+                        .sorted()
+                        .dedup()
+                        .collect_vec()
+                })
+                .collect_vec();
+            if grouping_sets.len() > MAX_GROUPING_SETS {
+                return Err(DatabaseError::UnsupportedStmt(format!(
+                    "more than {} grouping sets",
+                    MAX_GROUPING_SETS
+                )));
+            }
+        }
+        if has_grouping_sets {
+            self.context.grouping_sets = grouping_sets;
+        }
+        if group_by_exprs.iter().any(ScalarExpression::has_agg_call) {
+            return Err(DatabaseError::UnsupportedStmt(
+                "aggregate functions are not allowed in GROUP BY".to_string(),
+            ));
+        }
 
         for expr in group_by_exprs.iter_mut() {
             self.visit_group_by_expr(select_list, expr);
         }
-        Ok(())
+        self.validate_groupby_illegal_column(select_list)
     }
 
     pub fn extract_having_orderby_aggregate(
         &mut self,
         having: &Option<Expr>,
         orderbys: &[OrderByExpr],
+        select_list: &[ScalarExpression],
     ) -> Result<(Option<ScalarExpression>, Option<Vec<SortField>>), DatabaseError> {
         // Extract having expression.
         let return_having = if let Some(having) = having {
@@ -78,14 +158,31 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                     asc,
                     nulls_first,
                 } = orderby;
-                let mut expr = self.bind_expr(expr)?;
+                let mut expr = if let Some(position) = Self::orderby_position(expr) {
+                    // `ORDER BY 1` sorts by the first item of the select list
+                    position
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|position| position.checked_sub(1))
+                        .and_then(|i| select_list.get(i))
+                        .cloned()
+                        .ok_or_else(|| {
+                            DatabaseError::InvalidValue(format!(
+                                "ORDER BY position {} is not in select list",
+                                position
+                            ))
+                        })?
+                } else {
+                    self.context.allow_window = true;
+                    let expr = self.bind_expr(expr);
+                    self.context.allow_window = false;
+                    expr?
+                };
                 self.visit_column_agg_expr(&mut expr)?;
 
-                return_orderby.push(SortField::new(
-                    expr,
-                    asc.map_or(true, |asc| asc),
-                    nulls_first.map_or(false, |first| first),
-                ));
+                let asc = asc.map_or(true, |asc| asc);
+                // NULLs are larger than any value by default, as in PostgreSQL
+                return_orderby.push(SortField::new(expr, asc, nulls_first.unwrap_or(!asc)));
             }
             Some(return_orderby)
         } else {
@@ -94,6 +191,49 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         Ok((return_having, return_orderby))
     }
 
+    /// the 1-based select item referenced by an integer literal, other constants (e.g. `2.5`)
+    /// stay constants
+    fn orderby_position(expr: &Expr) -> Option<&str> {
+        match expr {
+            Expr::Value(Value::Number(number, _))
+                if !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit()) =>
+            {
+                Some(number)
+            }
+            _ => None,
+        }
+    }
+
+    /// Extracts the aggregate functions called in the keys of the window functions, which are
+    /// evaluated on the output of the aggregation.
+    pub fn extract_window_aggregate(&mut self) -> Result<(), DatabaseError> {
+        let mut window_calls = mem::take(&mut self.context.window_calls);
+
+        for expr in window_calls.iter_mut().flat_map(WindowCall::exprs_mut) {
+            self.visit_column_agg_expr(expr)?;
+        }
+        self.context.window_calls = window_calls;
+
+        for agg_call in self.context.agg_calls.iter() {
+            if self.is_window_output(agg_call) {
+                return Err(DatabaseError::UnsupportedStmt(format!(
+                    "window function in the aggregate function: {}",
+                    agg_call
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn is_window_output(&self, expr: &ScalarExpression) -> bool {
+        expr.referenced_columns(true).iter().any(|column| {
+            self.context
+                .window_calls
+                .iter()
+                .any(|call| &call.output == column)
+        })
+    }
+
     fn visit_column_agg_expr(&mut self, expr: &mut ScalarExpression) -> Result<(), DatabaseError> {
         match expr {
             ScalarExpression::AggCall { .. } => {
@@ -144,6 +284,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 self.visit_column_agg_expr(expr)?;
                 self.visit_column_agg_expr(in_expr)?;
             }
+            ScalarExpression::Extract { expr, .. } => self.visit_column_agg_expr(expr)?,
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -209,60 +350,55 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         Ok(())
     }
 
-    /// Validate select exprs must appear in the GROUP BY clause or be used in
-    /// an aggregate function.
-    /// e.g. SELECT a,count(b) FROM t GROUP BY a. it's ok.
-    ///      SELECT a,b FROM t GROUP BY a.        it's error.
-    ///      SELECT a,count(b) FROM t GROUP BY b. it's error.
+    /// Validate select exprs must appear in the GROUP BY clause, be computed from them or be used
+    /// in an aggregate function.
+    /// e.g. SELECT a,count(b) FROM t GROUP BY a.         it's ok.
+    ///      SELECT (a+1)*2,count(b) FROM t GROUP BY a+1. it's ok.
+    ///      SELECT a,b FROM t GROUP BY a.                it's error.
+    ///      SELECT a,count(b) FROM t GROUP BY b.         it's error.
+    ///      SELECT a,count(b) FROM t GROUP BY a+1.       it's error.
     fn validate_groupby_illegal_column(
-        &mut self,
+        &self,
         select_items: &[ScalarExpression],
-        groupby: &[ScalarExpression],
     ) -> Result<(), DatabaseError> {
-        let mut group_raw_exprs = vec![];
-        for expr in groupby {
-            if let ScalarExpression::Alias { alias, .. } = expr {
-                let alias_expr = select_items.iter().find(|column| {
-                    if let ScalarExpression::Alias {
-                        alias: inner_alias, ..
-                    } = &column
-                    {
-                        alias == inner_alias
-                    } else {
-                        false
-                    }
-                });
-
-                if let Some(inner_expr) = alias_expr {
-                    group_raw_exprs.push(inner_expr);
-                }
-            } else {
-                group_raw_exprs.push(expr);
+        for expr in select_items {
+            if self.is_window_output(expr) {
+                continue;
             }
+            self.validate_grouped_expr(expr)?;
         }
-        let mut group_raw_set: HashSet<&ScalarExpression, RandomState> =
-            HashSet::from_iter(group_raw_exprs.iter().copied());
 
-        for expr in select_items {
-            if expr.has_agg_call() {
+        Ok(())
+    }
+
+    /// Validate the argument of `GROUPING()` is one of the GROUP BY expressions.
+    /// e.g. SELECT a,grouping(a) FROM t GROUP BY a. it's ok.
+    ///      SELECT a,grouping(b) FROM t GROUP BY a. it's error.
+    pub fn validate_grouping_args(&self) -> Result<(), DatabaseError> {
+        for expr in self.context.agg_calls.iter() {
+            let ScalarExpression::AggCall {
+                kind: AggKind::Grouping,
+                args,
+                ..
+            } = expr
+            else {
                 continue;
-            }
-            group_raw_set.remove(expr);
+            };
+            let arg = args[0].unpack_alias_ref();
 
-            if !group_raw_exprs.iter().contains(&expr) {
+            if !self
+                .context
+                .group_by_exprs
+                .iter()
+                .any(|group_by_expr| group_by_expr.unpack_alias_ref() == arg)
+            {
                 return Err(DatabaseError::AggMiss(format!(
-                    "`{}` must appear in the GROUP BY clause or be used in an aggregate function",
-                    expr
+                    "arguments to GROUPING must be grouping expressions: {}",
+                    arg
                 )));
             }
         }
 
-        if !group_raw_set.is_empty() {
-            return Err(DatabaseError::AggMiss(
-                "in the GROUP BY clause the field must be in the select clause".to_string(),
-            ));
-        }
-
         Ok(())
     }
 
@@ -289,12 +425,27 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
 
         if let Some(i) = select_list.iter().position(|column| column == expr) {
             self.context.group_by_exprs.push(select_list[i].clone())
+        } else {
+            // the group key is not selected, e.g. `SELECT count(*) FROM t GROUP BY a + 1`
+            self.context.group_by_exprs.push(expr.clone())
         }
     }
 
-    /// Validate having or orderby clause is valid, if SQL has group by clause.
+    /// Validate having or orderby clause is valid, if SQL has group by clause or aggregate functions.
     pub fn validate_having_orderby(&self, expr: &ScalarExpression) -> Result<(), DatabaseError> {
-        if self.context.group_by_exprs.is_empty() {
+        // without any aggregation `HAVING` filters the rows like `WHERE`
+        if self.context.group_by_exprs.is_empty() && self.context.agg_calls.is_empty() {
+            return Ok(());
+        }
+        self.validate_grouped_expr(expr)
+    }
+
+    fn validate_grouped_expr(&self, expr: &ScalarExpression) -> Result<(), DatabaseError> {
+        // a computed group key (e.g. `a + 1` of `GROUP BY a + 1`) is read from the output of the
+        // aggregation by its output column like `ScalarExpression::try_reference`
+        if self.context.group_by_exprs.iter().any(|group_by_expr| {
+            group_by_expr.output_column().summary() == expr.output_column().summary()
+        }) {
             return Ok(());
         }
 
@@ -318,7 +469,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                     return Ok(());
                 }
                 if matches!(expr, ScalarExpression::Alias { .. }) {
-                    return self.validate_having_orderby(expr.unpack_alias_ref());
+                    return self.validate_grouped_expr(expr.unpack_alias_ref());
                 }
 
                 Err(DatabaseError::AggMiss(
@@ -329,13 +480,13 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 ))
             }
 
-            ScalarExpression::TypeCast { expr, .. } => self.validate_having_orderby(expr),
-            ScalarExpression::IsNull { expr, .. } => self.validate_having_orderby(expr),
-            ScalarExpression::Unary { expr, .. } => self.validate_having_orderby(expr),
+            ScalarExpression::TypeCast { expr, .. } => self.validate_grouped_expr(expr),
+            ScalarExpression::IsNull { expr, .. } => self.validate_grouped_expr(expr),
+            ScalarExpression::Unary { expr, .. } => self.validate_grouped_expr(expr),
             ScalarExpression::In { expr, args, .. } => {
-                self.validate_having_orderby(expr)?;
+                self.validate_grouped_expr(expr)?;
                 for arg in args {
-                    self.validate_having_orderby(arg)?;
+                    self.validate_grouped_expr(arg)?;
                 }
                 Ok(())
             }
@@ -344,8 +495,8 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 right_expr,
                 ..
             } => {
-                self.validate_having_orderby(left_expr)?;
-                self.validate_having_orderby(right_expr)?;
+                self.validate_grouped_expr(left_expr)?;
+                self.validate_grouped_expr(right_expr)?;
                 Ok(())
             }
             ScalarExpression::Between {
@@ -354,9 +505,9 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 right_expr,
                 ..
             } => {
-                self.validate_having_orderby(expr)?;
-                self.validate_having_orderby(left_expr)?;
-                self.validate_having_orderby(right_expr)?;
+                self.validate_grouped_expr(expr)?;
+                self.validate_grouped_expr(left_expr)?;
+                self.validate_grouped_expr(right_expr)?;
                 Ok(())
             }
             ScalarExpression::SubString {
@@ -364,28 +515,29 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 for_expr,
                 from_expr,
             } => {
-                self.validate_having_orderby(expr)?;
+                self.validate_grouped_expr(expr)?;
                 if let Some(expr) = for_expr {
-                    self.validate_having_orderby(expr)?;
+                    self.validate_grouped_expr(expr)?;
                 }
                 if let Some(expr) = from_expr {
-                    self.validate_having_orderby(expr)?;
+                    self.validate_grouped_expr(expr)?;
                 }
                 Ok(())
             }
             ScalarExpression::Position { expr, in_expr } => {
-                self.validate_having_orderby(expr)?;
-                self.validate_having_orderby(in_expr)?;
+                self.validate_grouped_expr(expr)?;
+                self.validate_grouped_expr(in_expr)?;
                 Ok(())
             }
+            ScalarExpression::Extract { expr, .. } => self.validate_grouped_expr(expr),
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
                 ..
             } => {
-                self.validate_having_orderby(expr)?;
+                self.validate_grouped_expr(expr)?;
                 if let Some(trim_what_expr) = trim_what_expr {
-                    self.validate_having_orderby(trim_what_expr)?;
+                    self.validate_grouped_expr(trim_what_expr)?;
                 }
                 Ok(())
             }
@@ -395,7 +547,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             | ScalarExpression::ScalaFunction(ScalarFunction { args, .. })
             | ScalarExpression::Coalesce { exprs: args, .. } => {
                 for expr in args {
-                    self.validate_having_orderby(expr)?;
+                    self.validate_grouped_expr(expr)?;
                 }
                 Ok(())
             }
@@ -405,9 +557,9 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 right_expr,
                 ..
             } => {
-                self.validate_having_orderby(condition)?;
-                self.validate_having_orderby(left_expr)?;
-                self.validate_having_orderby(right_expr)?;
+                self.validate_grouped_expr(condition)?;
+                self.validate_grouped_expr(left_expr)?;
+                self.validate_grouped_expr(right_expr)?;
 
                 Ok(())
             }
@@ -421,8 +573,8 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 right_expr,
                 ..
             } => {
-                self.validate_having_orderby(left_expr)?;
-                self.validate_having_orderby(right_expr)?;
+                self.validate_grouped_expr(left_expr)?;
+                self.validate_grouped_expr(right_expr)?;
 
                 Ok(())
             }
@@ -433,14 +585,14 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 ..
             } => {
                 if let Some(expr) = operand_expr {
-                    self.validate_having_orderby(expr)?;
+                    self.validate_grouped_expr(expr)?;
                 }
                 for (expr_1, expr_2) in expr_pairs {
-                    self.validate_having_orderby(expr_1)?;
-                    self.validate_having_orderby(expr_2)?;
+                    self.validate_grouped_expr(expr_1)?;
+                    self.validate_grouped_expr(expr_2)?;
                 }
                 if let Some(expr) = else_expr {
-                    self.validate_having_orderby(expr)?;
+                    self.validate_grouped_expr(expr)?;
                 }
 
                 Ok(())
@@ -43,6 +43,8 @@ pub enum FileFormat {
         /// Whether or not the file has a header line.
         header: bool,
     },
+    /// One JSON object per line, keyed by the column names.
+    Json,
 }
 
 impl std::fmt::Display for ExtSource {
@@ -72,31 +74,42 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         target: CopyTarget,
         options: &[CopyOption],
     ) -> Result<LogicalPlan, DatabaseError> {
-        let (table_name, ..) = match source {
-            CopySource::Table {
-                table_name,
-                columns,
-            } => (table_name, columns),
+        let ext_source = ExtSource {
+            path: match target {
+                CopyTarget::File { filename } => filename.into(),
+                t => {
+                    return Err(DatabaseError::UnsupportedStmt(format!(
+                        "copy target: {:?}",
+                        t
+                    )))
+                }
+            },
+            format: FileFormat::from_options(options)?,
+        };
+        let table_name = match source {
+            CopySource::Table { table_name, .. } => table_name,
+            CopySource::Query(query) if to => {
+                // COPY (<query>) TO <dest_file>
+                let mut plan = self.bind_query(&query)?;
+                let schema_ref = plan.output_schema().clone();
+
+                return Ok(LogicalPlan::new(
+                    Operator::CopyToFile(CopyToFileOperator {
+                        target: ext_source,
+                        schema_ref,
+                    }),
+                    Childrens::Only(plan),
+                ));
+            }
             CopySource::Query(_) => {
                 return Err(DatabaseError::UnsupportedStmt("'COPY SOURCE'".to_string()));
             }
         };
         let table_name = Arc::new(lower_case_name(&table_name)?);
+        self.reference_table(&table_name);
 
         if let Some(table) = self.context.table(table_name.clone())? {
             let schema_ref = table.schema_ref().clone();
-            let ext_source = ExtSource {
-                path: match target {
-                    CopyTarget::File { filename } => filename.into(),
-                    t => {
-                        return Err(DatabaseError::UnsupportedStmt(format!(
-                            "copy target: {:?}",
-                            t
-                        )))
-                    }
-                },
-                format: FileFormat::from_options(options),
-            };
 
             if to {
                 // COPY <source_table> TO <dest_file>
@@ -108,6 +121,11 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                     Childrens::Only(TableScanOperator::build(table_name, table)),
                 ))
             } else {
+                if matches!(ext_source.format, FileFormat::Json) {
+                    return Err(DatabaseError::UnsupportedStmt(
+                        "COPY FROM in JSON format".to_string(),
+                    ));
+                }
                 // COPY <dest_table> FROM <source_file>
                 Ok(LogicalPlan::new(
                     Operator::CopyFromFile(CopyFromFileOperator {
@@ -126,28 +144,45 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
 
 impl FileFormat {
     /// Create from copy options.
-    pub fn from_options(options: &[CopyOption]) -> Self {
+    pub fn from_options(options: &[CopyOption]) -> Result<Self, DatabaseError> {
+        let mut is_json = false;
         let mut delimiter = ',';
         let mut quote = '"';
         let mut escape = None;
         let mut header = false;
         for opt in options {
             match opt {
-                CopyOption::Format(fmt) => {
-                    debug_assert_eq!(fmt.value.to_lowercase(), "csv", "only support CSV format")
-                }
+                CopyOption::Format(fmt) if fmt.value.eq_ignore_ascii_case("csv") => is_json = false,
+                CopyOption::Format(fmt) if fmt.value.eq_ignore_ascii_case("json") => is_json = true,
                 CopyOption::Delimiter(c) => delimiter = *c,
                 CopyOption::Header(b) => header = *b,
                 CopyOption::Quote(c) => quote = *c,
                 CopyOption::Escape(c) => escape = Some(*c),
-                o => panic!("unsupported copy option: {:?}", o),
+                o => {
+                    return Err(DatabaseError::UnsupportedStmt(format!(
+                        "copy option: {}",
+                        o
+                    )))
+                }
             }
         }
-        FileFormat::Csv {
+        if is_json {
+            if let Some(o) = options
+                .iter()
+                .find(|opt| !matches!(opt, CopyOption::Format(_)))
+            {
+                return Err(DatabaseError::UnsupportedStmt(format!(
+                    "copy option: {} in JSON format",
+                    o
+                )));
+            }
+            return Ok(FileFormat::Json);
+        }
+        Ok(FileFormat::Csv {
             delimiter,
             quote,
             escape,
             header,
-        }
+        })
     }
 }
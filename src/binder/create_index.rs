@@ -1,4 +1,5 @@
 use crate::binder::{lower_case_name, Binder, Source};
+use crate::catalog::TableName;
 use crate::errors::DatabaseError;
 use crate::expression::ScalarExpression;
 use crate::planner::operator::create_index::CreateIndexOperator;
@@ -8,7 +9,8 @@ use crate::planner::{Childrens, LogicalPlan};
 use crate::storage::Transaction;
 use crate::types::index::IndexType;
 use crate::types::value::DataValue;
-use sqlparser::ast::{ObjectName, OrderByExpr};
+use crate::types::LogicalType;
+use sqlparser::ast::{Expr, ObjectName, OrderByExpr};
 use std::sync::Arc;
 
 impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A> {
@@ -22,6 +24,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
     ) -> Result<LogicalPlan, DatabaseError> {
         let table_name = Arc::new(lower_case_name(table_name)?);
         let index_name = lower_case_name(name)?;
+        let predicate = self.context.index_predicate.take();
         let ty = if is_unique {
             IndexType::Unique
         } else if exprs.len() == 1 {
@@ -39,11 +42,30 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             Source::View(view) => LogicalPlan::clone(&view.plan),
         };
         let mut columns = Vec::with_capacity(exprs.len());
+        let mut index_expr = None;
 
         for expr in exprs {
-            // TODO: Expression Index
             match self.bind_expr(&expr.expr)? {
                 ScalarExpression::ColumnRef(column) => columns.push(column),
+                // e.g. `CREATE INDEX idx ON t ((json_extract(j, '$.id')))`
+                expr if exprs.len() == 1 => {
+                    let referenced_columns = expr.referenced_columns(true);
+
+                    if referenced_columns.is_empty()
+                        || expr.has_agg_call()
+                        || !expr.is_deterministic()
+                        || referenced_columns
+                            .iter()
+                            .any(|column| column.table_name() != Some(&table_name))
+                    {
+                        return Err(DatabaseError::UnsupportedStmt(format!(
+                            "'CREATE INDEX' by {}",
+                            expr
+                        )));
+                    }
+                    columns = referenced_columns;
+                    index_expr = Some(expr);
+                }
                 expr => {
                     return Err(DatabaseError::UnsupportedStmt(format!(
                         "'CREATE INDEX' by {}",
@@ -53,6 +75,10 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             }
         }
 
+        let predicate = predicate
+            .map(|predicate| self.bind_index_predicate(&predicate, &table_name))
+            .transpose()?;
+
         Ok(LogicalPlan::new(
             Operator::CreateIndex(CreateIndexOperator {
                 table_name,
@@ -60,8 +86,40 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                 index_name,
                 if_not_exists,
                 ty,
+                expr: index_expr,
+                predicate,
             }),
             Childrens::Only(plan),
         ))
     }
+
+    /// The predicate of a partial index must be a deterministic condition on the columns of the
+    /// table, so that whether a row is indexed only depends on the row.
+    fn bind_index_predicate(
+        &mut self,
+        predicate: &Expr,
+        table_name: &TableName,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let predicate = self.bind_expr(predicate)?;
+
+        if predicate.has_agg_call()
+            || !predicate.is_deterministic()
+            || predicate
+                .referenced_columns(true)
+                .iter()
+                .any(|column| column.table_name() != Some(table_name))
+        {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "'CREATE INDEX' where {}",
+                predicate
+            )));
+        }
+        if !matches!(
+            predicate.return_type(),
+            LogicalType::Boolean | LogicalType::SqlNull
+        ) {
+            return Err(DatabaseError::InvalidType);
+        }
+        Ok(predicate)
+    }
 }
@@ -129,7 +129,14 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                     }
                 }
                 ColumnOption::Default(expr) => {
-                    let mut expr = self.bind_expr(expr)?;
+                    // a default reads the clock when the row is inserted, not at `CREATE TABLE`,
+                    // and its casts are not lenient as it outlives this statement
+                    let now = self.context.now.take();
+                    let coerced_nulls = self.context.coerced_nulls.take();
+                    let expr = self.bind_expr(expr);
+                    self.context.now = now;
+                    self.context.coerced_nulls = coerced_nulls;
+                    let mut expr = expr?;
 
                     if !expr.referenced_columns(true).is_empty() {
                         return Err(DatabaseError::UnsupportedStmt(
@@ -140,6 +147,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                         expr = ScalarExpression::TypeCast {
                             expr: Box::new(expr),
                             ty: column_desc.column_datatype.clone(),
+                            is_try: false,
                         }
                     }
                     column_desc.default = Some(expr);
@@ -22,7 +22,17 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         query: &Query,
     ) -> Result<LogicalPlan, DatabaseError> {
         let view_name = Arc::new(lower_case_name(name)?);
-        let mut plan = self.bind_query(query)?;
+        // the plan of a view outlives this statement, so it neither reads the clock or the settings
+        // of this statement nor has its casts lenient, the settings are bound by each statement
+        // using it
+        let now = self.context.now.take();
+        let settings = self.context.settings.take();
+        let coerced_nulls = self.context.coerced_nulls.take();
+        let plan = self.bind_query(query);
+        self.context.now = now;
+        self.context.settings = settings;
+        self.context.coerced_nulls = coerced_nulls;
+        let mut plan = plan?;
 
         if !columns.is_empty() {
             let mapping_schema = plan.output_schema();
@@ -18,6 +18,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
     ) -> Result<LogicalPlan, DatabaseError> {
         if let TableFactor::Table { name, alias, .. } = &from.relation {
             let table_name = Arc::new(lower_case_name(name)?);
+            self.reference_table(&table_name);
             let mut table_alias = None;
             let mut alias_idents = None;
 
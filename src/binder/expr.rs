@@ -2,21 +2,33 @@ use crate::catalog::{ColumnCatalog, ColumnRef, TableName};
 use crate::errors::DatabaseError;
 use crate::expression;
 use crate::expression::agg::AggKind;
+use crate::parser;
 use itertools::Itertools;
 use sqlparser::ast::{
-    BinaryOperator, CharLengthUnits, DataType, Expr, Function, FunctionArg, FunctionArgExpr, Ident,
-    Query, UnaryOperator, Value,
+    Array, BinaryOperator, CharLengthUnits, DataType, DateTimeField, Expr, Function, FunctionArg,
+    FunctionArgExpr, Ident, Interval, JsonOperator, ObjectName, Query, SelectItem, SetExpr,
+    TableFactor, TableWithJoins, UnaryOperator, Value, Values,
 };
 use std::collections::HashMap;
+use std::mem;
 use std::slice;
 use std::sync::Arc;
 
 use super::{lower_ident, Binder, BinderContext, QueryBindStep, SubQueryType};
-use crate::expression::function::scala::{ArcScalarFunctionImpl, ScalarFunction};
+use crate::expression::function::scala::{
+    ArcScalarFunctionImpl, ScalarFunction, ScalarFunctionImpl,
+};
 use crate::expression::function::table::{ArcTableFunctionImpl, TableFunction};
 use crate::expression::function::FunctionSummary;
-use crate::expression::{AliasType, ScalarExpression};
-use crate::planner::{LogicalPlan, SchemaOutput};
+use crate::expression::{AliasType, DatePart, ScalarExpression};
+use crate::function::array::{ArrayElement, Cardinality};
+use crate::function::element_at::ElementAt;
+use crate::function::json_access::JsonAccess;
+use crate::function::safe_cast::SafeCast;
+use crate::planner::operator::limit::LimitOperator;
+use crate::planner::operator::project::ProjectOperator;
+use crate::planner::operator::Operator;
+use crate::planner::{Childrens, LogicalPlan, SchemaOutput};
 use crate::storage::Transaction;
 use crate::types::value::{DataValue, Utf8Type};
 use crate::types::{ColumnId, LogicalType};
@@ -50,17 +62,16 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             Expr::BinaryOp { left, right, op } => self.bind_binary_op_internal(left, right, op),
             Expr::Value(v) => {
                 let value = if let Value::Placeholder(name) = v {
-                    self.args
-                        .as_ref()
-                        .iter()
-                        .find_map(|(key, value)| (key == name).then(|| value.clone()))
-                        .ok_or_else(|| DatabaseError::ParametersNotFound(name.to_string()))?
+                    self.bind_placeholder(name)?
                 } else {
                     v.try_into()?
                 };
                 Ok(ScalarExpression::Constant(value))
             }
             Expr::Function(func) => self.bind_function(func),
+            Expr::AggregateExpressionWithFilter { expr, filter } => {
+                self.bind_aggregate_filter(expr, filter)
+            }
             Expr::Nested(expr) => self.bind_expr(expr),
             Expr::UnaryOp { expr, op } => self.bind_unary_op_internal(expr, op),
             Expr::Like {
@@ -71,6 +82,19 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             } => self.bind_like(*negated, expr, pattern, escape_char),
             Expr::IsNull(expr) => self.bind_is_null(expr, false),
             Expr::IsNotNull(expr) => self.bind_is_null(expr, true),
+            Expr::IsDistinctFrom(left, right) => {
+                let expr = self.bind_binary_op_internal(left, right, &BinaryOperator::Spaceship)?;
+
+                Ok(ScalarExpression::Unary {
+                    op: expression::UnaryOperator::Not,
+                    expr: Box::new(expr),
+                    evaluator: None,
+                    ty: LogicalType::Boolean,
+                })
+            }
+            Expr::IsNotDistinctFrom(left, right) => {
+                self.bind_binary_op_internal(left, right, &BinaryOperator::Spaceship)
+            }
             Expr::InList {
                 expr,
                 list,
@@ -78,7 +102,8 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             } => self.bind_is_in(expr, list, *negated),
             Expr::Cast {
                 expr, data_type, ..
-            } => self.bind_cast(expr, data_type),
+            } => self.bind_cast(expr, data_type, false),
+            Expr::TryCast { expr, data_type } => self.bind_cast(expr, data_type, true),
             Expr::TypedString { data_type, value } => {
                 let logical_type = LogicalType::try_from(data_type.clone())?;
                 let value = DataValue::Utf8 {
@@ -90,17 +115,13 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
 
                 Ok(ScalarExpression::Constant(value))
             }
+            Expr::Interval(interval) => self.bind_interval(interval),
             Expr::Between {
                 expr,
                 negated,
                 low,
                 high,
-            } => Ok(ScalarExpression::Between {
-                negated: *negated,
-                expr: Box::new(self.bind_expr(expr)?),
-                left_expr: Box::new(self.bind_expr(low)?),
-                right_expr: Box::new(self.bind_expr(high)?),
-            }),
+            } => self.bind_between(expr, *negated, low, high),
             Expr::Substring {
                 expr,
                 substring_for,
@@ -123,6 +144,28 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     from_expr,
                 })
             }
+            Expr::Extract { field, expr } => Ok(ScalarExpression::Extract {
+                field: DatePart::try_from(*field)?,
+                expr: Box::new(self.bind_expr(expr)?),
+            }),
+            // `CEIL(x)` and `FLOOR(x)` are parsed apart from the functions for `CEIL(x TO <field>)`
+            Expr::Ceil { expr: arg, field } | Expr::Floor { expr: arg, field }
+                if *field == DateTimeField::NoDateTime =>
+            {
+                let name = if matches!(expr, Expr::Ceil { .. }) {
+                    "ceil"
+                } else {
+                    "floor"
+                };
+                self.bind_function(&Function {
+                    name: ObjectName(vec![Ident::new(name)]),
+                    args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(*arg.clone()))],
+                    over: None,
+                    distinct: false,
+                    special: false,
+                    order_by: vec![],
+                })
+            }
             Expr::Position { expr, r#in } => Ok(ScalarExpression::Position {
                 expr: Box::new(self.bind_expr(expr)?),
                 in_expr: Box::new(self.bind_expr(r#in)?),
@@ -143,7 +186,17 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 })
             }
             Expr::Subquery(subquery) => {
-                let (sub_query, column) = self.bind_subquery(subquery)?;
+                let (sub_query, column, params) = self.bind_subquery(subquery)?;
+                if !params.is_empty() {
+                    if !self.context.is_step(&QueryBindStep::Where) {
+                        return Err(DatabaseError::UnsupportedStmt(
+                            "correlated subquery can only appear in `WHERE`".to_string(),
+                        ));
+                    }
+                    self.context
+                        .sub_query(SubQueryType::CorrelatedSubQuery(params, sub_query));
+                    return Ok(ScalarExpression::ColumnRef(column));
+                }
                 let (expr, sub_query) = if !self.context.is_step(&QueryBindStep::Where) {
                     self.bind_temp_table(column, sub_query)?
                 } else {
@@ -157,8 +210,11 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 subquery,
                 negated,
             } => {
+                if let Some(values) = Self::values_source(subquery) {
+                    return self.bind_in_values(expr, &values.rows, *negated);
+                }
                 let left_expr = Box::new(self.bind_expr(expr)?);
-                let (sub_query, column) = self.bind_subquery(subquery)?;
+                let (sub_query, column, params) = self.bind_subquery(subquery)?;
 
                 if !self.context.is_step(&QueryBindStep::Where) {
                     return Err(DatabaseError::UnsupportedStmt(
@@ -168,7 +224,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
 
                 let (alias_expr, sub_query) = self.bind_temp_table(column, sub_query)?;
                 self.context
-                    .sub_query(SubQueryType::InSubQuery(*negated, sub_query));
+                    .sub_query(SubQueryType::InSubQuery(*negated, params, sub_query));
 
                 Ok(ScalarExpression::Binary {
                     op: expression::BinaryOperator::Eq,
@@ -178,6 +234,9 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     ty: LogicalType::Boolean,
                 })
             }
+            Expr::Exists { .. } => Err(DatabaseError::UnsupportedStmt(
+                "'EXISTS (SUBQUERY)' can only be a conjunct of `WHERE`".to_string(),
+            )),
             Expr::Tuple(exprs) => {
                 let mut bond_exprs = Vec::with_capacity(exprs.len());
 
@@ -192,16 +251,11 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 results,
                 else_result,
             } => {
+                // the branches are unified to their common type, an untyped NULL fits any of them
                 let fn_check_ty = |ty: &mut LogicalType, result_ty| {
-                    if result_ty != LogicalType::SqlNull {
-                        if ty == &LogicalType::SqlNull {
-                            *ty = result_ty;
-                        } else if ty != &result_ty {
-                            return Err(DatabaseError::Incomparable(ty.clone(), result_ty));
-                        }
-                    }
+                    *ty = LogicalType::max_logical_type(ty, &result_ty)?;
 
-                    Ok(())
+                    Ok::<_, DatabaseError>(())
                 };
                 let mut operand_expr = None;
                 let mut ty = LogicalType::SqlNull;
@@ -214,7 +268,16 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     let result_ty = result.return_type();
 
                     fn_check_ty(&mut ty, result_ty)?;
-                    expr_pairs.push((self.bind_expr(&conditions[i])?, result))
+                    let condition = self.bind_expr(&conditions[i])?;
+                    // without an operand each condition is a predicate on its own
+                    if operand_expr.is_none() {
+                        let condition_ty = condition.return_type();
+
+                        if !LogicalType::can_implicit_cast(&condition_ty, &LogicalType::Boolean) {
+                            return Err(DatabaseError::CaseConditionNotBoolean(condition_ty));
+                        }
+                    }
+                    expr_pairs.push((condition, result))
                 }
 
                 let mut else_expr = None;
@@ -233,6 +296,93 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     ty,
                 })
             }
+            Expr::Array(Array { elem, .. }) => {
+                let mut exprs = Vec::with_capacity(elem.len());
+                let mut ty = LogicalType::SqlNull;
+
+                for expr in elem {
+                    let mut expr = self.bind_expr(expr)?;
+                    expr.constant_calculation()?;
+
+                    ty = LogicalType::max_logical_type(&ty, &expr.return_type())?;
+                    exprs.push(expr);
+                }
+                // a literal list is folded here so that it can be inserted by `VALUES`
+                if exprs
+                    .iter()
+                    .all(|expr| matches!(expr, ScalarExpression::Constant(_)))
+                {
+                    let values = exprs
+                        .into_iter()
+                        .map(|expr| match expr {
+                            ScalarExpression::Constant(value) => value.cast(&ty),
+                            _ => unreachable!(),
+                        })
+                        .try_collect()?;
+                    return Ok(ScalarExpression::Constant(DataValue::List(values)));
+                }
+                Ok(ScalarExpression::TypeCast {
+                    expr: Box::new(ScalarExpression::Tuple(exprs)),
+                    ty: LogicalType::List(Box::new(ty)),
+                    is_try: false,
+                })
+            }
+            Expr::JsonAccess {
+                left,
+                operator,
+                right,
+            } => {
+                if let Some(expr) = Self::reassociate_json_access(left, operator, right) {
+                    return self.bind_expr(&expr);
+                }
+                let is_text = match operator {
+                    JsonOperator::Arrow => false,
+                    JsonOperator::LongArrow => true,
+                    operator => {
+                        return Err(DatabaseError::UnsupportedStmt(format!(
+                            "json operator: {}",
+                            operator
+                        )))
+                    }
+                };
+                let left = self.bind_expr(left)?;
+                // a string is read as a JSON document
+                let left = match left.return_type() {
+                    LogicalType::Json | LogicalType::SqlNull => left,
+                    LogicalType::Varchar(..) | LogicalType::Char(..) => {
+                        ScalarExpression::TypeCast {
+                            expr: Box::new(left),
+                            ty: LogicalType::Json,
+                            is_try: false,
+                        }
+                    }
+                    _ => return Err(DatabaseError::InvalidType),
+                };
+                let args = vec![left, self.bind_expr(right)?];
+                let arg_types = args.iter().map(ScalarExpression::return_type).collect_vec();
+
+                Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                    args,
+                    inner: ArcScalarFunctionImpl(JsonAccess::new(arg_types, is_text)),
+                }))
+            }
+            Expr::ArrayIndex { obj, indexes } => {
+                let mut expr = self.bind_expr(obj)?;
+
+                for index in indexes {
+                    let LogicalType::List(element_ty) = expr.return_type() else {
+                        return Err(DatabaseError::InvalidType);
+                    };
+                    let args = vec![expr, self.bind_expr(index)?];
+                    let arg_types = args.iter().map(ScalarExpression::return_type).collect_vec();
+
+                    expr = ScalarExpression::ScalaFunction(ScalarFunction {
+                        args,
+                        inner: ArcScalarFunctionImpl(ArrayElement::new(arg_types, *element_ty)),
+                    });
+                }
+                Ok(expr)
+            }
             expr => Err(DatabaseError::UnsupportedStmt(expr.to_string())),
         }
     }
@@ -251,36 +401,24 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 alias_column,
             )))),
         };
-        let alias_plan = self.bind_project(sub_query, vec![alias_expr.clone()])?;
+        // not `bind_project`, which would move the bind step of the outer query to `Project`
+        let alias_plan = LogicalPlan::new(
+            Operator::Project(ProjectOperator {
+                exprs: vec![alias_expr.clone()],
+            }),
+            Childrens::Only(sub_query),
+        );
         Ok((alias_expr, alias_plan))
     }
 
+    /// Returns the plan of the subquery, its output column and the columns of this query it
+    /// references, see [`Binder::bind_correlated_column`].
+    #[allow(clippy::type_complexity)]
     fn bind_subquery(
         &mut self,
         subquery: &Query,
-    ) -> Result<(LogicalPlan, ColumnRef), DatabaseError> {
-        let BinderContext {
-            table_cache,
-            view_cache,
-            transaction,
-            scala_functions,
-            table_functions,
-            temp_table_id,
-            ..
-        } = &self.context;
-        let mut binder = Binder::new(
-            BinderContext::new(
-                table_cache,
-                view_cache,
-                *transaction,
-                scala_functions,
-                table_functions,
-                temp_table_id.clone(),
-            ),
-            self.args,
-            Some(self),
-        );
-        let mut sub_query = binder.bind_query(subquery)?;
+    ) -> Result<(LogicalPlan, ColumnRef, Vec<(ScalarExpression, ColumnRef)>), DatabaseError> {
+        let (mut sub_query, params) = self.bind_subquery_plan(subquery)?;
         let sub_query_schema = sub_query.output_schema();
 
         if sub_query_schema.len() != 1 {
@@ -290,9 +428,59 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             ));
         }
         let column = sub_query_schema[0].clone();
-        Ok((sub_query, column))
+        Ok((sub_query, column, params))
+    }
+
+    /// Same as [`Binder::bind_subquery`] but the subquery may return any number of columns.
+    #[allow(clippy::type_complexity)]
+    fn bind_subquery_plan(
+        &mut self,
+        subquery: &Query,
+    ) -> Result<(LogicalPlan, Vec<(ScalarExpression, ColumnRef)>), DatabaseError> {
+        let mut binder = Binder::new(self.context.child(), self.args, Some(self));
+        binder.context.correlated_params = Some(Vec::new());
+        let sub_query = binder.bind_query(subquery)?;
+        let params = binder.context.correlated_params.take().unwrap_or_default();
+
+        Ok((sub_query, params))
+    }
+
+    /// A column of the outer query referenced by a subquery is bound as a parameter column, which
+    /// the outer query fills with the value of `outer_expr` for each of its rows.
+    fn bind_correlated_column(
+        &mut self,
+        outer_expr: ScalarExpression,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        if self.context.correlated_params.is_none() {
+            return Ok(outer_expr);
+        }
+        if !self.context.is_step(&QueryBindStep::Where) {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "the outer column: {} can only be referenced in `WHERE` of the subquery",
+                outer_expr
+            )));
+        }
+        if let Some((_, param)) = self
+            .context
+            .correlated_params
+            .iter()
+            .flatten()
+            .find(|(expr, _)| expr == &outer_expr)
+        {
+            return Ok(ScalarExpression::ColumnRef(param.clone()));
+        }
+        let mut param = ColumnCatalog::clone(&outer_expr.output_column());
+        param.set_ref_table(self.context.temp_table(), ColumnId::new(), true);
+        let param = ColumnRef::from(param);
+        self.context
+            .correlated_params
+            .get_or_insert_with(Vec::new)
+            .push((outer_expr, param.clone()));
+
+        Ok(ScalarExpression::ColumnRef(param))
     }
 
+    /// `a LIKE ANY (ARRAY[p1, p2])` is bound as `a LIKE p1 OR a LIKE p2` like `a = ANY (ARRAY[..])`
     pub fn bind_like(
         &mut self,
         negated: bool,
@@ -301,12 +489,27 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         escape_char: &Option<char>,
     ) -> Result<ScalarExpression, DatabaseError> {
         let left_expr = Box::new(self.bind_expr(expr)?);
-        let right_expr = Box::new(self.bind_expr(pattern)?);
         let op = if negated {
             expression::BinaryOperator::NotLike(*escape_char)
         } else {
             expression::BinaryOperator::Like(*escape_char)
         };
+        // the parser reads `ANY (..)` after `LIKE` as a function call
+        if let Expr::Function(Function { name, args, .. }) = pattern {
+            if name.to_string().eq_ignore_ascii_case("any") {
+                let [FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Array(Array {
+                    elem, ..
+                })))] = args.as_slice()
+                else {
+                    return Err(DatabaseError::UnsupportedStmt(format!(
+                        "'LIKE ANY' only supports array: {}",
+                        pattern
+                    )));
+                };
+                return self.bind_quantified_array(op, left_expr, elem, false);
+            }
+        }
+        let right_expr = Box::new(self.bind_expr(pattern)?);
         Ok(ScalarExpression::Binary {
             op,
             left_expr,
@@ -338,7 +541,25 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         if self.context.allow_default {
             try_default!(&full_name.0, full_name.1);
         }
-        if let Some(table) = full_name.0.or(bind_table_name) {
+        if let Some(table) = full_name.0.clone().or(bind_table_name) {
+            if let Some(parent) = self.parent.filter(|parent| {
+                !self.context.has_source(&table) && parent.context.has_source(&table)
+            }) {
+                if let Some(expr) = parent.context.expr_aliases.get(&full_name) {
+                    let expr = ScalarExpression::Alias {
+                        expr: Box::new(expr.clone()),
+                        alias: AliasType::Name(full_name.1),
+                    };
+                    return self.bind_correlated_column(expr);
+                }
+                let source = parent.context.bind_source(&table)?;
+                let schema_buf = self.table_schema_buf.entry(Arc::new(table)).or_default();
+                let column = source
+                    .column(&full_name.1, schema_buf)
+                    .ok_or_else(|| DatabaseError::ColumnNotFound(full_name.1.to_string()))?;
+
+                return self.bind_correlated_column(ScalarExpression::ColumnRef(column));
+            }
             let source = self.context.bind_source(&table)?;
             let schema_buf = self.table_schema_buf.entry(Arc::new(table)).or_default();
 
@@ -357,7 +578,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                             break;
                         }
                         if let Some(alias) = alias {
-                            *got_column = self.context.expr_aliases.iter().find_map(
+                            *got_column = context.expr_aliases.iter().find_map(
                                 |((alias_table, alias_column), expr)| {
                                     matches!(
                                         alias_table
@@ -382,8 +603,12 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             let mut got_column = None;
 
             op(&mut got_column, &self.context, &mut self.table_schema_buf);
-            if let Some(parent) = self.parent {
+            if let (None, Some(parent)) = (&got_column, self.parent) {
                 op(&mut got_column, &parent.context, &mut self.table_schema_buf);
+
+                if let Some(expr) = got_column {
+                    return self.bind_correlated_column(expr);
+                }
             }
             Ok(got_column.ok_or(DatabaseError::ColumnNotFound(full_name.1))?)
         }
@@ -395,24 +620,63 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         right: &Expr,
         op: &BinaryOperator,
     ) -> Result<ScalarExpression, DatabaseError> {
+        match right {
+            Expr::AnyOp(expr) | Expr::AllOp(expr) if matches!(expr.as_ref(), Expr::Subquery(_)) => {
+                return Err(DatabaseError::UnsupportedStmt(
+                    "'ANY'/'ALL (SUBQUERY)' can only be a conjunct of `WHERE`".to_string(),
+                ))
+            }
+            Expr::AnyOp(expr) => return self.bind_quantified_subquery(left, op, expr, false),
+            Expr::AllOp(expr) => return self.bind_quantified_subquery(left, op, expr, true),
+            _ => (),
+        }
         let left_expr = Box::new(self.bind_expr(left)?);
         let right_expr = Box::new(self.bind_expr(right)?);
+        self.infer_parameter_type(left, &right_expr.return_type());
+        self.infer_parameter_type(right, &left_expr.return_type());
+
+        // `1 < x < 10` is parsed as `(1 < x) < 10`, which compares a boolean with `10`
+        if matches!(expression::BinaryOperator::try_from(op.clone()), Ok(op) if op.is_comparison())
+        {
+            for (expr, other) in [(&left_expr, &right_expr), (&right_expr, &left_expr)] {
+                if matches!(expr.as_ref(), ScalarExpression::Binary { op, .. } if op.is_comparison())
+                    && !matches!(
+                        other.return_type(),
+                        LogicalType::Boolean | LogicalType::SqlNull
+                    )
+                {
+                    return Err(DatabaseError::ChainedComparison(format!(
+                        "{} {} {}",
+                        left, op, right
+                    )));
+                }
+            }
+        }
 
         let ty = match op {
             BinaryOperator::Plus
             | BinaryOperator::Minus
             | BinaryOperator::Multiply
+            | BinaryOperator::Divide
             | BinaryOperator::Modulo => {
-                LogicalType::max_logical_type(&left_expr.return_type(), &right_expr.return_type())?
-            }
-            BinaryOperator::Divide => {
-                if let LogicalType::Decimal(precision, scale) = LogicalType::max_logical_type(
-                    &left_expr.return_type(),
-                    &right_expr.return_type(),
-                )? {
-                    LogicalType::Decimal(precision, scale)
+                let (left_ty, right_ty) = (left_expr.return_type(), right_expr.return_type());
+
+                if let Some(ty) = LogicalType::decimal_arithmetic_type(
+                    &(op.clone()).try_into()?,
+                    &left_ty,
+                    &right_ty,
+                ) {
+                    ty
                 } else {
-                    LogicalType::Double
+                    let ty = LogicalType::max_logical_type(&left_ty, &right_ty)?;
+
+                    if matches!(op, BinaryOperator::Divide)
+                        && !matches!(ty, LogicalType::Decimal(_, _))
+                    {
+                        LogicalType::Double
+                    } else {
+                        ty
+                    }
                 }
             }
             BinaryOperator::Gt
@@ -421,10 +685,21 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
             | BinaryOperator::LtEq
             | BinaryOperator::Eq
             | BinaryOperator::NotEq
+            | BinaryOperator::Spaceship
             | BinaryOperator::And
             | BinaryOperator::Or
             | BinaryOperator::Xor => LogicalType::Boolean,
             BinaryOperator::StringConcat => LogicalType::Varchar(None, CharLengthUnits::Characters),
+            BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOr
+            | BinaryOperator::BitwiseXor
+            | BinaryOperator::PGBitwiseXor
+            | BinaryOperator::PGExp => {
+                LogicalType::max_logical_type(&left_expr.return_type(), &right_expr.return_type())?
+            }
+            BinaryOperator::PGBitwiseShiftLeft | BinaryOperator::PGBitwiseShiftRight => {
+                left_expr.return_type()
+            }
             op => return Err(DatabaseError::UnsupportedStmt(format!("{}", op))),
         };
 
@@ -437,6 +712,268 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         })
     }
 
+    fn bind_between(
+        &mut self,
+        expr: &Expr,
+        negated: bool,
+        low: &Expr,
+        high: &Expr,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let expr = Box::new(self.bind_expr(expr)?);
+        let expr_ty = expr.return_type();
+        // the lower bound of `BETWEEN SYMMETRIC a AND b` is wrapped into a call by the parser
+        let symmetric_low = match low {
+            Expr::Function(Function {
+                name: ObjectName(idents),
+                args,
+                ..
+            }) if matches!(idents.as_slice(), [ident] if parser::is_symmetric_bound(ident)) => {
+                match args.as_slice() {
+                    [FunctionArg::Unnamed(FunctionArgExpr::Expr(low))] => Some(low),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        let low = symmetric_low.unwrap_or(low);
+
+        self.infer_parameter_type(low, &expr_ty);
+        self.infer_parameter_type(high, &expr_ty);
+        let mut left_expr = Box::new(self.bind_expr(low)?);
+        let mut right_expr = Box::new(self.bind_expr(high)?);
+
+        if symmetric_low.is_none() {
+            return Ok(ScalarExpression::Between {
+                negated,
+                expr,
+                left_expr,
+                right_expr,
+            });
+        }
+        left_expr.constant_calculation()?;
+        right_expr.constant_calculation()?;
+
+        if let (ScalarExpression::Constant(left), ScalarExpression::Constant(right)) =
+            (left_expr.as_ref(), right_expr.as_ref())
+        {
+            if !left.is_null() && !right.is_null() {
+                // the constant bounds are ordered as min/max, so that it detaches into a range
+                let ty =
+                    LogicalType::max_logical_type(&left.logical_type(), &right.logical_type())?;
+                if left.clone().cast(&ty)? > right.clone().cast(&ty)? {
+                    mem::swap(&mut left_expr, &mut right_expr);
+                }
+                return Ok(ScalarExpression::Between {
+                    negated,
+                    expr,
+                    left_expr,
+                    right_expr,
+                });
+            }
+        }
+        // `x BETWEEN SYMMETRIC a AND b` is `x BETWEEN a AND b OR x BETWEEN b AND a`,
+        // which also keeps the NULL of a bound the same as the standard
+        let connective = if negated {
+            expression::BinaryOperator::And
+        } else {
+            expression::BinaryOperator::Or
+        };
+        Ok(ScalarExpression::Binary {
+            op: connective,
+            left_expr: Box::new(ScalarExpression::Between {
+                negated,
+                expr: expr.clone(),
+                left_expr: left_expr.clone(),
+                right_expr: right_expr.clone(),
+            }),
+            right_expr: Box::new(ScalarExpression::Between {
+                negated,
+                expr,
+                left_expr: right_expr,
+                right_expr: left_expr,
+            }),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        })
+    }
+
+    /// Bind the predicate of `WHERE`, whose conjuncts may be `a op ANY/ALL (SUBQUERY)`
+    /// or `[NOT] EXISTS (SUBQUERY)`.
+    ///
+    /// Tips: these subqueries filter the whole row as a join, so under `OR`/`NOT`
+    /// or anywhere else they are rejected by [`Binder::bind_expr`]
+    pub(crate) fn bind_where_conjuncts(
+        &mut self,
+        expr: &Expr,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        match expr {
+            Expr::Nested(expr) => self.bind_where_conjuncts(expr),
+            Expr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => Ok(ScalarExpression::Binary {
+                op: expression::BinaryOperator::And,
+                left_expr: Box::new(self.bind_where_conjuncts(left)?),
+                right_expr: Box::new(self.bind_where_conjuncts(right)?),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            }),
+            Expr::BinaryOp { left, op, right } => match right.as_ref() {
+                Expr::AnyOp(right) => self.bind_quantified_subquery(left, op, right, false),
+                Expr::AllOp(right) => self.bind_quantified_subquery(left, op, right, true),
+                _ => self.bind_expr(expr),
+            },
+            Expr::Exists { subquery, negated } => self.bind_exists_subquery(subquery, *negated),
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: inner,
+            } => {
+                // `NOT (EXISTS ..)` is the same as `NOT EXISTS ..`
+                let mut inner = inner.as_ref();
+                while let Expr::Nested(expr) = inner {
+                    inner = expr;
+                }
+                match inner {
+                    Expr::Exists { subquery, negated } => {
+                        self.bind_exists_subquery(subquery, !*negated)
+                    }
+                    _ => self.bind_expr(expr),
+                }
+            }
+            expr => self.bind_expr(expr),
+        }
+    }
+
+    /// `EXISTS (SUBQUERY)` is bound as a semi join and `NOT EXISTS (SUBQUERY)` as an anti join,
+    /// which leaves `true` in place of the predicate.
+    fn bind_exists_subquery(
+        &mut self,
+        subquery: &Query,
+        negated: bool,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let (sub_query, params) = self.bind_subquery_plan(subquery)?;
+        // a single row of the subquery is enough to decide whether it exists
+        let sub_query = LimitOperator::build(None, Some(1), sub_query);
+        self.context
+            .sub_query(SubQueryType::ExistsSubQuery(negated, params, sub_query));
+
+        Ok(ScalarExpression::Constant(DataValue::Boolean(true)))
+    }
+
+    /// `a op ANY (SUBQUERY)` is bound as a semi join on `a op b`,
+    /// and `a op ALL (SUBQUERY)` as an anti join on the rows where `a op b` is false or unknown,
+    /// so `ALL` over an empty subquery is true while `ANY` over it is false.
+    fn bind_quantified_subquery(
+        &mut self,
+        left: &Expr,
+        op: &BinaryOperator,
+        right: &Expr,
+        is_all: bool,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let op: expression::BinaryOperator = op.clone().try_into()?;
+        if !matches!(
+            op,
+            expression::BinaryOperator::Gt
+                | expression::BinaryOperator::GtEq
+                | expression::BinaryOperator::Lt
+                | expression::BinaryOperator::LtEq
+                | expression::BinaryOperator::Eq
+                | expression::BinaryOperator::NotEq
+        ) {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "'ANY'/'ALL' only supports comparison: {}",
+                op
+            )));
+        }
+        let left_expr = Box::new(self.bind_expr(left)?);
+        let subquery = match right {
+            Expr::Subquery(subquery) => subquery,
+            Expr::Array(Array { elem, .. }) => {
+                return self.bind_quantified_array(op, left_expr, elem, is_all)
+            }
+            _ => {
+                return Err(DatabaseError::UnsupportedStmt(format!(
+                    "'ANY'/'ALL' only supports subquery or array: {}",
+                    right
+                )))
+            }
+        };
+        let (sub_query, column, params) = self.bind_subquery(subquery)?;
+        let (alias_expr, sub_query) = self.bind_temp_table(column, sub_query)?;
+        let comparison = ScalarExpression::Binary {
+            op,
+            left_expr,
+            right_expr: Box::new(alias_expr.unpack_alias()),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        if !is_all {
+            self.context
+                .sub_query(SubQueryType::InSubQuery(false, params, sub_query));
+            return Ok(comparison);
+        }
+        let violation = ScalarExpression::Binary {
+            op: expression::BinaryOperator::Or,
+            left_expr: Box::new(ScalarExpression::Unary {
+                op: expression::UnaryOperator::Not,
+                expr: Box::new(comparison.clone()),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            }),
+            right_expr: Box::new(ScalarExpression::IsNull {
+                negated: false,
+                expr: Box::new(comparison),
+            }),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        self.context
+            .sub_query(SubQueryType::AllSubQuery(violation, params, sub_query));
+
+        Ok(ScalarExpression::Constant(DataValue::Boolean(true)))
+    }
+
+    /// `x op ANY (ARRAY[a, b])` is `(x op a) OR (x op b)` and `ALL` joins them with `AND`,
+    /// so a NULL comparison decides the result only when no other element does,
+    /// an empty array is false for `ANY` and true for `ALL`
+    fn bind_quantified_array(
+        &mut self,
+        op: expression::BinaryOperator,
+        left_expr: Box<ScalarExpression>,
+        elements: &[Expr],
+        is_all: bool,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let connective = if is_all {
+            expression::BinaryOperator::And
+        } else {
+            expression::BinaryOperator::Or
+        };
+        let comparisons = elements
+            .iter()
+            .map(|element| {
+                Ok(ScalarExpression::Binary {
+                    op,
+                    left_expr: left_expr.clone(),
+                    right_expr: Box::new(self.bind_expr(element)?),
+                    evaluator: None,
+                    ty: LogicalType::Boolean,
+                })
+            })
+            .collect::<Result<Vec<_>, DatabaseError>>()?;
+
+        Ok(comparisons
+            .into_iter()
+            .reduce(|expr, comparison| ScalarExpression::Binary {
+                op: connective,
+                left_expr: Box::new(expr),
+                right_expr: Box::new(comparison),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            })
+            .unwrap_or(ScalarExpression::Constant(DataValue::Boolean(is_all))))
+    }
+
     fn bind_unary_op_internal(
         &mut self,
         expr: &Expr,
@@ -458,10 +995,26 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
     }
 
     fn bind_function(&mut self, func: &Function) -> Result<ScalarExpression, DatabaseError> {
-        if !matches!(self.context.step_now(), QueryBindStep::From) {
-            return Err(DatabaseError::UnsupportedStmt(
-                "`TableFunction` cannot bind in non-From step".to_string(),
-            ));
+        let function_name = func.name.to_string().to_lowercase();
+        if let Some(over) = &func.over {
+            return self.bind_window_function(&function_name, func, over);
+        }
+        // `mod(a, b)` is `a % b`
+        if function_name == "mod" {
+            let [FunctionArg::Unnamed(FunctionArgExpr::Expr(left)), FunctionArg::Unnamed(FunctionArgExpr::Expr(right))] =
+                func.args.as_slice()
+            else {
+                return Err(DatabaseError::MisMatch("number of mod() parameters", "2"));
+            };
+            return self.bind_binary_op_internal(left, right, &BinaryOperator::Modulo);
+        }
+        // only the ordered-set aggregate `percentile_cont(fraction) WITHIN GROUP (ORDER BY value)`
+        // orders its input
+        if !func.order_by.is_empty() && function_name != "percentile_cont" {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "ORDER BY in the arguments of {}()",
+                function_name
+            )));
         }
         let mut args = Vec::with_capacity(func.args.len());
 
@@ -471,6 +1024,17 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 FunctionArg::Unnamed(arg) => arg,
             };
             match arg_expr {
+                // the lists of these functions can also be written as arrays
+                FunctionArgExpr::Expr(Expr::Array(Array { elem, .. }))
+                    if matches!(
+                        function_name.as_str(),
+                        "element_at" | "cosine_similarity" | "l2_distance"
+                    ) =>
+                {
+                    let exprs = elem.iter().map(|expr| self.bind_expr(expr)).try_collect()?;
+
+                    args.push(ScalarExpression::Tuple(exprs))
+                }
                 FunctionArgExpr::Expr(expr) => args.push(self.bind_expr(expr)?),
                 FunctionArgExpr::Wildcard => args.push(Self::wildcard_expr()),
                 expr => {
@@ -481,7 +1045,6 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 }
             }
         }
-        let function_name = func.name.to_string().to_lowercase();
 
         match function_name.as_str() {
             "count" => {
@@ -493,6 +1056,43 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     kind: AggKind::Count,
                     args,
                     ty: LogicalType::Integer,
+                    filter: None,
+                });
+            }
+            "count_if" => {
+                if args.len() != 1 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of count_if() parameters",
+                        "1",
+                    ));
+                }
+                if !matches!(
+                    args[0].return_type(),
+                    LogicalType::Boolean | LogicalType::SqlNull
+                ) {
+                    return Err(DatabaseError::InvalidType);
+                }
+                return Ok(ScalarExpression::AggCall {
+                    distinct: false,
+                    kind: AggKind::CountIf,
+                    args,
+                    ty: LogicalType::Integer,
+                    filter: None,
+                });
+            }
+            "grouping" => {
+                if args.len() != 1 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of grouping() parameters",
+                        "1",
+                    ));
+                }
+                return Ok(ScalarExpression::AggCall {
+                    distinct: false,
+                    kind: AggKind::Grouping,
+                    args,
+                    ty: LogicalType::Integer,
+                    filter: None,
                 });
             }
             "sum" => {
@@ -506,6 +1106,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     kind: AggKind::Sum,
                     args,
                     ty,
+                    filter: None,
                 });
             }
             "min" => {
@@ -519,6 +1120,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     kind: AggKind::Min,
                     args,
                     ty,
+                    filter: None,
                 });
             }
             "max" => {
@@ -532,6 +1134,29 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     kind: AggKind::Max,
                     args,
                     ty,
+                    filter: None,
+                });
+            }
+            "max_by" | "min_by" => {
+                if args.len() != 2 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of max_by() or min_by() parameters",
+                        "2",
+                    ));
+                }
+                let kind = if function_name == "max_by" {
+                    AggKind::MaxBy
+                } else {
+                    AggKind::MinBy
+                };
+                let ty = args[0].return_type();
+
+                return Ok(ScalarExpression::AggCall {
+                    distinct: false,
+                    kind,
+                    args: vec![ScalarExpression::Tuple(args)],
+                    ty,
+                    filter: None,
                 });
             }
             "avg" => {
@@ -545,6 +1170,102 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     kind: AggKind::Avg,
                     args,
                     ty,
+                    filter: None,
+                });
+            }
+            "var_pop" | "var_samp" | "variance" | "stddev_pop" | "stddev_samp" | "stddev" => {
+                if args.len() != 1 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of variance() or stddev() parameters",
+                        "1",
+                    ));
+                }
+                if !args[0].return_type().is_numeric() {
+                    return Err(DatabaseError::InvalidType);
+                }
+                let kind = match function_name.as_str() {
+                    "var_pop" => AggKind::VarPop,
+                    "var_samp" | "variance" => AggKind::VarSamp,
+                    "stddev_pop" => AggKind::StddevPop,
+                    _ => AggKind::StddevSamp,
+                };
+                let arg = args.pop().unwrap();
+                let arg = if arg.return_type() == LogicalType::Double {
+                    arg
+                } else {
+                    ScalarExpression::TypeCast {
+                        expr: Box::new(arg),
+                        ty: LogicalType::Double,
+                        is_try: false,
+                    }
+                };
+
+                return Ok(ScalarExpression::AggCall {
+                    distinct: func.distinct,
+                    kind,
+                    args: vec![arg],
+                    ty: LogicalType::Double,
+                    filter: None,
+                });
+            }
+            "percentile_cont" | "median" => {
+                let (value, fraction) = if function_name == "median" {
+                    if args.len() != 1 {
+                        return Err(DatabaseError::MisMatch(
+                            "number of median() parameters",
+                            "1",
+                        ));
+                    }
+                    (args.pop().unwrap(), 0.5)
+                } else {
+                    if args.len() != 1 || func.order_by.len() != 1 {
+                        return Err(DatabaseError::MisMatch(
+                            "percentile_cont() parameters",
+                            "a fraction and WITHIN GROUP (ORDER BY value)",
+                        ));
+                    }
+                    let fraction = match args.pop().unwrap() {
+                        ScalarExpression::Constant(value) if value.logical_type().is_numeric() => {
+                            value.cast(&LogicalType::Double)?.double()
+                        }
+                        _ => None,
+                    }
+                    .filter(|fraction| (0.0..=1.0).contains(fraction))
+                    .ok_or_else(|| {
+                        DatabaseError::InvalidValue(
+                            "percentile_cont fraction must be a constant between 0 and 1"
+                                .to_string(),
+                        )
+                    })?;
+                    let order_by = &func.order_by[0];
+                    // a descending order takes the percentile from the other end
+                    let fraction = if order_by.asc == Some(false) {
+                        1.0 - fraction
+                    } else {
+                        fraction
+                    };
+
+                    (self.bind_expr(&order_by.expr)?, fraction)
+                };
+                if !value.return_type().is_numeric() {
+                    return Err(DatabaseError::InvalidType);
+                }
+                let value = if value.return_type() == LogicalType::Double {
+                    value
+                } else {
+                    ScalarExpression::TypeCast {
+                        expr: Box::new(value),
+                        ty: LogicalType::Double,
+                        is_try: false,
+                    }
+                };
+
+                return Ok(ScalarExpression::AggCall {
+                    distinct: false,
+                    kind: AggKind::PercentileCont,
+                    args: vec![value, ScalarExpression::Constant(DataValue::from(fraction))],
+                    ty: LogicalType::Double,
+                    filter: None,
                 });
             }
             "if" => {
@@ -567,7 +1288,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 if args.len() != 2 {
                     return Err(DatabaseError::MisMatch(
                         "number of nullif() parameters",
-                        "3",
+                        "2",
                     ));
                 }
                 let ty = Self::return_type(&args[0], &args[1])?;
@@ -584,7 +1305,7 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                 if args.len() != 2 {
                     return Err(DatabaseError::MisMatch(
                         "number of ifnull() parameters",
-                        "3",
+                        "2",
                     ));
                 }
                 let ty = Self::return_type(&args[0], &args[1])?;
@@ -597,23 +1318,68 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
                     ty,
                 });
             }
+            "element_at" => {
+                if args.len() != 3 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of element_at() parameters",
+                        "3",
+                    ));
+                }
+                let (LogicalType::Tuple(key_types), LogicalType::Tuple(value_types)) =
+                    (args[0].return_type(), args[1].return_type())
+                else {
+                    return Err(DatabaseError::InvalidType);
+                };
+                if key_types.len() != value_types.len() {
+                    return Err(DatabaseError::ValuesLenMismatch(
+                        key_types.len(),
+                        value_types.len(),
+                    ));
+                }
+                let fn_max_ty = |types: &[LogicalType]| {
+                    types.iter().try_fold(LogicalType::SqlNull, |max_ty, ty| {
+                        LogicalType::max_logical_type(&max_ty, ty)
+                    })
+                };
+                let key_ty = fn_max_ty(&[key_types, vec![args[2].return_type()]].concat())?;
+                let return_ty = fn_max_ty(&value_types)?;
+                let arg_types = args.iter().map(ScalarExpression::return_type).collect_vec();
+
+                return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                    args,
+                    inner: ArcScalarFunctionImpl(ElementAt::new(arg_types, key_ty, return_ty)),
+                }));
+            }
+            "cardinality" => {
+                if args.len() != 1 {
+                    return Err(DatabaseError::MisMatch(
+                        "number of cardinality() parameters",
+                        "1",
+                    ));
+                }
+                let arg_types = vec![args[0].return_type()];
+                if !matches!(arg_types[0], LogicalType::List(_) | LogicalType::SqlNull) {
+                    return Err(DatabaseError::InvalidType);
+                }
+
+                return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                    args,
+                    inner: ArcScalarFunctionImpl(Cardinality::new(arg_types)),
+                }));
+            }
             "coalesce" => {
                 let mut ty = LogicalType::SqlNull;
 
-                if !args.is_empty() {
-                    ty = args[0].return_type();
-
-                    for arg in args.iter_mut() {
-                        let temp_ty = arg.return_type();
+                for arg in args.iter() {
+                    let temp_ty = arg.return_type();
 
-                        if temp_ty == LogicalType::SqlNull {
-                            continue;
-                        }
-                        if ty == LogicalType::SqlNull && temp_ty != LogicalType::SqlNull {
-                            ty = temp_ty;
-                        } else if ty != temp_ty {
-                            ty = LogicalType::max_logical_type(&ty, &temp_ty)?;
-                        }
+                    if temp_ty == LogicalType::SqlNull {
+                        continue;
+                    }
+                    if ty == LogicalType::SqlNull {
+                        ty = temp_ty;
+                    } else if ty != temp_ty {
+                        ty = LogicalType::max_logical_type(&ty, &temp_ty)?;
                     }
                 }
                 return Ok(ScalarExpression::Coalesce { exprs: args, ty });
@@ -628,19 +1394,131 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         if let Some(function) = self.context.scala_functions.get(&summary) {
             return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
                 args,
-                inner: ArcScalarFunctionImpl(function.clone()),
+                inner: self.bind_scala_function(function),
             }));
         }
         if let Some(function) = self.context.table_functions.get(&summary) {
+            if !matches!(self.context.step_now(), QueryBindStep::From) {
+                return Err(DatabaseError::UnsupportedStmt(
+                    "`TableFunction` cannot bind in non-From step".to_string(),
+                ));
+            }
             return Ok(ScalarExpression::TableFunction(TableFunction {
                 args,
                 inner: ArcTableFunctionImpl(function.clone()),
             }));
         }
+        // fall back to the only scala function whose arguments can be implicitly cast from these
+        if let Ok((candidate, function)) = self
+            .context
+            .scala_functions
+            .iter()
+            .filter(|(candidate, _)| {
+                candidate.name == summary.name
+                    && candidate.arg_types.len() == summary.arg_types.len()
+                    && summary
+                        .arg_types
+                        .iter()
+                        .zip(candidate.arg_types.iter())
+                        .all(|(from, to)| LogicalType::can_implicit_cast(from, to))
+            })
+            .exactly_one()
+        {
+            return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                args: Self::cast_args(args, &candidate.arg_types),
+                inner: self.bind_scala_function(function),
+            }));
+        }
+        // at last, the function that takes any number of arguments
+        if let Some(function) = self
+            .context
+            .scala_functions
+            .get(&FunctionSummary {
+                name: summary.name.clone(),
+                arg_types: vec![],
+            })
+            .map(|function| function.variadic(&summary.arg_types))
+            .transpose()?
+            .flatten()
+        {
+            return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                args: Self::cast_args(args, &function.summary().arg_types),
+                inner: self.bind_scala_function(&function),
+            }));
+        }
 
         Err(DatabaseError::FunctionNotFound(summary.name))
     }
 
+    fn cast_args(args: Vec<ScalarExpression>, arg_types: &[LogicalType]) -> Vec<ScalarExpression> {
+        args.into_iter()
+            .zip(arg_types.iter())
+            .map(|(arg, ty)| {
+                if &arg.return_type() == ty {
+                    arg
+                } else {
+                    ScalarExpression::TypeCast {
+                        expr: Box::new(arg),
+                        ty: ty.clone(),
+                        is_try: false,
+                    }
+                }
+            })
+            .collect_vec()
+    }
+
+    /// binds the FILTER predicate onto the aggregate call, e.g. `COUNT(*) FILTER (WHERE a > 1)`
+    fn bind_aggregate_filter(
+        &mut self,
+        expr: &Expr,
+        filter: &Expr,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let mut expr = self.bind_expr(expr)?;
+        let ScalarExpression::AggCall {
+            filter: agg_filter, ..
+        } = &mut expr
+        else {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "FILTER for the non-aggregate function: {}",
+                expr
+            )));
+        };
+        let filter = self.bind_expr(filter)?;
+
+        if filter.has_agg_call() {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "aggregate function in FILTER: {}",
+                filter
+            )));
+        }
+        if !matches!(
+            filter.return_type(),
+            LogicalType::Boolean | LogicalType::SqlNull
+        ) {
+            return Err(DatabaseError::InvalidType);
+        }
+        *agg_filter = Some(Box::new(filter));
+
+        Ok(expr)
+    }
+
+    /// fixes the functions that read the clock at the time of the statement
+    fn bind_scala_function(&self, function: &Arc<dyn ScalarFunctionImpl>) -> ArcScalarFunctionImpl {
+        let function = self
+            .context
+            .now
+            .and_then(|now| function.at_time(now))
+            .unwrap_or_else(|| function.clone());
+        let function = self
+            .context
+            .settings
+            .as_ref()
+            .and_then(|settings| function.with_settings(settings))
+            .unwrap_or(function);
+
+        ArcScalarFunctionImpl(function)
+    }
+
     fn return_type(
         expr_1: &ScalarExpression,
         expr_2: &ScalarExpression,
@@ -673,21 +1551,285 @@ impl<'a, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '_, T
         negated: bool,
     ) -> Result<ScalarExpression, DatabaseError> {
         let args = list.iter().map(|expr| self.bind_expr(expr)).try_collect()?;
+        let expr = self.bind_expr(expr)?;
+        let expr_ty = expr.return_type();
+        for arg in list {
+            self.infer_parameter_type(arg, &expr_ty);
+        }
 
         Ok(ScalarExpression::In {
             negated,
-            expr: Box::new(self.bind_expr(expr)?),
+            expr: Box::new(expr),
+            args,
+        })
+    }
+
+    /// the rows of `x IN (VALUES ..)` are used as a literal `IN` list, a row of several columns
+    /// as a tuple compared with the tuple `x`
+    fn bind_in_values(
+        &mut self,
+        expr: &Expr,
+        rows: &[Vec<Expr>],
+        negated: bool,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let expr = self.bind_expr(expr)?;
+        let expr_ty = expr.return_type();
+        let expr_tys = match &expr_ty {
+            LogicalType::Tuple(types) => types.clone(),
+            ty => vec![ty.clone()],
+        };
+        if let Some(row) = rows.iter().find(|row| row.len() != expr_tys.len()) {
+            return Err(DatabaseError::ValuesLenMismatch(expr_tys.len(), row.len()));
+        }
+        let mut columns = Vec::with_capacity(expr_tys.len());
+        for (i, ty) in expr_tys.iter().enumerate() {
+            let column = rows.iter().map(|row| &row[i]).collect_vec();
+            let values = self.bind_values_rows(&column)?;
+
+            for value in column {
+                self.infer_parameter_type(value, ty);
+            }
+            columns.push(values.into_iter());
+        }
+        let args = if let LogicalType::Tuple(_) = expr_ty {
+            (0..rows.len())
+                .map(|_| {
+                    ScalarExpression::Tuple(columns.iter_mut().filter_map(Iterator::next).collect())
+                })
+                .collect()
+        } else {
+            columns.remove(0).collect()
+        };
+
+        Ok(ScalarExpression::In {
+            negated,
+            expr: Box::new(expr),
             args,
         })
     }
 
-    fn bind_cast(&mut self, expr: &Expr, ty: &DataType) -> Result<ScalarExpression, DatabaseError> {
+    /// sqlparser parses all of the rest as the right side of `->`, e.g. `j -> 'a' = 'b'` as
+    /// `j -> ('a' = 'b')`, so the access is moved down to the leftmost operand of the right side
+    fn reassociate_json_access(left: &Expr, operator: &JsonOperator, right: &Expr) -> Option<Expr> {
+        let fn_access = |key: &Expr| {
+            Box::new(
+                Self::reassociate_json_access(left, operator, key).unwrap_or_else(|| {
+                    Expr::JsonAccess {
+                        left: Box::new(left.clone()),
+                        operator: *operator,
+                        right: Box::new(key.clone()),
+                    }
+                }),
+            )
+        };
+
+        Some(match right {
+            Expr::JsonAccess {
+                left: key,
+                operator,
+                right,
+            } => Expr::JsonAccess {
+                left: fn_access(key),
+                operator: *operator,
+                right: right.clone(),
+            },
+            // the arithmetic binds tighter than `->`
+            Expr::BinaryOp {
+                left: key,
+                op,
+                right,
+            } if !matches!(
+                op,
+                BinaryOperator::Plus
+                    | BinaryOperator::Minus
+                    | BinaryOperator::Multiply
+                    | BinaryOperator::Divide
+                    | BinaryOperator::Modulo
+            ) =>
+            {
+                Expr::BinaryOp {
+                    left: fn_access(key),
+                    op: op.clone(),
+                    right: right.clone(),
+                }
+            }
+            Expr::IsNull(key) => Expr::IsNull(fn_access(key)),
+            Expr::IsNotNull(key) => Expr::IsNotNull(fn_access(key)),
+            Expr::Like {
+                negated,
+                expr: key,
+                pattern,
+                escape_char,
+            } => Expr::Like {
+                negated: *negated,
+                expr: fn_access(key),
+                pattern: pattern.clone(),
+                escape_char: *escape_char,
+            },
+            Expr::InList {
+                expr: key,
+                list,
+                negated,
+            } => Expr::InList {
+                expr: fn_access(key),
+                list: list.clone(),
+                negated: *negated,
+            },
+            Expr::Between {
+                expr: key,
+                negated,
+                low,
+                high,
+            } => Expr::Between {
+                expr: fn_access(key),
+                negated: *negated,
+                low: low.clone(),
+                high: high.clone(),
+            },
+            _ => return None,
+        })
+    }
+
+    /// the `VALUES` of `x IN (SELECT * FROM (VALUES ..))`, which `x IN (VALUES ..)` is parsed as,
+    /// see `parser::wrap_in_values`
+    fn values_source(query: &Query) -> Option<&Values> {
+        let is_bare = |query: &Query| {
+            query.with.is_none()
+                && query.order_by.is_empty()
+                && query.limit.is_none()
+                && query.offset.is_none()
+                && query.fetch.is_none()
+                && query.locks.is_empty()
+        };
+        let SetExpr::Select(select) = query.body.as_ref() else {
+            return None;
+        };
+        let (
+            [SelectItem::Wildcard(_)],
+            [TableWithJoins {
+                relation:
+                    TableFactor::Derived {
+                        lateral: false,
+                        subquery,
+                        alias: None,
+                    },
+                joins,
+            }],
+        ) = (select.projection.as_slice(), select.from.as_slice())
+        else {
+            return None;
+        };
+        if !is_bare(query)
+            || !is_bare(subquery)
+            || !joins.is_empty()
+            || select.distinct.is_some()
+            || select.selection.is_some()
+            || !select.group_by.is_empty()
+            || select.having.is_some()
+        {
+            return None;
+        }
+        match subquery.body.as_ref() {
+            SetExpr::Values(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// the rows of `VALUES` are unified to their common type like the columns of a table
+    fn bind_values_rows(&mut self, rows: &[&Expr]) -> Result<Vec<ScalarExpression>, DatabaseError> {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut ty = LogicalType::SqlNull;
+
+        for row in rows {
+            let mut value = self.bind_expr(row)?;
+            value.constant_calculation()?;
+
+            ty = LogicalType::max_logical_type(&ty, &value.return_type())?;
+            values.push(value);
+        }
+        values
+            .into_iter()
+            .map(|value| {
+                if value.return_type() == ty {
+                    return Ok(value);
+                }
+                Ok(match value {
+                    ScalarExpression::Constant(value) => {
+                        ScalarExpression::Constant(value.cast(&ty)?)
+                    }
+                    value => ScalarExpression::TypeCast {
+                        expr: Box::new(value),
+                        ty: ty.clone(),
+                        is_try: false,
+                    },
+                })
+            })
+            .try_collect()
+    }
+
+    fn bind_cast(
+        &mut self,
+        expr: &Expr,
+        ty: &DataType,
+        is_try: bool,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        let expr = self.bind_expr(expr)?;
+        let ty = LogicalType::try_from(ty.clone())?;
+
+        if let Some(coerced_nulls) = self
+            .context
+            .coerced_nulls
+            .as_ref()
+            .filter(|_| !is_try && self.context.allow_lenient_cast)
+        {
+            return Ok(ScalarExpression::ScalaFunction(ScalarFunction {
+                inner: ArcScalarFunctionImpl(SafeCast::new(
+                    expr.return_type(),
+                    ty,
+                    coerced_nulls.clone(),
+                )),
+                args: vec![expr],
+            }));
+        }
         Ok(ScalarExpression::TypeCast {
-            expr: Box::new(self.bind_expr(expr)?),
-            ty: LogicalType::try_from(ty.clone())?,
+            expr: Box::new(expr),
+            ty,
+            is_try,
         })
     }
 
+    fn bind_interval(&mut self, interval: &Interval) -> Result<ScalarExpression, DatabaseError> {
+        if interval.last_field.is_some() || interval.fractional_seconds_precision.is_some() {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "interval: {}",
+                interval
+            )));
+        }
+        let mut value = match interval.value.as_ref() {
+            Expr::Value(Value::SingleQuotedString(value) | Value::Number(value, _)) => {
+                value.clone()
+            }
+            expr => {
+                return Err(DatabaseError::UnsupportedStmt(format!(
+                    "interval value: {}",
+                    expr
+                )))
+            }
+        };
+        // e.g. `INTERVAL '15' MINUTE`
+        if let Some(field) = interval.leading_field {
+            value = format!("{} {}", value, field);
+        }
+        let value = DataValue::Utf8 {
+            value,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        }
+        .cast(&LogicalType::Interval)?;
+
+        Ok(ScalarExpression::Constant(value))
+    }
+
     fn wildcard_expr() -> ScalarExpression {
         ScalarExpression::Constant(DataValue::Utf8 {
             value: "*".to_string(),
@@ -1,14 +1,22 @@
 use crate::binder::{lower_case_name, Binder};
+use crate::catalog::TableName;
 use crate::errors::DatabaseError;
 use crate::expression::ScalarExpression;
-use crate::planner::operator::insert::InsertOperator;
+use crate::planner::operator::insert::{
+    InsertOperator, OnConflict, OnConflictAction, EXCLUDED_TABLE,
+};
 use crate::planner::operator::values::ValuesOperator;
 use crate::planner::operator::Operator;
 use crate::planner::{Childrens, LogicalPlan};
 use crate::storage::Transaction;
+use crate::types::index::{IndexMetaRef, IndexType};
 use crate::types::tuple::SchemaRef;
 use crate::types::value::DataValue;
-use sqlparser::ast::{Expr, Ident, ObjectName};
+use itertools::Itertools;
+use sqlparser::ast::{
+    ConflictTarget, DoUpdate, Expr, Ident, ObjectName, OnConflict as SqlOnConflict,
+    OnConflictAction as SqlOnConflictAction, OnInsert,
+};
 use std::slice;
 use std::sync::Arc;
 
@@ -20,10 +28,12 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
         expr_rows: &Vec<Vec<Expr>>,
         is_overwrite: bool,
         is_mapping_by_name: bool,
+        on: &Option<OnInsert>,
     ) -> Result<LogicalPlan, DatabaseError> {
         // FIXME: Make it better to detect the current BindStep
         self.context.allow_default = true;
         let table_name = Arc::new(lower_case_name(name)?);
+        self.reference_table(&table_name);
 
         let source = self
             .context
@@ -68,6 +78,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             let mut row = Vec::with_capacity(expr_row.len());
 
             for (i, expr) in expr_row.iter().enumerate() {
+                self.infer_parameter_type(expr, schema_ref[i].datatype());
                 let mut expression = self.bind_expr(expr)?;
 
                 expression.constant_calculation()?;
@@ -81,7 +92,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
                     }
                     ScalarExpression::Empty => {
                         let default_value = schema_ref[i]
-                            .default_value()?
+                            .default_value(self.context.now, self.context.settings.as_ref())?
                             .ok_or(DatabaseError::DefaultNotExist)?;
                         row.push(default_value);
                     }
@@ -90,19 +101,147 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             }
             rows.push(row);
         }
+        let on_conflict = match on {
+            Some(OnInsert::OnConflict(on_conflict)) => {
+                Some(self.bind_on_conflict(&table_name, on_conflict)?)
+            }
+            Some(on) => return Err(DatabaseError::UnsupportedStmt(on.to_string())),
+            None => None,
+        };
         self.context.allow_default = false;
         let values_plan = self.bind_values(rows, schema_ref);
+        let defaults = self.bind_defaults(&table_name)?;
 
         Ok(LogicalPlan::new(
             Operator::Insert(InsertOperator {
                 table_name,
                 is_overwrite,
                 is_mapping_by_name,
+                defaults,
+                on_conflict,
             }),
             Childrens::Only(values_plan),
         ))
     }
 
+    /// Binds `ON CONFLICT`, whose target must be the primary key or a unique index of the
+    /// table, the primary key if omitted
+    fn bind_on_conflict(
+        &mut self,
+        table_name: &TableName,
+        SqlOnConflict {
+            conflict_target,
+            action,
+        }: &SqlOnConflict,
+    ) -> Result<OnConflict, DatabaseError> {
+        let table = self
+            .context
+            .table(table_name.clone())?
+            .ok_or(DatabaseError::TableNotFound)?
+            .clone();
+        let is_conflict_index = |index_meta: &&IndexMetaRef| {
+            matches!(
+                index_meta.ty,
+                IndexType::PrimaryKey { .. } | IndexType::Unique
+            ) && index_meta.expr.is_none()
+                && index_meta.predicate.is_none()
+        };
+        let index_meta = match conflict_target {
+            Some(ConflictTarget::Columns(idents)) => {
+                let mut column_ids = Vec::with_capacity(idents.len());
+                for ident in idents {
+                    match self.bind_column_ref_from_identifiers(
+                        slice::from_ref(ident),
+                        Some(table_name.to_string()),
+                    )? {
+                        ScalarExpression::ColumnRef(column) => column_ids.push(column.id()),
+                        _ => return Err(DatabaseError::InvalidColumn(ident.to_string())),
+                    }
+                }
+                column_ids.sort();
+                table
+                    .indexes()
+                    .filter(is_conflict_index)
+                    .find(|index_meta| {
+                        index_meta
+                            .column_ids
+                            .iter()
+                            .sorted()
+                            .copied()
+                            .map(Some)
+                            .eq(column_ids.iter().copied())
+                    })
+            }
+            Some(ConflictTarget::OnConstraint(name)) => {
+                let name = lower_case_name(name)?;
+                table
+                    .indexes()
+                    .filter(is_conflict_index)
+                    .find(|index_meta| index_meta.name == name)
+            }
+            None => table
+                .indexes()
+                .find(|index_meta| matches!(index_meta.ty, IndexType::PrimaryKey { .. })),
+        }
+        .ok_or_else(|| {
+            DatabaseError::ConflictTargetNotFound(
+                conflict_target
+                    .as_ref()
+                    .map(|target| target.to_string())
+                    .unwrap_or_default(),
+            )
+        })?;
+
+        let action = match action {
+            SqlOnConflictAction::DoNothing => OnConflictAction::DoNothing,
+            SqlOnConflictAction::DoUpdate(DoUpdate {
+                assignments,
+                selection,
+            }) => {
+                for column in OnConflict::excluded_schema(&table) {
+                    self.context.add_alias(
+                        Some(EXCLUDED_TABLE.to_string()),
+                        column.name().to_string(),
+                        ScalarExpression::ColumnRef(column),
+                    );
+                }
+                let value_exprs = self.bind_assignments(table_name, assignments)?;
+                let predicate = selection
+                    .as_ref()
+                    .map(|selection| self.bind_expr(selection))
+                    .transpose()?;
+
+                OnConflictAction::DoUpdate {
+                    value_exprs,
+                    predicate,
+                }
+            }
+        };
+
+        Ok(OnConflict {
+            index_id: index_meta.id,
+            action,
+        })
+    }
+
+    /// Binds the defaults of the columns of the table for all the rows of the statement.
+    pub(crate) fn bind_defaults(
+        &self,
+        table_name: &TableName,
+    ) -> Result<Vec<Option<ScalarExpression>>, DatabaseError> {
+        let table = self
+            .context
+            .table(table_name.clone())?
+            .ok_or(DatabaseError::TableNotFound)?;
+
+        table
+            .columns()
+            .map(|column| {
+                column.statement_default(self.context.now, self.context.settings.as_ref())
+            })
+            .collect()
+    }
+
     pub(crate) fn bind_values(
         &mut self,
         rows: Vec<Vec<DataValue>>,
@@ -17,9 +17,14 @@ mod select;
 mod show;
 mod truncate;
 mod update;
+mod window;
 
-use sqlparser::ast::{Ident, ObjectName, ObjectType, SetExpr, Statement};
+use chrono::{Local, NaiveDateTime};
+use sqlparser::ast::{Expr, Ident, ObjectName, ObjectType, SetExpr, Statement, Value};
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::mem;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
@@ -29,10 +34,13 @@ use crate::db::{ScalaFunctions, TableFunctions};
 use crate::errors::DatabaseError;
 use crate::expression::ScalarExpression;
 use crate::planner::operator::join::JoinType;
+use crate::planner::operator::window::WindowCall;
 use crate::planner::{LogicalPlan, SchemaOutput};
+use crate::setting::SettingsRef;
 use crate::storage::{TableCache, Transaction, ViewCache};
 use crate::types::tuple::SchemaRef;
 use crate::types::value::DataValue;
+use crate::types::LogicalType;
 
 pub enum InputRefType {
     AggCall,
@@ -61,7 +69,8 @@ pub fn command_type(stmt: &Statement) -> Result<CommandType, DatabaseError> {
         | Statement::Update { .. }
         | Statement::Delete { .. }
         | Statement::Insert { .. }
-        | Statement::Copy { .. } => Ok(CommandType::DML),
+        | Statement::Copy { .. }
+        | Statement::SetVariable { .. } => Ok(CommandType::DML),
         stmt => Err(DatabaseError::UnsupportedStmt(stmt.to_string())),
     }
 }
@@ -83,13 +92,25 @@ pub enum QueryBindStep {
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub enum SubQueryType {
     SubQuery(LogicalPlan),
-    InSubQuery(bool, LogicalPlan),
+    /// (is not, the parameters of the subquery if it is correlated, plan)
+    InSubQuery(bool, Vec<(ScalarExpression, ColumnRef)>, LogicalPlan),
+    /// (the condition that violates `ALL`, the parameters of the subquery if it is correlated, plan)
+    AllSubQuery(
+        ScalarExpression,
+        Vec<(ScalarExpression, ColumnRef)>,
+        LogicalPlan,
+    ),
+    /// (is not, the parameters of the subquery if it is correlated, plan)
+    ExistsSubQuery(bool, Vec<(ScalarExpression, ColumnRef)>, LogicalPlan),
+    /// (the outer expressions with the parameter columns bound to them, plan)
+    CorrelatedSubQuery(Vec<(ScalarExpression, ColumnRef)>, LogicalPlan),
 }
 
 #[derive(Debug, Clone)]
 pub enum Source<'a> {
     Table(&'a TableCatalog),
-    View(&'a View),
+    /// A view of the catalog, or a common table expression of the `WITH` clause owned by the query
+    View(Cow<'a, View>),
 }
 
 #[derive(Clone)]
@@ -106,7 +127,12 @@ pub struct BinderContext<'a, T: Transaction> {
     table_aliases: HashMap<TableName, TableName>,
     // agg
     group_by_exprs: Vec<ScalarExpression>,
+    // the positions of `group_by_exprs` in each grouping set of `ROLLUP`, `CUBE` or `GROUPING SETS`
+    grouping_sets: Vec<Vec<usize>>,
     pub(crate) agg_calls: Vec<ScalarExpression>,
+    // window
+    pub(crate) window_calls: Vec<WindowCall>,
+    allow_window: bool,
     // join
     using: HashSet<String>,
 
@@ -114,7 +140,26 @@ pub struct BinderContext<'a, T: Transaction> {
     sub_queries: HashMap<QueryBindStep, Vec<SubQueryType>>,
 
     temp_table_id: Arc<AtomicUsize>,
+    // the common table expressions of the `WITH` clause, see [`Binder::cte`]
+    ctes: HashMap<TableName, View>,
+    // the columns of the outer query referenced by a subquery, `None` when not binding a subquery
+    correlated_params: Option<Vec<(ScalarExpression, ColumnRef)>>,
     pub(crate) allow_default: bool,
+    /// the clock reading of the statement, shared by its functions that read the current time
+    pub(crate) now: Option<NaiveDateTime>,
+    /// the number of values coerced to NULL by the `CAST`s of the select list, `None` unless
+    /// the setting `lenient_cast` is true
+    pub(crate) coerced_nulls: Option<Arc<AtomicUsize>>,
+    allow_lenient_cast: bool,
+    /// the settings of the session the statement runs in, see
+    /// [`ScalarFunctionImpl::with_settings`](crate::expression::function::scala::ScalarFunctionImpl::with_settings)
+    pub(crate) settings: Option<SettingsRef>,
+    /// the last key of a keyset page, whose rows after it in the order of the `ORDER BY` of the
+    /// statement are kept, see [`Database::keyset_page`](crate::db::Database::keyset_page)
+    pub(crate) keyset: Option<Vec<DataValue>>,
+    /// the predicate of the partial index of `CREATE INDEX`, which `sqlparser` can not parse into
+    /// the statement, see [`parse_sql_with_index_predicates`](crate::parser::parse_sql_with_index_predicates)
+    pub(crate) index_predicate: Option<Expr>,
 }
 
 impl Source<'_> {
@@ -179,15 +224,45 @@ impl<'a, T: Transaction> BinderContext<'a, T> {
             expr_aliases: Default::default(),
             table_aliases: Default::default(),
             group_by_exprs: vec![],
+            grouping_sets: vec![],
             agg_calls: Default::default(),
+            window_calls: Default::default(),
+            allow_window: false,
             using: Default::default(),
             bind_step: QueryBindStep::From,
             sub_queries: Default::default(),
             temp_table_id,
+            ctes: Default::default(),
+            correlated_params: None,
             allow_default: false,
+            now: Some(Local::now().naive_local()),
+            coerced_nulls: None,
+            allow_lenient_cast: false,
+            settings: None,
+            keyset: None,
+            index_predicate: None,
         }
     }
 
+    /// a fresh context for binding a part of the statement on its own, such as a subquery, which
+    /// shares the clock, the settings and the lenient cast counter of the statement but not its
+    /// keyset page key, which belongs to the query of the statement only
+    pub(crate) fn child(&self) -> Self {
+        let mut context = BinderContext::new(
+            self.table_cache,
+            self.view_cache,
+            self.transaction,
+            self.scala_functions,
+            self.table_functions,
+            self.temp_table_id.clone(),
+        );
+        context.now = self.now;
+        context.coerced_nulls = self.coerced_nulls.clone();
+        context.settings = self.settings.clone();
+
+        context
+    }
+
     pub fn temp_table(&mut self) -> TableName {
         Arc::new(format!(
             "_temp_table_{}_",
@@ -261,7 +336,7 @@ impl<'a, T: Transaction> BinderContext<'a, T> {
                 self.transaction
                     .view(self.table_cache, self.view_cache, table_name.clone())
             }?
-            .map(Source::View);
+            .map(|view| Source::View(Cow::Borrowed(view)));
         }
         if let Some(source) = &source {
             self.bind_table.insert(
@@ -283,6 +358,14 @@ impl<'a, T: Transaction> BinderContext<'a, T> {
         }
     }
 
+    /// Whether `table_name` is a table of this query, an aliased table is only named by its alias.
+    pub fn has_source(&self, table_name: &str) -> bool {
+        self.bind_table.keys().any(|(table, alias, _)| match alias {
+            Some(alias) => alias.as_str() == table_name,
+            None => table.as_str() == table_name,
+        })
+    }
+
     // Tips: The order of this index is based on Aggregate being bound first.
     pub fn input_ref_index(&self, ty: InputRefType) -> usize {
         match ty {
@@ -317,6 +400,10 @@ pub struct Binder<'a, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>>
     context: BinderContext<'a, T>,
     table_schema_buf: HashMap<TableName, Option<SchemaOutput>>,
     args: &'a A,
+    // the expected types of the placeholders missing from `args`, only collected when preparing
+    parameter_types: Option<RefCell<Vec<(String, LogicalType)>>>,
+    // the names of the tables and views the statement is bound to, only collected when preparing
+    referenced_tables: Option<RefCell<Vec<TableName>>>,
     pub(crate) parent: Option<&'b Binder<'a, 'b, T, A>>,
 }
 
@@ -330,13 +417,108 @@ impl<'a, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '
             context,
             table_schema_buf: Default::default(),
             args,
+            parameter_types: None,
+            referenced_tables: None,
             parent,
         }
     }
 
+    /// Bind the placeholders missing from `args` as `NULL` and collect the types they are
+    /// expected to have, see [`Binder::take_parameter_types`].
+    pub(crate) fn with_parameter_types(mut self) -> Self {
+        self.parameter_types = Some(RefCell::new(Vec::new()));
+        self
+    }
+
+    /// Ordered by the names of the placeholders, with `?2` before `?10`.
+    pub(crate) fn take_parameter_types(&mut self) -> Vec<(String, LogicalType)> {
+        let mut types = self
+            .parameter_types
+            .as_mut()
+            .map(|types| mem::take(types.get_mut()))
+            .unwrap_or_default();
+        types.sort_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        types
+    }
+
+    fn root_parameter_types(&self) -> Option<&RefCell<Vec<(String, LogicalType)>>> {
+        match self.parent {
+            Some(parent) => parent.root_parameter_types(),
+            None => self.parameter_types.as_ref(),
+        }
+    }
+
+    /// Collect the names of the tables and views the statement is bound to, see
+    /// [`Binder::take_referenced_tables`].
+    pub(crate) fn with_referenced_tables(mut self) -> Self {
+        self.referenced_tables = Some(RefCell::new(Vec::new()));
+        self
+    }
+
+    pub(crate) fn take_referenced_tables(&mut self) -> Vec<TableName> {
+        let mut tables = self
+            .referenced_tables
+            .as_mut()
+            .map(|tables| mem::take(tables.get_mut()))
+            .unwrap_or_default();
+        tables.sort();
+        tables.dedup();
+        tables
+    }
+
+    fn root_referenced_tables(&self) -> Option<&RefCell<Vec<TableName>>> {
+        match self.parent {
+            Some(parent) => parent.root_referenced_tables(),
+            None => self.referenced_tables.as_ref(),
+        }
+    }
+
+    /// Record `table_name` as a table or view the statement is bound to, whether it exists or not.
+    pub(crate) fn reference_table(&self, table_name: &TableName) {
+        if let Some(tables) = self.root_referenced_tables() {
+            tables.borrow_mut().push(table_name.clone());
+        }
+    }
+
+    pub(crate) fn bind_placeholder(&self, name: &str) -> Result<DataValue, DatabaseError> {
+        if let Some(value) = self
+            .args
+            .as_ref()
+            .iter()
+            .find_map(|(key, value)| (*key == name).then(|| value.clone()))
+        {
+            return Ok(value);
+        }
+        if self.root_parameter_types().is_some() {
+            return Ok(DataValue::Null);
+        }
+        Err(DatabaseError::ParametersNotFound(name.to_string()))
+    }
+
+    /// Record `ty` as the type of `expr` if it is a placeholder whose type is not known yet.
+    pub(crate) fn infer_parameter_type(&self, expr: &Expr, ty: &LogicalType) {
+        let mut expr = expr;
+        while let Expr::Nested(inner) = expr {
+            expr = inner;
+        }
+        let (Expr::Value(Value::Placeholder(name)), Some(types)) =
+            (expr, self.root_parameter_types())
+        else {
+            return;
+        };
+        let mut types = types.borrow_mut();
+
+        if ty != &LogicalType::SqlNull && types.iter().all(|(key, _)| key != name) {
+            types.push((name.clone(), ty.clone()));
+        }
+    }
+
     pub fn bind(&mut self, stmt: &Statement) -> Result<LogicalPlan, DatabaseError> {
         let plan = match stmt {
-            Statement::Query(query) => self.bind_query(query)?,
+            Statement::Query(query) => {
+                let keyset = self.context.keyset.take();
+                self.bind_query_with_keyset(query, keyset)?
+            }
             Statement::AlterTable { name, operation } => self.bind_alter_table(name, operation)?,
             Statement::CreateTable {
                 name,
@@ -371,11 +553,12 @@ impl<'a, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '
                 columns,
                 source,
                 overwrite,
+                on,
                 ..
             } => {
                 // TODO: support body on Insert
                 if let SetExpr::Values(values) = source.body.as_ref() {
-                    self.bind_insert(table_name, columns, &values.rows, *overwrite, false)?
+                    self.bind_insert(table_name, columns, &values.rows, *overwrite, false, on)?
                 } else {
                     return Err(DatabaseError::UnsupportedStmt(format!(
                         "insert body: {:#?}",
@@ -447,7 +630,7 @@ impl<'a, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, '
 
     pub fn bind_set_expr(&mut self, set_expr: &SetExpr) -> Result<LogicalPlan, DatabaseError> {
         match set_expr {
-            SetExpr::Select(select) => self.bind_select(select, &[]),
+            SetExpr::Select(select) => self.bind_select(select, &[], None),
             SetExpr::Query(query) => self.bind_query(query),
             SetExpr::SetOperation {
                 op,
@@ -480,7 +663,7 @@ fn lower_ident(ident: &Ident) -> String {
 }
 
 /// Convert an object name into lower case
-fn lower_case_name(name: &ObjectName) -> Result<String, DatabaseError> {
+pub(crate) fn lower_case_name(name: &ObjectName) -> Result<String, DatabaseError> {
     if name.0.len() == 1 {
         return Ok(lower_ident(&name.0[0]));
     }
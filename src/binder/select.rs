@@ -1,5 +1,6 @@
-use std::borrow::Borrow;
-use std::collections::HashSet;
+use std::borrow::{Borrow, Cow};
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::sync::Arc;
 
 use crate::{
@@ -18,37 +19,53 @@ use super::{
     lower_case_name, lower_ident, Binder, BinderContext, QueryBindStep, Source, SubQueryType,
 };
 
+use crate::catalog::view::View;
 use crate::catalog::{ColumnCatalog, ColumnRef, ColumnSummary, TableName};
 use crate::errors::DatabaseError;
 use crate::execution::dql::join::joins_nullable;
 use crate::expression::{AliasType, BinaryOperator};
+use crate::planner::operator::except::ExceptOperator;
 use crate::planner::operator::function_scan::FunctionScanOperator;
 use crate::planner::operator::insert::InsertOperator;
+use crate::planner::operator::intersect::IntersectOperator;
 use crate::planner::operator::join::JoinCondition;
 use crate::planner::operator::sort::{SortField, SortOperator};
 use crate::planner::operator::union::UnionOperator;
+use crate::planner::operator::values::ValuesOperator;
 use crate::planner::{Childrens, LogicalPlan, SchemaOutput};
 use crate::storage::Transaction;
 use crate::types::tuple::{Schema, SchemaRef};
 use crate::types::{ColumnId, LogicalType};
 use itertools::Itertools;
 use sqlparser::ast::{
-    Distinct, Expr, Ident, Join, JoinConstraint, JoinOperator, Offset, OrderByExpr, Query, Select,
-    SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, TableAlias, TableFactor,
-    TableWithJoins,
+    Cte, Distinct, Expr, Ident, Join, JoinConstraint, JoinOperator, Offset, OrderByExpr, Query,
+    Select, SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, TableAlias, TableFactor,
+    TableWithJoins, With,
 };
 
 impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'a, 'b, T, A> {
     pub(crate) fn bind_query(&mut self, query: &Query) -> Result<LogicalPlan, DatabaseError> {
+        self.bind_query_with_keyset(query, None)
+    }
+
+    /// Binds a query whose rows start after `keyset` in the order of its `ORDER BY`,
+    /// which only the query of the statement has, see [`BinderContext::keyset`]
+    pub(crate) fn bind_query_with_keyset(
+        &mut self,
+        query: &Query,
+        keyset: Option<Vec<DataValue>>,
+    ) -> Result<LogicalPlan, DatabaseError> {
         let origin_step = self.context.step_now();
 
-        if let Some(_with) = &query.with {
-            // TODO support with clause.
-        }
+        let origin_ctes = query
+            .with
+            .as_ref()
+            .map(|with| self.bind_with(with))
+            .transpose()?;
 
         let mut plan = match query.body.borrow() {
-            SetExpr::Select(select) => self.bind_select(select, &query.order_by),
-            SetExpr::Query(query) => self.bind_query(query),
+            SetExpr::Select(select) => self.bind_select(select, &query.order_by, keyset),
+            SetExpr::Query(query) => self.bind_query_with_keyset(query, keyset),
             SetExpr::SetOperation {
                 op,
                 set_quantifier,
@@ -69,15 +86,99 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
         if limit.is_some() || offset.is_some() {
             plan = self.bind_limit(plan, limit, offset)?;
         }
+        // the common table expressions are only in the scope of this query
+        if let Some(ctes) = origin_ctes {
+            self.context.ctes = ctes;
+        }
 
         self.context.step(origin_step);
         Ok(plan)
     }
 
+    /// Binds each common table expression as a view only known by this query, which later ones
+    /// and the body of the query can reference, and returns the ones in scope before.
+    fn bind_with(&mut self, with: &With) -> Result<HashMap<TableName, View>, DatabaseError> {
+        if with.recursive {
+            return Err(DatabaseError::UnsupportedStmt(
+                "recursive `WITH` is not supported".to_string(),
+            ));
+        }
+        let origin_ctes = self.context.ctes.clone();
+        let mut cte_names = HashSet::with_capacity(with.cte_tables.len());
+
+        for Cte {
+            alias: TableAlias { name, columns },
+            query,
+            ..
+        } in with.cte_tables.iter()
+        {
+            let cte_name = Arc::new(lower_ident(name));
+
+            if !cte_names.insert(cte_name.clone())
+                || self.context.table(cte_name.clone())?.is_some()
+                || self.context.view(cte_name.clone())?.is_some()
+            {
+                return Err(DatabaseError::DuplicateCte(cte_name.to_string()));
+            }
+            let mut binder = Binder::new(self.context.child(), self.args, Some(self));
+            let mut plan = binder.bind_query(query)?;
+            let mapping_schema = plan.output_schema().clone();
+
+            if !columns.is_empty() && columns.len() != mapping_schema.len() {
+                return Err(DatabaseError::MisMatch("alias", "columns"));
+            }
+            // the columns of the common table expression belong to it rather than to the tables
+            // it reads, so that it can be referenced by its name
+            let exprs = mapping_schema
+                .iter()
+                .enumerate()
+                .map(|(i, mapping_column)| {
+                    let column_name = columns
+                        .get(i)
+                        .map(lower_ident)
+                        .unwrap_or_else(|| mapping_column.name().to_string());
+                    let mut column = ColumnCatalog::new(
+                        column_name,
+                        mapping_column.nullable(),
+                        mapping_column.desc().clone(),
+                    );
+                    column.set_ref_table(cte_name.clone(), ColumnId::new(), true);
+
+                    ScalarExpression::Alias {
+                        expr: Box::new(ScalarExpression::ColumnRef(mapping_column.clone())),
+                        alias: AliasType::Expr(Box::new(ScalarExpression::ColumnRef(
+                            ColumnRef::from(column),
+                        ))),
+                    }
+                })
+                .collect_vec();
+            let plan = binder.bind_project(plan, exprs)?;
+
+            self.context.ctes.insert(
+                cte_name.clone(),
+                View {
+                    name: cte_name,
+                    plan: Box::new(plan),
+                },
+            );
+        }
+        Ok(origin_ctes)
+    }
+
+    /// The common table expression named `table_name` in the scope of this query or its outer
+    /// queries.
+    fn cte(&self, table_name: &TableName) -> Option<&View> {
+        self.context
+            .ctes
+            .get(table_name)
+            .or_else(|| self.parent.and_then(|parent| parent.cte(table_name)))
+    }
+
     pub(crate) fn bind_select(
         &mut self,
         select: &Select,
         orderby: &[OrderByExpr],
+        keyset: Option<Vec<DataValue>>,
     ) -> Result<LogicalPlan, DatabaseError> {
         let mut plan = if select.from.is_empty() {
             LogicalPlan::new(Operator::Dummy, Childrens::None)
@@ -99,7 +200,12 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
         // Resolve scalar function call.
         // TODO support SRF(Set-Returning Function).
 
-        let mut select_list = self.normalize_select_item(&select.projection, &plan)?;
+        self.context.allow_window = true;
+        self.context.allow_lenient_cast = true;
+        let select_list = self.normalize_select_item(&select.projection, &plan);
+        self.context.allow_window = false;
+        self.context.allow_lenient_cast = false;
+        let mut select_list = select_list?;
 
         if let Some(predicate) = &select.selection {
             plan = self.bind_where(plan, predicate)?;
@@ -114,14 +220,22 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
         let mut having_orderby = (None, None);
 
         if select.having.is_some() || !orderby.is_empty() {
-            having_orderby = self.extract_having_orderby_aggregate(&select.having, orderby)?;
+            having_orderby =
+                self.extract_having_orderby_aggregate(&select.having, orderby, &select_list)?;
         }
 
-        if !self.context.agg_calls.is_empty() || !self.context.group_by_exprs.is_empty() {
+        self.extract_window_aggregate()?;
+
+        if !self.context.agg_calls.is_empty()
+            || !self.context.group_by_exprs.is_empty()
+            || !select.group_by.is_empty()
+        {
+            self.validate_grouping_args()?;
             plan = self.bind_aggregate(
                 plan,
                 self.context.agg_calls.clone(),
                 self.context.group_by_exprs.clone(),
+                self.context.grouping_sets.clone(),
             );
         }
 
@@ -129,11 +243,18 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
             plan = self.bind_having(plan, having)?;
         }
 
+        if !self.context.window_calls.is_empty() {
+            plan = self.bind_window(plan);
+        }
+
         if let Some(Distinct::Distinct) = select.distinct {
             plan = self.bind_distinct(plan, select_list.clone());
         }
 
         if let Some(orderby) = having_orderby.1 {
+            if let Some(last_key) = keyset {
+                plan = self.bind_keyset(plan, &orderby, last_key)?;
+            }
             plan = self.bind_sort(plan, orderby);
         }
 
@@ -142,11 +263,16 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
         }
 
         if let Some(SelectInto { name, .. }) = &select.into {
+            let table_name = Arc::new(lower_case_name(name)?);
+            let defaults = self.bind_defaults(&table_name)?;
+
             plan = LogicalPlan::new(
                 Operator::Insert(InsertOperator {
-                    table_name: Arc::new(lower_case_name(name)?),
+                    table_name,
                     is_overwrite: false,
                     is_mapping_by_name: true,
+                    defaults,
+                    on_conflict: None,
                 }),
                 Childrens::Only(plan),
             )
@@ -166,75 +292,135 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
             SetQuantifier::All => true,
             SetQuantifier::Distinct | SetQuantifier::None => false,
         };
-        let mut left_plan = self.bind_set_expr(left)?;
-        let mut right_plan = self.bind_set_expr(right)?;
-        let fn_eq = |left_schema: &Schema, right_schema: &Schema| {
-            let left_len = left_schema.len();
+        let mut left_plan = self.bind_set_operand(left)?;
+        let mut right_plan = self.bind_set_operand(right)?;
+        let left_schema = left_plan.output_schema().clone();
+        let right_schema = right_plan.output_schema().clone();
+
+        if left_schema.len() != right_schema.len() {
+            return Err(DatabaseError::SetOperationColumnsMismatch(
+                op.to_string(),
+                left_schema.len(),
+                right_schema.len(),
+            ));
+        }
+        let mut types = Vec::with_capacity(left_schema.len());
+
+        for (i, (left_column, right_column)) in
+            left_schema.iter().zip(right_schema.iter()).enumerate()
+        {
+            let (left_ty, right_ty) = (left_column.datatype(), right_column.datatype());
 
-            if left_len != right_schema.len() {
-                return false;
+            if left_ty == right_ty {
+                types.push(left_ty.clone());
+            } else if Self::is_set_operation_coercible(left_ty, right_ty) {
+                types.push(LogicalType::max_logical_type(left_ty, right_ty)?);
+            } else {
+                return Err(DatabaseError::SetOperationTypesMismatch(
+                    op.to_string(),
+                    i + 1,
+                    left_ty.clone(),
+                    right_ty.clone(),
+                ));
             }
-            for i in 0..left_len {
-                if left_schema[i].datatype() != right_schema[i].datatype() {
-                    return false;
-                }
+        }
+        // both sides output the names of the left side, cast to the common types
+        let mut left_plan = self.cast_set_operand(left_plan, &left_schema, &left_schema, &types);
+        let mut right_plan = self.cast_set_operand(right_plan, &right_schema, &left_schema, &types);
+        let left_schema = left_plan.output_schema().clone();
+        let right_schema = right_plan.output_schema().clone();
+
+        Ok(match op {
+            // Tips: the distinct `EXCEPT` cannot be derived from `EXCEPT ALL`, so all of the set
+            // operations deduplicate in their executors rather than with an aggregation
+            SetOperator::Union => {
+                UnionOperator::build(left_schema, right_schema, is_all, left_plan, right_plan)
             }
-            true
-        };
-        match (op, is_all) {
-            (SetOperator::Union, true) => {
-                let left_schema = left_plan.output_schema();
-                let right_schema = right_plan.output_schema();
-
-                if !fn_eq(left_schema, right_schema) {
-                    return Err(DatabaseError::MisMatch(
-                        "the output types on the left",
-                        "the output types on the right",
-                    ));
-                }
-                Ok(UnionOperator::build(
-                    left_schema.clone(),
-                    right_schema.clone(),
-                    left_plan,
-                    right_plan,
-                ))
+            SetOperator::Except => {
+                ExceptOperator::build(left_schema, right_schema, is_all, left_plan, right_plan)
             }
-            (SetOperator::Union, false) => {
-                let left_schema = left_plan.output_schema();
-                let right_schema = right_plan.output_schema();
-
-                if !fn_eq(left_schema, right_schema) {
-                    return Err(DatabaseError::MisMatch(
-                        "the output types on the left",
-                        "the output types on the right",
-                    ));
-                }
-                let union_op = Operator::Union(UnionOperator {
-                    left_schema_ref: left_schema.clone(),
-                    _right_schema_ref: right_schema.clone(),
-                });
-                let distinct_exprs = left_schema
-                    .iter()
-                    .cloned()
-                    .map(ScalarExpression::ColumnRef)
-                    .collect_vec();
-
-                Ok(self.bind_distinct(
-                    LogicalPlan::new(
-                        union_op,
-                        Childrens::Twins {
-                            left: left_plan,
-                            right: right_plan,
-                        },
-                    ),
-                    distinct_exprs,
-                ))
+            SetOperator::Intersect => {
+                IntersectOperator::build(left_schema, right_schema, is_all, left_plan, right_plan)
             }
-            (set_operator, _) => Err(DatabaseError::UnsupportedStmt(format!(
-                "set operator: {:?}",
-                set_operator
-            ))),
+        })
+    }
+
+    /// numbers, strings, dates and NULL are each promoted to a common type across the sides
+    fn is_set_operation_coercible(left: &LogicalType, right: &LogicalType) -> bool {
+        let is_string =
+            |ty: &LogicalType| matches!(ty, LogicalType::Char(..) | LogicalType::Varchar(..));
+        let is_date = |ty: &LogicalType| matches!(ty, LogicalType::Date | LogicalType::DateTime);
+
+        matches!(left, LogicalType::SqlNull)
+            || matches!(right, LogicalType::SqlNull)
+            || (left.is_numeric() && right.is_numeric())
+            || (is_string(left) && is_string(right))
+            || (is_date(left) && is_date(right))
+    }
+
+    fn cast_set_operand(
+        &mut self,
+        plan: LogicalPlan,
+        schema: &Schema,
+        name_schema: &Schema,
+        types: &[LogicalType],
+    ) -> LogicalPlan {
+        if schema
+            .iter()
+            .zip(types)
+            .all(|(column, ty)| column.datatype() == ty)
+        {
+            return plan;
         }
+        let exprs = schema
+            .iter()
+            .zip(name_schema.iter())
+            .zip(types)
+            .map(|((column, name_column), ty)| {
+                let expr = ScalarExpression::ColumnRef(column.clone());
+
+                if column.datatype() == ty {
+                    return expr;
+                }
+                // a column of a temp table, so that it cannot be mistaken for the uncast column
+                // of the same name below it
+                let mut cast_column = ColumnCatalog::clone(name_column);
+                cast_column.desc_mut().column_datatype = ty.clone();
+                cast_column.set_ref_table(self.context.temp_table(), ColumnId::new(), true);
+
+                ScalarExpression::Alias {
+                    expr: Box::new(ScalarExpression::TypeCast {
+                        expr: Box::new(expr),
+                        ty: ty.clone(),
+                        is_try: false,
+                    }),
+                    alias: AliasType::Expr(Box::new(ScalarExpression::ColumnRef(ColumnRef::from(
+                        cast_column,
+                    )))),
+                }
+            })
+            .collect_vec();
+
+        LogicalPlan::new(
+            Operator::Project(ProjectOperator { exprs }),
+            Childrens::Only(plan),
+        )
+    }
+
+    /// binds a side of the set operation in its own context, so that the tables, aliases and
+    /// aggregations of one side cannot be resolved by the other
+    fn bind_set_operand(&mut self, set_expr: &SetExpr) -> Result<LogicalPlan, DatabaseError> {
+        let mut context = self.context.child();
+        context.ctes = self.context.ctes.clone();
+        context.allow_lenient_cast = self.context.allow_lenient_cast;
+        // the outer columns referenced by either side are parameters of the enclosing subquery
+        context.correlated_params = self.context.correlated_params.take();
+        let origin_context = mem::replace(&mut self.context, context);
+        let plan = self.bind_set_expr(set_expr);
+        let mut operand_context = mem::replace(&mut self.context, origin_context);
+        self.context.correlated_params = operand_context.correlated_params.take();
+
+        plan
     }
 
     pub(crate) fn bind_table_ref(
@@ -389,13 +575,32 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
             alias_idents = Some(columns);
         }
 
-        let source = self
-            .context
-            .source_and_bind(table_name.clone(), table_alias.as_ref(), join_type, false)?
-            .ok_or(DatabaseError::SourceNotFound)?;
+        let source = if let Some(view) = self.cte(&table_name) {
+            let source = Source::View(Cow::Owned(view.clone()));
+
+            self.context.bind_table.insert(
+                (table_name.clone(), table_alias.clone(), join_type),
+                source.clone(),
+            );
+            source
+        } else {
+            self.reference_table(&table_name);
+            self.context
+                .source_and_bind(table_name.clone(), table_alias.as_ref(), join_type, false)?
+                .ok_or(DatabaseError::SourceNotFound)?
+        };
         let mut plan = match source {
             Source::Table(table) => TableScanOperator::build(table_name.clone(), table),
-            Source::View(view) => LogicalPlan::clone(&view.plan),
+            Source::View(view) => {
+                let mut plan = LogicalPlan::clone(&view.plan);
+                if let Some(now) = self.context.now {
+                    plan.at_time(now);
+                }
+                if let Some(settings) = &self.context.settings {
+                    plan.with_settings(settings);
+                }
+                plan
+            }
         };
 
         if let Some(idents) = alias_idents {
@@ -512,7 +717,17 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
 
         source = context.table(table_name.clone())?.map(Source::Table);
         if source.is_none() {
-            source = context.view(table_name)?.map(Source::View);
+            source = context
+                .view(table_name.clone())?
+                .map(|view| Source::View(Cow::Borrowed(view)));
+        }
+        // a common table expression is only known by the query it is bound to
+        if source.is_none() {
+            source = context
+                .bind_table
+                .iter()
+                .find(|((bind_table_name, ..), _)| bind_table_name == &table_name)
+                .map(|(_, source)| source.clone());
         }
         for column in source
             .ok_or(DatabaseError::SourceNotFound)?
@@ -551,27 +766,7 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
             JoinOperator::CrossJoin => (JoinType::Cross, None),
             _ => unimplemented!(),
         };
-        let BinderContext {
-            table_cache,
-            view_cache,
-            transaction,
-            scala_functions,
-            table_functions,
-            temp_table_id,
-            ..
-        } = &self.context;
-        let mut binder = Binder::new(
-            BinderContext::new(
-                table_cache,
-                view_cache,
-                *transaction,
-                scala_functions,
-                table_functions,
-                temp_table_id.clone(),
-            ),
-            self.args,
-            Some(self),
-        );
+        let mut binder = Binder::new(self.context.child(), self.args, Some(self));
         let mut right = binder.bind_single_table_ref(relation, Some(join_type))?;
         self.extend(binder.context);
 
@@ -592,55 +787,135 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
     ) -> Result<LogicalPlan, DatabaseError> {
         self.context.step(QueryBindStep::Where);
 
-        let predicate = self.bind_expr(predicate)?;
+        let predicate = self.bind_where_conjuncts(predicate)?;
+        // the rows of the parameters are filled in with each row of the outer query on execution
+        if let Some(params) = self
+            .context
+            .correlated_params
+            .as_ref()
+            .filter(|params| !params.is_empty())
+        {
+            let values = LogicalPlan::new(
+                Operator::Values(ValuesOperator {
+                    rows: vec![],
+                    schema_ref: Arc::new(params.iter().map(|(_, param)| param.clone()).collect()),
+                }),
+                Childrens::None,
+            );
+            children = LJoinOperator::build(children, values, JoinCondition::None, JoinType::Cross);
+        }
 
         if let Some(sub_queries) = self.context.sub_queries_at_now() {
+            let mut is_predicate_joined = false;
+            let mut is_semi_joined = false;
+            let mut is_correlated = false;
+
             for sub_query in sub_queries {
                 let mut on_keys: Vec<(ScalarExpression, ScalarExpression)> = vec![];
+                let mut null_safe_keys: Vec<(ScalarExpression, ScalarExpression)> = vec![];
                 let mut filter = vec![];
 
                 let (mut plan, join_ty) = match sub_query {
                     SubQueryType::SubQuery(plan) => (plan, JoinType::Inner),
-                    SubQueryType::InSubQuery(is_not, plan) => {
+                    SubQueryType::InSubQuery(is_not, params, plan) => {
                         let join_ty = if is_not {
                             JoinType::LeftAnti
                         } else {
                             JoinType::LeftSemi
                         };
+                        is_semi_joined = true;
+
+                        if !params.is_empty() {
+                            is_predicate_joined = true;
+                            children = LJoinOperator::build(
+                                children,
+                                plan,
+                                JoinCondition::Dependent {
+                                    params,
+                                    filter: Some(predicate.clone()),
+                                },
+                                join_ty,
+                            );
+                            continue;
+                        }
                         (plan, join_ty)
                     }
+                    SubQueryType::AllSubQuery(violation, params, plan) => {
+                        let on = if params.is_empty() {
+                            JoinCondition::On {
+                                on: vec![],
+                                filter: Some(violation),
+                                null_equals_null: false,
+                            }
+                        } else {
+                            JoinCondition::Dependent {
+                                params,
+                                filter: Some(violation),
+                            }
+                        };
+                        children = LJoinOperator::build(children, plan, on, JoinType::LeftAnti);
+                        continue;
+                    }
+                    SubQueryType::ExistsSubQuery(is_not, params, plan) => {
+                        let join_ty = if is_not {
+                            JoinType::LeftAnti
+                        } else {
+                            JoinType::LeftSemi
+                        };
+                        let on = if params.is_empty() {
+                            JoinCondition::None
+                        } else {
+                            JoinCondition::Dependent {
+                                params,
+                                filter: None,
+                            }
+                        };
+                        children = LJoinOperator::build(children, plan, on, join_ty);
+                        continue;
+                    }
+                    SubQueryType::CorrelatedSubQuery(params, plan) => {
+                        is_correlated = true;
+                        children = LJoinOperator::build(
+                            children,
+                            plan,
+                            JoinCondition::Dependent {
+                                params,
+                                filter: None,
+                            },
+                            JoinType::LeftOuter,
+                        );
+                        continue;
+                    }
                 };
+                is_predicate_joined = true;
 
                 Self::extract_join_keys(
                     predicate.clone(),
                     &mut on_keys,
+                    &mut null_safe_keys,
                     &mut filter,
                     children.output_schema(),
                     plan.output_schema(),
                 )?;
 
-                // combine multiple filter exprs into one BinaryExpr
-                let join_filter = filter
-                    .into_iter()
-                    .reduce(|acc, expr| ScalarExpression::Binary {
-                        op: BinaryOperator::And,
-                        left_expr: Box::new(acc),
-                        right_expr: Box::new(expr),
-                        evaluator: None,
-                        ty: LogicalType::Boolean,
-                    });
-
                 children = LJoinOperator::build(
                     children,
                     plan,
-                    JoinCondition::On {
-                        on: on_keys,
-                        filter: join_filter,
-                    },
+                    Self::build_join_condition(on_keys, null_safe_keys, filter),
                     join_ty,
                 );
             }
-            return Ok(children);
+            // the predicate is kept above the correlated scalar subqueries, which is not possible
+            // once the columns of an `IN (SUBQUERY)` are dropped by its semi join
+            if is_correlated && is_semi_joined {
+                return Err(DatabaseError::UnsupportedStmt(
+                    "correlated scalar subquery with 'IN (SUBQUERY)' in the same `WHERE`"
+                        .to_string(),
+                ));
+            }
+            if is_predicate_joined && !is_correlated {
+                return Ok(children);
+            }
         }
         Ok(FilterOperator::build(predicate, children, false))
     }
@@ -656,6 +931,58 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
         Ok(FilterOperator::build(having, children, true))
     }
 
+    /// Keeps the rows after `last_key` in the order of `orderby`, i.e. `(keys) > (last_key)`
+    /// or `<` when the keys are descending, on the same expressions as the sort.
+    fn bind_keyset(
+        &mut self,
+        children: LogicalPlan,
+        orderby: &[SortField],
+        last_key: Vec<DataValue>,
+    ) -> Result<LogicalPlan, DatabaseError> {
+        if orderby.len() != last_key.len() {
+            return Err(DatabaseError::MisMatch("ORDER BY keys", "last key values"));
+        }
+        // the keys are checked to be in the same direction by `keyset_page`
+        let asc = orderby[0].asc;
+        let mut keys = Vec::with_capacity(orderby.len());
+        let mut values = Vec::with_capacity(orderby.len());
+
+        for (sort_field, value) in orderby.iter().zip(last_key) {
+            values.push(ScalarExpression::Constant(
+                value.cast(&sort_field.expr.return_type())?,
+            ));
+            // the filter is below the select list, so its aliases are unwrapped like a `WHERE`
+            keys.push(match &sort_field.expr {
+                ScalarExpression::Alias {
+                    expr,
+                    alias: AliasType::Name(_),
+                } => expr.as_ref().clone(),
+                expr => expr.clone(),
+            });
+        }
+        let (keys, values) = if keys.len() == 1 {
+            (keys.remove(0), values.remove(0))
+        } else {
+            (
+                ScalarExpression::Tuple(keys),
+                ScalarExpression::Tuple(values),
+            )
+        };
+        let predicate = ScalarExpression::Binary {
+            op: if asc {
+                BinaryOperator::Gt
+            } else {
+                BinaryOperator::Lt
+            },
+            left_expr: Box::new(keys),
+            right_expr: Box::new(values),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+
+        Ok(FilterOperator::build(predicate, children, false))
+    }
+
     pub(crate) fn bind_project(
         &mut self,
         children: LogicalPlan,
@@ -783,6 +1110,8 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
             JoinConstraint::On(expr) => {
                 // left and right columns that match equi-join pattern
                 let mut on_keys: Vec<(ScalarExpression, ScalarExpression)> = vec![];
+                // left and right columns that match `IS NOT DISTINCT FROM` pattern
+                let mut null_safe_keys: Vec<(ScalarExpression, ScalarExpression)> = vec![];
                 // expression that didn't match equi-join pattern
                 let mut filter = vec![];
                 let expr = self.bind_expr(expr)?;
@@ -790,26 +1119,14 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                 Self::extract_join_keys(
                     expr,
                     &mut on_keys,
+                    &mut null_safe_keys,
                     &mut filter,
                     left_schema,
                     right_schema,
                 )?;
 
-                // combine multiple filter exprs into one BinaryExpr
-                let join_filter = filter
-                    .into_iter()
-                    .reduce(|acc, expr| ScalarExpression::Binary {
-                        op: BinaryOperator::And,
-                        left_expr: Box::new(acc),
-                        right_expr: Box::new(expr),
-                        evaluator: None,
-                        ty: LogicalType::Boolean,
-                    });
                 // TODO: handle cross join if on_keys is empty
-                Ok(JoinCondition::On {
-                    on: on_keys,
-                    filter: join_filter,
-                })
+                Ok(Self::build_join_condition(on_keys, null_safe_keys, filter))
             }
             JoinConstraint::Using(idents) => {
                 let mut on_keys: Vec<(ScalarExpression, ScalarExpression)> = Vec::new();
@@ -834,6 +1151,7 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                 Ok(JoinCondition::On {
                     on: on_keys,
                     filter: None,
+                    null_equals_null: false,
                 })
             }
             JoinConstraint::None => Ok(JoinCondition::None),
@@ -858,11 +1176,53 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                 Ok(JoinCondition::On {
                     on: on_keys,
                     filter: None,
+                    null_equals_null: false,
                 })
             }
         }
     }
 
+    /// A join matches `NULL` keys either for all of its keys or for none, so the keys compared by
+    /// `IS NOT DISTINCT FROM` are only joined on when there is no `=` key, otherwise they are
+    /// checked by the filter
+    fn build_join_condition(
+        mut on_keys: Vec<(ScalarExpression, ScalarExpression)>,
+        null_safe_keys: Vec<(ScalarExpression, ScalarExpression)>,
+        mut filter: Vec<ScalarExpression>,
+    ) -> JoinCondition {
+        let null_equals_null = on_keys.is_empty() && !null_safe_keys.is_empty();
+
+        if null_equals_null {
+            on_keys = null_safe_keys;
+        } else {
+            filter.extend(null_safe_keys.into_iter().map(|(left_expr, right_expr)| {
+                ScalarExpression::Binary {
+                    op: BinaryOperator::Spaceship,
+                    left_expr: Box::new(left_expr),
+                    right_expr: Box::new(right_expr),
+                    evaluator: None,
+                    ty: LogicalType::Boolean,
+                }
+            }));
+        }
+        // combine multiple filter exprs into one BinaryExpr
+        let join_filter = filter
+            .into_iter()
+            .reduce(|acc, expr| ScalarExpression::Binary {
+                op: BinaryOperator::And,
+                left_expr: Box::new(acc),
+                right_expr: Box::new(expr),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            });
+
+        JoinCondition::On {
+            on: on_keys,
+            filter: join_filter,
+            null_equals_null,
+        }
+    }
+
     /// for sqlrs
     /// original idea from datafusion planner.rs
     /// Extracts equijoin ON condition be a single Eq or multiple conjunctive Eqs
@@ -873,10 +1233,12 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
     /// foo = bar => accum=[(foo, bar)] accum_filter=[]
     /// foo = bar AND bar = baz => accum=[(foo, bar), (bar, baz)] accum_filter=[]
     /// foo = bar AND baz > 1 => accum=[(foo, bar)] accum_filter=[baz > 1]
+    /// foo IS NOT DISTINCT FROM bar => accum_null_safe=[(foo, bar)] accum_filter=[]
     /// ```
     fn extract_join_keys(
         expr: ScalarExpression,
         accum: &mut Vec<(ScalarExpression, ScalarExpression)>,
+        accum_null_safe: &mut Vec<(ScalarExpression, ScalarExpression)>,
         accum_filter: &mut Vec<ScalarExpression>,
         left_schema: &Schema,
         right_schema: &Schema,
@@ -898,7 +1260,12 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                 ..
             } => {
                 match op {
-                    BinaryOperator::Eq => {
+                    BinaryOperator::Eq | BinaryOperator::Spaceship => {
+                        let keys = if matches!(op, BinaryOperator::Eq) {
+                            &mut *accum
+                        } else {
+                            &mut *accum_null_safe
+                        };
                         match (left_expr.unpack_alias_ref(), right_expr.unpack_alias_ref()) {
                             // example: foo = bar
                             (ScalarExpression::ColumnRef(l), ScalarExpression::ColumnRef(r)) => {
@@ -906,13 +1273,13 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                                 if fn_contains(left_schema, l.summary())
                                     && fn_contains(right_schema, r.summary())
                                 {
-                                    accum.push((*left_expr, *right_expr));
+                                    keys.push((*left_expr, *right_expr));
                                 } else if fn_contains(left_schema, r.summary())
                                     && fn_contains(right_schema, l.summary())
                                 {
-                                    accum.push((*right_expr, *left_expr));
+                                    keys.push((*right_expr, *left_expr));
                                 } else if fn_or_contains(left_schema, right_schema, l.summary())
-                                    || fn_or_contains(left_schema, right_schema, r.summary())
+                                    && fn_or_contains(left_schema, right_schema, r.summary())
                                 {
                                     accum_filter.push(ScalarExpression::Binary {
                                         left_expr,
@@ -923,9 +1290,20 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                                     });
                                 }
                             }
-                            (ScalarExpression::ColumnRef(column), _)
-                            | (_, ScalarExpression::ColumnRef(column)) => {
-                                if fn_or_contains(left_schema, right_schema, column.summary()) {
+                            (ScalarExpression::ColumnRef(_), _)
+                            | (_, ScalarExpression::ColumnRef(_)) => {
+                                // conditions referencing other subqueries are left to their joins
+                                if [&left_expr, &right_expr].iter().all(|expr| {
+                                    expr.unpack_alias_ref().referenced_columns(true).iter().all(
+                                        |column| {
+                                            fn_or_contains(
+                                                left_schema,
+                                                right_schema,
+                                                column.summary(),
+                                            )
+                                        },
+                                    )
+                                }) {
                                     accum_filter.push(ScalarExpression::Binary {
                                         left_expr,
                                         right_expr,
@@ -958,6 +1336,7 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                         Self::extract_join_keys(
                             *left_expr,
                             accum,
+                            accum_null_safe,
                             accum_filter,
                             left_schema,
                             right_schema,
@@ -965,6 +1344,7 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
                         Self::extract_join_keys(
                             *right_expr,
                             accum,
+                            accum_null_safe,
                             accum_filter,
                             left_schema,
                             right_schema,
@@ -1016,6 +1396,9 @@ impl<'a: 'b, 'b, T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'
 mod tests {
     use crate::binder::test::build_t1_table;
     use crate::errors::DatabaseError;
+    use crate::planner::operator::Operator;
+    use crate::types::LogicalType;
+    use sqlparser::ast::CharLengthUnits;
 
     #[test]
     fn test_select_bind() -> Result<(), DatabaseError> {
@@ -1046,4 +1429,152 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_set_operation_coercion() -> Result<(), DatabaseError> {
+        let table_states = build_t1_table()?;
+
+        let mut plan = table_states
+            .plan("select c1, null from t1 union all select cast(c3 as bigint), c4 from t2")?;
+        let output_schema = plan.output_schema();
+        assert_eq!(output_schema[0].name(), "c1");
+        assert_eq!(output_schema[0].datatype(), &LogicalType::Bigint);
+        assert_eq!(output_schema[1].datatype(), &LogicalType::Integer);
+        assert!(matches!(plan.operator, Operator::Union(_)));
+
+        let (mut left_plan, mut right_plan) = plan.childrens.pop_twins();
+        assert!(matches!(left_plan.operator, Operator::Project(_)));
+        assert_eq!(
+            left_plan.output_schema()[0].datatype(),
+            &LogicalType::Bigint
+        );
+        assert_eq!(
+            right_plan.output_schema()[1].datatype(),
+            &LogicalType::Integer
+        );
+
+        assert!(matches!(
+            table_states.plan("select c1 from t1 union select 'a' from t2"),
+            Err(DatabaseError::SetOperationTypesMismatch(..))
+        ));
+        assert!(matches!(
+            table_states.plan("select c1, c2 from t1 except select c3 from t2"),
+            Err(DatabaseError::SetOperationColumnsMismatch(..))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_colon_cast() -> Result<(), DatabaseError> {
+        let table_states = build_t1_table()?;
+
+        for (sql, expected) in [
+            (
+                "select c1::bigint from t1",
+                "select cast(c1 as bigint) from t1",
+            ),
+            (
+                "select c1::bigint::varchar from t1",
+                "select cast(cast(c1 as bigint) as varchar) from t1",
+            ),
+            ("select '1'::int + 1", "select cast('1' as int) + 1"),
+        ] {
+            let (Operator::Project(op), Operator::Project(expected_op)) = (
+                table_states.plan(sql)?.operator,
+                table_states.plan(expected)?.operator,
+            ) else {
+                unreachable!()
+            };
+            assert_eq!(op.exprs, expected_op.exprs, "{}", sql);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_nullif_type() -> Result<(), DatabaseError> {
+        let table_states = build_t1_table()?;
+
+        for sql in [
+            "select coalesce(c1, cast(c2 as bigint), null) from t1",
+            "select coalesce(null, c1, cast(c2 as bigint)) from t1",
+            "select nullif(c1, cast(c2 as bigint)) from t1",
+        ] {
+            let mut plan = table_states.plan(sql)?;
+            assert_eq!(
+                plan.output_schema()[0].datatype(),
+                &LogicalType::Bigint,
+                "{}",
+                sql
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_when_type() -> Result<(), DatabaseError> {
+        let table_states = build_t1_table()?;
+
+        for (sql, expected) in [
+            (
+                "select case when c1 > 1 then c2 else null end from t1",
+                LogicalType::Integer,
+            ),
+            (
+                "select case when c1 > 1 then null when c1 > 0 then c2 end from t1",
+                LogicalType::Integer,
+            ),
+            (
+                "select case when c1 > 1 then c2 else cast(c1 as bigint) end from t1",
+                LogicalType::Bigint,
+            ),
+            (
+                "select case c1 when 1 then null else 'a' end from t1",
+                LogicalType::Varchar(None, CharLengthUnits::Characters),
+            ),
+        ] {
+            let mut plan = table_states.plan(sql)?;
+            let column = &plan.output_schema()[0];
+
+            assert_eq!(column.datatype(), &expected, "{}", sql);
+            assert!(column.nullable(), "{}", sql);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chained_comparison() -> Result<(), DatabaseError> {
+        let table_states = build_t1_table()?;
+
+        for sql in [
+            "select * from t1 where 1 < c1 < 10",
+            "select * from t1 where c1 = c2 = 1",
+            "select * from t1 where 1 < (c1 < 10)",
+        ] {
+            assert!(
+                matches!(
+                    table_states.plan(sql),
+                    Err(DatabaseError::ChainedComparison(_))
+                ),
+                "{}",
+                sql
+            );
+        }
+        let Err(err) = table_states.plan("select * from t1 where 1 < c1 < 10") else {
+            unreachable!()
+        };
+        assert_eq!(
+            err.to_string(),
+            "comparison operators cannot be chained: 1 < c1 < 10, \
+            use `x BETWEEN a AND b` or `a < x AND x < b` instead"
+        );
+        // comparing the boolean with a boolean is fine
+        table_states.plan("select * from t1 where c1 < c2 = true")?;
+        table_states.plan("select * from t1 where (c1 < c2) = (c2 < 10)")?;
+
+        Ok(())
+    }
 }
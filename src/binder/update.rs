@@ -1,4 +1,5 @@
 use crate::binder::{lower_case_name, Binder};
+use crate::catalog::{ColumnRef, TableName};
 use crate::errors::DatabaseError;
 use crate::expression::ScalarExpression;
 use crate::planner::operator::update::UpdateOperator;
@@ -27,40 +28,7 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             if let Some(predicate) = selection {
                 plan = self.bind_where(plan, predicate)?;
             }
-            let mut value_exprs = Vec::with_capacity(assignments.len());
-
-            if assignments.is_empty() {
-                return Err(DatabaseError::ColumnsEmpty);
-            }
-            for Assignment { id, value } in assignments {
-                let expression = self.bind_expr(value)?;
-
-                for ident in id {
-                    match self.bind_column_ref_from_identifiers(
-                        slice::from_ref(ident),
-                        Some(table_name.to_string()),
-                    )? {
-                        ScalarExpression::ColumnRef(column) => {
-                            let mut expr = if matches!(expression, ScalarExpression::Empty) {
-                                let default_value = column
-                                    .default_value()?
-                                    .ok_or(DatabaseError::DefaultNotExist)?;
-                                ScalarExpression::Constant(default_value)
-                            } else {
-                                expression.clone()
-                            };
-                            if &expr.return_type() != column.datatype() {
-                                expr = ScalarExpression::TypeCast {
-                                    expr: Box::new(expr),
-                                    ty: column.datatype().clone(),
-                                }
-                            }
-                            value_exprs.push((column, expr));
-                        }
-                        _ => return Err(DatabaseError::InvalidColumn(ident.to_string())),
-                    }
-                }
-            }
+            let value_exprs = self.bind_assignments(&table_name, assignments)?;
             self.context.allow_default = false;
             Ok(LogicalPlan::new(
                 Operator::Update(UpdateOperator {
@@ -73,4 +41,53 @@ impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A>
             unreachable!("only table")
         }
     }
+
+    /// Binds the `SET` of `UPDATE` and `ON CONFLICT DO UPDATE`, each value is cast to
+    /// the type of its column
+    pub(crate) fn bind_assignments(
+        &mut self,
+        table_name: &TableName,
+        assignments: &[Assignment],
+    ) -> Result<Vec<(ColumnRef, ScalarExpression)>, DatabaseError> {
+        let mut value_exprs = Vec::with_capacity(assignments.len());
+
+        if assignments.is_empty() {
+            return Err(DatabaseError::ColumnsEmpty);
+        }
+        for Assignment { id, value } in assignments {
+            let expression = self.bind_expr(value)?;
+
+            for ident in id {
+                match self.bind_column_ref_from_identifiers(
+                    slice::from_ref(ident),
+                    Some(table_name.to_string()),
+                )? {
+                    ScalarExpression::ColumnRef(column) => {
+                        self.infer_parameter_type(value, column.datatype());
+                        // a volatile default is evaluated for each updated row
+                        let mut expr = if matches!(expression, ScalarExpression::Empty) {
+                            column
+                                .statement_default(
+                                    self.context.now,
+                                    self.context.settings.as_ref(),
+                                )?
+                                .ok_or(DatabaseError::DefaultNotExist)?
+                        } else {
+                            expression.clone()
+                        };
+                        if &expr.return_type() != column.datatype() {
+                            expr = ScalarExpression::TypeCast {
+                                expr: Box::new(expr),
+                                ty: column.datatype().clone(),
+                                is_try: false,
+                            }
+                        }
+                        value_exprs.push((column, expr));
+                    }
+                    _ => return Err(DatabaseError::InvalidColumn(ident.to_string())),
+                }
+            }
+        }
+        Ok(value_exprs)
+    }
 }
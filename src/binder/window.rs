@@ -0,0 +1,109 @@
+use crate::binder::Binder;
+use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
+use crate::errors::DatabaseError;
+use crate::expression::ScalarExpression;
+use crate::planner::operator::sort::SortField;
+use crate::planner::operator::window::{WindowCall, WindowKind, WindowOperator};
+use crate::planner::LogicalPlan;
+use crate::storage::Transaction;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use sqlparser::ast::{Function, OrderByExpr, WindowSpec, WindowType};
+
+impl<T: Transaction, A: AsRef<[(&'static str, DataValue)]>> Binder<'_, '_, T, A> {
+    /// Binds `func` as a call of the [`WindowOperator`] of the query, which is read as the column
+    /// of its results.
+    ///
+    /// Tips: the ranking functions do not depend on the window frame, so it is ignored
+    pub(crate) fn bind_window_function(
+        &mut self,
+        function_name: &str,
+        func: &Function,
+        over: &WindowType,
+    ) -> Result<ScalarExpression, DatabaseError> {
+        if !self.context.allow_window {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "window function `{}` is only allowed in the select list and ORDER BY",
+                func
+            )));
+        }
+        let kind = match function_name {
+            "row_number" => WindowKind::RowNumber,
+            "rank" => WindowKind::Rank,
+            "dense_rank" => WindowKind::DenseRank,
+            _ => return Err(DatabaseError::FunctionNotFound(function_name.to_string())),
+        };
+        if !func.args.is_empty() {
+            return Err(DatabaseError::MisMatch(
+                "number of window function parameters",
+                "0",
+            ));
+        }
+        let WindowType::WindowSpec(spec) = over else {
+            return Err(DatabaseError::UnsupportedStmt(format!(
+                "named window: {}",
+                over
+            )));
+        };
+        // the window functions cannot be nested
+        self.context.allow_window = false;
+        let exprs = self.bind_window_spec(spec);
+        self.context.allow_window = true;
+        let (partition_by, order_by) = exprs?;
+
+        if let Some(call) = self.context.window_calls.iter().find(|call| {
+            call.kind == kind && call.partition_by == partition_by && call.order_by == order_by
+        }) {
+            return Ok(ScalarExpression::ColumnRef(call.output.clone()));
+        }
+        let mut call = WindowCall {
+            kind,
+            partition_by,
+            order_by,
+            output: ColumnRef::from(ColumnCatalog::new_dummy(String::new())),
+        };
+        // named by the call like the output column of an aggregate function
+        call.output = ColumnRef::from(ColumnCatalog::new(
+            call.to_string(),
+            false,
+            ColumnDesc::new(LogicalType::Bigint, None, false, None)?,
+        ));
+        self.context.window_calls.push(call.clone());
+
+        Ok(ScalarExpression::ColumnRef(call.output))
+    }
+
+    fn bind_window_spec(
+        &mut self,
+        WindowSpec {
+            partition_by,
+            order_by,
+            ..
+        }: &WindowSpec,
+    ) -> Result<(Vec<ScalarExpression>, Vec<SortField>), DatabaseError> {
+        let mut partition_exprs = Vec::with_capacity(partition_by.len());
+        for expr in partition_by {
+            partition_exprs.push(self.bind_expr(expr)?);
+        }
+        let mut sort_fields = Vec::with_capacity(order_by.len());
+        for OrderByExpr {
+            expr,
+            asc,
+            nulls_first,
+        } in order_by
+        {
+            let asc = asc.map_or(true, |asc| asc);
+            sort_fields.push(SortField::new(
+                self.bind_expr(expr)?,
+                asc,
+                nulls_first.unwrap_or(!asc),
+            ));
+        }
+
+        Ok((partition_exprs, sort_fields))
+    }
+
+    pub(crate) fn bind_window(&mut self, children: LogicalPlan) -> LogicalPlan {
+        WindowOperator::build(self.context.window_calls.clone(), children)
+    }
+}
@@ -1,8 +1,10 @@
 use crate::catalog::TableName;
 use crate::errors::DatabaseError;
 use crate::expression::ScalarExpression;
+use crate::setting::SettingsRef;
 use crate::types::value::DataValue;
 use crate::types::{ColumnId, LogicalType};
+use chrono::NaiveDateTime;
 use kite_sql_serde_macros::ReferenceSerialization;
 use sqlparser::ast::CharLengthUnits;
 use std::hash::Hash;
@@ -165,14 +167,46 @@ impl ColumnCatalog {
         &self.desc.column_datatype
     }
 
-    pub(crate) fn default_value(&self) -> Result<Option<DataValue>, DatabaseError> {
-        self.desc
-            .default
-            .as_ref()
+    /// Evaluates the default of the column at `now`, the time of the statement it is used in,
+    /// so that the rows of a statement share it (e.g. `DEFAULT current_timestamp`).
+    /// `None` reads the clock.
+    ///
+    /// Tips: a default reading the settings (e.g. `DEFAULT current_setting('myapp.user')`)
+    /// reads `settings`, the ones of the session of the statement, or the defaults when `None`
+    pub(crate) fn default_value(
+        &self,
+        now: Option<NaiveDateTime>,
+        settings: Option<&SettingsRef>,
+    ) -> Result<Option<DataValue>, DatabaseError> {
+        self.statement_default(now, settings)?
             .map(|expr| expr.eval(None))
             .transpose()
     }
 
+    /// The default of the column for the rows of a statement at `now` and with `settings`,
+    /// evaluated once unless it gives a new value on every call (e.g. `DEFAULT gen_random_uuid()`),
+    /// which is then evaluated for each row.
+    pub(crate) fn statement_default(
+        &self,
+        now: Option<NaiveDateTime>,
+        settings: Option<&SettingsRef>,
+    ) -> Result<Option<ScalarExpression>, DatabaseError> {
+        let Some(expr) = &self.desc.default else {
+            return Ok(None);
+        };
+        let mut expr = expr.clone();
+        if let Some(now) = now {
+            expr.at_time(now);
+        }
+        if let Some(settings) = settings {
+            expr.with_settings(settings);
+        }
+        if expr.is_deterministic() {
+            return Ok(Some(ScalarExpression::Constant(expr.eval(None)?)));
+        }
+        Ok(Some(expr))
+    }
+
     pub(crate) fn desc(&self) -> &ColumnDesc {
         &self.desc
     }
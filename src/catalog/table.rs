@@ -1,5 +1,6 @@
 use crate::catalog::{ColumnCatalog, ColumnRef, ColumnRelation};
 use crate::errors::DatabaseError;
+use crate::expression::ScalarExpression;
 use crate::types::index::{IndexMeta, IndexMetaRef, IndexType};
 use crate::types::tuple::SchemaRef;
 use crate::types::{ColumnId, LogicalType};
@@ -39,9 +40,12 @@ impl TableCatalog {
     }
 
     pub(crate) fn get_unique_index(&self, col_id: &ColumnId) -> Option<&IndexMetaRef> {
-        self.indexes
-            .iter()
-            .find(|meta| matches!(meta.ty, IndexType::Unique) && &meta.column_ids[0] == col_id)
+        self.indexes.iter().find(|meta| {
+            matches!(meta.ty, IndexType::Unique)
+                && meta.expr.is_none()
+                && meta.predicate.is_none()
+                && &meta.column_ids[0] == col_id
+        })
     }
 
     #[allow(dead_code)]
@@ -128,6 +132,8 @@ impl TableCatalog {
         name: String,
         column_ids: Vec<ColumnId>,
         ty: IndexType,
+        expr: Option<ScalarExpression>,
+        predicate: Option<ScalarExpression>,
     ) -> Result<&IndexMeta, DatabaseError> {
         for index in self.indexes.iter() {
             if index.name == name {
@@ -163,7 +169,9 @@ impl TableCatalog {
                 .clone();
             val_tys.push(val_ty)
         }
-        let value_ty = if val_tys.len() == 1 {
+        let value_ty = if let Some(expr) = &expr {
+            expr.return_type()
+        } else if val_tys.len() == 1 {
             val_tys.pop().unwrap()
         } else {
             LogicalType::Tuple(val_tys)
@@ -177,6 +185,8 @@ impl TableCatalog {
             value_ty,
             name,
             ty,
+            expr,
+            predicate,
         };
         self.indexes.push(Arc::new(index));
         Ok(self.indexes.last().unwrap())
@@ -1,35 +1,71 @@
-use crate::binder::{command_type, Binder, BinderContext, CommandType};
+use crate::binder::{command_type, lower_case_name, Binder, BinderContext, CommandType};
+use crate::catalog::{ColumnCatalog, ColumnRef, TableName};
 use crate::errors::DatabaseError;
 use crate::execution::{build_write, Executor};
 use crate::expression::function::scala::ScalarFunctionImpl;
 use crate::expression::function::table::TableFunctionImpl;
 use crate::expression::function::FunctionSummary;
+use crate::function::bit_count::BitCount;
 use crate::function::char_length::CharLength;
 use crate::function::current_date::CurrentDate;
+use crate::function::current_setting::CurrentSetting;
+use crate::function::current_time::CurrentTime;
+use crate::function::current_timestamp::CurrentTimestamp;
+use crate::function::current_user::CurrentUser;
+use crate::function::date_name::DateName;
+use crate::function::date_round::DateRound;
+use crate::function::date_trunc::DateTrunc;
+use crate::function::datediff::DateDiff;
+use crate::function::encode::{Decode, Encode};
+use crate::function::gen_random_uuid::GenRandomUuid;
+use crate::function::greatest_least::GreatestLeast;
+use crate::function::haversine_distance::HaversineDistance;
+use crate::function::json_extract::JsonExtract;
+use crate::function::levenshtein::Levenshtein;
 use crate::function::lower::Lower;
+use crate::function::math::{Math, MathKind};
+use crate::function::number_format::{ToChar, ToNumber};
 use crate::function::numbers::Numbers;
+use crate::function::pad::Pad;
+use crate::function::replace::Replace;
+use crate::function::set_config::SetConfig;
+use crate::function::soundex::Soundex;
+use crate::function::substring::Substring;
+use crate::function::try_divide::TryDivide;
 use crate::function::upper::Upper;
+use crate::function::uuid_v7::UuidV7;
+use crate::function::vector_distance::VectorDistance;
+use crate::function::width_bucket::WidthBucket;
 use crate::optimizer::heuristic::batch::HepBatchStrategy;
 use crate::optimizer::heuristic::optimizer::HepOptimizer;
 use crate::optimizer::rule::implementation::ImplementationRuleImpl;
 use crate::optimizer::rule::normalization::NormalizationRuleImpl;
-use crate::parser::parse_sql;
-use crate::planner::LogicalPlan;
+use crate::parser::parse_sql_with_index_predicates;
+use crate::planner::operator::insert::InsertOperator;
+use crate::planner::operator::values::ValuesOperator;
+use crate::planner::operator::Operator;
+use crate::planner::{Childrens, LogicalPlan};
+use crate::setting::{Settings, SettingsRef, DEFAULT_SETTINGS};
 use crate::storage::rocksdb::RocksStorage;
 use crate::storage::{StatisticsMetaCache, Storage, TableCache, Transaction, ViewCache};
 use crate::types::tuple::{SchemaRef, Tuple};
+use crate::types::tuple_builder::TupleBuilder;
 use crate::types::value::DataValue;
+use crate::types::LogicalType;
 use crate::utils::lru::SharedLruCache;
 use ahash::HashMap;
+use chrono::Local;
 use parking_lot::lock_api::{ArcRwLockReadGuard, ArcRwLockWriteGuard};
 use parking_lot::{RawRwLock, RwLock};
+use sqlparser::ast::{Expr, ObjectName, SetExpr, UnaryOperator, Value};
 use std::hash::RandomState;
 use std::marker::PhantomData;
 use std::mem;
 use std::ops::{Coroutine, CoroutineState};
 use std::path::PathBuf;
 use std::pin::Pin;
-use std::sync::atomic::AtomicUsize;
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 pub(crate) type ScalaFunctions = HashMap<FunctionSummary, Arc<dyn ScalarFunctionImpl>>;
@@ -37,6 +73,107 @@ pub(crate) type TableFunctions = HashMap<FunctionSummary, Arc<dyn TableFunctionI
 
 pub type Statement = sqlparser::ast::Statement;
 
+/// A parsed statement and the versions of the tables and views it was prepared against.
+///
+/// Tips: once a table or view it references is altered or dropped, the statement must be
+/// prepared again, executing it returns [`DatabaseError::SchemaChanged`]
+///
+/// Tips: a [`Statement`] parsed elsewhere is converted with `PreparedStatement::from`,
+/// which tracks no table and is never rejected
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    inner: Statement,
+    schema_versions: Vec<(TableName, usize)>,
+    parameter_types: Vec<(String, LogicalType)>,
+    /// the last key of the keyset page to run, see [`Database::keyset_page`]
+    keyset: Option<Vec<DataValue>>,
+    /// the predicate of a partial index, which `sqlparser` can not parse into `CREATE INDEX`
+    index_predicate: Option<Expr>,
+}
+
+impl From<Statement> for PreparedStatement {
+    fn from(inner: Statement) -> Self {
+        PreparedStatement {
+            inner,
+            schema_versions: vec![],
+            parameter_types: vec![],
+            keyset: None,
+            index_predicate: None,
+        }
+    }
+}
+
+impl PreparedStatement {
+    pub fn statement(&self) -> &Statement {
+        &self.inner
+    }
+
+    /// The types that the placeholders (e.g. `?1`) are expected to have, ordered by their names
+    /// and inferred from the column or expression they are compared with or assigned to.
+    ///
+    /// Tips: the placeholders whose type can not be inferred are missing and accept any value
+    pub fn parameter_types(&self) -> &[(String, LogicalType)] {
+        &self.parameter_types
+    }
+
+    fn check_parameters(&self, params: &[(&'static str, DataValue)]) -> Result<(), DatabaseError> {
+        for (name, value) in params {
+            let Some((_, expected)) = self.parameter_types.iter().find(|(key, _)| key == name)
+            else {
+                continue;
+            };
+            let ty = value.logical_type();
+            let is_string =
+                |ty: &LogicalType| matches!(ty, LogicalType::Char(..) | LogicalType::Varchar(..));
+
+            if !(LogicalType::can_implicit_cast(&ty, expected)
+                || ty.is_numeric() && expected.is_numeric()
+                || is_string(&ty) && is_string(expected))
+            {
+                return Err(DatabaseError::ParameterTypeMismatch(
+                    name.to_string(),
+                    expected.clone(),
+                    ty,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The definition of a table, see [`Database::table_schema`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSchema {
+    pub name: String,
+    /// in the order they were declared
+    pub columns: Vec<ColumnSchema>,
+}
+
+/// The definition of a column, like a row of `DESCRIBE`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSchema {
+    pub name: String,
+    pub datatype: LogicalType,
+    pub nullable: bool,
+    pub is_primary: bool,
+    pub is_unique: bool,
+    /// the text of the default expression
+    pub default: Option<String>,
+}
+
+impl From<&ColumnRef> for ColumnSchema {
+    fn from(column: &ColumnRef) -> Self {
+        ColumnSchema {
+            name: column.name().to_string(),
+            datatype: column.datatype().clone(),
+            nullable: column.nullable(),
+            is_primary: column.desc().is_primary(),
+            is_unique: column.desc().is_unique(),
+            default: column.desc().default.as_ref().map(|expr| expr.to_string()),
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) enum MetaDataLock {
     Read(ArcRwLockReadGuard<RawRwLock, ()>),
@@ -56,12 +193,77 @@ impl DataBaseBuilder {
             scala_functions: Default::default(),
             table_functions: Default::default(),
         };
+        for ty in [
+            LogicalType::Tinyint,
+            LogicalType::Smallint,
+            LogicalType::Integer,
+            LogicalType::Bigint,
+            LogicalType::UTinyint,
+            LogicalType::USmallint,
+            LogicalType::UInteger,
+            LogicalType::UBigint,
+        ] {
+            builder = builder.register_scala_function(BitCount::new(ty));
+        }
         builder = builder.register_scala_function(CharLength::new("char_length".to_lowercase()));
         builder =
             builder.register_scala_function(CharLength::new("character_length".to_lowercase()));
         builder = builder.register_scala_function(CurrentDate::new());
+        builder = builder.register_scala_function(CurrentSetting::new(false));
+        builder = builder.register_scala_function(CurrentSetting::new(true));
+        builder = builder.register_scala_function(CurrentTime::new());
+        builder = builder
+            .register_scala_function(CurrentTimestamp::new("current_timestamp".to_lowercase()));
+        builder = builder.register_scala_function(CurrentTimestamp::new("now".to_lowercase()));
+        builder = builder.register_scala_function(CurrentUser::new("current_user".to_lowercase()));
+        builder = builder.register_scala_function(CurrentUser::new("session_user".to_lowercase()));
+        builder = builder.register_scala_function(DateName::new(false, LogicalType::Date));
+        builder = builder.register_scala_function(DateName::new(false, LogicalType::DateTime));
+        builder = builder.register_scala_function(DateName::new(true, LogicalType::Date));
+        builder = builder.register_scala_function(DateName::new(true, LogicalType::DateTime));
+        builder = builder.register_scala_function(DateRound::new(LogicalType::Date));
+        builder = builder.register_scala_function(DateRound::new(LogicalType::DateTime));
+        builder = builder.register_scala_function(DateRound::new(LogicalType::Time));
+        builder = builder.register_scala_function(DateTrunc::new(LogicalType::Date));
+        builder = builder.register_scala_function(DateTrunc::new(LogicalType::DateTime));
+        builder = builder.register_scala_function(DateDiff::new());
+        builder = builder.register_scala_function(Decode::new());
+        builder = builder.register_scala_function(Encode::new());
+        builder = builder.register_scala_function(GenRandomUuid::new());
+        builder = builder.register_scala_function(GreatestLeast::new(true));
+        builder = builder.register_scala_function(GreatestLeast::new(false));
+        builder = builder.register_scala_function(HaversineDistance::new());
+        builder = builder.register_scala_function(JsonExtract::new());
+        builder = builder.register_scala_function(Levenshtein::new(false));
+        builder = builder.register_scala_function(Levenshtein::new(true));
         builder = builder.register_scala_function(Lower::new());
+        for kind in [
+            MathKind::Abs,
+            MathKind::Ceil,
+            MathKind::Floor,
+            MathKind::Round,
+            MathKind::Power,
+            MathKind::Sqrt,
+            MathKind::Exp,
+            MathKind::Ln,
+            MathKind::Log,
+        ] {
+            builder = builder.register_scala_function(Math::new(kind));
+        }
+        builder = builder.register_scala_function(Pad::new(true));
+        builder = builder.register_scala_function(Pad::new(false));
+        builder = builder.register_scala_function(Replace::new());
+        builder = builder.register_scala_function(SetConfig::new());
+        builder = builder.register_scala_function(Soundex::new());
+        builder = builder.register_scala_function(ToChar::new());
+        builder = builder.register_scala_function(ToNumber::new());
+        builder = builder.register_scala_function(Substring::new("substr".to_lowercase()));
+        builder = builder.register_scala_function(TryDivide::new());
         builder = builder.register_scala_function(Upper::new());
+        builder = builder.register_scala_function(UuidV7::new());
+        builder = builder.register_scala_function(VectorDistance::new(true));
+        builder = builder.register_scala_function(VectorDistance::new(false));
+        builder = builder.register_scala_function(WidthBucket::new());
         builder = builder.register_table_function(Numbers::new());
         builder
     }
@@ -86,15 +288,21 @@ impl DataBaseBuilder {
         let table_cache = SharedLruCache::new(48, 4, RandomState::new())?;
         let view_cache = SharedLruCache::new(12, 4, RandomState::new())?;
 
+        let mut transaction = storage.transaction()?;
+        transaction.upgrade(&table_cache)?;
+        transaction.commit()?;
+
         Ok(Database {
             storage,
             mdl: Default::default(),
+            settings: Default::default(),
             state: Arc::new(State {
                 scala_functions: self.scala_functions,
                 table_functions: self.table_functions,
                 meta_cache,
                 table_cache,
                 view_cache,
+                schema_versions: Default::default(),
                 _p: Default::default(),
             }),
         })
@@ -107,6 +315,8 @@ pub(crate) struct State<S> {
     meta_cache: StatisticsMetaCache,
     table_cache: TableCache,
     view_cache: ViewCache,
+    /// the version of a table or view is bumped by every `DDL` that alters or drops it
+    schema_versions: RwLock<HashMap<TableName, usize>>,
     _p: PhantomData<S>,
 }
 
@@ -126,10 +336,9 @@ impl<S: Storage> State<S> {
     pub(crate) fn view_cache(&self) -> &ViewCache {
         &self.view_cache
     }
-
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn build_plan<A: AsRef<[(&'static str, DataValue)]>>(
-        stmt: &Statement,
+        stmt: &PreparedStatement,
         params: A,
         table_cache: &TableCache,
         view_cache: &ViewCache,
@@ -137,19 +346,22 @@ impl<S: Storage> State<S> {
         transaction: &<S as Storage>::TransactionType<'_>,
         scala_functions: &ScalaFunctions,
         table_functions: &TableFunctions,
+        settings: &SettingsRef,
+        coerced_nulls: Option<Arc<AtomicUsize>>,
     ) -> Result<LogicalPlan, DatabaseError> {
-        let mut binder = Binder::new(
-            BinderContext::new(
-                table_cache,
-                view_cache,
-                transaction,
-                scala_functions,
-                table_functions,
-                Arc::new(AtomicUsize::new(0)),
-            ),
-            &params,
-            None,
+        let mut context = BinderContext::new(
+            table_cache,
+            view_cache,
+            transaction,
+            scala_functions,
+            table_functions,
+            Arc::new(AtomicUsize::new(0)),
         );
+        context.settings = Some(settings.clone());
+        context.keyset = stmt.keyset.clone();
+        context.index_predicate = stmt.index_predicate.clone();
+        context.coerced_nulls = coerced_nulls;
+        let mut binder = Binder::new(context, &params, None);
         /// Build a logical plan.
         ///
         /// SELECT a,b FROM t1 ORDER BY a LIMIT 1;
@@ -157,7 +369,7 @@ impl<S: Storage> State<S> {
         ///   Sort(a)
         ///     Limit(1)
         ///       Project(a,b)
-        let source_plan = binder.bind(stmt)?;
+        let source_plan = binder.bind(&stmt.inner)?;
         // println!("source_plan plan: {:#?}", source_plan);
 
         let best_plan = Self::default_optimizer(source_plan)
@@ -196,7 +408,9 @@ impl<S: Storage> State<S> {
                 vec![
                     NormalizationRuleImpl::LimitProjectTranspose,
                     NormalizationRuleImpl::PushLimitThroughJoin,
+                    NormalizationRuleImpl::PushLimitThroughUnion,
                     NormalizationRuleImpl::PushLimitIntoTableScan,
+                    NormalizationRuleImpl::PushLimitIntoSort,
                 ],
             )
             .batch(
@@ -208,6 +422,20 @@ impl<S: Storage> State<S> {
                     NormalizationRuleImpl::CombineFilter,
                 ],
             )
+            .batch(
+                "Eliminate Operators".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![
+                    NormalizationRuleImpl::EliminateRedundantDistinct,
+                    NormalizationRuleImpl::EliminateRedundantSort,
+                    NormalizationRuleImpl::PushSortIntoTableScan,
+                ],
+            )
+            .batch(
+                "Projection Fusion".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![NormalizationRuleImpl::CombineProjectIntoScan],
+            )
             .batch(
                 "Expression Remapper".to_string(),
                 HepBatchStrategy::once_topdown(),
@@ -247,17 +475,87 @@ impl<S: Storage> State<S> {
             ])
     }
 
-    fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<Statement, DatabaseError> {
-        let mut stmts = parse_sql(sql)?;
-        stmts.pop().ok_or(DatabaseError::EmptyStatement)
+    fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<PreparedStatement, DatabaseError> {
+        let (inner, index_predicate) = parse_sql_with_index_predicates(sql)?
+            .pop()
+            .ok_or(DatabaseError::EmptyStatement)?;
+
+        Ok(PreparedStatement {
+            index_predicate,
+            ..PreparedStatement::from(inner)
+        })
+    }
+
+    /// Bind `statement` without its parameters to infer their types and record the versions of
+    /// the tables and views it references.
+    ///
+    /// Tips: the statements that can not be bound without their parameters (e.g. `LIMIT ?1`)
+    /// are left with the types and tables found before the failure
+    fn bind_prepared(
+        &self,
+        transaction: &S::TransactionType<'_>,
+        statement: &mut PreparedStatement,
+    ) -> Result<(), DatabaseError> {
+        if !matches!(
+            command_type(&statement.inner)?,
+            CommandType::DQL | CommandType::DML
+        ) {
+            return Ok(());
+        }
+        let mut binder = Binder::new(
+            BinderContext::new(
+                self.table_cache(),
+                self.view_cache(),
+                transaction,
+                self.scala_functions(),
+                self.table_functions(),
+                Arc::new(AtomicUsize::new(0)),
+            ),
+            &[],
+            None,
+        )
+        .with_parameter_types()
+        .with_referenced_tables();
+        let _ = binder.bind(&statement.inner);
+        statement.parameter_types = binder.take_parameter_types();
+
+        let schema_versions = self.schema_versions.read();
+        statement.schema_versions = binder
+            .take_referenced_tables()
+            .into_iter()
+            .map(|table_name| {
+                let version = schema_versions.get(&table_name).copied().unwrap_or(0);
+                (table_name, version)
+            })
+            .collect();
+
+        Ok(())
     }
 
     fn execute<'a, A: AsRef<[(&'static str, DataValue)]>>(
         &'a self,
         transaction: &'a mut S::TransactionType<'_>,
-        stmt: &Statement,
+        stmt: &PreparedStatement,
         params: A,
-    ) -> Result<(SchemaRef, Executor<'a>), DatabaseError> {
+        settings: &SettingsRef,
+    ) -> Result<(SchemaRef, Executor<'a>, Arc<AtomicUsize>), DatabaseError> {
+        {
+            let schema_versions = self.schema_versions.read();
+            if stmt.schema_versions.iter().any(|(table_name, version)| {
+                schema_versions.get(table_name).copied().unwrap_or(0) != *version
+            }) {
+                return Err(DatabaseError::SchemaChanged);
+            }
+        }
+        stmt.check_parameters(params.as_ref())?;
+        if let Statement::SetVariable {
+            variable, value, ..
+        } = &stmt.inner
+        {
+            let (schema, executor) = self.set_variable(variable, value, settings)?;
+            return Ok((schema, executor, Default::default()));
+        }
+        let coerced_nulls = Arc::new(AtomicUsize::new(0));
         let mut plan = Self::build_plan(
             stmt,
             params,
@@ -267,7 +565,15 @@ impl<S: Storage> State<S> {
             transaction,
             self.scala_functions(),
             self.table_functions(),
+            settings,
+            settings
+                .snapshot()
+                .lenient_cast()
+                .then(|| coerced_nulls.clone()),
         )?;
+        for table_name in changed_tables(&stmt.inner) {
+            *self.schema_versions.write().entry(table_name).or_default() += 1;
+        }
         let schema = plan.output_schema().clone();
         let executor = build_write(
             plan,
@@ -275,46 +581,199 @@ impl<S: Storage> State<S> {
             transaction,
         );
 
+        Ok((schema, executor, coerced_nulls))
+    }
+
+    fn set_variable<'a>(
+        &self,
+        variable: &ObjectName,
+        value: &[Expr],
+        settings: &SettingsRef,
+    ) -> Result<(SchemaRef, Executor<'a>), DatabaseError> {
+        let name = variable.to_string();
+        let unsupported = || {
+            DatabaseError::UnsupportedStmt(format!(
+                "SET {} only allows a single literal value",
+                name
+            ))
+        };
+        let value = match value {
+            [Expr::Identifier(ident)] if ident.value.eq_ignore_ascii_case("default") => None,
+            [Expr::Identifier(ident)] => Some(DataValue::from(ident.value.clone())),
+            [Expr::Value(value)] => Some(DataValue::try_from(value)?),
+            [Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr,
+            }] => match expr.as_ref() {
+                Expr::Value(Value::Number(number, long)) => Some(DataValue::try_from(
+                    &Value::Number(format!("-{}", number), *long),
+                )?),
+                _ => return Err(unsupported()),
+            },
+            _ => return Err(unsupported()),
+        };
+        settings.set(&name, value)?;
+
+        let schema = Arc::new(vec![ColumnRef::from(ColumnCatalog::new_dummy(
+            "SET SUCCESS".to_string(),
+        ))]);
+        let executor: Executor = Box::new(
+            #[coroutine]
+            move || {
+                yield Ok(TupleBuilder::build_result(name));
+            },
+        );
         Ok((schema, executor))
     }
 }
 
+/// The tables and views altered or dropped by the `DDL`, which prepared statements may reference
+fn changed_tables(stmt: &Statement) -> Vec<TableName> {
+    let names = match stmt {
+        Statement::AlterTable { name, .. }
+        | Statement::CreateView {
+            name,
+            or_replace: true,
+            ..
+        } => slice::from_ref(name),
+        Statement::Drop { names, .. } => names.as_slice(),
+        _ => &[],
+    };
+    names
+        .iter()
+        .filter_map(|name| lower_case_name(name).ok())
+        .map(Arc::new)
+        .collect()
+}
+
+/// Rewrites an ordered `SELECT` into the page that follows `last_key`
+fn keyset_statement(
+    statement: &PreparedStatement,
+    last_key: Option<&[DataValue]>,
+    limit: usize,
+) -> Result<PreparedStatement, DatabaseError> {
+    let unsupported =
+        |reason: &str| DatabaseError::UnsupportedStmt(format!("keyset pagination {}", reason));
+    let Statement::Query(query) = &statement.inner else {
+        return Err(unsupported("only supports queries"));
+    };
+    let mut query = query.clone();
+    if query.limit.is_some() || query.offset.is_some() || query.fetch.is_some() {
+        return Err(unsupported("already controls LIMIT and OFFSET"));
+    }
+    let Some(first) = query.order_by.first() else {
+        return Err(unsupported("requires an ORDER BY"));
+    };
+    let asc = first.asc.unwrap_or(true);
+    if query
+        .order_by
+        .iter()
+        .any(|order_by| order_by.asc.unwrap_or(true) != asc)
+    {
+        return Err(unsupported(
+            "requires all ORDER BY keys in the same direction",
+        ));
+    }
+    query.limit = Some(Expr::Value(Value::Number(limit.to_string(), false)));
+
+    // the page key is bound on the `ORDER BY` of the `SELECT`, see `Binder::bind_keyset`
+    if last_key.is_some() && !matches!(query.body.as_ref(), SetExpr::Select(_)) {
+        return Err(unsupported("only supports a single SELECT"));
+    }
+
+    Ok(PreparedStatement {
+        inner: Statement::Query(query),
+        schema_versions: statement.schema_versions.clone(),
+        parameter_types: statement.parameter_types.clone(),
+        keyset: last_key.map(<[DataValue]>::to_vec),
+        index_predicate: statement.index_predicate.clone(),
+    })
+}
+
 pub struct Database<S: Storage> {
     pub(crate) storage: S,
     mdl: Arc<RwLock<()>>,
+    /// the defaults changed by `SET` run on the database itself,
+    /// each session and transaction starts with a copy of its own
+    settings: SettingsRef,
     pub(crate) state: Arc<State<S>>,
 }
 
 impl<S: Storage> Database<S> {
     /// Run SQL queries.
     pub fn run<T: AsRef<str>>(&self, sql: T) -> Result<DatabaseIter<'_, S>, DatabaseError> {
-        let statement = self.prepare(sql)?;
+        let statement = self.state.prepare(sql)?;
 
         self.execute(&statement, &[])
     }
 
-    pub fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<Statement, DatabaseError> {
-        self.state.prepare(sql)
+    /// Parse a statement with placeholders (e.g. `?1`) and infer the types of its parameters,
+    /// see [`PreparedStatement::parameter_types`].
+    pub fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<PreparedStatement, DatabaseError> {
+        let mut statement = self.state.prepare(sql)?;
+        let _guard = self.mdl.read_arc();
+        let transaction = self.storage.transaction()?;
+        self.state.bind_prepared(&transaction, &mut statement)?;
+
+        Ok(statement)
     }
 
     fn execute<A: AsRef<[(&'static str, DataValue)]>>(
         &self,
-        statement: &Statement,
+        statement: &PreparedStatement,
         params: A,
     ) -> Result<DatabaseIter<S>, DatabaseError> {
-        let _guard = if matches!(command_type(statement)?, CommandType::DDL) {
+        let _guard = if matches!(command_type(&statement.inner)?, CommandType::DDL) {
             MetaDataLock::Write(self.mdl.write_arc())
         } else {
             MetaDataLock::Read(self.mdl.read_arc())
         };
         let transaction = Box::into_raw(Box::new(self.storage.transaction()?));
-        let (schema, executor) =
-            self.state
-                .execute(unsafe { &mut (*transaction) }, statement, params)?;
-        let inner = Box::into_raw(Box::new(TransactionIter::new(schema, executor)));
+        let (schema, executor, coerced_nulls) = self.state.execute(
+            unsafe { &mut (*transaction) },
+            statement,
+            params,
+            &self.settings,
+        )?;
+        let inner = Box::into_raw(Box::new(TransactionIter::new(
+            schema,
+            executor,
+            self.settings.snapshot(),
+            coerced_nulls,
+        )));
         Ok(DatabaseIter { transaction, inner })
     }
 
+    /// Run the page of an ordered query that follows `last_key` (the first page when `None`).
+    ///
+    /// The `ORDER BY` items (including positions and aliases) are the page key: the rows are
+    /// filtered by `(keys) > (last_key)` (or `<` when the keys are descending) before they are
+    /// sorted and the page size becomes the `LIMIT`, so a composite index on the keys is scanned
+    /// from the last seen key instead of skipping an `OFFSET`.
+    ///
+    /// Tips: the keys should be unique and non-null, rows whose key compares as `NULL` are never returned
+    pub fn keyset_page<A: AsRef<[(&'static str, DataValue)]>>(
+        &self,
+        statement: &PreparedStatement,
+        params: A,
+        last_key: Option<&[DataValue]>,
+        limit: usize,
+    ) -> Result<DatabaseIter<'_, S>, DatabaseError> {
+        let statement = keyset_statement(statement, last_key, limit)?;
+
+        self.execute(&statement, params)
+    }
+
+    /// The names of all of the tables, views excluded, in alphabetical order.
+    pub fn table_names(&self) -> Result<Vec<String>, DatabaseError> {
+        self.new_transaction()?.table_names()
+    }
+
+    /// The columns of the table `table_name`, or `None` when it does not exist.
+    pub fn table_schema(&self, table_name: &str) -> Result<Option<TableSchema>, DatabaseError> {
+        self.new_transaction()?.table_schema(table_name)
+    }
+
     pub fn new_transaction(&self) -> Result<DBTransaction<S>, DatabaseError> {
         let guard = self.mdl.read_arc();
         let transaction = self.storage.transaction()?;
@@ -322,15 +781,77 @@ impl<S: Storage> Database<S> {
 
         Ok(DBTransaction {
             inner: transaction,
+            savepoints: Vec::new(),
+            settings: self.settings.fork(),
             _guard: guard,
             state,
         })
     }
+
+    /// A session whose `SET`s are only seen by its own statements and transactions,
+    /// e.g. one for each connection of a server sharing the database.
+    pub fn new_session(&self) -> DBSession<S> {
+        DBSession {
+            inner: Database {
+                storage: self.storage.clone(),
+                mdl: self.mdl.clone(),
+                settings: self.settings.fork(),
+                state: self.state.clone(),
+            },
+        }
+    }
+}
+
+/// The settings of a session start with the ones of the database at the time it is created,
+/// see [`Database::new_session`].
+pub struct DBSession<S: Storage> {
+    inner: Database<S>,
+}
+
+impl<S: Storage> DBSession<S> {
+    pub fn run<T: AsRef<str>>(&self, sql: T) -> Result<DatabaseIter<'_, S>, DatabaseError> {
+        self.inner.run(sql)
+    }
+
+    pub fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<PreparedStatement, DatabaseError> {
+        self.inner.prepare(sql)
+    }
+
+    pub fn keyset_page<A: AsRef<[(&'static str, DataValue)]>>(
+        &self,
+        statement: &PreparedStatement,
+        params: A,
+        last_key: Option<&[DataValue]>,
+        limit: usize,
+    ) -> Result<DatabaseIter<'_, S>, DatabaseError> {
+        self.inner.keyset_page(statement, params, last_key, limit)
+    }
+
+    pub fn table_names(&self) -> Result<Vec<String>, DatabaseError> {
+        self.inner.table_names()
+    }
+
+    pub fn table_schema(&self, table_name: &str) -> Result<Option<TableSchema>, DatabaseError> {
+        self.inner.table_schema(table_name)
+    }
+
+    pub fn new_transaction(&self) -> Result<DBTransaction<S>, DatabaseError> {
+        self.inner.new_transaction()
+    }
 }
 
 pub trait ResultIter: Iterator<Item = Result<Tuple, DatabaseError>> {
     fn schema(&self) -> &SchemaRef;
 
+    /// The session settings at the time the statement was executed
+    fn settings(&self) -> &Settings {
+        &DEFAULT_SETTINGS
+    }
+
+    /// The number of values that failed their `CAST` and were coerced to NULL so far,
+    /// always 0 unless the setting `lenient_cast` is true, see [`Settings::lenient_cast`]
+    fn coerced_nulls(&self) -> usize;
+
     fn done(self) -> Result<(), DatabaseError>;
 }
 
@@ -363,6 +884,14 @@ impl<S: Storage> ResultIter for DatabaseIter<'_, S> {
         unsafe { (*self.inner).schema() }
     }
 
+    fn settings(&self) -> &Settings {
+        unsafe { (*self.inner).settings() }
+    }
+
+    fn coerced_nulls(&self) -> usize {
+        unsafe { (*self.inner).coerced_nulls() }
+    }
+
     fn done(mut self) -> Result<(), DatabaseError> {
         unsafe {
             Box::from_raw(mem::replace(&mut self.inner, std::ptr::null_mut())).done()?;
@@ -376,6 +905,10 @@ impl<S: Storage> ResultIter for DatabaseIter<'_, S> {
 
 pub struct DBTransaction<'a, S: Storage + 'a> {
     inner: S::TransactionType<'a>,
+    /// Savepoints in the order they were set, released or overwritten ones are left unnamed
+    /// since the storage can only unwind its savepoints from the most recent one
+    savepoints: Vec<Option<String>>,
+    settings: SettingsRef,
     _guard: ArcRwLockReadGuard<RawRwLock, ()>,
     state: Arc<State<S>>,
 }
@@ -387,22 +920,217 @@ impl<S: Storage> DBTransaction<'_, S> {
         self.execute(&statement, &[])
     }
 
-    pub fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<Statement, DatabaseError> {
-        self.state.prepare(sql)
+    /// See [`Database::prepare`].
+    pub fn prepare<T: AsRef<str>>(&self, sql: T) -> Result<PreparedStatement, DatabaseError> {
+        let mut statement = self.state.prepare(sql)?;
+        self.state.bind_prepared(&self.inner, &mut statement)?;
+
+        Ok(statement)
     }
 
     pub fn execute<A: AsRef<[(&'static str, DataValue)]>>(
         &mut self,
-        statement: &Statement,
+        statement: &PreparedStatement,
         params: A,
     ) -> Result<TransactionIter, DatabaseError> {
-        if matches!(command_type(statement)?, CommandType::DDL) {
+        if matches!(command_type(&statement.inner)?, CommandType::DDL) {
             return Err(DatabaseError::UnsupportedStmt(
                 "`DDL` is not allowed to execute within a transaction".to_string(),
             ));
         }
-        let (schema, executor) = self.state.execute(&mut self.inner, statement, params)?;
-        Ok(TransactionIter::new(schema, executor))
+        let (schema, executor, coerced_nulls) =
+            self.state
+                .execute(&mut self.inner, statement, params, &self.settings)?;
+        Ok(TransactionIter::new(
+            schema,
+            executor,
+            self.settings.snapshot(),
+            coerced_nulls,
+        ))
+    }
+
+    /// Run the page of an ordered query that follows `last_key`, see [`Database::keyset_page`].
+    pub fn keyset_page<A: AsRef<[(&'static str, DataValue)]>>(
+        &mut self,
+        statement: &PreparedStatement,
+        params: A,
+        last_key: Option<&[DataValue]>,
+        limit: usize,
+    ) -> Result<TransactionIter, DatabaseError> {
+        let statement = keyset_statement(statement, last_key, limit)?;
+
+        self.execute(&statement, params)
+    }
+
+    /// Set a savepoint named `name`, an existing savepoint with the same name is replaced.
+    pub fn savepoint<T: AsRef<str>>(&mut self, name: T) {
+        let name = name.as_ref();
+
+        for savepoint in self.savepoints.iter_mut() {
+            if savepoint.as_deref() == Some(name) {
+                *savepoint = None;
+            }
+        }
+        self.inner.set_savepoint();
+        self.savepoints.push(Some(name.to_string()));
+    }
+
+    /// Discard the writes made after the savepoint `name`, the savepoint itself is kept.
+    pub fn rollback_to<T: AsRef<str>>(&mut self, name: T) -> Result<(), DatabaseError> {
+        let position = self.savepoint_position(name.as_ref())?;
+
+        for _ in position..self.savepoints.len() {
+            self.inner.rollback_to_savepoint()?;
+        }
+        self.savepoints.truncate(position);
+        self.savepoint(name);
+
+        Ok(())
+    }
+
+    /// Remove the savepoint `name` and the ones set after it, keeping their writes.
+    pub fn release<T: AsRef<str>>(&mut self, name: T) -> Result<(), DatabaseError> {
+        let position = self.savepoint_position(name.as_ref())?;
+
+        for savepoint in self.savepoints[position..].iter_mut() {
+            *savepoint = None;
+        }
+        Ok(())
+    }
+
+    fn savepoint_position(&self, name: &str) -> Result<usize, DatabaseError> {
+        self.savepoints
+            .iter()
+            .rposition(|savepoint| savepoint.as_deref() == Some(name))
+            .ok_or_else(|| DatabaseError::SavepointNotFound(name.to_string()))
+    }
+
+    /// Insert `rows` into the `columns` of a table (all of its columns when empty) through a
+    /// single `INSERT`, instead of binding a statement for every row.
+    ///
+    /// Every row is checked against the columns before anything is written, an invalid row is
+    /// reported as [`DatabaseError::BatchRow`] with its index in `rows`.
+    pub fn insert_batch(
+        &mut self,
+        table_name: &str,
+        columns: &[&str],
+        mut rows: Vec<Vec<DataValue>>,
+    ) -> Result<usize, DatabaseError> {
+        let table_name = Arc::new(table_name.to_lowercase());
+        let table = self
+            .inner
+            .table(self.state.table_cache(), table_name.clone())?
+            .ok_or(DatabaseError::TableNotFound)?;
+        let schema_ref: SchemaRef = if columns.is_empty() {
+            Arc::new(table.columns().cloned().collect())
+        } else {
+            Arc::new(
+                columns
+                    .iter()
+                    .map(|name| {
+                        table
+                            .get_column_by_name(&name.to_lowercase())
+                            .cloned()
+                            .ok_or_else(|| DatabaseError::ColumnNotFound(name.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+        };
+        // the omitted columns are filled by their default values
+        let now = Local::now().naive_local();
+        let defaults = table
+            .columns()
+            .map(|column| column.statement_default(Some(now), Some(&self.settings)))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (column, default) in table.columns().zip(defaults.iter()) {
+            if !column.nullable() && !schema_ref.contains(column) && default.is_none() {
+                return Err(DatabaseError::NotNull);
+            }
+        }
+        for (i, row) in rows.iter_mut().enumerate() {
+            let check_row = |row: &mut Vec<DataValue>| {
+                if row.len() != schema_ref.len() {
+                    return Err(DatabaseError::ValuesLenMismatch(
+                        schema_ref.len(),
+                        row.len(),
+                    ));
+                }
+                for (value, column) in row.iter_mut().zip(schema_ref.iter()) {
+                    if value.is_null() {
+                        if !column.nullable() {
+                            return Err(DatabaseError::NotNull);
+                        }
+                        continue;
+                    }
+                    let ty = column.datatype();
+
+                    if &value.logical_type() != ty {
+                        *value = mem::replace(value, DataValue::Null).cast(ty)?;
+                    }
+                    value.check_len(ty)?;
+                }
+                Ok(())
+            };
+            check_row(row).map_err(|err| DatabaseError::BatchRow(i, Box::new(err)))?;
+        }
+        let rows_len = rows.len();
+        let plan = LogicalPlan::new(
+            Operator::Insert(InsertOperator {
+                table_name,
+                is_overwrite: false,
+                is_mapping_by_name: false,
+                defaults,
+                on_conflict: None,
+            }),
+            Childrens::Only(LogicalPlan::new(
+                Operator::Values(ValuesOperator { rows, schema_ref }),
+                Childrens::None,
+            )),
+        );
+        let executor = build_write(
+            plan,
+            (
+                self.state.table_cache(),
+                self.state.view_cache(),
+                self.state.meta_cache(),
+            ),
+            &mut self.inner,
+        );
+        TransactionIter::new(
+            Arc::new(vec![]),
+            executor,
+            self.settings.snapshot(),
+            Default::default(),
+        )
+        .done()?;
+
+        Ok(rows_len)
+    }
+
+    /// See [`Database::table_names`].
+    pub fn table_names(&self) -> Result<Vec<String>, DatabaseError> {
+        let mut names = self
+            .inner
+            .table_metas()?
+            .into_iter()
+            .map(|meta| meta.table_name.to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+
+        Ok(names)
+    }
+
+    /// See [`Database::table_schema`].
+    pub fn table_schema(&self, table_name: &str) -> Result<Option<TableSchema>, DatabaseError> {
+        let table_name = Arc::new(table_name.to_lowercase());
+
+        Ok(self
+            .inner
+            .table(self.state.table_cache(), table_name)?
+            .map(|table| TableSchema {
+                name: table.name().to_string(),
+                columns: table.columns().map(ColumnSchema::from).collect(),
+            }))
     }
 
     pub fn commit(self) -> Result<(), DatabaseError> {
@@ -415,14 +1143,23 @@ impl<S: Storage> DBTransaction<'_, S> {
 pub struct TransactionIter<'a> {
     executor: Executor<'a>,
     schema: SchemaRef,
+    settings: Arc<Settings>,
+    coerced_nulls: Arc<AtomicUsize>,
     is_over: bool,
 }
 
 impl<'a> TransactionIter<'a> {
-    fn new(schema: SchemaRef, executor: Executor<'a>) -> Self {
+    fn new(
+        schema: SchemaRef,
+        executor: Executor<'a>,
+        settings: Arc<Settings>,
+        coerced_nulls: Arc<AtomicUsize>,
+    ) -> Self {
         Self {
             executor,
             schema,
+            settings,
+            coerced_nulls,
             is_over: false,
         }
     }
@@ -449,6 +1186,14 @@ impl ResultIter for TransactionIter<'_> {
         &self.schema
     }
 
+    fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    fn coerced_nulls(&self) -> usize {
+        self.coerced_nulls.load(Ordering::SeqCst)
+    }
+
     fn done(mut self) -> Result<(), DatabaseError> {
         for result in self.by_ref() {
             let _ = result?;
@@ -460,15 +1205,64 @@ impl ResultIter for TransactionIter<'_> {
 #[cfg(test)]
 pub(crate) mod test {
     use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
-    use crate::db::{DataBaseBuilder, DatabaseError, ResultIter};
-    use crate::storage::{Storage, TableCache, Transaction};
-    use crate::types::tuple::Tuple;
-    use crate::types::value::DataValue;
+    use crate::db::{
+        ColumnSchema, DataBaseBuilder, DatabaseError, DatabaseIter, ResultIter, TableSchema,
+        TransactionIter,
+    };
+    use crate::expression::function::scala::{FuncMonotonicity, ScalarFunctionImpl};
+    use crate::expression::function::FunctionSummary;
+    use crate::expression::ScalarExpression;
+    use crate::serdes::{ReferenceSerialization, ReferenceTables};
+    use crate::storage::rocksdb::{RocksStorage, RocksTransaction};
+    use crate::storage::table_codec::{BumpBytes, TableCodec};
+    use crate::storage::{InnerIter, Storage, TableCache, Transaction};
+    use crate::types::index::IndexMetaV1;
+    use crate::types::tuple::{create_table, Tuple};
+    use crate::types::value::{DataValue, Utf8Type};
     use crate::types::LogicalType;
+    use bumpalo::Bump;
     use chrono::{Datelike, Local};
+    use itertools::Itertools;
+    use ordered_float::OrderedFloat;
+    use serde::{Deserialize, Serialize};
+    use sqlparser::ast::CharLengthUnits;
+    use std::collections::Bound;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
     use tempfile::TempDir;
 
+    static COUNTED_EVALS: AtomicUsize = AtomicUsize::new(0);
+
+    /// `counted(v)` returns `v` and counts how many times it is evaluated
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Counted {
+        summary: FunctionSummary,
+    }
+
+    #[typetag::serde]
+    impl ScalarFunctionImpl for Counted {
+        fn eval(
+            &self,
+            exprs: &[ScalarExpression],
+            tuples: Option<(&Tuple, &[ColumnRef])>,
+        ) -> Result<DataValue, DatabaseError> {
+            COUNTED_EVALS.fetch_add(1, Ordering::SeqCst);
+            exprs[0].eval(tuples)
+        }
+
+        fn monotonicity(&self) -> Option<FuncMonotonicity> {
+            None
+        }
+
+        fn return_type(&self) -> &LogicalType {
+            &LogicalType::Integer
+        }
+
+        fn summary(&self) -> &FunctionSummary {
+            &self.summary
+        }
+    }
+
     pub(crate) fn build_table<T: Transaction>(
         table_cache: &TableCache,
         transaction: &mut T,
@@ -511,73 +1305,803 @@ pub(crate) mod test {
         Ok(())
     }
 
-    /// use [CurrentDate](crate::function::current_date::CurrentDate) on this case
     #[test]
-    fn test_udf() -> Result<(), DatabaseError> {
+    fn test_float_precision_and_decimal_scale() -> Result<(), DatabaseError> {
         let temp_dir = TempDir::new().expect("unable to create temporary working directory");
-        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
-        let mut iter = kite_sql.run("select current_date()")?;
+        let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let cells = |iter| -> Result<Vec<String>, DatabaseError> {
+            let table = create_table(iter)?;
+
+            Ok(table
+                .row_iter()
+                .flat_map(|row| row.cell_iter().map(|cell| cell.content()))
+                .collect())
+        };
+
+        database
+            .run("create table t1 (a int primary key, b decimal(10, 3), c double)")?
+            .done()?;
+        database
+            .run("insert into t1 values (0, 1.5, 3.14159), (1, 2, 1.0)")?
+            .done()?;
 
         assert_eq!(
-            iter.schema(),
-            &Arc::new(vec![ColumnRef::from(ColumnCatalog::new(
-                "current_date()".to_string(),
-                true,
-                ColumnDesc::new(LogicalType::Date, None, false, None).unwrap()
-            ))])
+            cells(database.run("select b, c from t1")?)?,
+            vec!["1.500", "3.14159", "2.000", "1.0"]
         );
+
+        database.run("set float_precision = 2")?.done()?;
         assert_eq!(
-            iter.next().unwrap()?,
-            Tuple::new(
-                None,
-                vec![DataValue::Date32(Local::now().num_days_from_ce())]
-            )
+            cells(database.run("select b, c from t1")?)?,
+            vec!["1.500", "3.14", "2.000", "1.00"]
+        );
+
+        database.run("set float_precision to default")?.done()?;
+        assert_eq!(
+            cells(database.run("select c from t1")?)?,
+            vec!["3.14159", "1.0"]
         );
-        assert!(iter.next().is_none());
+
+        assert!(matches!(
+            database.run("set float_precision = -1"),
+            Err(DatabaseError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            database.run("set not_a_setting = 1"),
+            Err(DatabaseError::SettingNotFound(_))
+        ));
 
         Ok(())
     }
 
-    /// use [Numbers](crate::function::numbers::Numbers) on this case
     #[test]
-    fn test_udtf() -> Result<(), DatabaseError> {
+    fn test_transaction_settings() -> Result<(), DatabaseError> {
         let temp_dir = TempDir::new().expect("unable to create temporary working directory");
-        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
-        let mut iter = kite_sql.run(
-            "SELECT * FROM (select * from table(numbers(10)) a ORDER BY number LIMIT 5) OFFSET 3",
-        )?;
+        let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let current = |mut iter: TransactionIter| -> Result<DataValue, DatabaseError> {
+            let tuple = iter.next().unwrap()?;
+            iter.done()?;
 
-        let mut column = ColumnCatalog::new(
-            "number".to_string(),
-            true,
-            ColumnDesc::new(LogicalType::Integer, None, false, None).unwrap(),
+            Ok(tuple.values[0].clone())
+        };
+
+        database.run("set float_precision = 2")?.done()?;
+        let mut transaction = database.new_transaction()?;
+        assert_eq!(
+            transaction.run("select 1")?.settings().float_precision(),
+            Some(2)
         );
-        let number_column_id = iter.schema()[0].id().unwrap();
-        column.set_ref_table(Arc::new("a".to_string()), number_column_id, false);
 
-        assert_eq!(iter.schema(), &Arc::new(vec![ColumnRef::from(column)]));
+        transaction.run("set float_precision = 4")?.done()?;
         assert_eq!(
-            iter.next().unwrap()?,
-            Tuple::new(None, vec![DataValue::Int32(3)])
+            current(transaction.run("select set_config('myapp.user', 'kip', false)")?)?,
+            DataValue::from("kip".to_string())
         );
         assert_eq!(
-            iter.next().unwrap()?,
-            Tuple::new(None, vec![DataValue::Int32(4)])
+            current(transaction.run("select current_setting('float_precision')")?)?,
+            DataValue::from("4".to_string())
         );
+        assert_eq!(
+            transaction.run("select 1")?.settings().float_precision(),
+            Some(4)
+        );
+        database.run("set float_precision = 3")?.done()?;
+        assert_eq!(
+            transaction.run("select 1")?.settings().float_precision(),
+            Some(4)
+        );
+        transaction.commit()?;
+
+        let mut iter = database.run("select current_setting('myapp.user', true)")?;
+        assert_eq!(iter.settings().float_precision(), Some(3));
+        assert_eq!(iter.next().unwrap()?.values[0], DataValue::Null);
+        iter.done()?;
+
         Ok(())
     }
 
     #[test]
-    fn test_prepare_statment() -> Result<(), DatabaseError> {
+    fn test_settings_of_stored_expressions() -> Result<(), DatabaseError> {
         let temp_dir = TempDir::new().expect("unable to create temporary working directory");
-        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
-
-        kite_sql
-            .run("create table t1 (a int primary key, b int)")?
-            .done()?;
-        kite_sql.run("insert into t1 values(0, 0)")?.done()?;
-        kite_sql.run("insert into t1 values(1, 1)")?.done()?;
-        kite_sql.run("insert into t1 values(2, 2)")?.done()?;
+        let current = |mut iter: DatabaseIter<RocksStorage>| -> Result<DataValue, DatabaseError> {
+            let tuple = iter.next().unwrap()?;
+            iter.done()?;
+
+            Ok(tuple.values[0].clone())
+        };
+        {
+            let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+            database
+                .run("create view v_user as select current_setting('myapp.user', true) as u")?
+                .done()?;
+            database
+                .run(
+                    "create table t_user (id int primary key, \
+                     u varchar default current_setting('myapp.user', true))",
+                )?
+                .done()?;
+            database.run("set myapp.user = 'kip'")?.done()?;
+            assert_eq!(
+                current(database.run("select u from v_user")?)?,
+                DataValue::from("kip".to_string())
+            );
+        }
+        // the view and the default are read back from the catalog
+        let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let session = database.new_session();
+
+        session.run("set myapp.user = 'sql'")?.done()?;
+        assert_eq!(
+            current(session.run("select u from v_user")?)?,
+            DataValue::from("sql".to_string())
+        );
+        assert_eq!(
+            current(database.run("select u from v_user")?)?,
+            DataValue::Null
+        );
+
+        session.run("insert into t_user (id) values (0)")?.done()?;
+        assert_eq!(
+            current(session.run("select u from t_user")?)?,
+            DataValue::from("sql".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_settings() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let current = |mut iter: DatabaseIter<RocksStorage>| -> Result<DataValue, DatabaseError> {
+            let tuple = iter.next().unwrap()?;
+            iter.done()?;
+
+            Ok(tuple.values[0].clone())
+        };
+
+        database.run("set float_precision = 2")?.done()?;
+        let session_1 = database.new_session();
+        let session_2 = database.new_session();
+
+        session_1.run("set float_precision = 4")?.done()?;
+        session_1
+            .run("select set_config('myapp.user', 'kip', false)")?
+            .done()?;
+        assert_eq!(
+            session_1.run("select 1")?.settings().float_precision(),
+            Some(4)
+        );
+        assert_eq!(
+            session_2.run("select 1")?.settings().float_precision(),
+            Some(2)
+        );
+        assert_eq!(
+            current(session_1.run("select current_setting('myapp.user', true)")?)?,
+            DataValue::from("kip".to_string())
+        );
+        assert_eq!(
+            current(session_2.run("select current_setting('myapp.user', true)")?)?,
+            DataValue::Null
+        );
+
+        let mut transaction = session_2.new_transaction()?;
+        assert_eq!(
+            transaction.run("select 1")?.settings().float_precision(),
+            Some(2)
+        );
+        transaction.commit()?;
+
+        // the defaults of the database are untouched by its sessions
+        assert_eq!(
+            database.run("select 1")?.settings().float_precision(),
+            Some(2)
+        );
+        assert_eq!(
+            database
+                .new_session()
+                .run("select 1")?
+                .settings()
+                .float_precision(),
+            Some(2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lenient_cast() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let database = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        database
+            .run("create table staging (id int primary key, v varchar)")?
+            .done()?;
+        database
+            .run(
+                "insert into staging values (0, '10'), (1, 'abc'), (2, null), (3, '30'), (4, '4x')",
+            )?
+            .done()?;
+        database
+            .run("create table clean (id int primary key, n int)")?
+            .done()?;
+
+        let mut iter = database.run("select id, cast(v as int) from staging")?;
+        assert!(iter.any(|tuple| matches!(tuple, Err(DatabaseError::ParseInt(_)))));
+
+        database.run("set lenient_cast = true")?.done()?;
+        let mut iter = database.run("select id, cast(v as int) as n into clean from staging")?;
+        for tuple in iter.by_ref() {
+            let _ = tuple?;
+        }
+        // a NULL is not a failed cast
+        assert_eq!(iter.coerced_nulls(), 2);
+        iter.done()?;
+
+        let iter = database.run("select n from clean")?;
+        assert_eq!(iter.coerced_nulls(), 0);
+        assert_eq!(
+            iter.map(|tuple| tuple.map(|tuple| tuple.values[0].clone()))
+                .collect::<Result<Vec<_>, _>>()?,
+            vec![
+                DataValue::Int32(10),
+                DataValue::Null,
+                DataValue::Null,
+                DataValue::Int32(30),
+                DataValue::Null,
+            ]
+        );
+
+        // so are the ones of the select list of a common table expression
+        let mut iter = database
+            .run("with c as (select id, cast(v as int) as n from staging) select n from c")?;
+        for tuple in iter.by_ref() {
+            let _ = tuple?;
+        }
+        assert_eq!(iter.coerced_nulls(), 2);
+        iter.done()?;
+
+        // only the casts of the select list are lenient
+        let mut iter = database.run("select id from staging where cast(v as int) > 0")?;
+        assert!(iter.any(|tuple| tuple.is_err()));
+
+        // a view is not bound with the settings of the statement creating it
+        database
+            .run("create view lenient_view as select id, cast(v as int) as n from staging")?
+            .done()?;
+
+        database.run("set lenient_cast = false")?.done()?;
+        let mut iter = database.run("select cast(v as int) from staging")?;
+        assert!(iter.any(|tuple| tuple.is_err()));
+
+        let mut iter = database.run("select n from lenient_view")?;
+        assert!(iter.any(|tuple| tuple.is_err()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_uncorrelated_in_subquery_evaluated_once() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let database = DataBaseBuilder::path(temp_dir.path())
+            .register_scala_function(Arc::new(Counted {
+                summary: FunctionSummary {
+                    name: "counted".to_string(),
+                    arg_types: vec![LogicalType::Integer],
+                },
+            }))
+            .build()?;
+        let evaluate = |sql: &str| -> Result<(usize, usize), DatabaseError> {
+            COUNTED_EVALS.store(0, Ordering::SeqCst);
+            let mut iter = database.run(sql)?;
+            let rows = iter.by_ref().collect::<Result<Vec<_>, _>>()?.len();
+            iter.done()?;
+
+            Ok((rows, COUNTED_EVALS.load(Ordering::SeqCst)))
+        };
+
+        evaluate("create table t1 (id int primary key, a int, b int)")?;
+        evaluate("create table t2 (id int primary key, v int)")?;
+        evaluate("insert into t1 values (0, 0, 1), (1, 1, 2), (2, 2, 3), (3, 3, 4)")?;
+        evaluate("insert into t2 values (0, 1), (1, 2), (2, 5)")?;
+
+        // hash join
+        assert_eq!(
+            evaluate("select id from t1 where a in (select counted(v) from t2)")?,
+            (2, 3)
+        );
+        assert_eq!(
+            evaluate("select id from t1 where a not in (select counted(v) from t2)")?,
+            (2, 3)
+        );
+        // nested loop join
+        assert_eq!(
+            evaluate("select id from t1 where a + 1 in (select counted(v) from t2)")?,
+            (2, 3)
+        );
+        // the same subquery appears twice
+        assert_eq!(
+            evaluate(
+                "select id from t1 where a in (select counted(v) from t2) and b in (select counted(v) from t2)"
+            )?,
+            (1, 6)
+        );
+
+        Ok(())
+    }
+
+    /// use [CurrentDate](crate::function::current_date::CurrentDate) on this case
+    #[test]
+    fn test_udf() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let mut iter = kite_sql.run("select current_date()")?;
+
+        assert_eq!(
+            iter.schema(),
+            &Arc::new(vec![ColumnRef::from(ColumnCatalog::new(
+                "current_date()".to_string(),
+                true,
+                ColumnDesc::new(LogicalType::Date, None, false, None).unwrap()
+            ))])
+        );
+        assert_eq!(
+            iter.next().unwrap()?,
+            Tuple::new(
+                None,
+                vec![DataValue::Date32(Local::now().num_days_from_ce())]
+            )
+        );
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_now_within_statement() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key)")?
+            .done()?;
+        kite_sql
+            .run("insert into t1 values (0), (1), (2)")?
+            .done()?;
+
+        let mut iter = kite_sql.run("select now(), current_timestamp, now() from t1")?;
+        assert!(iter
+            .schema()
+            .iter()
+            .all(|column| column.datatype() == &LogicalType::DateTime));
+        let rows = iter.by_ref().collect::<Result<Vec<_>, _>>()?;
+        iter.done()?;
+
+        let now = rows[0].values[0].clone();
+        assert_eq!(rows.len(), 3);
+        assert!(rows
+            .iter()
+            .flat_map(|row| row.values.iter())
+            .all(|value| value == &now));
+
+        // a view reads the clock of the statement querying it, not the one of its creation
+        kite_sql
+            .run("create view v1 as select current_timestamp as at")?
+            .done()?;
+        let mut iter = kite_sql.run("select at, now() from v1")?;
+        let row = iter.next().unwrap()?;
+        iter.done()?;
+        assert_eq!(row.values[0], row.values[1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_now_within_statement() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, created datetime default current_timestamp, at datetime null)")?
+            .done()?;
+        let values = (0..2000).map(|i| format!("({})", i)).join(", ");
+        kite_sql
+            .run(format!("insert into t1 (a) values {}", values))?
+            .done()?;
+        let mut iter = kite_sql.run("select count(distinct created) from t1")?;
+        assert_eq!(iter.next().unwrap()?.values, vec![DataValue::Int32(1)]);
+        iter.done()?;
+        // the default agrees with `now()` of the same statement
+        kite_sql
+            .run("update t1 set created = default, at = now()")?
+            .done()?;
+        let mut iter = kite_sql.run("select count(*) from t1 where created = at")?;
+        assert_eq!(iter.next().unwrap()?.values, vec![DataValue::Int32(2000)]);
+        iter.done()?;
+
+        Ok(())
+    }
+
+    /// use [Numbers](crate::function::numbers::Numbers) on this case
+    #[test]
+    fn test_udtf() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+        let mut iter = kite_sql.run(
+            "SELECT * FROM (select * from table(numbers(10)) a ORDER BY number LIMIT 5) OFFSET 3",
+        )?;
+
+        let mut column = ColumnCatalog::new(
+            "number".to_string(),
+            true,
+            ColumnDesc::new(LogicalType::Integer, None, false, None).unwrap(),
+        );
+        let number_column_id = iter.schema()[0].id().unwrap();
+        column.set_ref_table(Arc::new("a".to_string()), number_column_id, false);
+
+        assert_eq!(iter.schema(), &Arc::new(vec![ColumnRef::from(column)]));
+        assert_eq!(
+            iter.next().unwrap()?,
+            Tuple::new(None, vec![DataValue::Int32(3)])
+        );
+        assert_eq!(
+            iter.next().unwrap()?,
+            Tuple::new(None, vec![DataValue::Int32(4)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_introspection() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t2 (id int primary key, name varchar(10) unique null, score int default 1)")?
+            .done()?;
+        kite_sql
+            .run("create table t1 (a bigint primary key)")?
+            .done()?;
+        kite_sql.run("create view v1 as select * from t1")?.done()?;
+
+        assert_eq!(kite_sql.table_names()?, vec!["t1", "t2"]);
+        assert_eq!(
+            kite_sql.table_schema("T2")?,
+            Some(TableSchema {
+                name: "t2".to_string(),
+                columns: vec![
+                    ColumnSchema {
+                        name: "id".to_string(),
+                        datatype: LogicalType::Integer,
+                        nullable: false,
+                        is_primary: true,
+                        is_unique: false,
+                        default: None,
+                    },
+                    ColumnSchema {
+                        name: "name".to_string(),
+                        datatype: LogicalType::Varchar(Some(10), CharLengthUnits::Characters),
+                        nullable: true,
+                        is_primary: false,
+                        is_unique: true,
+                        default: None,
+                    },
+                    ColumnSchema {
+                        name: "score".to_string(),
+                        datatype: LogicalType::Integer,
+                        nullable: true,
+                        is_primary: false,
+                        is_unique: false,
+                        default: Some("1".to_string()),
+                    },
+                ],
+            })
+        );
+        assert_eq!(kite_sql.table_schema("v1")?, None);
+        assert_eq!(kite_sql.table_schema("t3")?, None);
+
+        kite_sql.run("drop table t2")?.done()?;
+        let transaction = kite_sql.new_transaction()?;
+        assert_eq!(transaction.table_names()?, vec!["t1"]);
+        assert_eq!(transaction.table_schema("t2")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyset_page() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (id int primary key, a int, b int)")?
+            .done()?;
+        kite_sql.run("create index idx_a_b on t1 (a, b)")?.done()?;
+        let values = (0..200)
+            .rev()
+            .map(|i| format!("({}, {}, {})", i, i / 10, i % 10))
+            .join(", ");
+        kite_sql
+            .run(format!("insert into t1 values {}", values))?
+            .done()?;
+        kite_sql.run("analyze table t1")?.done()?;
+
+        let statement =
+            kite_sql.prepare("select id, a, b from t1 where id % 3 != ?1 order by a, b")?;
+        let params = [("?1", DataValue::Int32(0))];
+        let mut last_key = None;
+        let mut ids = Vec::new();
+        loop {
+            let iter = kite_sql.keyset_page(&statement, params.clone(), last_key.as_deref(), 7)?;
+            let tuples = iter.collect::<Result<Vec<_>, _>>()?;
+            assert!(tuples.len() <= 7);
+            let Some(last) = tuples.last() else {
+                break;
+            };
+            last_key = Some(last.values[1..].to_vec());
+            ids.extend(tuples.iter().map(|tuple| tuple.values[0].clone()));
+        }
+        assert_eq!(
+            ids,
+            (0..200)
+                .filter(|i| i % 3 != 0)
+                .map(DataValue::Int32)
+                .collect_vec()
+        );
+
+        let statement = kite_sql.prepare("select id from t1 order by id desc")?;
+        let mut transaction = kite_sql.new_transaction()?;
+        let mut last_key = None;
+        let mut ids = Vec::new();
+        loop {
+            let iter = transaction.keyset_page(&statement, [], last_key.as_deref(), 30)?;
+            let tuples = iter.collect::<Result<Vec<_>, _>>()?;
+            let Some(last) = tuples.last() else {
+                break;
+            };
+            last_key = Some(last.values.clone());
+            ids.extend(tuples.iter().map(|tuple| tuple.values[0].clone()));
+        }
+        transaction.commit()?;
+        assert_eq!(ids, (0..200).rev().map(DataValue::Int32).collect_vec());
+
+        // positions and aliases are keys as they are sorted on, with any number of them
+        let statement = kite_sql.prepare(
+            "select id, a * 10 + b as k, a, b, a, b, a, b, a, b from t1 order by 2, id, a, b, 5, 6, 7, 8, 9",
+        )?;
+        let last_key = [55, 55, 5, 5, 5, 5, 5, 5, 5].map(DataValue::Int32);
+        let tuples = kite_sql
+            .keyset_page(&statement, [], Some(&last_key), 3)?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            tuples
+                .iter()
+                .map(|tuple| tuple.values[0].clone())
+                .collect_vec(),
+            (56..59).map(DataValue::Int32).collect_vec()
+        );
+        let statement = kite_sql.prepare("select id as k from t1 order by k desc")?;
+        let tuples = kite_sql
+            .keyset_page(&statement, [], Some(&[DataValue::Int32(3)]), 10)?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            tuples
+                .iter()
+                .map(|tuple| tuple.values[0].clone())
+                .collect_vec(),
+            (0..3).rev().map(DataValue::Int32).collect_vec()
+        );
+        assert!(kite_sql
+            .keyset_page(
+                &statement,
+                [],
+                Some(&[DataValue::Int32(3), DataValue::Int32(3)]),
+                10
+            )
+            .is_err());
+
+        // the page starts at the `ORDER BY` of the statement, not of the derived table
+        let statement = kite_sql
+            .prepare("select s.id from (select id, a from t1 order by a desc) s order by s.id")?;
+        let tuples = kite_sql
+            .keyset_page(&statement, [], Some(&[DataValue::Int32(3)]), 5)?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            tuples
+                .iter()
+                .map(|tuple| tuple.values[0].clone())
+                .collect_vec(),
+            (4..9).map(DataValue::Int32).collect_vec()
+        );
+
+        let statement = kite_sql.prepare("select id from t1 order by a, b desc")?;
+        assert!(kite_sql.keyset_page(&statement, [], None, 10).is_err());
+        let statement = kite_sql.prepare("select id from t1 order by a limit 1")?;
+        assert!(kite_sql.keyset_page(&statement, [], None, 10).is_err());
+
+        Ok(())
+    }
+
+    /// rewrites the index metas of `table_name` into the format of the storage versions before `2`
+    fn rewrite_index_metas_into_v1(
+        transaction: &mut RocksTransaction,
+        table_name: &str,
+    ) -> Result<(), DatabaseError> {
+        let arena = Bump::new();
+        let table_codec = TableCodec::default();
+        let (min, max) = table_codec.index_meta_bound(table_name);
+
+        let mut iter = transaction.range(Bound::Included(min), Bound::Included(max))?;
+        let mut entries = Vec::new();
+        while let Some(entry) = iter.try_next()? {
+            entries.push(entry);
+        }
+        drop(iter);
+        for (key, value) in entries {
+            let index_meta = TableCodec::decode_index_meta::<RocksTransaction>(&value)?;
+            assert!(index_meta.expr.is_none() && index_meta.predicate.is_none());
+
+            let mut old_key = BumpBytes::new_in(&arena);
+            old_key.extend_from_slice(&key);
+            let mut old_value = BumpBytes::new_in(&arena);
+            IndexMetaV1 {
+                id: index_meta.id,
+                column_ids: index_meta.column_ids,
+                table_name: index_meta.table_name,
+                pk_ty: index_meta.pk_ty,
+                value_ty: index_meta.value_ty,
+                name: index_meta.name,
+                ty: index_meta.ty,
+            }
+            .encode(&mut old_value, true, &mut ReferenceTables::new())?;
+            transaction.set(old_key, old_value)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_index_metas_v1() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        {
+            let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+            kite_sql
+                .run("create table t1 (id int primary key, a int, b int)")?
+                .done()?;
+            kite_sql.run("create index idx_a on t1 (a)")?.done()?;
+            kite_sql.run("create unique index uk_b on t1 (b)")?.done()?;
+            kite_sql
+                .run("insert into t1 values (0, 0, 0), (1, 10, 1), (2, 20, 2)")?
+                .done()?;
+
+            // rewrite the database into the storage version `1`
+            let arena = Bump::new();
+            let table_codec = TableCodec::default();
+            let mut transaction = kite_sql.storage.transaction()?;
+            rewrite_index_metas_into_v1(&mut transaction, "t1")?;
+
+            let mut version = BumpBytes::new_in(&arena);
+            version.extend_from_slice(&1u32.to_be_bytes());
+            transaction.set(table_codec.encode_version_key(), version)?;
+            transaction.commit()?;
+        }
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        let mut iter = kite_sql.run("explain select id from t1 where a = 10")?;
+        let plan = iter.next().unwrap()?.values[0].utf8().unwrap().to_string();
+        assert!(plan.contains("IndexScan By idx_a"), "{}", plan);
+        iter.done()?;
+
+        let mut iter = kite_sql.run("select id from t1 where a = 10")?;
+        assert_eq!(iter.next().unwrap()?.values, vec![DataValue::Int32(1)]);
+        assert!(iter.next().is_none());
+        iter.done()?;
+
+        assert!(kite_sql
+            .run("insert into t1 values (3, 30, 2)")?
+            .done()
+            .is_err());
+        kite_sql
+            .run("create index idx_sum on t1 ((a + b)) where a > 0")?
+            .done()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_index_keys_without_null_tag() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        {
+            let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+            kite_sql
+                .run("create table t1 (id int primary key, a int null)")?
+                .done()?;
+            kite_sql.run("create index idx_a on t1 (a)")?.done()?;
+            let values = (0..200)
+                .map(|i| {
+                    if i % 4 == 0 {
+                        format!("({}, null)", i)
+                    } else {
+                        format!("({}, {})", i, i)
+                    }
+                })
+                .join(", ");
+            kite_sql
+                .run(format!("insert into t1 values {}", values))?
+                .done()?;
+
+            // rewrite the database into the storage version `0`: index keys have no NULL tag
+            let arena = Bump::new();
+            let table_codec = TableCodec::default();
+            let mut transaction = kite_sql.storage.transaction()?;
+            let index_id = transaction
+                .table(kite_sql.state.table_cache(), Arc::new("t1".to_string()))?
+                .unwrap()
+                .indexes()
+                .find(|index_meta| index_meta.name == "idx_a")
+                .unwrap()
+                .id;
+            let (min, max) = table_codec.index_bound("t1", &index_id)?;
+            let tag_offset = min.len();
+
+            let mut iter = transaction.range(Bound::Included(min), Bound::Included(max))?;
+            let mut entries = Vec::new();
+            while let Some(entry) = iter.try_next()? {
+                entries.push(entry);
+            }
+            drop(iter);
+            for (key, value) in entries {
+                transaction.remove(&key)?;
+
+                let mut old_key = BumpBytes::new_in(&arena);
+                old_key.extend_from_slice(&key[..tag_offset]);
+                old_key.extend_from_slice(&key[tag_offset + 1..]);
+                let mut old_value = BumpBytes::new_in(&arena);
+                old_value.extend_from_slice(&value);
+                transaction.set(old_key, old_value)?;
+            }
+            rewrite_index_metas_into_v1(&mut transaction, "t1")?;
+            transaction.remove(&table_codec.encode_version_key())?;
+            transaction.commit()?;
+        }
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+        kite_sql.run("analyze table t1")?.done()?;
+
+        for (sql, ids) in [
+            ("select id from t1 where a = 5", vec![5]),
+            (
+                "select id from t1 where a is null",
+                (0..200).step_by(4).collect_vec(),
+            ),
+            ("select id from t1 where a > 195", vec![197, 198, 199]),
+        ] {
+            let mut iter = kite_sql.run(format!("explain {}", sql))?;
+            let plan = iter.next().unwrap()?.values[0].utf8().unwrap().to_string();
+            assert!(plan.contains("IndexScan By idx_a"), "{}", plan);
+            iter.done()?;
+
+            let iter = kite_sql.run(sql)?;
+            let mut tuples = iter
+                .map(|tuple| Ok(tuple?.values[0].i32().unwrap()))
+                .collect::<Result<Vec<_>, DatabaseError>>()?;
+            tuples.sort();
+            assert_eq!(tuples, ids);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_statment() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b int)")?
+            .done()?;
+        kite_sql.run("insert into t1 values(0, 0)")?.done()?;
+        kite_sql.run("insert into t1 values(1, 1)")?.done()?;
+        kite_sql.run("insert into t1 values(2, 2)")?.done()?;
 
         // Filter
         {
@@ -636,13 +2160,143 @@ pub(crate) mod test {
         TableScan t1 -> [a, b] [SeqScan]
     Projection [t1.a, t1.b] [Project]
       Filter (t1.a > 1), Is Having: false [Filter]
-        TableScan t1 -> [a, b] [SeqScan]"
+        TableScan t1 -> [a, b] [IndexScan By pk_index => (1, +inf)]"
             )
         }
 
         Ok(())
     }
 
+    #[test]
+    fn test_prepare_statement_schema_changed() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b int)")?
+            .done()?;
+        kite_sql.run("insert into t1 values(0, 0)")?.done()?;
+
+        let statement = kite_sql.prepare("select * from t1 where a = ?1")?;
+        let params = [("?1", DataValue::Int32(0))];
+        {
+            let iter = kite_sql.execute(&statement, &params)?;
+            assert_eq!(iter.schema().len(), 2);
+            iter.done()?;
+        }
+        // creating or altering another table leaves the prepared statement untouched
+        kite_sql
+            .run("create table t2 (a int primary key)")?
+            .done()?;
+        kite_sql.execute(&statement, &params)?.done()?;
+        let sub_query_statement =
+            kite_sql.prepare("select a from t1 where a in (select a from t2)")?;
+        kite_sql.run("alter table t2 add column b int")?.done()?;
+        kite_sql.execute(&statement, &params)?.done()?;
+        // the tables of the subqueries are tracked as well
+        assert!(matches!(
+            kite_sql.execute(&sub_query_statement, []),
+            Err(DatabaseError::SchemaChanged)
+        ));
+
+        kite_sql.run("alter table t1 add column c int")?.done()?;
+        assert!(matches!(
+            kite_sql.execute(&statement, &params),
+            Err(DatabaseError::SchemaChanged)
+        ));
+        let statement = kite_sql.prepare("select * from t1 where a = ?1")?;
+        {
+            let iter = kite_sql.execute(&statement, &params)?;
+            assert_eq!(iter.schema().len(), 3);
+            iter.done()?;
+        }
+
+        kite_sql.run("drop table t1")?.done()?;
+        let mut transaction = kite_sql.new_transaction()?;
+        assert!(matches!(
+            transaction.execute(&statement, &params),
+            Err(DatabaseError::SchemaChanged)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepare_statement_parameter_types() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b varchar(10), c double)")?
+            .done()?;
+        kite_sql.run("insert into t1 values(0, 'a', 0.5)")?.done()?;
+
+        let statement = kite_sql
+            .prepare("update t1 set b = ?1, c = (?2) where a = ?3 and c between ?4 and ?5")?;
+        assert_eq!(
+            statement.parameter_types(),
+            [
+                (
+                    "?1".to_string(),
+                    LogicalType::Varchar(Some(10), CharLengthUnits::Characters)
+                ),
+                ("?2".to_string(), LogicalType::Double),
+                ("?3".to_string(), LogicalType::Integer),
+                ("?4".to_string(), LogicalType::Double),
+                ("?5".to_string(), LogicalType::Double),
+            ]
+        );
+        let params = [
+            (
+                "?1",
+                DataValue::Utf8 {
+                    value: "b".to_string(),
+                    ty: Utf8Type::Variable(None),
+                    unit: CharLengthUnits::Characters,
+                },
+            ),
+            ("?2", DataValue::Int32(1)),
+            ("?3", DataValue::Int64(0)),
+            ("?4", DataValue::Int32(0)),
+            ("?5", DataValue::Float64(OrderedFloat(1.0))),
+        ];
+        kite_sql.execute(&statement, &params)?.done()?;
+
+        let mut iter = kite_sql.run("select b, c from t1")?;
+        assert_eq!(
+            iter.next().unwrap()?.values,
+            vec![
+                DataValue::Utf8 {
+                    value: "b".to_string(),
+                    ty: Utf8Type::Variable(Some(10)),
+                    unit: CharLengthUnits::Characters,
+                },
+                DataValue::Float64(OrderedFloat(1.0)),
+            ]
+        );
+        iter.done()?;
+
+        let mut params = params.to_vec();
+        params[2].1 = DataValue::Utf8 {
+            value: "0".to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        assert!(matches!(
+            kite_sql.execute(&statement, &params),
+            Err(DatabaseError::ParameterTypeMismatch(name, LogicalType::Integer, _)) if name == "?3"
+        ));
+        let transaction = kite_sql.new_transaction()?;
+        let statement =
+            transaction.prepare("select * from t1 where a in (select a from t1 where c > ?1)")?;
+        assert_eq!(
+            statement.parameter_types(),
+            [("?1".to_string(), LogicalType::Double)]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_transaction_sql() -> Result<(), DatabaseError> {
         let temp_dir = TempDir::new().expect("unable to create temporary working directory");
@@ -694,4 +2348,176 @@ pub(crate) mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_transaction_savepoint() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b int)")?
+            .done()?;
+
+        let mut tx = kite_sql.new_transaction()?;
+        tx.run("insert into t1 values(0, 0)")?.done()?;
+        tx.savepoint("s1");
+        tx.run("insert into t1 values(1, 1)")?.done()?;
+        tx.savepoint("s2");
+        tx.run("insert into t1 values(2, 2)")?.done()?;
+        tx.release("s2")?;
+        assert!(matches!(
+            tx.rollback_to("s2"),
+            Err(DatabaseError::SavepointNotFound(_))
+        ));
+        tx.rollback_to("s1")?;
+        tx.run("insert into t1 values(3, 3)")?.done()?;
+        // the savepoint is kept after rolling back to it
+        tx.rollback_to("s1")?;
+        // re-using a name moves the savepoint
+        tx.run("insert into t1 values(4, 4)")?.done()?;
+        tx.savepoint("s1");
+        tx.run("insert into t1 values(5, 5)")?.done()?;
+        tx.rollback_to("s1")?;
+        tx.commit()?;
+
+        let mut iter = kite_sql.run("select * from t1")?;
+        assert_eq!(
+            iter.next().unwrap()?.values,
+            vec![DataValue::Int32(0), DataValue::Int32(0)]
+        );
+        assert_eq!(
+            iter.next().unwrap()?.values,
+            vec![DataValue::Int32(4), DataValue::Int32(4)]
+        );
+        assert!(iter.next().is_none());
+        iter.done()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_failed_insert_keeps_index() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b int, c int not null)")?
+            .done()?;
+        kite_sql.run("create index idx_b on t1 (b)")?.done()?;
+
+        let mut tx = kite_sql.new_transaction()?;
+        // the third row is rejected after the first two are appended
+        assert!(matches!(
+            tx.run("insert into t1 values (0, 0, 0), (1, 1, 1), (2, 2, null)")
+                .and_then(|iter| iter.done()),
+            Err(DatabaseError::NotNull)
+        ));
+        tx.commit()?;
+
+        let rows = |sql: &str| -> Result<Vec<Tuple>, DatabaseError> {
+            kite_sql.run(sql)?.collect::<Result<Vec<_>, _>>()
+        };
+        let full_scan = rows("select a, b from t1")?;
+        assert_eq!(full_scan.len(), 2);
+        assert_eq!(full_scan, rows("select a, b from t1 where b >= 0")?);
+        assert!(
+            rows("explain select a, b from t1 where b >= 0")?[0].values[0]
+                .to_string()
+                .contains("IndexScan By idx_b")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_batch() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b varchar(8), c int default 7)")?
+            .done()?;
+        kite_sql.run("create index idx_b on t1 (b)")?.done()?;
+
+        let rows = (0..10_000)
+            .map(|i| {
+                vec![
+                    // cast to the type of the column
+                    DataValue::Int64(i),
+                    DataValue::from(format!("b_{}", i)),
+                ]
+            })
+            .collect_vec();
+        let mut transaction = kite_sql.new_transaction()?;
+        assert_eq!(transaction.insert_batch("t1", &["a", "b"], rows)?, 10_000);
+        transaction.commit()?;
+
+        let mut iter = kite_sql.run("select count(*), sum(a), sum(c) from t1")?;
+        assert_eq!(
+            iter.next().unwrap()?.values,
+            vec![
+                DataValue::Int32(10_000),
+                DataValue::Int32(49_995_000),
+                DataValue::Int32(70_000)
+            ]
+        );
+        iter.done()?;
+        let mut iter = kite_sql.run("select a, c from t1 where b = 'b_9999'")?;
+        assert_eq!(
+            iter.next().unwrap()?.values,
+            vec![DataValue::Int32(9999), DataValue::Int32(7)]
+        );
+        iter.done()?;
+
+        let mut transaction = kite_sql.new_transaction()?;
+        for (columns, rows, expected) in [
+            (
+                vec![],
+                vec![
+                    vec![DataValue::Int32(10_000), DataValue::Null, DataValue::Null],
+                    vec![DataValue::Int32(10_001), DataValue::Null],
+                ],
+                (1, "values length not match, expect 3, got 2"),
+            ),
+            (
+                vec!["a", "b"],
+                vec![
+                    vec![DataValue::Int32(10_000), DataValue::Null],
+                    vec![
+                        DataValue::Int32(10_001),
+                        DataValue::from("b_too_long".to_string()),
+                    ],
+                ],
+                (1, "too long"),
+            ),
+            (
+                vec!["b", "a"],
+                vec![vec![DataValue::from("b".to_string()), DataValue::Null]],
+                (0, "cannot be Null"),
+            ),
+        ] {
+            let Err(DatabaseError::BatchRow(i, err)) =
+                transaction.insert_batch("t1", &columns, rows)
+            else {
+                unreachable!()
+            };
+            assert_eq!(i, expected.0);
+            assert!(err.to_string().contains(expected.1), "{}", err);
+        }
+        assert!(matches!(
+            transaction.insert_batch("t1", &["d"], vec![]),
+            Err(DatabaseError::ColumnNotFound(_))
+        ));
+        assert!(matches!(
+            transaction.insert_batch("t1", &["b"], vec![]),
+            Err(DatabaseError::NotNull)
+        ));
+        transaction.commit()?;
+
+        let mut iter = kite_sql.run("select count(*) from t1")?;
+        assert_eq!(iter.next().unwrap()?.values, vec![DataValue::Int32(10_000)]);
+        iter.done()?;
+
+        Ok(())
+    }
 }
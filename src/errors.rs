@@ -11,6 +11,8 @@ use std::string::FromUtf8Error;
 pub enum DatabaseError {
     #[error("agg miss: {0}")]
     AggMiss(String),
+    #[error("row {0} of the batch: {1}")]
+    BatchRow(usize, #[source] Box<DatabaseError>),
     #[error("bindcode: {0}")]
     Bincode(
         #[source]
@@ -19,8 +21,12 @@ pub enum DatabaseError {
     ),
     #[error("cache size overflow")]
     CacheSizeOverFlow,
+    #[error("argument of CASE/WHEN must be type boolean, not type {0}")]
+    CaseConditionNotBoolean(LogicalType),
     #[error("cast fail")]
     CastFail,
+    #[error("comparison operators cannot be chained: {0}, use `x BETWEEN a AND b` or `a < x AND x < b` instead")]
+    ChainedComparison(String),
     #[error("channel close")]
     ChannelClose,
     #[error("columns empty")]
@@ -29,6 +35,10 @@ pub enum DatabaseError {
     ColumnIdNotFound(String),
     #[error("column: {0} not found")]
     ColumnNotFound(String),
+    #[error("there is no primary key or unique index matching the ON CONFLICT target: {0}")]
+    ConflictTargetNotFound(String),
+    #[error("line {0} of the file: {1}")]
+    CopyLine(u64, #[source] Box<DatabaseError>),
     #[error("csv error: {0}")]
     Csv(
         #[from]
@@ -39,8 +49,12 @@ pub enum DatabaseError {
     DefaultNotColumnRef,
     #[error("default does not exist")]
     DefaultNotExist,
+    #[error("division by zero")]
+    DivisionByZero,
     #[error("column: {0} already exists")]
     DuplicateColumn(String),
+    #[error("common table expression: {0} already exists")]
+    DuplicateCte(String),
     #[error("table or view: {0} hash already exists")]
     DuplicateSourceHash(String),
     #[error("index: {0} already exists")]
@@ -49,6 +63,8 @@ pub enum DatabaseError {
     DuplicatePrimaryKey,
     #[error("the column has been declared unique and the value already exists")]
     DuplicateUniqueValue,
+    #[error("ON CONFLICT DO UPDATE command cannot affect row a second time")]
+    DuplicateConflictUpdate,
     #[error("function: {0} not found")]
     FunctionNotFound(String),
     #[error("empty plan")]
@@ -85,12 +101,16 @@ pub enum DatabaseError {
     MisMatch(&'static str, &'static str),
     #[error("add column must be nullable or specify a default value")]
     NeedNullAbleOrDefault,
+    #[error("parameter: {0} expects {1}, but got {2}")]
+    ParameterTypeMismatch(String, LogicalType, LogicalType),
     #[error("parameter: {0} not found")]
     ParametersNotFound(String),
     #[error("no transaction begin")]
     NoTransactionBegin,
     #[error("cannot be Null")]
     NotNull,
+    #[error("numeric value out of range")]
+    NumericOverflow,
     #[error("parser bool: {0}")]
     ParseBool(
         #[source]
@@ -131,10 +151,22 @@ pub enum DatabaseError {
         #[from]
         rocksdb::Error,
     ),
+    #[error("savepoint: {0} does not exist")]
+    SavepointNotFound(String),
+    #[error("the schema has changed since the statement was prepared, it must be prepared again")]
+    SchemaChanged,
+    #[error("unrecognized setting: {0}")]
+    SettingNotFound(String),
+    #[error("each {0} query must have the same number of columns, left: {1}, right: {2}")]
+    SetOperationColumnsMismatch(String, usize, usize),
+    #[error("{0} types do not match at column {1}: {2} and {3}")]
+    SetOperationTypesMismatch(String, usize, LogicalType, LogicalType),
     #[error("the number of caches cannot be divisible by the number of shards")]
     SharedNotAlign,
     #[error("the table or view not found")]
     SourceNotFound,
+    #[error("more than one row returned by a subquery used as an expression")]
+    SubQueryMoreThanOneRow,
     #[error("the table already exists")]
     TableExists,
     #[error("the table not found")]
@@ -48,12 +48,14 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for AddColumn {
                 }
                 types.push(column.datatype().clone());
 
+                // the existing rows share the default evaluated once for the statement
+                let default_value = throw!(column.default_value(None, None));
                 let mut coroutine = build_read(self.input, cache, transaction);
 
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
                     let mut tuple: Tuple = throw!(tuple);
 
-                    if let Some(value) = throw!(column.default_value()) {
+                    if let Some(value) = default_value.clone() {
                         if let Some(unique_values) = &mut unique_values {
                             unique_values.push((tuple.id().unwrap().clone(), value.clone()));
                         }
@@ -6,7 +6,7 @@ use crate::planner::operator::create_index::CreateIndexOperator;
 use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::Index;
+use crate::types::index::{Index, IndexMeta};
 use crate::types::tuple::Tuple;
 use crate::types::tuple_builder::TupleBuilder;
 use crate::types::value::DataValue;
@@ -41,23 +41,40 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for CreateIndex {
                     columns,
                     if_not_exists,
                     ty,
+                    expr,
+                    predicate,
                 } = self.op;
 
-                let (column_ids, column_exprs): (Vec<ColumnId>, Vec<ScalarExpression>) = columns
-                    .into_iter()
-                    .filter_map(|column| {
-                        column
-                            .id()
-                            .map(|id| (id, ScalarExpression::ColumnRef(column)))
-                    })
-                    .unzip();
+                let (column_ids, mut column_exprs): (Vec<ColumnId>, Vec<ScalarExpression>) =
+                    columns
+                        .into_iter()
+                        .filter_map(|column| {
+                            column
+                                .id()
+                                .map(|id| (id, ScalarExpression::ColumnRef(column)))
+                        })
+                        .unzip();
+                if let Some(expr) = &expr {
+                    let mut expr = expr.clone();
+                    throw!(expr.bind_evaluator());
+                    column_exprs = vec![expr];
+                }
                 let schema = self.input.output_schema().clone();
+                let bound_predicate = if let Some(predicate) = &predicate {
+                    let mut predicate = predicate.clone();
+                    throw!(predicate.bind_evaluator());
+                    Some(predicate)
+                } else {
+                    None
+                };
                 let index_id = match unsafe { &mut (*transaction) }.add_index_meta(
                     cache.0,
                     &table_name,
                     index_name,
                     column_ids,
                     ty,
+                    expr,
+                    predicate,
                 ) {
                     Ok(index_id) => index_id,
                     Err(DatabaseError::DuplicateIndex(index_name)) => {
@@ -74,6 +91,13 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for CreateIndex {
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
                     let mut tuple: Tuple = throw!(tuple);
 
+                    if !throw!(IndexMeta::is_indexed(
+                        bound_predicate.as_ref(),
+                        &tuple,
+                        &schema
+                    )) {
+                        continue;
+                    }
                     let Some(value) = DataValue::values_to_tuple(throw!(Projection::projection(
                         &tuple,
                         &column_exprs,
@@ -4,11 +4,12 @@ use crate::execution::dql::projection::Projection;
 use crate::execution::{build_read, Executor, WriteExecutor};
 use crate::optimizer::core::histogram::HistogramBuilder;
 use crate::optimizer::core::statistics_meta::StatisticsMeta;
+use crate::optimizer::core::table_statistics::TableStatisticsBuilder;
 use crate::planner::operator::analyze::AnalyzeOperator;
 use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::IndexMetaRef;
+use crate::types::index::{IndexMeta, IndexMetaRef};
 use crate::types::tuple::Tuple;
 use crate::types::value::{DataValue, Utf8Type};
 use itertools::Itertools;
@@ -77,16 +78,24 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Analyze {
                     builders.push((
                         index.id,
                         throw!(index.column_exprs(&table)),
+                        throw!(index.predicate_expr()),
                         HistogramBuilder::new(index, None),
                     ));
                 }
 
+                let mut table_statistics_builder =
+                    throw!(TableStatisticsBuilder::new(table_name.clone(), &schema));
                 let mut coroutine = build_read(input, cache, transaction);
 
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
                     let tuple = throw!(tuple);
+                    table_statistics_builder.append(&tuple.values);
 
-                    for (_, exprs, builder) in builders.iter_mut() {
+                    for (_, exprs, predicate, builder) in builders.iter_mut() {
+                        // a partial index only holds the rows its predicate passes
+                        if !throw!(IndexMeta::is_indexed(predicate.as_ref(), &tuple, &schema)) {
+                            continue;
+                        }
                         let values = throw!(Projection::projection(&tuple, exprs, &schema));
 
                         if values.len() == 1 {
@@ -97,6 +106,8 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Analyze {
                     }
                 }
                 drop(coroutine);
+                throw!(unsafe { &mut (*transaction) }
+                    .save_table_statistics(&table_statistics_builder.build()));
                 let mut values = Vec::with_capacity(builders.len());
                 let dir_path = dirs::home_dir()
                     .expect("Your system does not have a Config directory!")
@@ -108,13 +119,22 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Analyze {
 
                 let mut active_index_paths = HashSet::new();
 
-                for (index_id, _, builder) in builders {
+                for (index_id, _, predicate, builder) in builders {
+                    // a partial index may hold fewer values than the buckets
+                    let number_of_buckets = if predicate.is_some() {
+                        DEFAULT_NUM_OF_BUCKETS.min(builder.values_len())
+                    } else {
+                        DEFAULT_NUM_OF_BUCKETS
+                    };
+                    if number_of_buckets == 0 {
+                        continue;
+                    }
                     let index_file = OsStr::new(&index_id.to_string()).to_os_string();
                     let path = dir_path.join(&index_file);
                     let temp_path = path.with_extension("tmp");
                     let path_str: String = path.to_string_lossy().into();
 
-                    let (histogram, sketch) = throw!(builder.build(DEFAULT_NUM_OF_BUCKETS));
+                    let (histogram, sketch) = throw!(builder.build(number_of_buckets));
                     let meta = StatisticsMeta::new(histogram, sketch);
 
                     throw!(meta.to_file(&temp_path));
@@ -166,14 +186,21 @@ mod test {
     use crate::execution::dml::analyze::{DEFAULT_NUM_OF_BUCKETS, DEFAULT_STATISTICS_META_PATH};
     use crate::optimizer::core::statistics_meta::StatisticsMeta;
     use crate::storage::rocksdb::RocksTransaction;
+    use crate::storage::{Storage, Transaction};
+    use crate::types::value::{DataValue, Utf8Type};
+    use chrono::{Duration, Utc};
+    use itertools::Itertools;
+    use sqlparser::ast::CharLengthUnits;
     use std::ffi::OsStr;
     use std::fs;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     #[test]
     fn test_analyze() -> Result<(), DatabaseError> {
         test_statistics_meta()?;
         test_clean_expired_index()?;
+        test_table_statistics()?;
 
         Ok(())
     }
@@ -269,4 +296,87 @@ mod test {
 
         Ok(())
     }
+
+    fn test_table_statistics() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let kite_sql = DataBaseBuilder::path(temp_dir.path()).build()?;
+
+        kite_sql
+            .run("create table t1 (a int primary key, b int null, c varchar null)")?
+            .done()?;
+        let values = (0..DEFAULT_NUM_OF_BUCKETS + 1)
+            .map(|i| {
+                if i % 10 == 0 {
+                    format!("({i}, null, 'v{}')", i % 7)
+                } else {
+                    format!("({i}, {}, 'v{}')", i % 20, i % 7)
+                }
+            })
+            .join(", ");
+        kite_sql
+            .run(format!("insert into t1 values {values}"))?
+            .done()?;
+        let table_name = "t1".to_string();
+        {
+            let transaction = kite_sql.storage.transaction()?;
+            let loader = transaction.meta_loader(kite_sql.state.meta_cache());
+
+            assert!(loader.load_table(&table_name)?.is_none());
+        }
+
+        let before = Utc::now();
+        kite_sql.run("analyze table t1")?.done()?;
+        let after = Utc::now();
+
+        let transaction = kite_sql.storage.transaction()?;
+        let table = transaction
+            .table(kite_sql.state.table_cache(), Arc::new(table_name.clone()))?
+            .unwrap()
+            .clone();
+        let loader = transaction.meta_loader(kite_sql.state.meta_cache());
+        let statistics = loader.load_table(&table_name)?.unwrap();
+
+        assert_eq!(statistics.row_count(), 101);
+        assert!(statistics.analyzed_at() >= before - Duration::milliseconds(1));
+        assert!(statistics.analyzed_at() <= after);
+
+        for (name, null_count, distinct_count, min, max) in [
+            ("a", 0, 101, DataValue::Int32(0), DataValue::Int32(100)),
+            ("b", 11, 18, DataValue::Int32(1), DataValue::Int32(19)),
+            (
+                "c",
+                0,
+                7,
+                DataValue::Utf8 {
+                    value: "v0".to_string(),
+                    ty: Utf8Type::Variable(None),
+                    unit: CharLengthUnits::Characters,
+                },
+                DataValue::Utf8 {
+                    value: "v6".to_string(),
+                    ty: Utf8Type::Variable(None),
+                    unit: CharLengthUnits::Characters,
+                },
+            ),
+        ] {
+            let column = statistics
+                .column(table.get_column_id_by_name(name).unwrap())
+                .unwrap();
+
+            assert_eq!(column.null_count(), null_count);
+            // HyperLogLog is an estimate
+            assert!(column.distinct_count().abs_diff(distinct_count) <= 1);
+            assert_eq!(column.min(), &min);
+            assert_eq!(column.max(), &max);
+        }
+        drop(transaction);
+
+        kite_sql.run("drop table t1")?.done()?;
+        let transaction = kite_sql.storage.transaction()?;
+        let loader = transaction.meta_loader(kite_sql.state.meta_cache());
+
+        assert!(loader.load_table(&table_name)?.is_none());
+
+        Ok(())
+    }
 }
@@ -90,24 +90,42 @@ impl CopyFromFile {
                 .escape(escape.map(|c| c as u8))
                 .has_headers(header)
                 .from_reader(&mut buf_reader),
+            FileFormat::Json => {
+                return Err(DatabaseError::UnsupportedStmt(
+                    "COPY FROM in JSON format".to_string(),
+                ))
+            }
         };
 
         let column_count = self.op.schema_ref.len();
         let tuple_builder = TupleBuilder::new(&self.op.schema_ref, Some(&pk_indices));
 
+        // quoted fields may contain delimiters, quotes (doubled) and line breaks as RFC 4180
         for record in reader.records() {
             // read records and push raw str rows into data chunk builder
-            let record = record?;
+            let record = match record {
+                Ok(record) => record,
+                Err(err) => {
+                    let line = err.position().map_or(0, |position| position.line());
+                    return Err(DatabaseError::CopyLine(line, Box::new(err.into())));
+                }
+            };
+            let line = record.position().map_or(0, |position| position.line());
 
             if !(record.len() == column_count
                 || record.len() == column_count + 1 && record.get(column_count) == Some(""))
             {
-                return Err(DatabaseError::MisMatch("columns", "values"));
+                return Err(DatabaseError::CopyLine(
+                    line,
+                    Box::new(DatabaseError::MisMatch("columns", "values")),
+                ));
             }
+            let tuple = tuple_builder
+                .build_with_row(record.iter().take(column_count))
+                .map_err(|err| DatabaseError::CopyLine(line, Box::new(err)))?;
 
             self.size += 1;
-            tx.send(tuple_builder.build_with_row(record.iter())?)
-                .map_err(|_| DatabaseError::ChannelClose)?;
+            tx.send(tuple).map_err(|_| DatabaseError::ChannelClose)?;
         }
         Ok(())
     }
@@ -128,6 +146,7 @@ mod tests {
     use crate::db::{DataBaseBuilder, ResultIter};
     use crate::errors::DatabaseError;
     use crate::storage::Storage;
+    use crate::types::value::DataValue;
     use crate::types::LogicalType;
     use sqlparser::ast::CharLengthUnits;
     use std::io::Write;
@@ -236,4 +255,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn read_malformed_csv() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().unwrap();
+        let db = DataBaseBuilder::path(temp_dir.path()).build()?;
+        db.run("create table test_copy (a int primary key, b varchar)")?
+            .done()?;
+
+        for (csv, expected_line) in [("a,b\n1,\"x,y\"\ntwo,z\n", 3), ("1,x\n2\n", 2)] {
+            let mut file = tempfile::NamedTempFile::new().expect("failed to create temp file");
+            write!(file, "{}", csv).expect("failed to write file");
+
+            let result = db
+                .run(format!(
+                    "copy test_copy from '{}' (header true)",
+                    file.path().display()
+                ))?
+                .collect::<Result<Vec<_>, _>>();
+            assert!(
+                matches!(result, Err(DatabaseError::CopyLine(line, _)) if line == expected_line),
+                "{:?}",
+                result
+            );
+        }
+        let mut iter = db.run("select count(*) from test_copy")?;
+        assert_eq!(iter.next().unwrap()?.values, vec![DataValue::Int32(0)]);
+        iter.done()?;
+
+        Ok(())
+    }
 }
@@ -6,6 +6,9 @@ use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
 use crate::types::tuple_builder::TupleBuilder;
+use crate::types::value::DataValue;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::ops::Coroutine;
 use std::ops::CoroutineState;
 use std::pin::Pin;
@@ -34,22 +37,18 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for CopyToFile {
                 let CopyToFile { input, .. } = self;
 
                 let mut coroutine = build_read(input, cache, transaction);
+                let mut result = Ok(());
 
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
-                    let tuple = throw!(tuple);
-
-                    throw!(writer
-                        .write_record(
-                            tuple
-                                .values
-                                .iter()
-                                .map(|v| v.to_string())
-                                .collect::<Vec<_>>()
-                        )
-                        .map_err(DatabaseError::from));
+                    result = tuple.and_then(|tuple| writer.write(&tuple.values));
+                    if result.is_err() {
+                        break;
+                    }
                 }
-
-                throw!(writer.flush().map_err(DatabaseError::from));
+                // the rows written before an error are still flushed into the file
+                let flushed = writer.flush();
+                throw!(result);
+                throw!(flushed);
 
                 yield Ok(TupleBuilder::build_result(format!("{}", self.op)));
             },
@@ -57,8 +56,101 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for CopyToFile {
     }
 }
 
+enum FileWriter {
+    Csv(Box<csv::Writer<File>>),
+    Json {
+        writer: BufWriter<File>,
+        columns: Vec<String>,
+    },
+}
+
+impl FileWriter {
+    /// NULL is written as an empty field in CSV and `null` in JSON
+    fn write(&mut self, values: &[DataValue]) -> Result<(), DatabaseError> {
+        match self {
+            FileWriter::Csv(writer) => writer.write_record(values.iter().map(|value| {
+                if value.is_null() {
+                    String::new()
+                } else {
+                    value.to_string()
+                }
+            }))?,
+            FileWriter::Json { writer, columns } => {
+                let mut line = String::from("{");
+
+                for (i, (column, value)) in columns.iter().zip(values).enumerate() {
+                    if i > 0 {
+                        line.push(',');
+                    }
+                    push_json_string(&mut line, column);
+                    line.push(':');
+                    push_json_value(&mut line, value);
+                }
+                line.push_str("}\n");
+                writer.write_all(line.as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), DatabaseError> {
+        match self {
+            FileWriter::Csv(writer) => writer.flush()?,
+            FileWriter::Json { writer, .. } => writer.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Numbers, booleans and NULL are written as JSON literals, lists as arrays
+/// and the other values as the strings they display as.
+fn push_json_value(buf: &mut String, value: &DataValue) {
+    match value {
+        DataValue::Null => buf.push_str("null"),
+        DataValue::Boolean(_)
+        | DataValue::Int8(_)
+        | DataValue::Int16(_)
+        | DataValue::Int32(_)
+        | DataValue::Int64(_)
+        | DataValue::UInt8(_)
+        | DataValue::UInt16(_)
+        | DataValue::UInt32(_)
+        | DataValue::UInt64(_)
+        | DataValue::Decimal(_) => buf.push_str(&value.to_string()),
+        DataValue::Float32(v) if v.is_finite() => buf.push_str(&value.to_string()),
+        DataValue::Float64(v) if v.is_finite() => buf.push_str(&value.to_string()),
+        DataValue::Tuple(values, _) => {
+            buf.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    buf.push(',');
+                }
+                push_json_value(buf, value);
+            }
+            buf.push(']');
+        }
+        _ => push_json_string(buf, &value.to_string()),
+    }
+}
+
+fn push_json_string(buf: &mut String, value: &str) {
+    buf.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c.is_control() => buf.push_str(&format!("\\u{:04x}", c as u32)),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
 impl CopyToFile {
-    fn create_writer(&self) -> Result<csv::Writer<std::fs::File>, DatabaseError> {
+    fn create_writer(&self) -> Result<FileWriter, DatabaseError> {
         let mut writer = match self.op.target.format {
             FileFormat::Csv {
                 delimiter,
@@ -70,6 +162,17 @@ impl CopyToFile {
                 .quote(quote as u8)
                 .has_headers(header)
                 .from_path(self.op.target.path.clone())?,
+            FileFormat::Json => {
+                return Ok(FileWriter::Json {
+                    writer: BufWriter::new(File::create(&self.op.target.path)?),
+                    columns: self
+                        .op
+                        .schema_ref
+                        .iter()
+                        .map(|c| c.name().to_string())
+                        .collect(),
+                })
+            }
         };
 
         if let FileFormat::Csv { header: true, .. } = self.op.target.format {
@@ -82,7 +185,7 @@ impl CopyToFile {
             writer.write_record(headers)?;
         }
 
-        Ok(writer)
+        Ok(FileWriter::Csv(Box::new(writer)))
     }
 }
 
@@ -220,4 +323,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn write_query() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new()?;
+        let db = DataBaseBuilder::path(temp_dir.path()).build()?;
+        db.run(
+            "create table t1 (a int primary key, b decimal(5,2), c date, d datetime, e varchar)",
+        )?
+        .done()?;
+        db.run(
+            "insert into t1 values (1, 1.5, '2024-01-02', '2024-01-02 03:04:05', 'a,\"b\"'), \
+                                   (2, null, null, null, null), \
+                                   (3, 2, '2024-03-04', '2024-03-04 00:00:00', 'x')",
+        )?
+        .done()?;
+
+        let csv_path = temp_dir.path().join("out.csv");
+        db.run(format!(
+            "copy (select a, b, c, d, e from t1 where a < 3) to '{}' (format csv, header true)",
+            csv_path.display()
+        ))?
+        .done()?;
+        let mut rdr = csv::Reader::from_path(&csv_path)?;
+        assert_eq!(rdr.headers()?, vec!["a", "b", "c", "d", "e"]);
+        let records = rdr.records().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            records,
+            vec![
+                vec!["1", "1.50", "2024-01-02", "2024-01-02 03:04:05", "a,\"b\""],
+                vec!["2", "", "", "", ""],
+            ]
+        );
+
+        let json_path = temp_dir.path().join("out.json");
+        db.run(format!(
+            "copy t1 to '{}' (format json)",
+            json_path.display()
+        ))?
+        .done()?;
+        assert_eq!(
+            std::fs::read_to_string(&json_path)?,
+            "{\"a\":1,\"b\":1.50,\"c\":\"2024-01-02\",\"d\":\"2024-01-02 03:04:05\",\"e\":\"a,\\\"b\\\"\"}\n\
+             {\"a\":2,\"b\":null,\"c\":null,\"d\":null,\"e\":null}\n\
+             {\"a\":3,\"b\":2.00,\"c\":\"2024-03-04\",\"d\":\"2024-03-04 00:00:00\",\"e\":\"x\"}\n"
+        );
+
+        // the rows before the failing one are still in the file
+        let error_path = temp_dir.path().join("error.csv");
+        let result = db
+            .run(format!(
+                "copy (select a, cast(e as int) from t1 where a > 1) to '{}' (format csv)",
+                error_path.display()
+            ))?
+            .collect::<Result<Vec<_>, _>>();
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&error_path)?, "2,\n");
+
+        Ok(())
+    }
 }
@@ -7,10 +7,11 @@ use crate::planner::operator::delete::DeleteOperator;
 use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::{Index, IndexId, IndexType};
+use crate::types::index::{Index, IndexId, IndexMeta, IndexType};
 use crate::types::tuple::Tuple;
 use crate::types::tuple_builder::TupleBuilder;
 use crate::types::value::DataValue;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ops::Coroutine;
 use std::ops::CoroutineState;
@@ -54,32 +55,29 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Delete {
                     let mut tuple: Tuple = throw!(tuple);
 
                     for index_meta in table.indexes() {
-                        if let Some(Value { exprs, values, .. }) = indexes.get_mut(&index_meta.id) {
-                            let Some(data_value) = DataValue::values_to_tuple(throw!(
-                                Projection::projection(&tuple, exprs, &schema)
-                            )) else {
-                                continue;
-                            };
-                            values.push(data_value);
-                        } else {
-                            let mut values = Vec::with_capacity(table.indexes().len());
-                            let exprs = throw!(index_meta.column_exprs(table));
-                            let Some(data_value) = DataValue::values_to_tuple(throw!(
-                                Projection::projection(&tuple, &exprs, &schema)
-                            )) else {
-                                continue;
-                            };
-                            values.push(data_value);
-
-                            indexes.insert(
-                                index_meta.id,
-                                Value {
-                                    exprs,
-                                    values,
-                                    index_ty: index_meta.ty,
-                                },
-                            );
+                        let Value {
+                            exprs,
+                            predicate,
+                            values,
+                            ..
+                        } = match indexes.entry(index_meta.id) {
+                            Entry::Occupied(entry) => entry.into_mut(),
+                            Entry::Vacant(entry) => entry.insert(Value {
+                                exprs: throw!(index_meta.column_exprs(table)),
+                                predicate: throw!(index_meta.predicate_expr()),
+                                values: Vec::with_capacity(table.indexes().len()),
+                                index_ty: index_meta.ty,
+                            }),
+                        };
+                        if !throw!(IndexMeta::is_indexed(predicate.as_ref(), &tuple, &schema)) {
+                            continue;
                         }
+                        let Some(data_value) = DataValue::values_to_tuple(throw!(
+                            Projection::projection(&tuple, exprs, &schema)
+                        )) else {
+                            continue;
+                        };
+                        values.push(data_value);
                     }
                     if let Some(tuple_id) = tuple.id() {
                         for (
@@ -110,6 +108,7 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Delete {
 
 struct Value {
     exprs: Vec<ScalarExpression>,
+    predicate: Option<ScalarExpression>,
     values: Vec<DataValue>,
     index_ty: IndexType,
 }
@@ -1,18 +1,20 @@
-use crate::catalog::{ColumnCatalog, TableName};
+use crate::catalog::{ColumnCatalog, ColumnRef, TableName};
 use crate::errors::DatabaseError;
 use crate::execution::dql::projection::Projection;
 use crate::execution::{build_read, Executor, WriteExecutor};
-use crate::planner::operator::insert::InsertOperator;
+use crate::expression::ScalarExpression;
+use crate::planner::operator::insert::{InsertOperator, OnConflict, OnConflictAction};
 use crate::planner::LogicalPlan;
-use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::storage::{IndexBatch, StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::Index;
-use crate::types::tuple::Tuple;
+use crate::types::index::{Index, IndexId, IndexMeta, IndexMetaRef};
+use crate::types::tuple::{Tuple, TupleId};
 use crate::types::tuple_builder::TupleBuilder;
 use crate::types::value::DataValue;
-use crate::types::ColumnId;
+use crate::types::{ColumnId, LogicalType};
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::ops::Coroutine;
 use std::ops::CoroutineState;
 use std::pin::Pin;
@@ -22,6 +24,8 @@ pub struct Insert {
     input: LogicalPlan,
     is_overwrite: bool,
     is_mapping_by_name: bool,
+    defaults: Vec<Option<ScalarExpression>>,
+    on_conflict: Option<OnConflict>,
 }
 
 impl From<(InsertOperator, LogicalPlan)> for Insert {
@@ -31,6 +35,8 @@ impl From<(InsertOperator, LogicalPlan)> for Insert {
                 table_name,
                 is_overwrite,
                 is_mapping_by_name,
+                defaults,
+                on_conflict,
             },
             input,
         ): (InsertOperator, LogicalPlan),
@@ -40,6 +46,8 @@ impl From<(InsertOperator, LogicalPlan)> for Insert {
             input,
             is_overwrite,
             is_mapping_by_name,
+            defaults,
+            on_conflict,
         }
     }
 }
@@ -74,20 +82,15 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Insert {
                     mut input,
                     is_overwrite,
                     is_mapping_by_name,
+                    defaults,
+                    on_conflict,
                 } = self;
+                let mut inserted = 0;
+                let mut updated = 0;
 
+                // an omitted primary key is filled by its default or rejected as null below
                 let schema = input.output_schema().clone();
 
-                let primary_keys = schema
-                    .iter()
-                    .filter_map(|column| column.desc().primary().map(|i| (i, column)))
-                    .sorted_by_key(|(i, _)| *i)
-                    .map(|(_, col)| col.key(is_mapping_by_name))
-                    .collect_vec();
-                if primary_keys.is_empty() {
-                    throw!(Err(DatabaseError::NotNull))
-                }
-
                 if let Some(table_catalog) =
                     throw!(unsafe { &mut (*transaction) }.table(cache.0, table_name.clone()))
                         .cloned()
@@ -95,65 +98,276 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Insert {
                     let mut index_metas = Vec::new();
                     for index_meta in table_catalog.indexes() {
                         let exprs = throw!(index_meta.column_exprs(&table_catalog));
-                        index_metas.push((index_meta, exprs));
+                        let predicate = throw!(index_meta.predicate_expr());
+                        index_metas.push((index_meta, exprs, predicate));
                     }
 
                     let types = table_catalog.types();
                     let indices = table_catalog.primary_keys_indices();
+                    let table_schema = table_catalog.schema_ref();
+                    // the existing row followed by the row proposed for insertion
+                    let conflict_schema = table_schema
+                        .iter()
+                        .cloned()
+                        .chain(OnConflict::excluded_schema(&table_catalog))
+                        .collect_vec();
+                    let mut index_batch = IndexBatch::default();
+                    // the primary keys of the rows inserted or updated by the statement
+                    let mut written_pks = HashSet::new();
                     let mut coroutine = build_read(input, cache, transaction);
 
-                    while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
-                        let Tuple { values, .. } = throw!(tuple);
-
-                        let mut tuple_map = HashMap::new();
-                        for (i, value) in values.into_iter().enumerate() {
-                            tuple_map.insert(schema[i].key(is_mapping_by_name), value);
-                        }
-                        let mut values = Vec::with_capacity(table_catalog.columns_len());
+                    let mut insert_tuples = || -> Result<(), DatabaseError> {
+                        while let CoroutineState::Yielded(tuple) =
+                            Pin::new(&mut coroutine).resume(())
+                        {
+                            let Tuple { values, .. } = tuple?;
 
-                        for col in table_catalog.columns() {
-                            let value = {
-                                let mut value = tuple_map.remove(&col.key(is_mapping_by_name));
+                            let mut tuple_map = HashMap::new();
+                            for (i, value) in values.into_iter().enumerate() {
+                                tuple_map.insert(schema[i].key(is_mapping_by_name), value);
+                            }
+                            let mut values = Vec::with_capacity(table_catalog.columns_len());
 
-                                if value.is_none() {
-                                    value = throw!(col.default_value());
+                            for (col, default) in table_catalog.columns().zip(defaults.iter()) {
+                                let value = match tuple_map.remove(&col.key(is_mapping_by_name)) {
+                                    Some(value) => value,
+                                    None => match default {
+                                        Some(default) => default.eval(None)?,
+                                        None => DataValue::Null,
+                                    },
+                                };
+                                if value.is_null() && !col.nullable() {
+                                    return Err(DatabaseError::NotNull);
                                 }
-                                value.unwrap_or(DataValue::Null)
-                            };
-                            if value.is_null() && !col.nullable() {
-                                yield Err(DatabaseError::NotNull);
-                                return;
+                                values.push(value)
                             }
-                            values.push(value)
-                        }
-                        let mut tuple = Tuple::new(Some(indices.clone()), values);
+                            let mut tuple = Tuple::new(Some(indices.clone()), values);
 
-                        for (index_meta, exprs) in index_metas.iter() {
-                            let values = throw!(Projection::projection(&tuple, exprs, &schema));
-                            let Some(value) = DataValue::values_to_tuple(values) else {
+                            if let Some(on_conflict) = &on_conflict {
+                                let transaction = unsafe { &mut (*transaction) };
+                                let Some(old_pk) = Self::conflict_tuple_id(
+                                    transaction,
+                                    &table_name,
+                                    on_conflict.index_id,
+                                    &index_metas,
+                                    &mut tuple,
+                                    table_schema,
+                                )?
+                                else {
+                                    if let Some(tuple_id) = tuple.id() {
+                                        written_pks.insert(tuple_id.clone());
+                                    }
+                                    // written at once to be in conflict with the following rows
+                                    Self::write_tuple(
+                                        transaction,
+                                        &table_name,
+                                        &index_metas,
+                                        tuple,
+                                        &types,
+                                        table_schema,
+                                        false,
+                                    )?;
+                                    inserted += 1;
+                                    continue;
+                                };
+                                let OnConflictAction::DoUpdate {
+                                    value_exprs,
+                                    predicate,
+                                } = &on_conflict.action
+                                else {
+                                    continue;
+                                };
+                                if written_pks.contains(&old_pk) {
+                                    return Err(DatabaseError::DuplicateConflictUpdate);
+                                }
+                                let Some(old_tuple) =
+                                    transaction.get_tuple(&table_catalog, &old_pk)?
+                                else {
+                                    return Err(DatabaseError::TupleIdNotFound(old_pk));
+                                };
+                                let conflict_tuple = Tuple::new(
+                                    None,
+                                    old_tuple
+                                        .values
+                                        .iter()
+                                        .chain(tuple.values.iter())
+                                        .cloned()
+                                        .collect_vec(),
+                                );
+                                if let Some(predicate) = predicate {
+                                    let value = predicate
+                                        .eval(Some((&conflict_tuple, &conflict_schema)))?;
+                                    if !matches!(value, DataValue::Boolean(true)) {
+                                        continue;
+                                    }
+                                }
+                                let mut values = old_tuple.values.clone();
+                                for (column, expr) in value_exprs {
+                                    let Some(i) = table_schema
+                                        .iter()
+                                        .position(|table_column| table_column.id() == column.id())
+                                    else {
+                                        continue;
+                                    };
+                                    let value =
+                                        expr.eval(Some((&conflict_tuple, &conflict_schema)))?;
+                                    if value.is_null() && !column.nullable() {
+                                        return Err(DatabaseError::NotNull);
+                                    }
+                                    values[i] = value;
+                                }
+                                let mut new_tuple = Tuple::new(Some(indices.clone()), values);
+                                let is_overwrite = new_tuple.id() == Some(&old_pk);
+                                if let Some(tuple_id) = new_tuple.id() {
+                                    written_pks.insert(tuple_id.clone());
+                                }
+
+                                for (index_meta, exprs, predicate) in index_metas.iter() {
+                                    if !IndexMeta::is_indexed(
+                                        predicate.as_ref(),
+                                        &old_tuple,
+                                        table_schema,
+                                    )? {
+                                        continue;
+                                    }
+                                    let values =
+                                        Projection::projection(&old_tuple, exprs, table_schema)?;
+                                    let Some(value) = DataValue::values_to_tuple(values) else {
+                                        continue;
+                                    };
+                                    let index = Index::new(index_meta.id, &value, index_meta.ty);
+                                    transaction.del_index(&table_name, &index, &old_pk)?;
+                                }
+                                if !is_overwrite {
+                                    transaction.remove_tuple(&table_name, &old_pk)?;
+                                }
+                                Self::write_tuple(
+                                    transaction,
+                                    &table_name,
+                                    &index_metas,
+                                    new_tuple,
+                                    &types,
+                                    table_schema,
+                                    is_overwrite,
+                                )?;
+                                updated += 1;
                                 continue;
-                            };
-                            let Some(tuple_id) = tuple.id() else {
-                                unreachable!()
-                            };
-                            let index = Index::new(index_meta.id, &value, index_meta.ty);
-                            throw!(unsafe { &mut (*transaction) }.add_index(
+                            }
+                            for (index_meta, exprs, predicate) in index_metas.iter() {
+                                if !IndexMeta::is_indexed(predicate.as_ref(), &tuple, table_schema)?
+                                {
+                                    continue;
+                                }
+                                let values = Projection::projection(&tuple, exprs, table_schema)?;
+                                let Some(value) = DataValue::values_to_tuple(values) else {
+                                    continue;
+                                };
+                                let Some(tuple_id) = tuple.id() else {
+                                    unreachable!()
+                                };
+                                let index = Index::new(index_meta.id, &value, index_meta.ty);
+                                index_batch.push(
+                                    unsafe { &mut (*transaction) },
+                                    &table_name,
+                                    index,
+                                    tuple_id,
+                                )?;
+                            }
+                            unsafe { &mut (*transaction) }.append_tuple(
                                 &table_name,
-                                index,
-                                tuple_id
-                            ));
+                                tuple,
+                                &types,
+                                is_overwrite,
+                            )?;
+                            if index_batch.is_full() {
+                                unsafe { &mut (*transaction) }
+                                    .add_index_batch(mem::take(&mut index_batch))?;
+                            }
                         }
-                        throw!(unsafe { &mut (*transaction) }.append_tuple(
-                            &table_name,
-                            tuple,
-                            &types,
-                            is_overwrite
-                        ));
-                    }
+                        Ok(())
+                    };
+                    let result = insert_tuples();
                     drop(coroutine);
+                    // the rows appended before a failed one are kept by the transaction, so are
+                    // their index entries
+                    let flushed = unsafe { &mut (*transaction) }.add_index_batch(index_batch);
+                    throw!(result);
+                    throw!(flushed);
+                }
+                if on_conflict.is_some() {
+                    yield Ok(TupleBuilder::build_result(format!(
+                        "insert {} rows, update {} rows",
+                        inserted, updated
+                    )));
+                } else {
+                    yield Ok(TupleBuilder::build_result("1".to_string()));
                 }
-                yield Ok(TupleBuilder::build_result("1".to_string()));
             },
         )
     }
 }
+
+type IndexMetas<'a> = [(
+    &'a IndexMetaRef,
+    Vec<ScalarExpression>,
+    Option<ScalarExpression>,
+)];
+
+impl Insert {
+    /// The primary key of the stored row which `tuple` is in conflict with on the index
+    /// `index_id`
+    fn conflict_tuple_id<T: Transaction>(
+        transaction: &T,
+        table_name: &str,
+        index_id: IndexId,
+        index_metas: &IndexMetas,
+        tuple: &mut Tuple,
+        schema: &[ColumnRef],
+    ) -> Result<Option<TupleId>, DatabaseError> {
+        let Some((index_meta, exprs, _)) = index_metas
+            .iter()
+            .find(|(index_meta, ..)| index_meta.id == index_id)
+        else {
+            return Err(DatabaseError::InvalidIndex);
+        };
+        let values = Projection::projection(tuple, exprs, schema)?;
+        let Some(value) = DataValue::values_to_tuple(values) else {
+            return Ok(None);
+        };
+        let Some(tuple_id) = tuple.id() else {
+            unreachable!()
+        };
+
+        transaction.unique_tuple_id(
+            table_name,
+            &Index::new(index_meta.id, &value, index_meta.ty),
+            tuple_id,
+        )
+    }
+
+    fn write_tuple<T: Transaction>(
+        transaction: &mut T,
+        table_name: &str,
+        index_metas: &IndexMetas,
+        mut tuple: Tuple,
+        types: &[LogicalType],
+        schema: &[ColumnRef],
+        is_overwrite: bool,
+    ) -> Result<(), DatabaseError> {
+        let Some(tuple_id) = tuple.id().cloned() else {
+            unreachable!()
+        };
+        for (index_meta, exprs, predicate) in index_metas.iter() {
+            if !IndexMeta::is_indexed(predicate.as_ref(), &tuple, schema)? {
+                continue;
+            }
+            let values = Projection::projection(&tuple, exprs, schema)?;
+            let Some(value) = DataValue::values_to_tuple(values) else {
+                continue;
+            };
+            let index = Index::new(index_meta.id, &value, index_meta.ty);
+            transaction.add_index(table_name, index, &tuple_id)?;
+        }
+        transaction.append_tuple(table_name, tuple, types, is_overwrite)
+    }
+}
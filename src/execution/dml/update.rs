@@ -6,7 +6,7 @@ use crate::planner::operator::update::UpdateOperator;
 use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::Index;
+use crate::types::index::{Index, IndexMeta};
 use crate::types::tuple::types;
 use crate::types::tuple::Tuple;
 use crate::types::tuple_builder::TupleBuilder;
@@ -70,7 +70,8 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Update {
                     let mut index_metas = Vec::new();
                     for index_meta in table_catalog.indexes() {
                         let exprs = throw!(index_meta.column_exprs(&table_catalog));
-                        index_metas.push((index_meta, exprs));
+                        let predicate = throw!(index_meta.predicate_expr());
+                        index_metas.push((index_meta, exprs, predicate));
                     }
 
                     let mut coroutine = build_read(input, cache, transaction);
@@ -81,7 +82,14 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Update {
                         let mut is_overwrite = true;
 
                         let old_pk = tuple.id().cloned().unwrap();
-                        for (index_meta, exprs) in index_metas.iter() {
+                        for (index_meta, exprs, predicate) in index_metas.iter() {
+                            if !throw!(IndexMeta::is_indexed(
+                                predicate.as_ref(),
+                                &tuple,
+                                &input_schema
+                            )) {
+                                continue;
+                            }
                             let values =
                                 throw!(Projection::projection(&tuple, exprs, &input_schema));
                             let Some(value) = DataValue::values_to_tuple(values) else {
@@ -108,7 +116,14 @@ impl<'a, T: Transaction + 'a> WriteExecutor<'a, T> for Update {
                             );
                             is_overwrite = false;
                         }
-                        for (index_meta, exprs) in index_metas.iter() {
+                        for (index_meta, exprs, predicate) in index_metas.iter() {
+                            if !throw!(IndexMeta::is_indexed(
+                                predicate.as_ref(),
+                                &tuple,
+                                &input_schema
+                            )) {
+                                continue;
+                            }
                             let values =
                                 throw!(Projection::projection(&tuple, exprs, &input_schema));
                             let Some(value) = DataValue::values_to_tuple(values) else {
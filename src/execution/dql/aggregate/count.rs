@@ -28,6 +28,31 @@ impl Accumulator for CountAccumulator {
     }
 }
 
+/// Counts the rows where the condition is true, so false and null are not counted.
+pub struct CountIfAccumulator {
+    result: i32,
+}
+
+impl CountIfAccumulator {
+    pub fn new() -> Self {
+        Self { result: 0 }
+    }
+}
+
+impl Accumulator for CountIfAccumulator {
+    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
+        if let DataValue::Boolean(true) = value {
+            self.result += 1;
+        }
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::Int32(self.result))
+    }
+}
+
 pub struct DistinctCountAccumulator {
     distinct_values: HashSet<DataValue, RandomState>,
 }
@@ -0,0 +1,36 @@
+use crate::errors::DatabaseError;
+use crate::execution::dql::aggregate::Accumulator;
+use crate::types::value::DataValue;
+use ahash::RandomState;
+use std::collections::HashSet;
+
+/// Feeds each distinct value only once into the accumulator of an aggregate function called with
+/// `DISTINCT`, e.g. `SUM(DISTINCT x)`. NULLs are ignored as by the aggregate functions.
+pub struct DistinctAccumulator {
+    distinct_values: HashSet<DataValue, RandomState>,
+    inner: Box<dyn Accumulator>,
+}
+
+impl DistinctAccumulator {
+    pub fn new(inner: Box<dyn Accumulator>) -> Self {
+        Self {
+            distinct_values: HashSet::default(),
+            inner,
+        }
+    }
+}
+
+impl Accumulator for DistinctAccumulator {
+    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
+        if !value.is_null() && !self.distinct_values.contains(value) {
+            self.distinct_values.insert(value.clone());
+            self.inner.update_value(value)?;
+        }
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        self.inner.evaluate()
+    }
+}
@@ -0,0 +1,24 @@
+use crate::errors::DatabaseError;
+use crate::execution::dql::aggregate::Accumulator;
+use crate::types::value::DataValue;
+
+/// `GROUPING(col)` is `1` when `col` is aggregated away by the grouping set of the group.
+pub struct GroupingAccumulator {
+    is_grouped_away: bool,
+}
+
+impl GroupingAccumulator {
+    pub fn new(is_grouped_away: bool) -> Self {
+        GroupingAccumulator { is_grouped_away }
+    }
+}
+
+impl Accumulator for GroupingAccumulator {
+    fn update_value(&mut self, _: &DataValue) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::Int32(self.is_grouped_away as i32))
+    }
+}
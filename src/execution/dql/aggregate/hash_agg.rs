@@ -1,6 +1,8 @@
 use crate::errors::DatabaseError;
-use crate::execution::dql::aggregate::{create_accumulators, Accumulator};
+use crate::execution::dql::aggregate::grouping::GroupingAccumulator;
+use crate::execution::dql::aggregate::{create_accumulators, eval_agg_value, Accumulator};
 use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::expression::agg::AggKind;
 use crate::expression::ScalarExpression;
 use crate::planner::operator::aggregate::AggregateOperator;
 use crate::planner::LogicalPlan;
@@ -17,6 +19,7 @@ use std::pin::Pin;
 pub struct HashAggExecutor {
     agg_calls: Vec<ScalarExpression>,
     groupby_exprs: Vec<ScalarExpression>,
+    grouping_sets: Vec<Vec<usize>>,
     input: LogicalPlan,
 }
 
@@ -26,14 +29,22 @@ impl From<(AggregateOperator, LogicalPlan)> for HashAggExecutor {
             AggregateOperator {
                 agg_calls,
                 groupby_exprs,
+                grouping_sets,
                 ..
             },
             input,
         ): (AggregateOperator, LogicalPlan),
     ) -> Self {
+        // without `ROLLUP`, `CUBE` or `GROUPING SETS` the only grouping set holds every key
+        let grouping_sets = if grouping_sets.is_empty() {
+            vec![(0..groupby_exprs.len()).collect()]
+        } else {
+            grouping_sets
+        };
         HashAggExecutor {
             agg_calls,
             groupby_exprs,
+            grouping_sets,
             input,
         }
     }
@@ -51,12 +62,14 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashAggExecutor {
                 let HashAggExecutor {
                     agg_calls,
                     groupby_exprs,
+                    grouping_sets,
                     mut input,
                 } = self;
 
                 let schema_ref = input.output_schema().clone();
-                let mut group_hash_accs: HashMap<Vec<DataValue>, Vec<Box<dyn Accumulator>>> =
-                    HashMap::new();
+                let grouping_keys = Self::grouping_keys(&agg_calls, &groupby_exprs);
+                // the groups of each grouping set are told apart by the position of the set
+                let mut group_hash_accs = HashMap::new();
 
                 let mut coroutine = build_read(input, cache, transaction);
 
@@ -65,32 +78,59 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashAggExecutor {
                     let mut values = Vec::with_capacity(agg_calls.len());
 
                     for expr in agg_calls.iter() {
-                        if let ScalarExpression::AggCall { args, .. } = expr {
-                            if args.len() > 1 {
+                        if let ScalarExpression::AggCall { kind, args, .. } = expr {
+                            // Tips: the fraction of `PERCENTILE_CONT` is a constant parameter of its accumulator
+                            if args.len() > 1 && !matches!(kind, AggKind::PercentileCont) {
                                 throw!(Err(DatabaseError::UnsupportedStmt("currently aggregate functions only support a single Column as a parameter".to_string())))
                             }
-                            values.push(throw!(args[0].eval(Some((&tuple, &schema_ref)))));
-                        } else {
-                            unreachable!()
                         }
+                        values.push(throw!(eval_agg_value(expr, &tuple, &schema_ref)));
                     }
                     let group_keys: Vec<DataValue> = throw!(groupby_exprs
                         .iter()
                         .map(|expr| expr.eval(Some((&tuple, &schema_ref))))
                         .try_collect());
 
-                    let entry = match group_hash_accs.entry(group_keys) {
-                        Entry::Occupied(entry) => entry.into_mut(),
-                        Entry::Vacant(entry) => {
-                            entry.insert(throw!(create_accumulators(&agg_calls)))
+                    for (i, set) in grouping_sets.iter().enumerate() {
+                        // the keys aggregated away by the grouping set are NULL
+                        let set_keys = group_keys
+                            .iter()
+                            .enumerate()
+                            .map(|(j, key)| {
+                                if set.contains(&j) {
+                                    key.clone()
+                                } else {
+                                    DataValue::Null
+                                }
+                            })
+                            .collect_vec();
+                        let entry = match group_hash_accs.entry((i, set_keys)) {
+                            Entry::Occupied(entry) => entry.into_mut(),
+                            Entry::Vacant(entry) => entry.insert(throw!(
+                                Self::create_accumulators(&agg_calls, &grouping_keys, set)
+                            )),
+                        };
+                        for (acc, value) in entry.iter_mut().zip_eq(values.iter()) {
+                            if let Some(value) = value {
+                                throw!(acc.update_value(value));
+                            }
+                        }
+                    }
+                }
+                // like an aggregation without `GROUP BY`, the empty grouping set has a group
+                // even without any row
+                if group_hash_accs.is_empty() {
+                    for (i, set) in grouping_sets.iter().enumerate() {
+                        if set.is_empty() {
+                            group_hash_accs.insert(
+                                (i, vec![DataValue::Null; groupby_exprs.len()]),
+                                throw!(Self::create_accumulators(&agg_calls, &grouping_keys, set)),
+                            );
                         }
-                    };
-                    for (acc, value) in entry.iter_mut().zip_eq(values.iter()) {
-                        throw!(acc.update_value(value));
                     }
                 }
 
-                for (group_keys, accs) in group_hash_accs {
+                for ((_, group_keys), accs) in group_hash_accs {
                     // Tips: Accumulator First
                     let values: Vec<DataValue> = throw!(accs
                         .iter()
@@ -104,6 +144,48 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashAggExecutor {
     }
 }
 
+impl HashAggExecutor {
+    /// The position of the `GROUP BY` expression of each `GROUPING()` in `agg_calls`
+    fn grouping_keys(
+        agg_calls: &[ScalarExpression],
+        groupby_exprs: &[ScalarExpression],
+    ) -> Vec<(usize, usize)> {
+        agg_calls
+            .iter()
+            .enumerate()
+            .filter_map(|(i, expr)| {
+                let ScalarExpression::AggCall {
+                    kind: AggKind::Grouping,
+                    args,
+                    ..
+                } = expr.unpack_alias_ref()
+                else {
+                    return None;
+                };
+                groupby_exprs
+                    .iter()
+                    .position(|groupby_expr| {
+                        groupby_expr.unpack_alias_ref() == args[0].unpack_alias_ref()
+                    })
+                    .map(|j| (i, j))
+            })
+            .collect()
+    }
+
+    fn create_accumulators(
+        agg_calls: &[ScalarExpression],
+        grouping_keys: &[(usize, usize)],
+        set: &[usize],
+    ) -> Result<Vec<Box<dyn Accumulator>>, DatabaseError> {
+        let mut accs = create_accumulators(agg_calls)?;
+
+        for (i, j) in grouping_keys {
+            accs[*i] = Box::new(GroupingAccumulator::new(!set.contains(j)));
+        }
+        Ok(accs)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
@@ -151,7 +233,9 @@ mod test {
                 kind: AggKind::Sum,
                 args: vec![ScalarExpression::ColumnRef(t1_schema[1].clone())],
                 ty: LogicalType::Integer,
+                filter: None,
             }],
+            grouping_sets: vec![],
             is_distinct: false,
         };
 
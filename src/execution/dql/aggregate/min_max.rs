@@ -26,7 +26,7 @@ impl Accumulator for MinMaxAccumulator {
         if !value.is_null() {
             if let Some(inner_value) = &self.inner {
                 let evaluator = EvaluatorFactory::binary_create(value.logical_type(), self.op)?;
-                if let DataValue::Boolean(result) = evaluator.0.binary_eval(inner_value, value) {
+                if let DataValue::Boolean(result) = evaluator.0.binary_eval(inner_value, value)? {
                     result
                 } else {
                     return Err(DatabaseError::InvalidType);
@@ -44,3 +44,61 @@ impl Accumulator for MinMaxAccumulator {
         Ok(self.inner.clone().unwrap_or(DataValue::Null))
     }
 }
+
+/// Tracks the extreme `key` of the group together with the `value` of its row,
+/// the pair arrives as a `(value, key)` tuple.
+///
+/// Tips: rows with a NULL key are skipped and on equal keys the first seen row wins
+pub struct MinMaxByAccumulator {
+    inner: Option<(DataValue, DataValue)>,
+    op: BinaryOperator,
+}
+
+impl MinMaxByAccumulator {
+    pub fn new(is_max: bool) -> Self {
+        let op = if is_max {
+            BinaryOperator::Lt
+        } else {
+            BinaryOperator::Gt
+        };
+
+        Self { inner: None, op }
+    }
+}
+
+impl Accumulator for MinMaxByAccumulator {
+    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
+        let DataValue::Tuple(values, _) = value else {
+            return Err(DatabaseError::InvalidType);
+        };
+        let [value, key] = values.as_slice() else {
+            return Err(DatabaseError::InvalidType);
+        };
+        if key.is_null() {
+            return Ok(());
+        }
+        let is_replace = if let Some((_, inner_key)) = &self.inner {
+            let evaluator = EvaluatorFactory::binary_create(key.logical_type(), self.op)?;
+            if let DataValue::Boolean(result) = evaluator.0.binary_eval(inner_key, key)? {
+                result
+            } else {
+                return Err(DatabaseError::InvalidType);
+            }
+        } else {
+            true
+        };
+        if is_replace {
+            self.inner = Some((value.clone(), key.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        Ok(self
+            .inner
+            .as_ref()
+            .map(|(value, _)| value.clone())
+            .unwrap_or(DataValue::Null))
+    }
+}
@@ -1,17 +1,29 @@
 mod avg;
 mod count;
+mod distinct;
+mod grouping;
 pub mod hash_agg;
 mod min_max;
+mod percentile;
 pub mod simple_agg;
 mod sum;
+mod variance;
 
+use crate::catalog::ColumnRef;
 use crate::errors::DatabaseError;
 use crate::execution::dql::aggregate::avg::AvgAccumulator;
-use crate::execution::dql::aggregate::count::{CountAccumulator, DistinctCountAccumulator};
-use crate::execution::dql::aggregate::min_max::MinMaxAccumulator;
-use crate::execution::dql::aggregate::sum::{DistinctSumAccumulator, SumAccumulator};
+use crate::execution::dql::aggregate::count::{
+    CountAccumulator, CountIfAccumulator, DistinctCountAccumulator,
+};
+use crate::execution::dql::aggregate::distinct::DistinctAccumulator;
+use crate::execution::dql::aggregate::grouping::GroupingAccumulator;
+use crate::execution::dql::aggregate::min_max::{MinMaxAccumulator, MinMaxByAccumulator};
+use crate::execution::dql::aggregate::percentile::PercentileContAccumulator;
+use crate::execution::dql::aggregate::sum::SumAccumulator;
+use crate::execution::dql::aggregate::variance::VarianceAccumulator;
 use crate::expression::agg::AggKind;
 use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
 use crate::types::value::DataValue;
 use itertools::Itertools;
 
@@ -28,18 +40,47 @@ pub trait Accumulator: Send + Sync {
 
 fn create_accumulator(expr: &ScalarExpression) -> Result<Box<dyn Accumulator>, DatabaseError> {
     if let ScalarExpression::AggCall {
-        kind, ty, distinct, ..
+        kind,
+        args,
+        ty,
+        distinct,
+        ..
     } = expr
     {
-        Ok(match (kind, distinct) {
+        let accumulator: Box<dyn Accumulator> = match (kind, distinct) {
             (AggKind::Count, false) => Box::new(CountAccumulator::new()),
             (AggKind::Count, true) => Box::new(DistinctCountAccumulator::new()),
-            (AggKind::Sum, false) => Box::new(SumAccumulator::new(ty)?),
-            (AggKind::Sum, true) => Box::new(DistinctSumAccumulator::new(ty)?),
+            (AggKind::CountIf, _) => Box::new(CountIfAccumulator::new()),
+            (AggKind::Sum, _) => Box::new(SumAccumulator::new(ty)?),
             (AggKind::Min, _) => Box::new(MinMaxAccumulator::new(false)),
             (AggKind::Max, _) => Box::new(MinMaxAccumulator::new(true)),
+            (AggKind::MinBy, _) => Box::new(MinMaxByAccumulator::new(false)),
+            (AggKind::MaxBy, _) => Box::new(MinMaxByAccumulator::new(true)),
             (AggKind::Avg, _) => Box::new(AvgAccumulator::new(ty)?),
-        })
+            (AggKind::VarPop, _) => Box::new(VarianceAccumulator::new(false, false)),
+            (AggKind::VarSamp, _) => Box::new(VarianceAccumulator::new(true, false)),
+            (AggKind::StddevPop, _) => Box::new(VarianceAccumulator::new(false, true)),
+            (AggKind::StddevSamp, _) => Box::new(VarianceAccumulator::new(true, true)),
+            (AggKind::Grouping, _) => Box::new(GroupingAccumulator::new(false)),
+            (AggKind::PercentileCont, _) => {
+                // the fraction is checked to be a constant when binding
+                let Some(ScalarExpression::Constant(DataValue::Float64(fraction))) = args.get(1)
+                else {
+                    return Err(DatabaseError::InvalidValue(
+                        "percentile_cont fraction must be a constant".to_string(),
+                    ));
+                };
+                Box::new(PercentileContAccumulator::new(fraction.0))
+            }
+        };
+        // `COUNT(DISTINCT ..)` has its own accumulator
+        Ok(
+            if *distinct && kind.allow_distinct() && !matches!(kind, AggKind::Count) {
+                Box::new(DistinctAccumulator::new(accumulator))
+            } else {
+                accumulator
+            },
+        )
     } else {
         unreachable!(
             "create_accumulator called with non-aggregate expression {}",
@@ -48,6 +89,28 @@ fn create_accumulator(expr: &ScalarExpression) -> Result<Box<dyn Accumulator>, D
     }
 }
 
+/// Evaluates the value of the aggregate function `expr` for `tuple`, `None` when the `FILTER` of
+/// the function rejects it.
+pub(crate) fn eval_agg_value(
+    expr: &ScalarExpression,
+    tuple: &Tuple,
+    schema: &[ColumnRef],
+) -> Result<Option<DataValue>, DatabaseError> {
+    let ScalarExpression::AggCall { args, filter, .. } = expr else {
+        unreachable!(
+            "eval_agg_value called with non-aggregate expression {}",
+            expr
+        );
+    };
+    if let Some(filter) = filter {
+        if !filter.eval(Some((tuple, schema)))?.is_true()? {
+            return Ok(None);
+        }
+    }
+    // Tips: the rest of the arguments are constant parameters of the accumulator
+    args[0].eval(Some((tuple, schema))).map(Some)
+}
+
 pub(crate) fn create_accumulators(
     exprs: &[ScalarExpression],
 ) -> Result<Vec<Box<dyn Accumulator>>, DatabaseError> {
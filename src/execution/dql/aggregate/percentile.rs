@@ -0,0 +1,56 @@
+use crate::errors::DatabaseError;
+use crate::execution::dql::aggregate::Accumulator;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ordered_float::OrderedFloat;
+
+/// Buffers all values of the group, and interpolates linearly between
+/// the two values closest to the `fraction` position once they are sorted.
+pub struct PercentileContAccumulator {
+    values: Vec<f64>,
+    fraction: f64,
+}
+
+impl PercentileContAccumulator {
+    pub fn new(fraction: f64) -> Self {
+        Self {
+            values: Vec::new(),
+            fraction,
+        }
+    }
+}
+
+impl Accumulator for PercentileContAccumulator {
+    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
+        if value.is_null() {
+            return Ok(());
+        }
+        let value = match value {
+            DataValue::Float64(value) => value.0,
+            value => value
+                .clone()
+                .cast(&LogicalType::Double)?
+                .double()
+                .ok_or(DatabaseError::InvalidType)?,
+        };
+        self.values.push(value);
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        if self.values.is_empty() {
+            return Ok(DataValue::Null);
+        }
+        let mut values = self.values.clone();
+        values.sort_by(f64::total_cmp);
+
+        let position = self.fraction * (values.len() - 1) as f64;
+        let (lower, upper) = (position.floor(), position.ceil());
+        let (lower_value, upper_value) = (values[lower as usize], values[upper as usize]);
+
+        Ok(DataValue::Float64(OrderedFloat(
+            lower_value + (upper_value - lower_value) * (position - lower),
+        )))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::execution::dql::aggregate::create_accumulators;
+use crate::execution::dql::aggregate::{create_accumulators, eval_agg_value};
 use crate::execution::{build_read, Executor, ReadExecutor};
 use crate::expression::ScalarExpression;
 use crate::planner::operator::aggregate::AggregateOperator;
@@ -47,17 +47,15 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for SimpleAggExecutor {
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
                     let tuple = throw!(tuple);
 
-                    let values: Vec<DataValue> = throw!(agg_calls
+                    let values: Vec<Option<DataValue>> = throw!(agg_calls
                         .iter()
-                        .map(|expr| match expr {
-                            ScalarExpression::AggCall { args, .. } =>
-                                args[0].eval(Some((&tuple, &schema))),
-                            _ => unreachable!(),
-                        })
+                        .map(|expr| eval_agg_value(expr, &tuple, &schema))
                         .try_collect());
 
                     for (acc, value) in accs.iter_mut().zip_eq(values.iter()) {
-                        throw!(acc.update_value(value));
+                        if let Some(value) = value {
+                            throw!(acc.update_value(value));
+                        }
                     }
                 }
                 let values: Vec<DataValue> =
@@ -4,8 +4,6 @@ use crate::expression::BinaryOperator;
 use crate::types::evaluator::{BinaryEvaluatorBox, EvaluatorFactory};
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
-use ahash::RandomState;
-use std::collections::HashSet;
 
 pub struct SumAccumulator {
     result: DataValue,
@@ -29,7 +27,7 @@ impl Accumulator for SumAccumulator {
             if self.result.is_null() {
                 self.result = value.clone();
             } else {
-                self.result = self.evaluator.0.binary_eval(&self.result, value);
+                self.result = self.evaluator.0.binary_eval(&self.result, value)?;
             }
         }
 
@@ -40,32 +38,3 @@ impl Accumulator for SumAccumulator {
         Ok(self.result.clone())
     }
 }
-
-pub struct DistinctSumAccumulator {
-    distinct_values: HashSet<DataValue, RandomState>,
-    inner: SumAccumulator,
-}
-
-impl DistinctSumAccumulator {
-    pub fn new(ty: &LogicalType) -> Result<Self, DatabaseError> {
-        Ok(Self {
-            distinct_values: HashSet::default(),
-            inner: SumAccumulator::new(ty)?,
-        })
-    }
-}
-
-impl Accumulator for DistinctSumAccumulator {
-    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
-        if !self.distinct_values.contains(value) {
-            self.distinct_values.insert(value.clone());
-            self.inner.update_value(value)?;
-        }
-
-        Ok(())
-    }
-
-    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
-        self.inner.evaluate()
-    }
-}
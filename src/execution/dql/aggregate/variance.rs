@@ -0,0 +1,67 @@
+use crate::errors::DatabaseError;
+use crate::execution::dql::aggregate::Accumulator;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ordered_float::OrderedFloat;
+
+/// Accumulates the variance with Welford's online algorithm,
+/// which does not lose precision on large values like the naive sum of squares does.
+pub struct VarianceAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    is_sample: bool,
+    is_stddev: bool,
+}
+
+impl VarianceAccumulator {
+    pub fn new(is_sample: bool, is_stddev: bool) -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            is_sample,
+            is_stddev,
+        }
+    }
+}
+
+impl Accumulator for VarianceAccumulator {
+    fn update_value(&mut self, value: &DataValue) -> Result<(), DatabaseError> {
+        if value.is_null() {
+            return Ok(());
+        }
+        let value = match value {
+            DataValue::Float64(value) => value.0,
+            value => value
+                .clone()
+                .cast(&LogicalType::Double)?
+                .double()
+                .ok_or(DatabaseError::InvalidType)?,
+        };
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<DataValue, DatabaseError> {
+        let divisor = if self.is_sample {
+            self.count.saturating_sub(1)
+        } else {
+            self.count
+        };
+        if divisor == 0 {
+            return Ok(DataValue::Null);
+        }
+        let variance = self.m2 / divisor as f64;
+
+        Ok(DataValue::Float64(OrderedFloat(if self.is_stddev {
+            variance.sqrt()
+        } else {
+            variance
+        })))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::planner::operator::except::ExceptOperator;
+use crate::planner::LogicalPlan;
+use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::throw;
+use crate::types::value::DataValue;
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use std::ops::Coroutine;
+use std::ops::CoroutineState;
+use std::pin::Pin;
+
+pub struct Except {
+    left_input: LogicalPlan,
+    right_input: LogicalPlan,
+    is_all: bool,
+}
+
+impl From<(ExceptOperator, LogicalPlan, LogicalPlan)> for Except {
+    fn from(
+        (ExceptOperator { is_all, .. }, left_input, right_input): (
+            ExceptOperator,
+            LogicalPlan,
+            LogicalPlan,
+        ),
+    ) -> Self {
+        Except {
+            left_input,
+            right_input,
+            is_all,
+        }
+    }
+}
+
+impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Except {
+    fn execute(
+        self,
+        cache: (&'a TableCache, &'a ViewCache, &'a StatisticsMetaCache),
+        transaction: *mut T,
+    ) -> Executor<'a> {
+        Box::new(
+            #[coroutine]
+            move || {
+                let Except {
+                    left_input,
+                    right_input,
+                    is_all,
+                } = self;
+                // NULLs are not distinct from each other in set operations, as `DataValue` compares them
+                let mut right_counts: HashMap<Vec<DataValue>, usize> = HashMap::new();
+                let mut coroutine = build_read(right_input, cache, transaction);
+
+                while let CoroutineState::Yielded(result) = Pin::new(&mut coroutine).resume(()) {
+                    let tuple = throw!(result);
+
+                    *right_counts.entry(tuple.values).or_default() += 1;
+                }
+                let mut emitted = HashSet::new();
+                let mut coroutine = build_read(left_input, cache, transaction);
+
+                while let CoroutineState::Yielded(result) = Pin::new(&mut coroutine).resume(()) {
+                    let tuple = throw!(result);
+
+                    if is_all {
+                        // each right row cancels out one equal left row
+                        if let Some(count) = right_counts.get_mut(&tuple.values) {
+                            if *count > 0 {
+                                *count -= 1;
+                                continue;
+                            }
+                        }
+                    } else if right_counts.contains_key(&tuple.values)
+                        || !emitted.insert(tuple.values.clone())
+                    {
+                        continue;
+                    }
+                    yield Ok(tuple);
+                }
+            },
+        )
+    }
+}
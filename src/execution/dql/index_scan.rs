@@ -1,18 +1,23 @@
+use crate::execution::dql::projection::Projection;
 use crate::execution::{Executor, ReadExecutor};
 use crate::expression::range_detacher::Range;
 use crate::planner::operator::table_scan::TableScanOperator;
 use crate::storage::{Iter, StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
-use crate::types::index::IndexMetaRef;
+use crate::types::index::{IndexMetaRef, IndexOrder};
+use itertools::Itertools;
 
 pub(crate) struct IndexScan {
     op: TableScanOperator,
     index_by: IndexMetaRef,
     ranges: Vec<Range>,
+    is_reverse: bool,
 }
 
-impl From<(TableScanOperator, IndexMetaRef, Range)> for IndexScan {
-    fn from((op, index_by, range): (TableScanOperator, IndexMetaRef, Range)) -> Self {
+impl From<(TableScanOperator, IndexMetaRef, Range, Option<IndexOrder>)> for IndexScan {
+    fn from(
+        (op, index_by, range, order): (TableScanOperator, IndexMetaRef, Range, Option<IndexOrder>),
+    ) -> Self {
         let ranges = match range {
             Range::SortedRanges(ranges) => ranges,
             range => vec![range],
@@ -22,6 +27,7 @@ impl From<(TableScanOperator, IndexMetaRef, Range)> for IndexScan {
             op,
             index_by,
             ranges,
+            is_reverse: matches!(order, Some(IndexOrder::Desc)),
         }
     }
 }
@@ -39,8 +45,15 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for IndexScan {
                     table_name,
                     columns,
                     limit,
+                    projection,
                     ..
                 } = self.op;
+                let schema = projection.is_some().then(|| {
+                    columns
+                        .iter()
+                        .map(|(_, column)| column.clone())
+                        .collect_vec()
+                });
 
                 let mut iter = unsafe { &(*transaction) }
                     .read_by_index(
@@ -50,10 +63,14 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for IndexScan {
                         columns,
                         self.index_by,
                         self.ranges,
+                        self.is_reverse,
                     )
                     .unwrap();
 
-                while let Some(tuple) = throw!(iter.next_tuple()) {
+                while let Some(mut tuple) = throw!(iter.next_tuple()) {
+                    if let (Some(exprs), Some(schema)) = (&projection, &schema) {
+                        tuple.values = throw!(Projection::projection(&tuple, exprs, schema));
+                    }
                     yield Ok(tuple);
                 }
             },
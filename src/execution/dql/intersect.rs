@@ -0,0 +1,77 @@
+use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::planner::operator::intersect::IntersectOperator;
+use crate::planner::LogicalPlan;
+use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::throw;
+use crate::types::value::DataValue;
+use ahash::{HashMap, HashMapExt};
+use std::ops::Coroutine;
+use std::ops::CoroutineState;
+use std::pin::Pin;
+
+pub struct Intersect {
+    left_input: LogicalPlan,
+    right_input: LogicalPlan,
+    is_all: bool,
+}
+
+impl From<(IntersectOperator, LogicalPlan, LogicalPlan)> for Intersect {
+    fn from(
+        (IntersectOperator { is_all, .. }, left_input, right_input): (
+            IntersectOperator,
+            LogicalPlan,
+            LogicalPlan,
+        ),
+    ) -> Self {
+        Intersect {
+            left_input,
+            right_input,
+            is_all,
+        }
+    }
+}
+
+impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Intersect {
+    fn execute(
+        self,
+        cache: (&'a TableCache, &'a ViewCache, &'a StatisticsMetaCache),
+        transaction: *mut T,
+    ) -> Executor<'a> {
+        Box::new(
+            #[coroutine]
+            move || {
+                let Intersect {
+                    left_input,
+                    right_input,
+                    is_all,
+                } = self;
+                // NULLs are not distinct from each other in set operations, as `DataValue` compares them
+                let mut right_counts: HashMap<Vec<DataValue>, usize> = HashMap::new();
+                let mut coroutine = build_read(right_input, cache, transaction);
+
+                while let CoroutineState::Yielded(result) = Pin::new(&mut coroutine).resume(()) {
+                    let tuple = throw!(result);
+
+                    *right_counts.entry(tuple.values).or_default() += 1;
+                }
+                let mut coroutine = build_read(left_input, cache, transaction);
+
+                while let CoroutineState::Yielded(result) = Pin::new(&mut coroutine).resume(()) {
+                    let tuple = throw!(result);
+
+                    if is_all {
+                        // each right row matches at most one equal left row
+                        match right_counts.get_mut(&tuple.values) {
+                            Some(count) if *count > 0 => *count -= 1,
+                            _ => continue,
+                        }
+                    } else if right_counts.remove(&tuple.values).is_none() {
+                        // removing the matched row emits each distinct row once
+                        continue;
+                    }
+                    yield Ok(tuple);
+                }
+            },
+        )
+    }
+}
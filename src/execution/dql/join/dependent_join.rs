@@ -0,0 +1,178 @@
+//! Defines the dependent join executor, which evaluates a correlated scalar subquery for each tuple
+//! of the outer query, see [`JoinCondition::Dependent`].
+
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::expression::ScalarExpression;
+use crate::planner::operator::join::{JoinCondition, JoinOperator, JoinType};
+use crate::planner::operator::Operator;
+use crate::planner::{Childrens, LogicalPlan};
+use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::throw;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, NULL_VALUE};
+use itertools::Itertools;
+use std::ops::Coroutine;
+use std::ops::CoroutineState;
+use std::pin::Pin;
+
+/// Re-executes `right_input` for every tuple of `left_input`:
+/// - [`JoinType::LeftOuter`] appends the only tuple it returns, or `NULL`s when it returns nothing
+/// - [`JoinType::LeftSemi`]/[`JoinType::LeftAnti`] keep the left tuple if some/none of the tuples
+///   it returns pass the filter
+pub struct DependentJoin {
+    left_input: LogicalPlan,
+    right_input: LogicalPlan,
+    ty: JoinType,
+    params: Vec<(ScalarExpression, ColumnRef)>,
+    filter: Option<ScalarExpression>,
+}
+
+impl From<(JoinOperator, LogicalPlan, LogicalPlan)> for DependentJoin {
+    fn from(
+        (JoinOperator { on, join_type }, left_input, right_input): (
+            JoinOperator,
+            LogicalPlan,
+            LogicalPlan,
+        ),
+    ) -> Self {
+        let JoinCondition::Dependent { params, filter } = on else {
+            unreachable!("DependentJoin must has dependent condition")
+        };
+        DependentJoin {
+            left_input,
+            right_input,
+            ty: join_type,
+            params,
+            filter,
+        }
+    }
+}
+
+impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for DependentJoin {
+    fn execute(
+        self,
+        cache: (&'a TableCache, &'a ViewCache, &'a StatisticsMetaCache),
+        transaction: *mut T,
+    ) -> Executor<'a> {
+        Box::new(
+            #[coroutine]
+            move || {
+                let DependentJoin {
+                    mut left_input,
+                    mut right_input,
+                    ty,
+                    params,
+                    filter,
+                } = self;
+
+                let left_schema = left_input.output_schema().clone();
+                let right_schema = right_input.output_schema().clone();
+                let full_schema = left_schema
+                    .iter()
+                    .chain(right_schema.iter())
+                    .cloned()
+                    .collect_vec();
+                let mut left_coroutine = build_read(left_input, cache, transaction);
+
+                while let CoroutineState::Yielded(left_tuple) =
+                    Pin::new(&mut left_coroutine).resume(())
+                {
+                    let mut left_tuple: Tuple = throw!(left_tuple);
+                    let mut values = Vec::with_capacity(params.len());
+
+                    for (outer_expr, _) in params.iter() {
+                        values.push(throw!(outer_expr.eval(Some((&left_tuple, &left_schema)))));
+                    }
+                    let mut plan = right_input.clone();
+                    Self::bind_params(&mut plan, &params, &values);
+
+                    let mut right_tuple = None;
+                    let mut has_matched = false;
+                    let mut right_coroutine = build_read(plan, cache, transaction);
+
+                    while let CoroutineState::Yielded(tuple) =
+                        Pin::new(&mut right_coroutine).resume(())
+                    {
+                        let tuple: Tuple = throw!(tuple);
+
+                        if matches!(ty, JoinType::LeftOuter) {
+                            if right_tuple.replace(tuple).is_some() {
+                                yield Err(DatabaseError::SubQueryMoreThanOneRow);
+                                return;
+                            }
+                            continue;
+                        }
+                        if let Some(filter) = &filter {
+                            let values = left_tuple
+                                .values
+                                .iter()
+                                .chain(tuple.values.iter())
+                                .cloned()
+                                .collect_vec();
+                            let value = throw!(filter
+                                .eval(Some((&Tuple::new(None, values), full_schema.as_slice()))));
+                            if !matches!(value, DataValue::Boolean(true)) {
+                                continue;
+                            }
+                        }
+                        has_matched = true;
+                        break;
+                    }
+                    match ty {
+                        JoinType::LeftOuter => {
+                            let mut right_values = right_tuple
+                                .map(|tuple: Tuple| tuple.values)
+                                .unwrap_or_else(|| vec![NULL_VALUE.clone(); right_schema.len()]);
+                            left_tuple.values.append(&mut right_values);
+
+                            yield Ok(Tuple::new(None, left_tuple.values));
+                        }
+                        JoinType::LeftSemi if has_matched => yield Ok(left_tuple),
+                        JoinType::LeftAnti if !has_matched => yield Ok(left_tuple),
+                        _ => (),
+                    }
+                }
+            },
+        )
+    }
+}
+
+impl DependentJoin {
+    /// Fill the parameters of the subquery, which are the columns of an empty [`Values`].
+    ///
+    /// [`Values`]: crate::planner::operator::values::ValuesOperator
+    fn bind_params(
+        plan: &mut LogicalPlan,
+        params: &[(ScalarExpression, ColumnRef)],
+        values: &[DataValue],
+    ) -> bool {
+        if let Operator::Values(op) = &mut plan.operator {
+            let row = op
+                .schema_ref
+                .iter()
+                .map(|column| {
+                    params
+                        .iter()
+                        .position(|(_, param)| param.summary() == column.summary())
+                        .map(|i| values[i].clone())
+                })
+                .collect::<Option<Vec<_>>>();
+
+            if let (true, Some(row)) = (op.rows.is_empty(), row) {
+                op.rows = vec![row];
+                return true;
+            }
+        }
+        match plan.childrens.as_mut() {
+            Childrens::Only(child) => Self::bind_params(child, params, values),
+            // both sides of a set operation may reference the parameters
+            Childrens::Twins { left, right } => {
+                let is_left_bound = Self::bind_params(left, params, values);
+                Self::bind_params(right, params, values) || is_left_bound
+            }
+            Childrens::None => false,
+        }
+    }
+}
@@ -54,38 +54,21 @@ impl HashJoin {
         Ok(values)
     }
 
-    pub(crate) fn filter(
-        mut tuple: Tuple,
+    /// Whether the joined tuple satisfies the non-equivalent part of the `ON` condition,
+    /// `NULL` is treated as unmatched.
+    fn is_matched(
+        tuple: &Tuple,
         schema: &Schema,
         filter: &Option<ScalarExpression>,
-        join_ty: &JoinType,
-        left_schema_len: usize,
-    ) -> Result<Option<Tuple>, DatabaseError> {
-        if let (Some(expr), false) = (filter, matches!(join_ty, JoinType::Full | JoinType::Cross)) {
-            match &expr.eval(Some((&tuple, schema)))? {
-                DataValue::Boolean(false) | DataValue::Null => {
-                    let full_schema_len = schema.len();
-
-                    match join_ty {
-                        JoinType::LeftOuter => {
-                            for i in left_schema_len..full_schema_len {
-                                tuple.values[i] = NULL_VALUE.clone();
-                            }
-                        }
-                        JoinType::RightOuter => {
-                            for i in 0..left_schema_len {
-                                tuple.values[i] = NULL_VALUE.clone();
-                            }
-                        }
-                        _ => return Ok(None),
-                    }
-                }
-                DataValue::Boolean(true) => (),
-                _ => return Err(DatabaseError::InvalidType),
-            }
+    ) -> Result<bool, DatabaseError> {
+        let Some(expr) = filter else {
+            return Ok(true);
+        };
+        match expr.eval(Some((tuple, schema)))? {
+            DataValue::Boolean(is_matched) => Ok(is_matched),
+            DataValue::Null => Ok(false),
+            _ => Err(DatabaseError::InvalidType),
         }
-
-        Ok(Some(tuple))
     }
 }
 
@@ -108,12 +91,19 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashJoin {
                 if ty == JoinType::Cross {
                     unreachable!("Cross join should not be in HashJoinExecutor");
                 }
-                let ((on_left_keys, on_right_keys), filter): (
+                let ((on_left_keys, on_right_keys), filter, null_equals_null): (
                     (Vec<ScalarExpression>, Vec<ScalarExpression>),
                     _,
+                    _,
                 ) = match on {
-                    JoinCondition::On { on, filter } => (on.into_iter().unzip(), filter),
-                    JoinCondition::None => unreachable!("HashJoin must has on condition"),
+                    JoinCondition::On {
+                        on,
+                        filter,
+                        null_equals_null,
+                    } => (on.into_iter().unzip(), filter, null_equals_null),
+                    JoinCondition::Dependent { .. } | JoinCondition::None => {
+                        unreachable!("HashJoin must has on condition")
+                    }
                 };
                 if on_left_keys.is_empty() || on_right_keys.is_empty() {
                     throw!(Err(DatabaseError::UnsupportedStmt(
@@ -145,10 +135,10 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashJoin {
 
                 // build phase:
                 // 1.construct hashtable, one hash key may contains multiple rows indices.
-                // 2.merged all left tuples.
+                // 2.merged all left tuples, each of them has a bit marking whether it is matched.
                 let mut coroutine = build_read(left_input, cache, transaction);
                 let mut build_map = HashMap::new();
-                let build_map_ptr: *mut HashMap<Vec<DataValue>, (Vec<Tuple>, bool, bool)> =
+                let build_map_ptr: *mut HashMap<Vec<DataValue>, (Vec<Tuple>, FixedBitSet)> =
                     &mut build_map;
 
                 while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
@@ -160,11 +150,9 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashJoin {
                     ));
 
                     unsafe {
-                        (*build_map_ptr)
-                            .entry(values)
-                            .or_insert_with(|| (Vec::new(), false, false))
-                            .0
-                            .push(tuple);
+                        let (tuples, matched_bits) = (*build_map_ptr).entry(values).or_default();
+                        tuples.push(tuple);
+                        matched_bits.grow(tuples.len());
                     }
                 }
 
@@ -180,82 +168,62 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashJoin {
                         &tuple,
                         &full_schema_ref[left_schema_len..]
                     ));
-                    let has_null = values.iter().any(|value| value.is_null());
+                    // `NULL` never equals anything, even another `NULL`, unless keys are compared
+                    // by `IS NOT DISTINCT FROM`
+                    let has_null = !null_equals_null && values.iter().any(|value| value.is_null());
                     let build_value = unsafe { (*build_map_ptr).get_mut(&values) };
                     drop(values);
+                    let mut is_probe_matched = false;
 
-                    if let (false, Some((tuples, is_used, is_filtered))) = (has_null, build_value) {
-                        let mut bits_option = None;
-                        *is_used = true;
+                    if let (false, Some((tuples, matched_bits))) = (has_null, build_value) {
+                        let is_semi = matches!(ty, JoinType::LeftSemi);
 
-                        match ty {
-                            JoinType::LeftSemi => {
-                                if *is_filtered {
-                                    continue;
-                                } else {
-                                    bits_option = Some(FixedBitSet::with_capacity(tuples.len()));
-                                }
-                            }
-                            JoinType::LeftAnti => continue,
-                            _ => (),
+                        // the filter of anti join is only applied on the left tuples that are not matched
+                        if matches!(ty, JoinType::LeftAnti) {
+                            matched_bits.insert_range(..);
+                            continue;
                         }
                         for (i, Tuple { values, .. }) in tuples.iter().enumerate() {
+                            // only whether a left tuple is matched matters to semi join
+                            if is_semi && matched_bits.contains(i) {
+                                continue;
+                            }
                             let full_values = values
                                 .iter()
                                 .chain(tuple.values.iter())
                                 .cloned()
                                 .collect_vec();
                             let tuple = Tuple::new(None, full_values);
-                            if let Some(tuple) = throw!(Self::filter(
-                                tuple,
-                                &full_schema_ref,
-                                &filter,
-                                &ty,
-                                left_schema_len
-                            )) {
-                                if let Some(bits) = bits_option.as_mut() {
-                                    bits.insert(i);
-                                } else {
-                                    yield Ok(tuple);
-                                }
+                            if !throw!(Self::is_matched(&tuple, &full_schema_ref, &filter)) {
+                                continue;
+                            }
+                            is_probe_matched = true;
+                            matched_bits.insert(i);
+
+                            if !is_semi {
+                                yield Ok(tuple);
                             }
                         }
-                        if let Some(bits) = bits_option {
-                            let mut cnt = 0;
-                            tuples.retain(|_| {
-                                let res = bits.contains(cnt);
-                                cnt += 1;
-                                res
-                            });
-                            *is_filtered = true
-                        }
-                    } else if matches!(ty, JoinType::RightOuter | JoinType::Full) {
+                    }
+                    if !is_probe_matched && matches!(ty, JoinType::RightOuter | JoinType::Full) {
                         let empty_len = full_schema_ref.len() - right_cols_len;
                         let values = (0..empty_len)
                             .map(|_| NULL_VALUE.clone())
                             .chain(tuple.values)
                             .collect_vec();
-                        let tuple = Tuple::new(None, values);
-                        if let Some(tuple) = throw!(Self::filter(
-                            tuple,
-                            &full_schema_ref,
-                            &filter,
-                            &ty,
-                            left_schema_len
-                        )) {
-                            yield Ok(tuple);
-                        }
+
+                        yield Ok(Tuple::new(None, values));
                     }
                 }
 
                 // left drop
                 match ty {
                     JoinType::LeftOuter | JoinType::Full => {
-                        for (_, (left_tuples, is_used, _)) in build_map {
-                            if is_used {
-                                continue;
-                            }
-                            for mut tuple in left_tuples {
+                        for (_, (left_tuples, matched_bits)) in build_map {
+                            for (i, mut tuple) in left_tuples.into_iter().enumerate() {
+                                if matched_bits.contains(i) {
+                                    continue;
+                                }
                                 while tuple.values.len() != full_schema_ref.len() {
                                     tuple.values.push(NULL_VALUE.clone());
                                 }
@@ -263,30 +231,22 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for HashJoin {
                             }
                         }
                     }
-                    JoinType::LeftSemi | JoinType::LeftAnti => {
-                        let is_left_semi = matches!(ty, JoinType::LeftSemi);
-
-                        for (_, (left_tuples, mut is_used, is_filtered)) in build_map {
-                            if is_left_semi {
-                                is_used = !is_used;
-                            }
-                            if is_used {
-                                continue;
-                            }
-                            if is_filtered {
-                                for tuple in left_tuples {
+                    JoinType::LeftSemi => {
+                        for (_, (left_tuples, matched_bits)) in build_map {
+                            for (i, tuple) in left_tuples.into_iter().enumerate() {
+                                if matched_bits.contains(i) {
                                     yield Ok(tuple);
                                 }
-                                continue;
                             }
-                            for tuple in left_tuples {
-                                if let Some(tuple) = throw!(Self::filter(
-                                    tuple,
-                                    &full_schema_ref,
-                                    &filter,
-                                    &ty,
-                                    left_schema_len
-                                )) {
+                        }
+                    }
+                    JoinType::LeftAnti => {
+                        for (_, (left_tuples, matched_bits)) in build_map {
+                            for (i, tuple) in left_tuples.into_iter().enumerate() {
+                                if matched_bits.contains(i) {
+                                    continue;
+                                }
+                                if throw!(Self::is_matched(&tuple, &full_schema_ref, &filter)) {
                                     yield Ok(tuple);
                                 }
                             }
@@ -306,7 +266,7 @@ mod test {
     use crate::execution::dql::join::hash_join::HashJoin;
     use crate::execution::dql::test::build_integers;
     use crate::execution::{try_collect, ReadExecutor};
-    use crate::expression::ScalarExpression;
+    use crate::expression::{BinaryOperator, ScalarExpression};
     use crate::planner::operator::join::{JoinCondition, JoinOperator, JoinType};
     use crate::planner::operator::values::ValuesOperator;
     use crate::planner::operator::Operator;
@@ -314,10 +274,13 @@ mod test {
     use crate::storage::rocksdb::RocksStorage;
     use crate::storage::table_codec::BumpBytes;
     use crate::storage::Storage;
+    use crate::types::evaluator::EvaluatorFactory;
+    use crate::types::tuple::Tuple;
     use crate::types::value::DataValue;
     use crate::types::LogicalType;
     use crate::utils::lru::SharedLruCache;
     use bumpalo::Bump;
+    use itertools::Itertools;
     use std::hash::RandomState;
     use std::sync::Arc;
     use tempfile::TempDir;
@@ -420,6 +383,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::Inner,
         };
@@ -459,6 +423,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::LeftOuter,
         };
@@ -545,6 +510,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::RightOuter,
         };
@@ -588,6 +554,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::Full,
         };
@@ -620,4 +587,216 @@ mod test {
 
         Ok(())
     }
+
+    fn build_values(
+        names: [&str; 2],
+        rows: Vec<[Option<i32>; 2]>,
+    ) -> (Vec<ColumnRef>, LogicalPlan) {
+        let desc = ColumnDesc::new(LogicalType::Integer, None, true, None).unwrap();
+        let columns = names
+            .into_iter()
+            .map(|name| ColumnRef::from(ColumnCatalog::new(name.to_string(), true, desc.clone())))
+            .collect_vec();
+        let rows = rows
+            .into_iter()
+            .map(|row| build_integers(row.to_vec()))
+            .collect_vec();
+
+        let plan = LogicalPlan {
+            operator: Operator::Values(ValuesOperator {
+                rows,
+                schema_ref: Arc::new(columns.clone()),
+            }),
+            childrens: Box::new(Childrens::None),
+            physical_option: None,
+            _output_schema_ref: None,
+        };
+        (columns, plan)
+    }
+
+    fn sorted(tuples: Vec<Vec<DataValue>>) -> Vec<Vec<DataValue>> {
+        tuples
+            .into_iter()
+            .sorted_by_key(|values| format!("{:?}", values))
+            .collect_vec()
+    }
+
+    #[test]
+    fn test_outer_join_with_filter_and_null_keys() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let storage = RocksStorage::new(temp_dir.path())?;
+        let mut transaction = storage.transaction()?;
+        let meta_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+        let view_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+        let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+        let (left_columns, left) = build_values(
+            ["k1", "v1"],
+            vec![
+                [Some(0), Some(1)],
+                [Some(1), Some(2)],
+                [Some(1), Some(5)],
+                [None, Some(3)],
+            ],
+        );
+        let (right_columns, right) = build_values(
+            ["k2", "v2"],
+            vec![[Some(1), Some(3)], [Some(2), Some(0)], [None, Some(3)]],
+        );
+        let (_, empty) = build_values(["k2", "v2"], vec![]);
+        // `on k1 = k2 and v1 < v2`
+        let op = |join_type| JoinOperator {
+            on: JoinCondition::On {
+                on: vec![(
+                    ScalarExpression::ColumnRef(left_columns[0].clone()),
+                    ScalarExpression::ColumnRef(right_columns[0].clone()),
+                )],
+                filter: Some(ScalarExpression::Binary {
+                    op: BinaryOperator::Lt,
+                    left_expr: Box::new(ScalarExpression::ColumnRef(left_columns[1].clone())),
+                    right_expr: Box::new(ScalarExpression::ColumnRef(right_columns[1].clone())),
+                    evaluator: Some(
+                        EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Lt)
+                            .unwrap(),
+                    ),
+                    ty: LogicalType::Boolean,
+                }),
+                null_equals_null: false,
+            },
+            join_type,
+        };
+        let mut join = |join_type, right: &LogicalPlan| -> Result<_, DatabaseError> {
+            let executor = HashJoin::from((op(join_type), left.clone(), right.clone()))
+                .execute((&table_cache, &view_cache, &meta_cache), &mut transaction);
+
+            Ok(sorted(
+                try_collect(executor)?
+                    .into_iter()
+                    .map(|Tuple { values, .. }| values)
+                    .collect_vec(),
+            ))
+        };
+        let expected = |rows: Vec<Vec<Option<i32>>>| {
+            sorted(rows.into_iter().map(build_integers).collect_vec())
+        };
+
+        assert_eq!(
+            join(JoinType::Inner, &right)?,
+            expected(vec![vec![Some(1), Some(2), Some(1), Some(3)]])
+        );
+        assert_eq!(
+            join(JoinType::LeftOuter, &right)?,
+            expected(vec![
+                vec![Some(0), Some(1), None, None],
+                vec![Some(1), Some(2), Some(1), Some(3)],
+                vec![Some(1), Some(5), None, None],
+                vec![None, Some(3), None, None],
+            ])
+        );
+        assert_eq!(
+            join(JoinType::RightOuter, &right)?,
+            expected(vec![
+                vec![Some(1), Some(2), Some(1), Some(3)],
+                vec![None, None, Some(2), Some(0)],
+                vec![None, None, None, Some(3)],
+            ])
+        );
+        assert_eq!(
+            join(JoinType::Full, &right)?,
+            expected(vec![
+                vec![Some(0), Some(1), None, None],
+                vec![Some(1), Some(2), Some(1), Some(3)],
+                vec![Some(1), Some(5), None, None],
+                vec![None, Some(3), None, None],
+                vec![None, None, Some(2), Some(0)],
+                vec![None, None, None, Some(3)],
+            ])
+        );
+        assert_eq!(
+            join(JoinType::LeftSemi, &right)?,
+            expected(vec![vec![Some(1), Some(2)]])
+        );
+
+        // the empty probe side still emits every left tuple for left & full outer join
+        let left_padded = expected(vec![
+            vec![Some(0), Some(1), None, None],
+            vec![Some(1), Some(2), None, None],
+            vec![Some(1), Some(5), None, None],
+            vec![None, Some(3), None, None],
+        ]);
+        assert_eq!(join(JoinType::Inner, &empty)?, expected(vec![]));
+        assert_eq!(join(JoinType::LeftOuter, &empty)?, left_padded);
+        assert_eq!(join(JoinType::RightOuter, &empty)?, expected(vec![]));
+        assert_eq!(join(JoinType::Full, &empty)?, left_padded);
+
+        Ok(())
+    }
+    #[test]
+    fn test_null_equals_null_join() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let storage = RocksStorage::new(temp_dir.path())?;
+        let mut transaction = storage.transaction()?;
+        let meta_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+        let view_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+        let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+        let (left_columns, left) = build_values(
+            ["k1", "v1"],
+            vec![[Some(0), Some(1)], [Some(1), Some(2)], [None, Some(3)]],
+        );
+        let (right_columns, right) = build_values(
+            ["k2", "v2"],
+            vec![[Some(1), Some(4)], [None, Some(5)], [None, Some(6)]],
+        );
+        // `on k1 is not distinct from k2`
+        let op = |join_type| JoinOperator {
+            on: JoinCondition::On {
+                on: vec![(
+                    ScalarExpression::ColumnRef(left_columns[0].clone()),
+                    ScalarExpression::ColumnRef(right_columns[0].clone()),
+                )],
+                filter: None,
+                null_equals_null: true,
+            },
+            join_type,
+        };
+        let mut join = |join_type| -> Result<_, DatabaseError> {
+            let executor = HashJoin::from((op(join_type), left.clone(), right.clone()))
+                .execute((&table_cache, &view_cache, &meta_cache), &mut transaction);
+
+            Ok(sorted(
+                try_collect(executor)?
+                    .into_iter()
+                    .map(|Tuple { values, .. }| values)
+                    .collect_vec(),
+            ))
+        };
+        let expected = |rows: Vec<Vec<Option<i32>>>| {
+            sorted(rows.into_iter().map(build_integers).collect_vec())
+        };
+
+        assert_eq!(
+            join(JoinType::Inner)?,
+            expected(vec![
+                vec![Some(1), Some(2), Some(1), Some(4)],
+                vec![None, Some(3), None, Some(5)],
+                vec![None, Some(3), None, Some(6)],
+            ])
+        );
+        assert_eq!(
+            join(JoinType::LeftOuter)?,
+            expected(vec![
+                vec![Some(0), Some(1), None, None],
+                vec![Some(1), Some(2), Some(1), Some(4)],
+                vec![None, Some(3), None, Some(5)],
+                vec![None, Some(3), None, Some(6)],
+            ])
+        );
+        assert_eq!(
+            join(JoinType::LeftAnti)?,
+            expected(vec![vec![Some(0), Some(1)]])
+        );
+
+        Ok(())
+    }
 }
@@ -26,6 +26,7 @@ struct EqualCondition {
     on_right_keys: Vec<ScalarExpression>,
     left_schema: SchemaRef,
     right_schema: SchemaRef,
+    null_equals_null: bool,
 }
 
 impl EqualCondition {
@@ -37,6 +38,7 @@ impl EqualCondition {
         on_right_keys: Vec<ScalarExpression>,
         left_schema: Arc<Schema>,
         right_schema: Arc<Schema>,
+        null_equals_null: bool,
     ) -> EqualCondition {
         if !on_left_keys.is_empty() && on_left_keys.len() != on_right_keys.len() {
             unreachable!("Unexpected join on condition.")
@@ -46,6 +48,7 @@ impl EqualCondition {
             on_right_keys,
             left_schema,
             right_schema,
+            null_equals_null,
         }
     }
 
@@ -61,6 +64,14 @@ impl EqualCondition {
         let right_values =
             Projection::projection(right_tuple, &self.on_right_keys, &self.right_schema)?;
 
+        if !self.null_equals_null
+            && left_values
+                .iter()
+                .chain(right_values.iter())
+                .any(DataValue::is_null)
+        {
+            return Ok(false);
+        }
         Ok(left_values == right_values)
     }
 }
@@ -91,9 +102,16 @@ impl From<(JoinOperator, LogicalPlan, LogicalPlan)> for NestedLoopJoin {
             LogicalPlan,
         ),
     ) -> Self {
-        let ((mut on_left_keys, mut on_right_keys), filter) = match on {
-            JoinCondition::On { on, filter } => (on.into_iter().unzip(), filter),
-            JoinCondition::None => ((vec![], vec![]), None),
+        let ((mut on_left_keys, mut on_right_keys), filter, null_equals_null) = match on {
+            JoinCondition::On {
+                on,
+                filter,
+                null_equals_null,
+            } => (on.into_iter().unzip(), filter, null_equals_null),
+            JoinCondition::None => ((vec![], vec![]), None, false),
+            JoinCondition::Dependent { .. } => {
+                unreachable!("Dependent join should not be in NestedLoopJoinExecutor")
+            }
         };
 
         let (mut left_input, mut right_input) = (left_input, right_input);
@@ -112,6 +130,7 @@ impl From<(JoinOperator, LogicalPlan, LogicalPlan)> for NestedLoopJoin {
             on_right_keys,
             left_schema.clone(),
             right_schema.clone(),
+            null_equals_null,
         );
 
         NestedLoopJoin {
@@ -145,9 +164,18 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for NestedLoopJoin {
                 } = self;
 
                 let right_schema_len = eq_cond.right_schema.len();
+                // the inner table is materialized once, rather than being re-executed for every outer tuple
+                let mut right_tuples = Vec::new();
+                let mut right_coroutine = build_read(right_input, cache, transaction);
+
+                while let CoroutineState::Yielded(right_tuple) =
+                    Pin::new(&mut right_coroutine).resume(())
+                {
+                    right_tuples.push(throw!(right_tuple));
+                }
+                let mut bitmap = matches!(ty, JoinType::Full)
+                    .then(|| FixedBitSet::with_capacity(right_tuples.len()));
                 let mut left_coroutine = build_read(left_input, cache, transaction);
-                let mut bitmap: Option<FixedBitSet> = None;
-                let mut first_matches = Vec::new();
 
                 while let CoroutineState::Yielded(left_tuple) =
                     Pin::new(&mut left_coroutine).resume(())
@@ -155,28 +183,24 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for NestedLoopJoin {
                     let left_tuple: Tuple = throw!(left_tuple);
                     let mut has_matched = false;
 
-                    let mut right_coroutine = build_read(right_input.clone(), cache, transaction);
-                    let mut right_idx = 0;
-
-                    while let CoroutineState::Yielded(right_tuple) =
-                        Pin::new(&mut right_coroutine).resume(())
-                    {
-                        let right_tuple: Tuple = throw!(right_tuple);
-
+                    // indexing keeps the borrow of `right_tuples` from living across `yield`
+                    #[allow(clippy::needless_range_loop)]
+                    for right_idx in 0..right_tuples.len() {
+                        let right_tuple = &right_tuples[right_idx];
                         let tuple = match (
                             filter.as_ref(),
-                            throw!(eq_cond.equals(&left_tuple, &right_tuple)),
+                            throw!(eq_cond.equals(&left_tuple, right_tuple)),
                         ) {
                             (None, true) if matches!(ty, JoinType::RightOuter) => {
                                 has_matched = true;
-                                Self::emit_tuple(&right_tuple, &left_tuple, ty, true)
+                                Self::emit_tuple(right_tuple, &left_tuple, ty, true)
                             }
                             (None, true) => {
                                 has_matched = true;
-                                Self::emit_tuple(&left_tuple, &right_tuple, ty, true)
+                                Self::emit_tuple(&left_tuple, right_tuple, ty, true)
                             }
                             (Some(filter), true) => {
-                                let new_tuple = Self::merge_tuple(&left_tuple, &right_tuple, &ty);
+                                let new_tuple = Self::merge_tuple(&left_tuple, right_tuple, &ty);
                                 let value =
                                     throw!(filter.eval(Some((&new_tuple, &output_schema_ref))));
                                 match &value {
@@ -184,18 +208,12 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for NestedLoopJoin {
                                         let tuple = match ty {
                                             JoinType::LeftAnti => None,
                                             JoinType::LeftSemi if has_matched => None,
-                                            JoinType::RightOuter => Self::emit_tuple(
-                                                &right_tuple,
-                                                &left_tuple,
-                                                ty,
-                                                true,
-                                            ),
-                                            _ => Self::emit_tuple(
-                                                &left_tuple,
-                                                &right_tuple,
-                                                ty,
-                                                true,
-                                            ),
+                                            JoinType::RightOuter => {
+                                                Self::emit_tuple(right_tuple, &left_tuple, ty, true)
+                                            }
+                                            _ => {
+                                                Self::emit_tuple(&left_tuple, right_tuple, ty, true)
+                                            }
                                         };
                                         has_matched = true;
                                         tuple
@@ -217,18 +235,11 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for NestedLoopJoin {
                             }
                             if let Some(bits) = bitmap.as_mut() {
                                 bits.insert(right_idx);
-                            } else if matches!(ty, JoinType::Full) {
-                                first_matches.push(right_idx);
                             }
                         }
                         if matches!(ty, JoinType::LeftAnti) && has_matched {
                             break;
                         }
-                        right_idx += 1;
-                    }
-
-                    if matches!(self.ty, JoinType::Full) && bitmap.is_none() {
-                        bitmap = Some(FixedBitSet::with_capacity(right_idx));
                     }
 
                     // handle no matched tuple case
@@ -255,24 +266,14 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for NestedLoopJoin {
                     }
                 }
 
-                if matches!(ty, JoinType::Full) {
-                    for idx in first_matches.into_iter() {
-                        bitmap.as_mut().unwrap().insert(idx);
-                    }
-
-                    let mut right_coroutine = build_read(right_input.clone(), cache, transaction);
-                    let mut idx = 0;
-                    while let CoroutineState::Yielded(right_tuple) =
-                        Pin::new(&mut right_coroutine).resume(())
-                    {
-                        if !bitmap.as_ref().unwrap().contains(idx) {
-                            let mut right_tuple: Tuple = throw!(right_tuple);
+                if let Some(bitmap) = bitmap {
+                    for (idx, mut right_tuple) in right_tuples.into_iter().enumerate() {
+                        if !bitmap.contains(idx) {
                             let mut values = vec![NULL_VALUE.clone(); right_schema_len];
                             values.append(&mut right_tuple.values);
 
                             yield Ok(Tuple::new(None, values))
                         }
-                        idx += 1;
                     }
                 }
             },
@@ -548,6 +549,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::Inner,
         };
@@ -577,6 +579,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::LeftOuter,
         };
@@ -618,6 +621,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::Cross,
         };
@@ -648,6 +652,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::Cross,
         };
@@ -681,6 +686,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: None,
+                null_equals_null: false,
             },
             join_type: JoinType::Cross,
         };
@@ -706,6 +712,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::LeftSemi,
         };
@@ -734,6 +741,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::LeftAnti,
         };
@@ -764,6 +772,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::RightOuter,
         };
@@ -799,6 +808,7 @@ mod test {
             on: JoinCondition::On {
                 on: keys,
                 filter: Some(filter),
+                null_equals_null: false,
             },
             join_type: JoinType::Full,
         };
@@ -1,10 +1,12 @@
 pub(crate) mod aggregate;
 pub(crate) mod describe;
 pub(crate) mod dummy;
+pub(crate) mod except;
 pub(crate) mod explain;
 pub(crate) mod filter;
 pub(crate) mod function_scan;
 pub(crate) mod index_scan;
+pub(crate) mod intersect;
 pub(crate) mod join;
 pub(crate) mod limit;
 pub(crate) mod projection;
@@ -13,6 +15,7 @@ pub(crate) mod show_table;
 pub(crate) mod sort;
 pub(crate) mod union;
 pub(crate) mod values;
+pub(crate) mod window;
 
 #[cfg(test)]
 pub(crate) mod test {
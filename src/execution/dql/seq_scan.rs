@@ -1,7 +1,9 @@
+use crate::execution::dql::projection::Projection;
 use crate::execution::{Executor, ReadExecutor};
 use crate::planner::operator::table_scan::TableScanOperator;
 use crate::storage::{Iter, StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
+use itertools::Itertools;
 
 pub(crate) struct SeqScan {
     op: TableScanOperator,
@@ -26,8 +28,15 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for SeqScan {
                     table_name,
                     columns,
                     limit,
+                    projection,
                     ..
                 } = self.op;
+                let schema = projection.is_some().then(|| {
+                    columns
+                        .iter()
+                        .map(|(_, column)| column.clone())
+                        .collect_vec()
+                });
 
                 let mut iter = throw!(unsafe { &mut (*transaction) }.read(
                     table_cache,
@@ -36,7 +45,10 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for SeqScan {
                     columns
                 ));
 
-                while let Some(tuple) = throw!(iter.next_tuple()) {
+                while let Some(mut tuple) = throw!(iter.next_tuple()) {
+                    if let (Some(exprs), Some(schema)) = (&projection, &schema) {
+                        tuple.values = throw!(Projection::projection(&tuple, exprs, schema));
+                    }
                     yield Ok(tuple);
                 }
             },
@@ -6,6 +6,7 @@ use crate::storage::table_codec::BumpBytes;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
 use crate::throw;
 use crate::types::tuple::{Schema, Tuple};
+use crate::types::value::DataValue;
 use bumpalo::Bump;
 use std::cmp::Ordering;
 use std::ops::Coroutine;
@@ -71,7 +72,7 @@ impl Iterator for RemappingIterator<'_> {
 
 const BUCKET_SIZE: usize = u8::MAX as usize + 1;
 
-// LSD Radix Sort
+// LSD Radix Sort, which is stable: the items of equal keys keep their order
 pub(crate) fn radix_sort<'a, T, A: AsRef<[u8]>>(
     mut tuples: BumpVec<'a, (T, A)>,
     arena: &'a Bump,
@@ -102,12 +103,110 @@ pub(crate) fn radix_sort<'a, T, A: AsRef<[u8]>>(
     result
 }
 
+pub(crate) fn compare_value(
+    value_1: &DataValue,
+    value_2: &DataValue,
+    asc: bool,
+    nulls_first: bool,
+) -> Ordering {
+    let fn_nulls_first = |nulls_first: bool| {
+        if nulls_first {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    };
+
+    match (value_1.is_null(), value_2.is_null()) {
+        (false, true) => fn_nulls_first(nulls_first),
+        (true, false) => fn_nulls_first(nulls_first).reverse(),
+        _ => {
+            let ordering = value_1.partial_cmp(value_2).unwrap_or(Ordering::Equal);
+            if !asc {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+    }
+}
+
+/// Keeps the `limit` smallest items by `compare` in a bounded binary max-heap,
+/// so that N items cost about `N * log(limit)` comparisons instead of `N * log(N)` of a full sort.
+pub(crate) struct TopN<T, F: FnMut(&T, &T) -> Ordering> {
+    heap: Vec<T>,
+    limit: usize,
+    compare: F,
+}
+
+impl<T, F: FnMut(&T, &T) -> Ordering> TopN<T, F> {
+    pub(crate) fn new(limit: usize, compare: F) -> Self {
+        TopN {
+            heap: Vec::with_capacity(limit.min(1024)),
+            limit,
+            compare,
+        }
+    }
+
+    pub(crate) fn push(&mut self, item: T) {
+        if self.heap.len() < self.limit {
+            self.heap.push(item);
+
+            let mut pos = self.heap.len() - 1;
+            while pos > 0 {
+                let parent = (pos - 1) / 2;
+                if (self.compare)(&self.heap[pos], &self.heap[parent]) != Ordering::Greater {
+                    break;
+                }
+                self.heap.swap(pos, parent);
+                pos = parent;
+            }
+        } else if self.limit > 0 && (self.compare)(&item, &self.heap[0]) == Ordering::Less {
+            // replace the largest one
+            self.heap[0] = item;
+
+            let mut pos = 0;
+            loop {
+                let left = pos * 2 + 1;
+                if left >= self.heap.len() {
+                    break;
+                }
+                let right = left + 1;
+                let mut largest = left;
+
+                if right < self.heap.len()
+                    && (self.compare)(&self.heap[right], &self.heap[left]) == Ordering::Greater
+                {
+                    largest = right;
+                }
+                if (self.compare)(&self.heap[largest], &self.heap[pos]) != Ordering::Greater {
+                    break;
+                }
+                self.heap.swap(pos, largest);
+                pos = largest;
+            }
+        }
+    }
+
+    pub(crate) fn into_sorted_vec(self) -> Vec<T> {
+        let TopN {
+            mut heap,
+            mut compare,
+            ..
+        } = self;
+        heap.sort_by(&mut compare);
+        heap
+    }
+}
+
 pub enum SortBy {
     Radix,
     Fast,
 }
 
 impl SortBy {
+    /// Sorts the tuples, numbered by their offsets from 0, by `sort_fields`,
+    /// the tuples of equal keys keep the order of their offsets.
     pub(crate) fn sorted_tuples<'a>(
         &self,
         arena: &'a Bump,
@@ -132,15 +231,20 @@ impl SortBy {
                     {
                         let mut key = BumpBytes::new_in(arena);
                         let tuple = tuple.as_ref().map(|(_, tuple)| tuple).unwrap();
-
-                        expr.eval(Some((tuple, schema)))?
-                            .memcomparable_encode(&mut key)?;
-                        if !asc {
-                            for byte in key.iter_mut() {
-                                *byte ^= 0xFF;
+                        let value = expr.eval(Some((tuple, schema)))?;
+
+                        // the tag ahead of the value places the NULLs regardless of `asc`
+                        if value.is_null() {
+                            key.push(if *nulls_first { 0 } else { 2 });
+                        } else {
+                            key.push(1);
+                            value.memcomparable_encode(&mut key)?;
+                            if !asc {
+                                for byte in key.iter_mut().skip(1) {
+                                    *byte ^= 0xFF;
+                                }
                             }
                         }
-                        key.push(if *nulls_first { u8::MIN } else { u8::MAX });
                         full_key.extend(key);
                     }
                     sort_keys.push((i, full_key))
@@ -154,13 +258,6 @@ impl SortBy {
                 }))
             }
             SortBy::Fast => {
-                let fn_nulls_first = |nulls_first: bool| {
-                    if nulls_first {
-                        Ordering::Greater
-                    } else {
-                        Ordering::Less
-                    }
-                };
                 // Extract the results of calculating SortFields to avoid double calculation
                 // of data during comparison
                 let mut eval_values = vec![Vec::with_capacity(sort_fields.len()); tuples.len()];
@@ -174,7 +271,8 @@ impl SortBy {
                     }
                 }
 
-                tuples.0.sort_by(|tuple_1, tuple_2| {
+                // the offsets break the ties, so that equal keys keep the input order
+                tuples.0.sort_unstable_by(|tuple_1, tuple_2| {
                     debug_assert!(tuple_1.is_some());
                     debug_assert!(tuple_2.is_some());
 
@@ -189,27 +287,18 @@ impl SortBy {
                         },
                     ) in sort_fields.iter().enumerate()
                     {
-                        let value_1 = &eval_values[x][*i_1];
-                        let value_2 = &eval_values[x][*i_2];
-
-                        ordering = match (value_1.is_null(), value_2.is_null()) {
-                            (false, true) => fn_nulls_first(*nulls_first),
-                            (true, false) => fn_nulls_first(*nulls_first).reverse(),
-                            _ => {
-                                let mut ordering =
-                                    value_1.partial_cmp(value_2).unwrap_or(Ordering::Equal);
-                                if !*asc {
-                                    ordering = ordering.reverse();
-                                }
-                                ordering
-                            }
-                        };
+                        ordering = compare_value(
+                            &eval_values[x][*i_1],
+                            &eval_values[x][*i_2],
+                            *asc,
+                            *nulls_first,
+                        );
                         if ordering != Ordering::Equal {
                             break;
                         }
                     }
 
-                    ordering
+                    ordering.then_with(|| i_1.cmp(i_2))
                 });
                 drop(eval_values);
 
@@ -265,24 +354,56 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Sort {
 
                 let mut coroutine = build_read(input, cache, transaction);
 
-                while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
-                    tuples.put((offset, throw!(tuple)));
-                    offset += 1;
-                }
+                if let Some(limit) = limit {
+                    // Top-N: only the first `limit` tuples are kept while reading,
+                    // the offsets break the ties like the full sort
+                    let mut top_n = TopN::new(
+                        limit,
+                        |(values_1, i_1, _): &(Vec<DataValue>, usize, Tuple),
+                         (values_2, i_2, _): &(Vec<DataValue>, usize, Tuple)| {
+                            sort_fields
+                                .iter()
+                                .zip(values_1.iter().zip(values_2.iter()))
+                                .map(|(field, (value_1, value_2))| {
+                                    compare_value(value_1, value_2, field.asc, field.nulls_first)
+                                })
+                                .find(|ordering| ordering != &Ordering::Equal)
+                                .unwrap_or_else(|| i_1.cmp(i_2))
+                        },
+                    );
+
+                    while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
+                        let tuple = throw!(tuple);
+                        let mut values = Vec::with_capacity(sort_fields.len());
 
-                let sort_by = if tuples.len() > 256 {
-                    SortBy::Radix
+                        for SortField { expr, .. } in sort_fields.iter() {
+                            values.push(throw!(expr.eval(Some((&tuple, &schema)))));
+                        }
+                        top_n.push((values, offset, tuple));
+                        offset += 1;
+                    }
+                    for (_, _, tuple) in top_n.into_sorted_vec() {
+                        yield Ok(tuple);
+                    }
                 } else {
-                    SortBy::Fast
-                };
-                let mut limit = limit.unwrap_or(tuples.len());
+                    while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
+                        tuples.put((offset, throw!(tuple)));
+                        offset += 1;
+                    }
 
-                for tuple in
-                    throw!(sort_by.sorted_tuples(unsafe { &*arena }, &schema, &sort_fields, tuples))
-                {
-                    if limit != 0 {
+                    let sort_by = if tuples.len() > 256 {
+                        SortBy::Radix
+                    } else {
+                        SortBy::Fast
+                    };
+
+                    for tuple in throw!(sort_by.sorted_tuples(
+                        unsafe { &*arena },
+                        &schema,
+                        &sort_fields,
+                        tuples
+                    )) {
                         yield Ok(tuple);
-                        limit -= 1;
                     }
                 }
             },
@@ -294,13 +415,14 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Sort {
 mod test {
     use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
     use crate::errors::DatabaseError;
-    use crate::execution::dql::sort::{radix_sort, BumpVec, NullableVec, SortBy};
+    use crate::execution::dql::sort::{radix_sort, BumpVec, NullableVec, SortBy, TopN};
     use crate::expression::ScalarExpression;
     use crate::planner::operator::sort::SortField;
     use crate::types::tuple::Tuple;
     use crate::types::value::DataValue;
     use crate::types::LogicalType;
     use bumpalo::Bump;
+    use itertools::Itertools;
     use std::sync::Arc;
 
     #[test]
@@ -717,4 +839,169 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_nulls_around_extreme_values() -> Result<(), DatabaseError> {
+        let fn_sort_fields = |asc: bool, nulls_first: bool| {
+            (0..2)
+                .map(|pos| SortField {
+                    expr: ScalarExpression::Reference {
+                        expr: Box::new(ScalarExpression::Empty),
+                        pos,
+                    },
+                    asc,
+                    nulls_first,
+                })
+                .collect_vec()
+        };
+        let schema = Arc::new(
+            ["c1", "c2"]
+                .into_iter()
+                .map(|name| {
+                    ColumnRef::from(ColumnCatalog::new(
+                        name.to_string(),
+                        true,
+                        ColumnDesc::new(LogicalType::Integer, None, false, None).unwrap(),
+                    ))
+                })
+                .collect_vec(),
+        );
+        let rows = [
+            (DataValue::Null, DataValue::Int32(i32::MIN)),
+            (DataValue::Int32(i32::MAX), DataValue::Int32(0)),
+            (DataValue::Null, DataValue::Int32(i32::MAX)),
+            (DataValue::Int32(i32::MIN), DataValue::Null),
+            (DataValue::Int32(i32::MAX), DataValue::Null),
+        ];
+
+        let arena = Bump::new();
+        let mut inner = BumpVec::new_in(&arena);
+        for (i, (c1, c2)) in rows.iter().enumerate() {
+            inner.push(Some((i, Tuple::new(None, vec![c1.clone(), c2.clone()]))));
+        }
+        let tuples = NullableVec(inner);
+
+        for (asc, nulls_first, expected) in [
+            (true, true, [0, 2, 3, 4, 1]),
+            (true, false, [3, 1, 4, 0, 2]),
+            (false, true, [2, 0, 4, 1, 3]),
+            (false, false, [1, 4, 3, 2, 0]),
+        ] {
+            let expected = expected
+                .into_iter()
+                .map(|i| vec![rows[i].0.clone(), rows[i].1.clone()])
+                .collect_vec();
+
+            for sort_by in [SortBy::Radix, SortBy::Fast] {
+                let sorted = sort_by
+                    .sorted_tuples(
+                        &arena,
+                        &schema,
+                        &fn_sort_fields(asc, nulls_first),
+                        tuples.clone(),
+                    )?
+                    .map(|tuple| tuple.values)
+                    .collect_vec();
+                assert_eq!(
+                    sorted, expected,
+                    "asc: {}, nulls_first: {}",
+                    asc, nulls_first
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_top_n() {
+        let values = (0..100_000_u64)
+            .map(|i| i.wrapping_mul(2_654_435_761) % 100_000)
+            .collect::<Vec<_>>();
+
+        let mut sort_comparisons = 0;
+        let mut sorted = values.clone();
+        sorted.sort_by(|a, b| {
+            sort_comparisons += 1;
+            a.cmp(b)
+        });
+
+        let mut top_n_comparisons = 0;
+        let mut top_n = TopN::new(10, |a: &u64, b: &u64| {
+            top_n_comparisons += 1;
+            a.cmp(b)
+        });
+        for value in values.iter() {
+            top_n.push(*value);
+        }
+        assert_eq!(top_n.into_sorted_vec(), sorted[..10]);
+        assert!(top_n_comparisons * 10 < sort_comparisons);
+
+        let mut top_n = TopN::new(0, |a: &u64, b: &u64| a.cmp(b));
+        top_n.push(1);
+        assert!(top_n.into_sorted_vec().is_empty());
+
+        let mut top_n = TopN::new(5, |a: &u64, b: &u64| a.cmp(b));
+        for value in [3, 1, 2] {
+            top_n.push(value);
+        }
+        assert_eq!(top_n.into_sorted_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_equal_keys_keep_input_order() -> Result<(), DatabaseError> {
+        let sort_fields = vec![SortField {
+            expr: ScalarExpression::Reference {
+                expr: Box::new(ScalarExpression::Empty),
+                pos: 0,
+            },
+            asc: false,
+            nulls_first: true,
+        }];
+        let schema = Arc::new(
+            ["c1", "c2"]
+                .into_iter()
+                .map(|name| {
+                    ColumnRef::from(ColumnCatalog::new(
+                        name.to_string(),
+                        true,
+                        ColumnDesc::new(LogicalType::Integer, None, false, None).unwrap(),
+                    ))
+                })
+                .collect_vec(),
+        );
+        // a few keys repeated all over, `c2` is the input position
+        let key = |i: i32| match i % 4 {
+            0 => DataValue::Null,
+            k => DataValue::Int32(k),
+        };
+        let mut expected = (0..1000).collect_vec();
+        expected.sort_by_key(|i| match key(*i) {
+            DataValue::Int32(k) => -k,
+            _ => i32::MIN,
+        });
+
+        for len in [100, 1000] {
+            let expected = expected.iter().filter(|i| **i < len).cloned().collect_vec();
+            let arena = Bump::new();
+            let mut inner = BumpVec::new_in(&arena);
+            for i in 0..len {
+                inner.push(Some((
+                    i as usize,
+                    Tuple::new(None, vec![key(i), DataValue::Int32(i)]),
+                )));
+            }
+            let tuples = NullableVec(inner);
+
+            for sort_by in [SortBy::Radix, SortBy::Fast] {
+                let sorted = sort_by
+                    .sorted_tuples(&arena, &schema, &sort_fields, tuples.clone())?
+                    .map(|tuple| tuple.values[1].i32().unwrap())
+                    .collect_vec();
+                assert_eq!(sorted, expected, "len: {}", len);
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,6 +1,9 @@
 use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::planner::operator::union::UnionOperator;
 use crate::planner::LogicalPlan;
 use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::throw;
+use ahash::{HashSet, HashSetExt};
 use std::ops::Coroutine;
 use std::ops::CoroutineState;
 use std::pin::Pin;
@@ -8,13 +11,21 @@ use std::pin::Pin;
 pub struct Union {
     left_input: LogicalPlan,
     right_input: LogicalPlan,
+    is_all: bool,
 }
 
-impl From<(LogicalPlan, LogicalPlan)> for Union {
-    fn from((left_input, right_input): (LogicalPlan, LogicalPlan)) -> Self {
+impl From<(UnionOperator, LogicalPlan, LogicalPlan)> for Union {
+    fn from(
+        (UnionOperator { is_all, .. }, left_input, right_input): (
+            UnionOperator,
+            LogicalPlan,
+            LogicalPlan,
+        ),
+    ) -> Self {
         Union {
             left_input,
             right_input,
+            is_all,
         }
     }
 }
@@ -31,16 +42,23 @@ impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Union {
                 let Union {
                     left_input,
                     right_input,
+                    is_all,
                 } = self;
-                let mut coroutine = build_read(left_input, cache, transaction);
+                // the tuples are streamed, only the distinct `UNION` keeps the ones already emitted
+                let mut emitted = HashSet::new();
 
-                while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
-                    yield tuple;
-                }
-                let mut coroutine = build_read(right_input, cache, transaction);
+                for input in [left_input, right_input] {
+                    let mut coroutine = build_read(input, cache, transaction);
+
+                    while let CoroutineState::Yielded(result) = Pin::new(&mut coroutine).resume(())
+                    {
+                        let tuple = throw!(result);
 
-                while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
-                    yield tuple;
+                        if !is_all && !emitted.insert(tuple.values.clone()) {
+                            continue;
+                        }
+                        yield Ok(tuple);
+                    }
                 }
             },
         )
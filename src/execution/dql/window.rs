@@ -0,0 +1,135 @@
+use crate::errors::DatabaseError;
+use crate::execution::dql::sort::compare_value;
+use crate::execution::{build_read, Executor, ReadExecutor};
+use crate::planner::operator::sort::SortField;
+use crate::planner::operator::window::{WindowCall, WindowKind, WindowOperator};
+use crate::planner::LogicalPlan;
+use crate::storage::{StatisticsMetaCache, TableCache, Transaction, ViewCache};
+use crate::throw;
+use crate::types::tuple::{Schema, Tuple};
+use crate::types::value::DataValue;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::ops::Coroutine;
+use std::ops::CoroutineState;
+use std::pin::Pin;
+
+pub struct Window {
+    calls: Vec<WindowCall>,
+    input: LogicalPlan,
+}
+
+impl From<(WindowOperator, LogicalPlan)> for Window {
+    fn from((WindowOperator { calls }, input): (WindowOperator, LogicalPlan)) -> Self {
+        Window { calls, input }
+    }
+}
+
+impl<'a, T: Transaction + 'a> ReadExecutor<'a, T> for Window {
+    fn execute(
+        self,
+        cache: (&'a TableCache, &'a ViewCache, &'a StatisticsMetaCache),
+        transaction: *mut T,
+    ) -> Executor<'a> {
+        Box::new(
+            #[coroutine]
+            move || {
+                let Window { calls, mut input } = self;
+
+                let schema = input.output_schema().clone();
+                let mut tuples = Vec::new();
+                let mut coroutine = build_read(input, cache, transaction);
+
+                while let CoroutineState::Yielded(tuple) = Pin::new(&mut coroutine).resume(()) {
+                    tuples.push(throw!(tuple));
+                }
+                let mut results = vec![Vec::with_capacity(calls.len()); tuples.len()];
+
+                for call in calls.iter() {
+                    for (i, value) in throw!(Self::eval_call(call, &tuples, &schema)) {
+                        results[i].push(value);
+                    }
+                }
+                for (mut tuple, mut values) in tuples.into_iter().zip(results) {
+                    tuple.values.append(&mut values);
+
+                    yield Ok(tuple);
+                }
+            },
+        )
+    }
+}
+
+impl Window {
+    /// Sorts the tuples by the partition and then by the order of the call, so that the tuples of
+    /// a partition are adjacent and ranked in order.
+    ///
+    /// Returns the result of the call for the position of each tuple.
+    fn eval_call(
+        call: &WindowCall,
+        tuples: &[Tuple],
+        schema: &Schema,
+    ) -> Result<Vec<(usize, DataValue)>, DatabaseError> {
+        let mut keys = Vec::with_capacity(tuples.len());
+
+        for tuple in tuples {
+            let partition = call
+                .partition_by
+                .iter()
+                .map(|expr| expr.eval(Some((tuple, schema))))
+                .try_collect::<_, Vec<_>, _>()?;
+            let order = call
+                .order_by
+                .iter()
+                .map(|SortField { expr, .. }| expr.eval(Some((tuple, schema))))
+                .try_collect::<_, Vec<_>, _>()?;
+            keys.push((partition, order));
+        }
+        let compare_partition = |i: usize, j: usize| {
+            keys[i]
+                .0
+                .iter()
+                .zip(keys[j].0.iter())
+                .map(|(value_1, value_2)| compare_value(value_1, value_2, true, true))
+                .find(|ordering| ordering != &Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        let compare_order = |i: usize, j: usize| {
+            call.order_by
+                .iter()
+                .zip(keys[i].1.iter().zip(keys[j].1.iter()))
+                .map(|(field, (value_1, value_2))| {
+                    compare_value(value_1, value_2, field.asc, field.nulls_first)
+                })
+                .find(|ordering| ordering != &Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        let mut indices = (0..tuples.len()).collect_vec();
+        indices.sort_by(|i, j| compare_partition(*i, *j).then_with(|| compare_order(*i, *j)));
+
+        let mut results = Vec::with_capacity(tuples.len());
+        let (mut row_number, mut rank, mut dense_rank) = (0, 0, 0);
+
+        for (pos, i) in indices.iter().enumerate() {
+            let prev = pos.checked_sub(1).map(|pos| indices[pos]);
+
+            if prev.map_or(true, |prev| compare_partition(prev, *i) != Ordering::Equal) {
+                (row_number, rank, dense_rank) = (1, 1, 1);
+            } else {
+                row_number += 1;
+                // the peers of the previous tuple keep its rank
+                if prev.is_some_and(|prev| compare_order(prev, *i) != Ordering::Equal) {
+                    rank = row_number;
+                    dense_rank += 1;
+                }
+            }
+            let value = match call.kind {
+                WindowKind::RowNumber => row_number,
+                WindowKind::Rank => rank,
+                WindowKind::DenseRank => dense_rank,
+            };
+            results.push((*i, DataValue::Int64(value)));
+        }
+        Ok(results)
+    }
+}
@@ -23,10 +23,13 @@ use crate::execution::dql::aggregate::hash_agg::HashAggExecutor;
 use crate::execution::dql::aggregate::simple_agg::SimpleAggExecutor;
 use crate::execution::dql::describe::Describe;
 use crate::execution::dql::dummy::Dummy;
+use crate::execution::dql::except::Except;
 use crate::execution::dql::explain::Explain;
 use crate::execution::dql::filter::Filter;
 use crate::execution::dql::function_scan::FunctionScan;
 use crate::execution::dql::index_scan::IndexScan;
+use crate::execution::dql::intersect::Intersect;
+use crate::execution::dql::join::dependent_join::DependentJoin;
 use crate::execution::dql::join::hash_join::HashJoin;
 use crate::execution::dql::limit::Limit;
 use crate::execution::dql::projection::Projection;
@@ -35,6 +38,7 @@ use crate::execution::dql::show_table::ShowTables;
 use crate::execution::dql::sort::Sort;
 use crate::execution::dql::union::Union;
 use crate::execution::dql::values::Values;
+use crate::execution::dql::window::Window;
 use crate::planner::operator::join::JoinCondition;
 use crate::planner::operator::{Operator, PhysicalOption};
 use crate::planner::LogicalPlan;
@@ -78,7 +82,7 @@ pub fn build_read<'a, T: Transaction + 'a>(
         Operator::Aggregate(op) => {
             let input = childrens.pop_only();
 
-            if op.groupby_exprs.is_empty() {
+            if op.groupby_exprs.is_empty() && op.grouping_sets.is_empty() {
                 SimpleAggExecutor::from((op, input)).execute(cache, transaction)
             } else {
                 HashAggExecutor::from((op, input)).execute(cache, transaction)
@@ -93,6 +97,9 @@ pub fn build_read<'a, T: Transaction + 'a>(
             let (left_input, right_input) = childrens.pop_twins();
 
             match &op.on {
+                JoinCondition::Dependent { .. } => {
+                    DependentJoin::from((op, left_input, right_input)).execute(cache, transaction)
+                }
                 JoinCondition::On { on, .. }
                     if !on.is_empty() && plan.physical_option == Some(PhysicalOption::HashJoin) =>
                 {
@@ -112,9 +119,11 @@ pub fn build_read<'a, T: Transaction + 'a>(
             if let Some(PhysicalOption::IndexScan(IndexInfo {
                 meta,
                 range: Some(range),
+                order,
+                ..
             })) = plan.physical_option
             {
-                IndexScan::from((op, meta, range)).execute(cache, transaction)
+                IndexScan::from((op, meta, range, order)).execute(cache, transaction)
             } else {
                 SeqScan::from(op).execute(cache, transaction)
             }
@@ -125,6 +134,11 @@ pub fn build_read<'a, T: Transaction + 'a>(
 
             Sort::from((op, input)).execute(cache, transaction)
         }
+        Operator::Window(op) => {
+            let input = childrens.pop_only();
+
+            Window::from((op, input)).execute(cache, transaction)
+        }
         Operator::Limit(op) => {
             let input = childrens.pop_only();
 
@@ -138,10 +152,20 @@ pub fn build_read<'a, T: Transaction + 'a>(
             Explain::from(input).execute(cache, transaction)
         }
         Operator::Describe(op) => Describe::from(op).execute(cache, transaction),
-        Operator::Union(_) => {
+        Operator::Union(op) => {
+            let (left_input, right_input) = childrens.pop_twins();
+
+            Union::from((op, left_input, right_input)).execute(cache, transaction)
+        }
+        Operator::Except(op) => {
+            let (left_input, right_input) = childrens.pop_twins();
+
+            Except::from((op, left_input, right_input)).execute(cache, transaction)
+        }
+        Operator::Intersect(op) => {
             let (left_input, right_input) = childrens.pop_twins();
 
-            Union::from((left_input, right_input)).execute(cache, transaction)
+            Intersect::from((op, left_input, right_input)).execute(cache, transaction)
         }
         _ => unreachable!(),
     }
@@ -5,18 +5,40 @@ pub enum AggKind {
     Avg,
     Max,
     Min,
+    /// `args[0]` is the `(value, key)` tuple
+    MaxBy,
+    /// `args[0]` is the `(value, key)` tuple
+    MinBy,
     Sum,
     Count,
+    CountIf,
+    VarPop,
+    VarSamp,
+    StddevPop,
+    StddevSamp,
+    /// `args[1]` is the constant fraction of the percentile
+    PercentileCont,
+    /// `args[0]` is one of the `GROUP BY` expressions
+    Grouping,
 }
 
 impl AggKind {
     pub fn allow_distinct(&self) -> bool {
         match self {
-            AggKind::Avg => false,
+            AggKind::Avg => true,
             AggKind::Max => false,
             AggKind::Min => false,
+            AggKind::MaxBy => false,
+            AggKind::MinBy => false,
             AggKind::Sum => true,
             AggKind::Count => true,
+            AggKind::CountIf => false,
+            AggKind::VarPop => true,
+            AggKind::VarSamp => true,
+            AggKind::StddevPop => true,
+            AggKind::StddevSamp => true,
+            AggKind::PercentileCont => false,
+            AggKind::Grouping => false,
         }
     }
 }
@@ -1,16 +1,15 @@
 use crate::catalog::ColumnRef;
 use crate::errors::DatabaseError;
 use crate::expression::function::scala::ScalarFunction;
-use crate::expression::{AliasType, BinaryOperator, ScalarExpression};
+use crate::expression::{AliasType, BinaryOperator, DatePart, ScalarExpression};
 use crate::types::evaluator::EvaluatorFactory;
 use crate::types::tuple::Tuple;
 use crate::types::value::{DataValue, Utf8Type};
 use crate::types::LogicalType;
+use chrono::{Datelike, Timelike};
 use itertools::Itertools;
 use regex::Regex;
 use sqlparser::ast::{CharLengthUnits, TrimWhereField};
-use std::cmp;
-use std::cmp::Ordering;
 
 macro_rules! eval_to_num {
     ($num_expr:expr, $tuple:expr) => {
@@ -23,6 +22,86 @@ macro_rules! eval_to_num {
 }
 
 impl ScalarExpression {
+    /// a row is NULL if all of its fields are NULL, and NOT NULL if none of them is,
+    /// so a partially NULL row is neither
+    pub(crate) fn eval_is_null(value: &DataValue, negated: bool) -> bool {
+        match value {
+            DataValue::Tuple(values, _) if negated => !values.iter().any(DataValue::is_null),
+            DataValue::Tuple(values, _) => values.iter().all(DataValue::is_null),
+            value => value.is_null() != negated,
+        }
+    }
+
+    /// rounds a decimal result to the scale of its type, a result wider than the precision overflows
+    pub(crate) fn fit_decimal(
+        value: DataValue,
+        ty: &LogicalType,
+    ) -> Result<DataValue, DatabaseError> {
+        match (&value, ty) {
+            (DataValue::Decimal(_), LogicalType::Decimal(_, Some(_))) => {
+                value.cast(ty).map_err(|_| DatabaseError::NumericOverflow)
+            }
+            _ => Ok(value),
+        }
+    }
+
+    /// the characters of `string` from the 1-based position `start`, and at most `len` of them,
+    /// as in Postgres a `start` before the first character still counts towards `len`
+    pub(crate) fn eval_substring(
+        string: &str,
+        start: i32,
+        len: Option<i32>,
+    ) -> Result<String, DatabaseError> {
+        let skip = start.max(1) as usize - 1;
+        let Some(len) = len else {
+            return Ok(string.chars().skip(skip).collect());
+        };
+        if len < 0 {
+            return Err(DatabaseError::InvalidValue(format!(
+                "substring length must not be negative: {}",
+                len
+            )));
+        }
+        // widened to i64 so that `start + len` can not overflow
+        let take = (start as i64 + len as i64 - 1 - skip as i64).max(0);
+
+        Ok(string.chars().skip(skip).take(take as usize).collect())
+    }
+
+    /// an interval is split into whole days and the time of day, e.g. the `hour` of
+    /// `1 day 02:30:00` is 2, and its `epoch` is the total seconds
+    pub(crate) fn eval_extract(
+        field: DatePart,
+        value: DataValue,
+    ) -> Result<DataValue, DatabaseError> {
+        let part = match value {
+            DataValue::Null => return Ok(DataValue::Null),
+            DataValue::Interval(seconds) => match field {
+                DatePart::Year | DatePart::Month => 0,
+                DatePart::Day => seconds / 86_400,
+                DatePart::Hour => seconds % 86_400 / 3_600,
+                DatePart::Minute => seconds % 3_600 / 60,
+                DatePart::Second => seconds % 60,
+                DatePart::Epoch => seconds,
+            },
+            value => {
+                let Some(datetime) = value.cast(&LogicalType::DateTime)?.datetime() else {
+                    return Ok(DataValue::Null);
+                };
+                match field {
+                    DatePart::Year => datetime.year() as i64,
+                    DatePart::Month => datetime.month() as i64,
+                    DatePart::Day => datetime.day() as i64,
+                    DatePart::Hour => datetime.hour() as i64,
+                    DatePart::Minute => datetime.minute() as i64,
+                    DatePart::Second => datetime.second() as i64,
+                    DatePart::Epoch => datetime.and_utc().timestamp(),
+                }
+            }
+        };
+        Ok(DataValue::Int64(part))
+    }
+
     pub fn eval(&self, tuple: Option<(&Tuple, &[ColumnRef])>) -> Result<DataValue, DatabaseError> {
         let check_cast = |value: DataValue, return_type: &LogicalType| {
             if value.logical_type() != *return_type {
@@ -66,29 +145,41 @@ impl ScalarExpression {
 
                 expr.eval(Some((tuple, schema)))
             }
-            ScalarExpression::TypeCast { expr, ty, .. } => Ok(expr.eval(tuple)?.cast(ty)?),
+            ScalarExpression::TypeCast { expr, ty, is_try } => {
+                let value = expr.eval(tuple)?.cast(ty);
+
+                if *is_try {
+                    Ok(value.unwrap_or(DataValue::Null))
+                } else {
+                    value
+                }
+            }
             ScalarExpression::Binary {
+                op,
                 left_expr,
                 right_expr,
                 evaluator,
-                ..
+                ty,
             } => {
                 let left = left_expr.eval(tuple)?;
+                // short-circuit: `true OR ..` and `false AND ..` do not depend on the right side
+                match (op, &left) {
+                    (BinaryOperator::Or, DataValue::Boolean(true))
+                    | (BinaryOperator::And, DataValue::Boolean(false)) => return Ok(left),
+                    _ => (),
+                }
                 let right = right_expr.eval(tuple)?;
 
-                Ok(evaluator
+                let value = evaluator
                     .as_ref()
                     .ok_or(DatabaseError::EvaluatorNotFound)?
                     .0
-                    .binary_eval(&left, &right))
-            }
-            ScalarExpression::IsNull { expr, negated } => {
-                let mut is_null = expr.eval(tuple)?.is_null();
-                if *negated {
-                    is_null = !is_null;
-                }
-                Ok(DataValue::Boolean(is_null))
+                    .binary_eval(&left, &right)?;
+                Self::fit_decimal(value, ty)
             }
+            ScalarExpression::IsNull { expr, negated } => Ok(DataValue::Boolean(
+                Self::eval_is_null(&expr.eval(tuple)?, *negated),
+            )),
             ScalarExpression::In {
                 expr,
                 args,
@@ -98,22 +189,21 @@ impl ScalarExpression {
                 if value.is_null() {
                     return Ok(DataValue::Null);
                 }
-                let mut is_in = false;
+                // a NULL in the list only makes it unknown when no other value matches
+                let mut has_null = false;
                 for arg in args {
                     let arg_value = arg.eval(tuple)?;
 
                     if arg_value.is_null() {
-                        return Ok(DataValue::Null);
-                    }
-                    if arg_value == value {
-                        is_in = true;
-                        break;
+                        has_null = true;
+                    } else if arg_value == value {
+                        return Ok(DataValue::Boolean(!*negated));
                     }
                 }
-                if *negated {
-                    is_in = !is_in;
+                if has_null {
+                    return Ok(DataValue::Null);
                 }
-                Ok(DataValue::Boolean(is_in))
+                Ok(DataValue::Boolean(*negated))
             }
             ScalarExpression::Unary {
                 expr, evaluator, ..
@@ -139,55 +229,47 @@ impl ScalarExpression {
                 let left = left_expr.eval(tuple)?;
                 let right = right_expr.eval(tuple)?;
 
-                let mut is_between = match (
-                    value.partial_cmp(&left).map(Ordering::is_ge),
-                    value.partial_cmp(&right).map(Ordering::is_le),
-                ) {
-                    (Some(true), Some(true)) => true,
-                    (None, _) | (_, None) => return Ok(DataValue::Null),
-                    _ => false,
-                };
-                if *negated {
-                    is_between = !is_between;
+                let is_below = (!value.is_null() && !left.is_null()).then(|| value < left);
+                let is_above = (!value.is_null() && !right.is_null()).then(|| value > right);
+
+                // `x BETWEEN l AND h` is `x >= l AND x <= h`, which is false once a side fails
+                // even if the other side is unknown
+                match (is_below, is_above) {
+                    (Some(true), _) | (_, Some(true)) => Ok(DataValue::Boolean(*negated)),
+                    (Some(false), Some(false)) => Ok(DataValue::Boolean(!*negated)),
+                    _ => Ok(DataValue::Null),
                 }
-                Ok(DataValue::Boolean(is_between))
             }
             ScalarExpression::SubString {
                 expr,
                 for_expr,
                 from_expr,
             } => {
-                if let Some(mut string) = expr
+                let Some(string) = expr
                     .eval(tuple)?
                     .cast(&LogicalType::Varchar(None, CharLengthUnits::Characters))?
                     .utf8()
                     .map(String::from)
-                {
-                    if let Some(from_expr) = from_expr {
-                        let mut from = eval_to_num!(from_expr, tuple).saturating_sub(1);
-                        let len_i = string.len() as i32;
-
-                        while from < 0 {
-                            from += len_i + 1;
-                        }
-                        if from > len_i {
-                            return Ok(DataValue::Null);
-                        }
-                        string = string.split_off(from as usize);
-                    }
-                    if let Some(for_expr) = for_expr {
-                        let for_i = cmp::min(eval_to_num!(for_expr, tuple) as usize, string.len());
-                        let _ = string.split_off(for_i);
-                    }
+                else {
+                    return Ok(DataValue::Null);
+                };
+                let start = match from_expr {
+                    Some(from_expr) => eval_to_num!(from_expr, tuple),
+                    None => 1,
+                };
+                let len = match for_expr {
+                    Some(for_expr) => Some(eval_to_num!(for_expr, tuple)),
+                    None => None,
+                };
 
-                    Ok(DataValue::Utf8 {
-                        value: string,
-                        ty: Utf8Type::Variable(None),
-                        unit: CharLengthUnits::Characters,
-                    })
-                } else {
-                    Ok(DataValue::Null)
-                }
+                Ok(DataValue::Utf8 {
+                    value: Self::eval_substring(&string, start, len)?,
+                    ty: Utf8Type::Variable(None),
+                    unit: CharLengthUnits::Characters,
+                })
+            }
+            ScalarExpression::Extract { field, expr } => {
+                Self::eval_extract(*field, expr.eval(tuple)?)
             }
             ScalarExpression::Position { expr, in_expr } => {
                 let unpack = |expr: &ScalarExpression| -> Result<String, DatabaseError> {
@@ -296,12 +378,13 @@ impl ScalarExpression {
                 right_expr,
                 ty,
             } => {
-                let mut value = left_expr.eval(tuple)?;
+                // both sides are compared as the common type, so `nullif(1, 1::bigint)` is NULL
+                let value = check_cast(left_expr.eval(tuple)?, ty)?;
 
-                if right_expr.eval(tuple)? == value {
-                    value = DataValue::Null;
+                if !value.is_null() && check_cast(right_expr.eval(tuple)?, ty)? == value {
+                    return Ok(DataValue::Null);
                 }
-                check_cast(value, ty)
+                Ok(value)
             }
             ScalarExpression::Coalesce { exprs, ty } => {
                 let mut value = None;
@@ -340,7 +423,7 @@ impl ScalarExpression {
                         }
                         evaluator
                             .0
-                            .binary_eval(operand_value, &when_value)
+                            .binary_eval(operand_value, &when_value)?
                             .is_true()?
                     } else {
                         when_value.is_true()?
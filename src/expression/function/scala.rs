@@ -2,9 +2,11 @@ use crate::catalog::ColumnRef;
 use crate::errors::DatabaseError;
 use crate::expression::function::FunctionSummary;
 use crate::expression::ScalarExpression;
+use crate::setting::SettingsRef;
 use crate::types::tuple::Tuple;
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
+use chrono::NaiveDateTime;
 use kite_sql_serde_macros::ReferenceSerialization;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -37,7 +39,7 @@ pub struct ScalarFunction {
 
 impl PartialEq for ScalarFunction {
     fn eq(&self, other: &Self) -> bool {
-        self.summary() == other.summary()
+        self.summary() == other.summary() && self.args == other.args
     }
 }
 
@@ -60,6 +62,49 @@ pub trait ScalarFunctionImpl: Debug + Send + Sync {
     // TODO: Exploiting monotonicity when optimizing `ScalarFunctionImpl::monotonicity()`
     fn monotonicity(&self) -> Option<FuncMonotonicity>;
 
+    /// For a monotonically increasing function that maps a whole range of its monotonic argument
+    /// onto the start of that range (e.g. `date_trunc('day', ts)`), returns the `[start, end)`
+    /// range that contains `value`, so that comparisons over the function can be rewritten into
+    /// ranges over the argument.
+    #[allow(unused_variables)]
+    fn preimage(
+        &self,
+        args: &[ScalarExpression],
+        value: &DataValue,
+    ) -> Result<Option<(DataValue, DataValue)>, DatabaseError> {
+        Ok(None)
+    }
+
+    /// For a function that reads the clock (e.g. `now()`), returns the function fixed at `now`,
+    /// the time the statement is bound, so that every call within a statement agrees.
+    #[allow(unused_variables)]
+    fn at_time(&self, now: NaiveDateTime) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        None
+    }
+
+    /// For a function that reads or changes the session settings (e.g. `current_setting(..)`),
+    /// returns the function bound to `settings`, the settings of the session the statement runs in.
+    #[allow(unused_variables)]
+    fn with_settings(&self, settings: &SettingsRef) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        None
+    }
+
+    /// Whether the function always gives the same result for the same arguments, a function that
+    /// reads the clock or the session is not (e.g. `now()`) and cannot be used as an index key.
+    fn is_deterministic(&self) -> bool {
+        true
+    }
+
+    /// For a function that takes any number of arguments (e.g. `greatest(a, b, ..)`), registered
+    /// without argument types, returns the function bound to the types of the given arguments.
+    #[allow(unused_variables)]
+    fn variadic(
+        &self,
+        arg_types: &[LogicalType],
+    ) -> Result<Option<Arc<dyn ScalarFunctionImpl>>, DatabaseError> {
+        Ok(None)
+    }
+
     fn return_type(&self) -> &LogicalType;
 
     fn summary(&self) -> &FunctionSummary;
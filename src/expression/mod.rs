@@ -1,19 +1,25 @@
 use self::agg::AggKind;
 use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
 use crate::errors::DatabaseError;
-use crate::expression::function::scala::ScalarFunction;
+use crate::expression::function::scala::{
+    ArcScalarFunctionImpl, ScalarFunction, ScalarFunctionImpl,
+};
 use crate::expression::function::table::TableFunction;
+use crate::setting::SettingsRef;
 use crate::types::evaluator::{BinaryEvaluatorBox, EvaluatorFactory, UnaryEvaluatorBox};
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
+use chrono::NaiveDateTime;
 use itertools::Itertools;
 use kite_sql_serde_macros::ReferenceSerialization;
 use sqlparser::ast::TrimWhereField;
 use sqlparser::ast::{
-    BinaryOperator as SqlBinaryOperator, CharLengthUnits, UnaryOperator as SqlUnaryOperator,
+    BinaryOperator as SqlBinaryOperator, CharLengthUnits, DateTimeField,
+    UnaryOperator as SqlUnaryOperator,
 };
 use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
+use std::sync::Arc;
 use std::{fmt, mem};
 
 pub mod agg;
@@ -43,6 +49,8 @@ pub enum ScalarExpression {
     TypeCast {
         expr: Box<ScalarExpression>,
         ty: LogicalType,
+        /// `TRY_CAST` evaluates to NULL instead of failing when the value cannot be cast
+        is_try: bool,
     },
     IsNull {
         negated: bool,
@@ -66,6 +74,8 @@ pub enum ScalarExpression {
         kind: AggKind,
         args: Vec<ScalarExpression>,
         ty: LogicalType,
+        /// `FILTER (WHERE ..)`, only the rows it passes are aggregated
+        filter: Option<Box<ScalarExpression>>,
     },
     In {
         negated: bool,
@@ -92,6 +102,11 @@ pub enum ScalarExpression {
         trim_what_expr: Option<Box<ScalarExpression>>,
         trim_where: Option<TrimWhereField>,
     },
+    /// `EXTRACT(field FROM expr)`
+    Extract {
+        field: DatePart,
+        expr: Box<ScalarExpression>,
+    },
     // Temporary expression used for expression substitution
     Empty,
     Reference {
@@ -191,9 +206,15 @@ impl ScalarExpression {
                 left_expr.try_reference(output_exprs);
                 right_expr.try_reference(output_exprs);
             }
-            ScalarExpression::AggCall { args, .. }
-            | ScalarExpression::Coalesce { exprs: args, .. }
-            | ScalarExpression::Tuple(args) => {
+            ScalarExpression::AggCall { args, filter, .. } => {
+                for arg in args {
+                    arg.try_reference(output_exprs);
+                }
+                if let Some(filter) = filter {
+                    filter.try_reference(output_exprs);
+                }
+            }
+            ScalarExpression::Coalesce { exprs: args, .. } | ScalarExpression::Tuple(args) => {
                 for arg in args {
                     arg.try_reference(output_exprs);
                 }
@@ -231,6 +252,9 @@ impl ScalarExpression {
                 expr.try_reference(output_exprs);
                 in_expr.try_reference(output_exprs);
             }
+            ScalarExpression::Extract { expr, .. } => {
+                expr.try_reference(output_exprs);
+            }
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -298,6 +322,170 @@ impl ScalarExpression {
         }
     }
 
+    /// Fixes the functions reading the clock (e.g. `now()`) at `now`, the time of the statement the
+    /// expression is evaluated in, see [`ScalarFunctionImpl::at_time`].
+    ///
+    /// Tips: an expression bound within the statement is already fixed at its time,
+    /// this is for the expressions stored in the catalog (e.g. the default of a column)
+    pub fn at_time(&mut self, now: NaiveDateTime) {
+        self.rebind_scala_functions(&|function| function.at_time(now))
+    }
+
+    /// Binds the functions reading or changing the session settings (e.g. `current_setting(..)`)
+    /// to `settings`, the settings of the session the expression is evaluated in,
+    /// see [`ScalarFunctionImpl::with_settings`].
+    ///
+    /// Tips: the settings are not serialized, so an expression stored in the catalog
+    /// (e.g. the body of a view) is bound to the ones of each statement using it
+    pub fn with_settings(&mut self, settings: &SettingsRef) {
+        self.rebind_scala_functions(&|function| function.with_settings(settings))
+    }
+
+    fn rebind_scala_functions(
+        &mut self,
+        fn_rebind: &impl Fn(&dyn ScalarFunctionImpl) -> Option<Arc<dyn ScalarFunctionImpl>>,
+    ) {
+        match self {
+            ScalarExpression::ScalaFunction(ScalarFunction { args, inner }) => {
+                for arg in args {
+                    arg.rebind_scala_functions(fn_rebind);
+                }
+                if let Some(function) = fn_rebind(inner.0.as_ref()) {
+                    *inner = ArcScalarFunctionImpl(function);
+                }
+            }
+            ScalarExpression::Alias { expr, .. }
+            | ScalarExpression::TypeCast { expr, .. }
+            | ScalarExpression::IsNull { expr, .. }
+            | ScalarExpression::Unary { expr, .. }
+            | ScalarExpression::Extract { expr, .. }
+            | ScalarExpression::Reference { expr, .. } => expr.rebind_scala_functions(fn_rebind),
+            ScalarExpression::Binary {
+                left_expr,
+                right_expr,
+                ..
+            }
+            | ScalarExpression::IfNull {
+                left_expr,
+                right_expr,
+                ..
+            }
+            | ScalarExpression::NullIf {
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                left_expr.rebind_scala_functions(fn_rebind);
+                right_expr.rebind_scala_functions(fn_rebind);
+            }
+            ScalarExpression::AggCall { args, filter, .. } => {
+                for arg in args {
+                    arg.rebind_scala_functions(fn_rebind);
+                }
+                if let Some(filter) = filter {
+                    filter.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::Coalesce { exprs: args, .. }
+            | ScalarExpression::Tuple(args)
+            | ScalarExpression::TableFunction(TableFunction { args, .. }) => {
+                for arg in args {
+                    arg.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::In { expr, args, .. } => {
+                expr.rebind_scala_functions(fn_rebind);
+                for arg in args {
+                    arg.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::Between {
+                expr,
+                left_expr,
+                right_expr,
+                ..
+            }
+            | ScalarExpression::If {
+                condition: expr,
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                expr.rebind_scala_functions(fn_rebind);
+                left_expr.rebind_scala_functions(fn_rebind);
+                right_expr.rebind_scala_functions(fn_rebind);
+            }
+            ScalarExpression::SubString {
+                expr,
+                for_expr,
+                from_expr,
+            } => {
+                expr.rebind_scala_functions(fn_rebind);
+                if let Some(expr) = for_expr {
+                    expr.rebind_scala_functions(fn_rebind);
+                }
+                if let Some(expr) = from_expr {
+                    expr.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::Position { expr, in_expr } => {
+                expr.rebind_scala_functions(fn_rebind);
+                in_expr.rebind_scala_functions(fn_rebind);
+            }
+            ScalarExpression::Trim {
+                expr,
+                trim_what_expr,
+                ..
+            } => {
+                expr.rebind_scala_functions(fn_rebind);
+                if let Some(trim_what_expr) = trim_what_expr {
+                    trim_what_expr.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::CaseWhen {
+                operand_expr,
+                expr_pairs,
+                else_expr,
+                ..
+            } => {
+                if let Some(expr) = operand_expr {
+                    expr.rebind_scala_functions(fn_rebind);
+                }
+                for (expr_1, expr_2) in expr_pairs {
+                    expr_1.rebind_scala_functions(fn_rebind);
+                    expr_2.rebind_scala_functions(fn_rebind);
+                }
+                if let Some(expr) = else_expr {
+                    expr.rebind_scala_functions(fn_rebind);
+                }
+            }
+            ScalarExpression::Constant(_)
+            | ScalarExpression::ColumnRef(_)
+            | ScalarExpression::Empty => (),
+        }
+    }
+
+    /// The type both sides of `left op right` are evaluated in, which is the type of the left
+    /// side alone for a shift, whose right side is the amount of any integer type.
+    pub(crate) fn binary_evaluation_type(
+        op: &BinaryOperator,
+        left_expr: &ScalarExpression,
+        right_expr: &ScalarExpression,
+    ) -> Result<LogicalType, DatabaseError> {
+        let (left_ty, right_ty) = (left_expr.return_type(), right_expr.return_type());
+
+        if op.is_shift() {
+            if !right_ty.is_signed_numeric()
+                && !right_ty.is_unsigned_numeric()
+                && right_ty != LogicalType::SqlNull
+            {
+                return Err(DatabaseError::UnsupportedBinaryOperator(right_ty, *op));
+            }
+            return Ok(left_ty.evaluation_type());
+        }
+        Ok(LogicalType::max_logical_type(&left_ty, &right_ty)?.evaluation_type())
+    }
+
     pub fn bind_evaluator(&mut self) -> Result<(), DatabaseError> {
         match self {
             ScalarExpression::Binary {
@@ -310,20 +498,21 @@ impl ScalarExpression {
                 left_expr.bind_evaluator()?;
                 right_expr.bind_evaluator()?;
 
-                let ty = LogicalType::max_logical_type(
-                    &left_expr.return_type(),
-                    &right_expr.return_type(),
-                )?;
+                let ty = Self::binary_evaluation_type(op, left_expr, right_expr)?;
                 let fn_cast = |expr: &mut ScalarExpression, ty: LogicalType| {
                     if expr.return_type() != ty {
                         *expr = ScalarExpression::TypeCast {
                             expr: Box::new(mem::replace(expr, ScalarExpression::Empty)),
                             ty,
+                            is_try: false,
                         }
                     }
                 };
                 fn_cast(left_expr, ty.clone());
-                fn_cast(right_expr, ty.clone());
+                // the amount of a shift keeps its own type
+                if !op.is_shift() {
+                    fn_cast(right_expr, ty.clone());
+                }
 
                 *evaluator = Some(EvaluatorFactory::binary_create(ty, *op)?);
             }
@@ -336,7 +525,8 @@ impl ScalarExpression {
                 expr.bind_evaluator()?;
 
                 let ty = expr.return_type();
-                if ty.is_unsigned_numeric() {
+                // the complement of an unsigned integer stays unsigned
+                if ty.is_unsigned_numeric() && *op != UnaryOperator::BitNot {
                     *expr.as_mut() = ScalarExpression::TypeCast {
                         expr: Box::new(mem::replace(expr, ScalarExpression::Empty)),
                         ty: match ty {
@@ -346,6 +536,7 @@ impl ScalarExpression {
                             LogicalType::UBigint => LogicalType::Bigint,
                             _ => unreachable!(),
                         },
+                        is_try: false,
                     }
                 }
                 *evaluator = Some(EvaluatorFactory::unary_create(ty, *op)?);
@@ -359,9 +550,15 @@ impl ScalarExpression {
             ScalarExpression::IsNull { expr, .. } => {
                 expr.bind_evaluator()?;
             }
-            ScalarExpression::AggCall { args, .. }
-            | ScalarExpression::Coalesce { exprs: args, .. }
-            | ScalarExpression::Tuple(args) => {
+            ScalarExpression::AggCall { args, filter, .. } => {
+                for arg in args {
+                    arg.bind_evaluator()?;
+                }
+                if let Some(filter) = filter {
+                    filter.bind_evaluator()?;
+                }
+            }
+            ScalarExpression::Coalesce { exprs: args, .. } | ScalarExpression::Tuple(args) => {
                 for arg in args {
                     arg.bind_evaluator()?;
                 }
@@ -381,6 +578,22 @@ impl ScalarExpression {
                 expr.bind_evaluator()?;
                 left_expr.bind_evaluator()?;
                 right_expr.bind_evaluator()?;
+
+                // the bounds are compared as the common type, like `expr >= left AND expr <= right`
+                let ty = LogicalType::max_logical_type(
+                    &LogicalType::max_logical_type(&expr.return_type(), &left_expr.return_type())?,
+                    &right_expr.return_type(),
+                )?
+                .evaluation_type();
+                for expr in [expr, left_expr, right_expr] {
+                    if expr.return_type() != ty {
+                        *expr.as_mut() = ScalarExpression::TypeCast {
+                            expr: Box::new(mem::replace(expr, ScalarExpression::Empty)),
+                            ty: ty.clone(),
+                            is_try: false,
+                        }
+                    }
+                }
             }
             ScalarExpression::SubString {
                 expr,
@@ -399,6 +612,9 @@ impl ScalarExpression {
                 expr.bind_evaluator()?;
                 in_expr.bind_evaluator()?;
             }
+            ScalarExpression::Extract { expr, .. } => {
+                expr.bind_evaluator()?;
+            }
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -513,6 +729,7 @@ impl ScalarExpression {
             ScalarExpression::Position { expr, in_expr } => {
                 expr.has_count_star() || in_expr.has_count_star()
             }
+            ScalarExpression::Extract { expr, .. } => expr.has_count_star(),
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -602,6 +819,7 @@ impl ScalarExpression {
                 LogicalType::Varchar(None, CharLengthUnits::Characters)
             }
             ScalarExpression::Position { .. } => LogicalType::Integer,
+            ScalarExpression::Extract { .. } => LogicalType::Bigint,
             ScalarExpression::Trim { .. } => {
                 LogicalType::Varchar(None, CharLengthUnits::Characters)
             }
@@ -621,10 +839,21 @@ impl ScalarExpression {
     }
 
     pub fn referenced_columns(&self, only_column_ref: bool) -> Vec<ColumnRef> {
+        self._referenced_columns(only_column_ref, false)
+    }
+
+    /// The columns read from the input of the expression, unlike [`ScalarExpression::referenced_columns`]
+    /// an aliased column (e.g. the output column of a sub query) is read as the alias itself
+    pub fn referenced_input_columns(&self) -> Vec<ColumnRef> {
+        self._referenced_columns(true, true)
+    }
+
+    fn _referenced_columns(&self, only_column_ref: bool, stop_at_alias: bool) -> Vec<ColumnRef> {
         fn columns_collect(
             expr: &ScalarExpression,
             vec: &mut Vec<ColumnRef>,
             only_column_ref: bool,
+            stop_at_alias: bool,
         ) {
             // When `ScalarExpression` is a complex type, it itself is also a special Column
             if !only_column_ref {
@@ -634,37 +863,52 @@ impl ScalarExpression {
                 ScalarExpression::ColumnRef(col) => {
                     vec.push(col.clone());
                 }
+                ScalarExpression::Alias {
+                    alias: AliasType::Expr(alias),
+                    ..
+                } if stop_at_alias => {
+                    vec.push(alias.output_column());
+                }
                 ScalarExpression::Alias { expr, .. } => {
-                    columns_collect(expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
                 }
                 ScalarExpression::TypeCast { expr, .. } => {
-                    columns_collect(expr, vec, only_column_ref)
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias)
                 }
                 ScalarExpression::IsNull { expr, .. } => {
-                    columns_collect(expr, vec, only_column_ref)
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias)
+                }
+                ScalarExpression::Unary { expr, .. } => {
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias)
                 }
-                ScalarExpression::Unary { expr, .. } => columns_collect(expr, vec, only_column_ref),
                 ScalarExpression::Binary {
                     left_expr,
                     right_expr,
                     ..
                 } => {
-                    columns_collect(left_expr, vec, only_column_ref);
-                    columns_collect(right_expr, vec, only_column_ref);
+                    columns_collect(left_expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(right_expr, vec, only_column_ref, stop_at_alias);
                 }
-                ScalarExpression::AggCall { args, .. }
-                | ScalarExpression::ScalaFunction(ScalarFunction { args, .. })
+                ScalarExpression::AggCall { args, filter, .. } => {
+                    for expr in args {
+                        columns_collect(expr, vec, only_column_ref, stop_at_alias)
+                    }
+                    if let Some(filter) = filter {
+                        columns_collect(filter, vec, only_column_ref, stop_at_alias)
+                    }
+                }
+                ScalarExpression::ScalaFunction(ScalarFunction { args, .. })
                 | ScalarExpression::TableFunction(TableFunction { args, .. })
                 | ScalarExpression::Tuple(args)
                 | ScalarExpression::Coalesce { exprs: args, .. } => {
                     for expr in args {
-                        columns_collect(expr, vec, only_column_ref)
+                        columns_collect(expr, vec, only_column_ref, stop_at_alias)
                     }
                 }
                 ScalarExpression::In { expr, args, .. } => {
-                    columns_collect(expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
                     for arg in args {
-                        columns_collect(arg, vec, only_column_ref)
+                        columns_collect(arg, vec, only_column_ref, stop_at_alias)
                     }
                 }
                 ScalarExpression::Between {
@@ -673,35 +917,38 @@ impl ScalarExpression {
                     right_expr,
                     ..
                 } => {
-                    columns_collect(expr, vec, only_column_ref);
-                    columns_collect(left_expr, vec, only_column_ref);
-                    columns_collect(right_expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(left_expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(right_expr, vec, only_column_ref, stop_at_alias);
                 }
                 ScalarExpression::SubString {
                     expr,
                     for_expr,
                     from_expr,
                 } => {
-                    columns_collect(expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
                     if let Some(for_expr) = for_expr {
-                        columns_collect(for_expr, vec, only_column_ref);
+                        columns_collect(for_expr, vec, only_column_ref, stop_at_alias);
                     }
                     if let Some(from_expr) = from_expr {
-                        columns_collect(from_expr, vec, only_column_ref);
+                        columns_collect(from_expr, vec, only_column_ref, stop_at_alias);
                     }
                 }
                 ScalarExpression::Position { expr, in_expr } => {
-                    columns_collect(expr, vec, only_column_ref);
-                    columns_collect(in_expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(in_expr, vec, only_column_ref, stop_at_alias);
+                }
+                ScalarExpression::Extract { expr, .. } => {
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias)
                 }
                 ScalarExpression::Trim {
                     expr,
                     trim_what_expr,
                     ..
                 } => {
-                    columns_collect(expr, vec, only_column_ref);
+                    columns_collect(expr, vec, only_column_ref, stop_at_alias);
                     if let Some(trim_what_expr) = trim_what_expr {
-                        columns_collect(trim_what_expr, vec, only_column_ref);
+                        columns_collect(trim_what_expr, vec, only_column_ref, stop_at_alias);
                     }
                 }
                 ScalarExpression::Constant(_) => (),
@@ -712,9 +959,9 @@ impl ScalarExpression {
                     right_expr,
                     ..
                 } => {
-                    columns_collect(condition, vec, only_column_ref);
-                    columns_collect(left_expr, vec, only_column_ref);
-                    columns_collect(right_expr, vec, only_column_ref);
+                    columns_collect(condition, vec, only_column_ref, stop_at_alias);
+                    columns_collect(left_expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(right_expr, vec, only_column_ref, stop_at_alias);
                 }
                 ScalarExpression::IfNull {
                     left_expr,
@@ -726,8 +973,8 @@ impl ScalarExpression {
                     right_expr,
                     ..
                 } => {
-                    columns_collect(left_expr, vec, only_column_ref);
-                    columns_collect(right_expr, vec, only_column_ref);
+                    columns_collect(left_expr, vec, only_column_ref, stop_at_alias);
+                    columns_collect(right_expr, vec, only_column_ref, stop_at_alias);
                 }
                 ScalarExpression::CaseWhen {
                     operand_expr,
@@ -736,21 +983,21 @@ impl ScalarExpression {
                     ..
                 } => {
                     if let Some(expr) = operand_expr {
-                        columns_collect(expr, vec, only_column_ref);
+                        columns_collect(expr, vec, only_column_ref, stop_at_alias);
                     }
                     for (expr_1, expr_2) in expr_pairs {
-                        columns_collect(expr_1, vec, only_column_ref);
-                        columns_collect(expr_2, vec, only_column_ref);
+                        columns_collect(expr_1, vec, only_column_ref, stop_at_alias);
+                        columns_collect(expr_2, vec, only_column_ref, stop_at_alias);
                     }
                     if let Some(expr) = else_expr {
-                        columns_collect(expr, vec, only_column_ref);
+                        columns_collect(expr, vec, only_column_ref, stop_at_alias);
                     }
                 }
             }
         }
         let mut exprs = Vec::new();
 
-        columns_collect(self, &mut exprs, only_column_ref);
+        columns_collect(self, &mut exprs, only_column_ref, stop_at_alias);
 
         exprs
     }
@@ -771,8 +1018,11 @@ impl ScalarExpression {
                 right_expr,
                 ..
             } => left_expr.has_table_ref_column() || right_expr.has_table_ref_column(),
-            ScalarExpression::AggCall { args, .. } => {
+            ScalarExpression::AggCall { args, filter, .. } => {
                 args.iter().any(ScalarExpression::has_table_ref_column)
+                    || filter
+                        .as_deref()
+                        .map_or(false, ScalarExpression::has_table_ref_column)
             }
             ScalarExpression::In { expr, args, .. } => {
                 expr.has_table_ref_column()
@@ -806,6 +1056,7 @@ impl ScalarExpression {
             ScalarExpression::Position { expr, in_expr } => {
                 expr.has_table_ref_column() || in_expr.has_table_ref_column()
             }
+            ScalarExpression::Extract { expr, .. } => expr.has_table_ref_column(),
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -915,6 +1166,7 @@ impl ScalarExpression {
             ScalarExpression::Position { expr, in_expr } => {
                 expr.has_agg_call() || in_expr.has_agg_call()
             }
+            ScalarExpression::Extract { expr, .. } => expr.has_agg_call(),
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -965,6 +1217,104 @@ impl ScalarExpression {
         }
     }
 
+    /// Whether the expression always gives the same result for the same input, which is false
+    /// when it calls a function such as `now()`.
+    pub fn is_deterministic(&self) -> bool {
+        match self {
+            ScalarExpression::Constant(_) | ScalarExpression::ColumnRef(_) => true,
+            ScalarExpression::Alias { expr, .. }
+            | ScalarExpression::TypeCast { expr, .. }
+            | ScalarExpression::IsNull { expr, .. }
+            | ScalarExpression::Unary { expr, .. }
+            | ScalarExpression::Extract { expr, .. } => expr.is_deterministic(),
+            ScalarExpression::Binary {
+                left_expr,
+                right_expr,
+                ..
+            }
+            | ScalarExpression::IfNull {
+                left_expr,
+                right_expr,
+                ..
+            }
+            | ScalarExpression::NullIf {
+                left_expr,
+                right_expr,
+                ..
+            } => left_expr.is_deterministic() && right_expr.is_deterministic(),
+            ScalarExpression::In { expr, args, .. } => {
+                expr.is_deterministic() && args.iter().all(Self::is_deterministic)
+            }
+            ScalarExpression::Between {
+                expr,
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                expr.is_deterministic()
+                    && left_expr.is_deterministic()
+                    && right_expr.is_deterministic()
+            }
+            ScalarExpression::SubString {
+                expr,
+                for_expr,
+                from_expr,
+            } => {
+                expr.is_deterministic()
+                    && for_expr.as_deref().map_or(true, Self::is_deterministic)
+                    && from_expr.as_deref().map_or(true, Self::is_deterministic)
+            }
+            ScalarExpression::Position { expr, in_expr } => {
+                expr.is_deterministic() && in_expr.is_deterministic()
+            }
+            ScalarExpression::Trim {
+                expr,
+                trim_what_expr,
+                ..
+            } => {
+                expr.is_deterministic()
+                    && trim_what_expr
+                        .as_deref()
+                        .map_or(true, Self::is_deterministic)
+            }
+            ScalarExpression::ScalaFunction(ScalarFunction { args, inner }) => {
+                inner.is_deterministic() && args.iter().all(Self::is_deterministic)
+            }
+            ScalarExpression::AggCall { args, filter, .. } => {
+                args.iter().all(Self::is_deterministic)
+                    && filter.as_deref().map_or(true, Self::is_deterministic)
+            }
+            ScalarExpression::Tuple(args) | ScalarExpression::Coalesce { exprs: args, .. } => {
+                args.iter().all(Self::is_deterministic)
+            }
+            ScalarExpression::If {
+                condition,
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                condition.is_deterministic()
+                    && left_expr.is_deterministic()
+                    && right_expr.is_deterministic()
+            }
+            ScalarExpression::CaseWhen {
+                operand_expr,
+                expr_pairs,
+                else_expr,
+                ..
+            } => {
+                operand_expr.as_deref().map_or(true, Self::is_deterministic)
+                    && expr_pairs.iter().all(|(expr_1, expr_2)| {
+                        expr_1.is_deterministic() && expr_2.is_deterministic()
+                    })
+                    && else_expr.as_deref().map_or(true, Self::is_deterministic)
+            }
+            ScalarExpression::TableFunction(_) => false,
+            ScalarExpression::Reference { expr, .. } => expr.is_deterministic(),
+            ScalarExpression::Empty => true,
+        }
+    }
+
     pub fn output_name(&self) -> String {
         match self {
             ScalarExpression::Constant(value) => format!("{}", value),
@@ -975,8 +1325,10 @@ impl ScalarExpression {
                     format!("({}) as ({})", expr, alias_expr.output_name())
                 }
             },
-            ScalarExpression::TypeCast { expr, ty } => {
-                format!("cast ({} as {})", expr.output_name(), ty)
+            ScalarExpression::TypeCast { expr, ty, is_try } => {
+                let cast = if *is_try { "try_cast" } else { "cast" };
+
+                format!("{} ({} as {})", cast, expr.output_name(), ty)
             }
             ScalarExpression::IsNull { expr, negated } => {
                 let suffix = if *negated { "is not null" } else { "is null" };
@@ -999,9 +1351,16 @@ impl ScalarExpression {
                 args,
                 kind,
                 distinct,
+                filter,
                 ..
             } => {
-                let args_str = args.iter().map(|expr| expr.output_name()).join(", ");
+                let args_str = match (kind, args.as_slice()) {
+                    // shows the `(value, key)` tuple as the two arguments it was called with
+                    (AggKind::MaxBy | AggKind::MinBy, [ScalarExpression::Tuple(args)]) => {
+                        args.iter().map(|expr| expr.output_name()).join(", ")
+                    }
+                    _ => args.iter().map(|expr| expr.output_name()).join(", "),
+                };
                 let op = |allow_distinct, distinct| {
                     if allow_distinct && distinct {
                         "distinct "
@@ -1009,11 +1368,16 @@ impl ScalarExpression {
                         ""
                     }
                 };
+                let filter_str = filter
+                    .as_ref()
+                    .map(|filter| format!(" filter (where {})", filter.output_name()))
+                    .unwrap_or_default();
                 format!(
-                    "{:?}({}{})",
+                    "{:?}({}{}){}",
                     kind,
                     op(kind.allow_distinct(), *distinct),
-                    args_str
+                    args_str,
+                    filter_str
                 )
             }
             ScalarExpression::In {
@@ -1066,6 +1430,9 @@ impl ScalarExpression {
                     in_expr.output_name()
                 )
             }
+            ScalarExpression::Extract { field, expr } => {
+                format!("extract({} from {})", field, expr.output_name())
+            }
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -1180,6 +1547,8 @@ pub enum UnaryOperator {
     Plus,
     Minus,
     Not,
+    /// `~`, the bitwise complement of an integer
+    BitNot,
 }
 
 impl TryFrom<SqlUnaryOperator> for UnaryOperator {
@@ -1190,6 +1559,7 @@ impl TryFrom<SqlUnaryOperator> for UnaryOperator {
             SqlUnaryOperator::Plus => Ok(UnaryOperator::Plus),
             SqlUnaryOperator::Minus => Ok(UnaryOperator::Minus),
             SqlUnaryOperator::Not => Ok(UnaryOperator::Not),
+            SqlUnaryOperator::PGBitwiseNot => Ok(UnaryOperator::BitNot),
             op => Err(DatabaseError::UnsupportedStmt(format!("{}", op))),
         }
     }
@@ -1217,6 +1587,90 @@ pub enum BinaryOperator {
 
     And,
     Or,
+
+    /// `&`, `|` and `#` (or `^`) of integers
+    BitAnd,
+    BitOr,
+    BitXor,
+    /// `<<` and `>>` of an integer, keeping the type of the left side
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BinaryOperator {
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::BitAnd
+                | BinaryOperator::BitOr
+                | BinaryOperator::BitXor
+                | BinaryOperator::ShiftLeft
+                | BinaryOperator::ShiftRight
+        )
+    }
+
+    pub fn is_shift(&self) -> bool {
+        matches!(self, BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight)
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            BinaryOperator::Gt
+                | BinaryOperator::Lt
+                | BinaryOperator::GtEq
+                | BinaryOperator::LtEq
+                | BinaryOperator::Spaceship
+                | BinaryOperator::Eq
+                | BinaryOperator::NotEq
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ReferenceSerialization)]
+pub enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// the number of seconds since `1970-01-01 00:00:00`, or the total seconds of an interval
+    Epoch,
+}
+
+impl TryFrom<DateTimeField> for DatePart {
+    type Error = DatabaseError;
+
+    fn try_from(value: DateTimeField) -> Result<Self, Self::Error> {
+        match value {
+            DateTimeField::Year => Ok(DatePart::Year),
+            DateTimeField::Month => Ok(DatePart::Month),
+            DateTimeField::Day => Ok(DatePart::Day),
+            DateTimeField::Hour => Ok(DatePart::Hour),
+            DateTimeField::Minute => Ok(DatePart::Minute),
+            DateTimeField::Second => Ok(DatePart::Second),
+            DateTimeField::Epoch => Ok(DatePart::Epoch),
+            field => Err(DatabaseError::UnsupportedStmt(format!(
+                "extract: {}",
+                field
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DatePart {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DatePart::Year => write!(f, "year"),
+            DatePart::Month => write!(f, "month"),
+            DatePart::Day => write!(f, "day"),
+            DatePart::Hour => write!(f, "hour"),
+            DatePart::Minute => write!(f, "minute"),
+            DatePart::Second => write!(f, "second"),
+            DatePart::Epoch => write!(f, "epoch"),
+        }
+    }
 }
 
 impl fmt::Display for ScalarExpression {
@@ -1250,6 +1704,11 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotEq => write!(f, "!="),
             BinaryOperator::And => write!(f, "&&"),
             BinaryOperator::Or => write!(f, "||"),
+            BinaryOperator::BitAnd => write!(f, "&"),
+            BinaryOperator::BitOr => write!(f, "|"),
+            BinaryOperator::BitXor => write!(f, "#"),
+            BinaryOperator::ShiftLeft => write!(f, "<<"),
+            BinaryOperator::ShiftRight => write!(f, ">>"),
             BinaryOperator::Like(escape_char) => {
                 write!(f, "like")?;
                 like_op(f, escape_char)
@@ -1268,6 +1727,7 @@ impl fmt::Display for UnaryOperator {
             UnaryOperator::Plus => write!(f, "+"),
             UnaryOperator::Minus => write!(f, "-"),
             UnaryOperator::Not => write!(f, "!"),
+            UnaryOperator::BitNot => write!(f, "~"),
         }
     }
 }
@@ -1292,6 +1752,14 @@ impl TryFrom<SqlBinaryOperator> for BinaryOperator {
             SqlBinaryOperator::NotEq => Ok(BinaryOperator::NotEq),
             SqlBinaryOperator::And => Ok(BinaryOperator::And),
             SqlBinaryOperator::Or => Ok(BinaryOperator::Or),
+            SqlBinaryOperator::BitwiseAnd => Ok(BinaryOperator::BitAnd),
+            SqlBinaryOperator::BitwiseOr => Ok(BinaryOperator::BitOr),
+            // `^` is the power in PostgreSQL, here it is the `#` of the other dialects
+            SqlBinaryOperator::BitwiseXor
+            | SqlBinaryOperator::PGBitwiseXor
+            | SqlBinaryOperator::PGExp => Ok(BinaryOperator::BitXor),
+            SqlBinaryOperator::PGBitwiseShiftLeft => Ok(BinaryOperator::ShiftLeft),
+            SqlBinaryOperator::PGBitwiseShiftRight => Ok(BinaryOperator::ShiftRight),
             op => Err(DatabaseError::UnsupportedStmt(format!("{}", op))),
         }
     }
@@ -1305,7 +1773,7 @@ mod test {
     use crate::expression::agg::AggKind;
     use crate::expression::function::scala::{ArcScalarFunctionImpl, ScalarFunction};
     use crate::expression::function::table::{ArcTableFunctionImpl, TableFunction};
-    use crate::expression::{AliasType, BinaryOperator, ScalarExpression, UnaryOperator};
+    use crate::expression::{AliasType, BinaryOperator, DatePart, ScalarExpression, UnaryOperator};
     use crate::function::current_date::CurrentDate;
     use crate::function::numbers::Numbers;
     use crate::serdes::{ReferenceSerialization, ReferenceTables};
@@ -1436,6 +1904,7 @@ mod test {
             ScalarExpression::TypeCast {
                 expr: Box::new(ScalarExpression::Empty),
                 ty: LogicalType::Integer,
+                is_try: false,
             },
             Some((&transaction, &table_cache)),
             &mut reference_tables,
@@ -1502,6 +1971,19 @@ mod test {
                 kind: AggKind::Avg,
                 args: vec![ScalarExpression::Empty],
                 ty: LogicalType::Integer,
+                filter: None,
+            },
+            Some((&transaction, &table_cache)),
+            &mut reference_tables,
+        )?;
+        fn_assert(
+            &mut cursor,
+            ScalarExpression::AggCall {
+                distinct: false,
+                kind: AggKind::StddevSamp,
+                args: vec![ScalarExpression::Empty],
+                ty: LogicalType::Double,
+                filter: None,
             },
             Some((&transaction, &table_cache)),
             &mut reference_tables,
@@ -1566,6 +2048,15 @@ mod test {
             Some((&transaction, &table_cache)),
             &mut reference_tables,
         )?;
+        fn_assert(
+            &mut cursor,
+            ScalarExpression::Extract {
+                field: DatePart::Epoch,
+                expr: Box::new(ScalarExpression::Empty),
+            },
+            Some((&transaction, &table_cache)),
+            &mut reference_tables,
+        )?;
         fn_assert(
             &mut cursor,
             ScalarExpression::Trim {
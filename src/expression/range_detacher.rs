@@ -1,5 +1,5 @@
 use crate::catalog::ColumnRef;
-use crate::expression::{BinaryOperator, ScalarExpression};
+use crate::expression::{BinaryOperator, ScalarExpression, UnaryOperator};
 use crate::types::value::{DataValue, NULL_VALUE};
 use crate::types::ColumnId;
 use itertools::Itertools;
@@ -10,7 +10,8 @@ use std::fmt::Formatter;
 use std::{fmt, mem};
 
 /// Used to represent binary relationships between fields and constants
-/// Tips: The NotEq case is ignored because it makes expression composition very complex
+/// Tips: The NotEq case is the complement of its value, so `c1 NOT IN (1, 2)` is detached into
+/// the ranges around `1` and `2`
 /// - [`Range::Scope`]:
 #[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
 pub enum Range {
@@ -198,19 +199,48 @@ impl<'a> RangeDetacher<'a> {
                     {
                         return self.new_range(*op, col, val, true);
                     }
+                    if let (ScalarExpression::Coalesce { .. }, Some(val)) =
+                        (left_expr.as_ref(), right_expr.unpack_val())
+                    {
+                        return self.detach_coalesce(*op, left_expr, val, false);
+                    } else if let (Some(val), ScalarExpression::Coalesce { .. }) =
+                        (left_expr.unpack_val(), right_expr.as_ref())
+                    {
+                        return self.detach_coalesce(*op, right_expr, val, true);
+                    }
 
                     None
                 }
                 (Some(binary), None) | (None, Some(binary)) => self.check_or(op, binary),
             },
+            // the rows of `NOT x` are out of the range of `x`
+            ScalarExpression::Unary {
+                op: UnaryOperator::Not,
+                ..
+            } => None,
             ScalarExpression::Alias { expr, .. }
             | ScalarExpression::TypeCast { expr, .. }
             | ScalarExpression::Unary { expr, .. }
             | ScalarExpression::In { expr, .. }
-            | ScalarExpression::Between { expr, .. }
             | ScalarExpression::SubString { expr, .. } => self.detach(expr),
+            ScalarExpression::Between {
+                expr,
+                left_expr,
+                right_expr,
+                negated,
+            } => {
+                let (ScalarExpression::ColumnRef(col), Some(left), Some(right)) = (
+                    expr.as_ref(),
+                    left_expr.unpack_val(),
+                    right_expr.unpack_val(),
+                ) else {
+                    return None;
+                };
+                self.detach_between(col, left, right, *negated)
+            }
             ScalarExpression::Position { expr, .. } => self.detach(expr),
             ScalarExpression::Trim { expr, .. } => self.detach(expr),
+            ScalarExpression::Extract { expr, .. } => self.detach(expr),
             ScalarExpression::IsNull { expr, negated, .. } => match expr.as_ref() {
                 ScalarExpression::ColumnRef(column) => {
                     if let (Some(col_id), Some(col_table)) = (column.id(), column.table_name()) {
@@ -238,14 +268,16 @@ impl<'a> RangeDetacher<'a> {
                 | ScalarExpression::SubString { .. }
                 | ScalarExpression::Position { .. }
                 | ScalarExpression::Trim { .. }
+                | ScalarExpression::Extract { .. }
                 | ScalarExpression::ScalaFunction(_)
                 | ScalarExpression::If { .. }
                 | ScalarExpression::IfNull { .. }
                 | ScalarExpression::NullIf { .. }
                 | ScalarExpression::Coalesce { .. }
                 | ScalarExpression::CaseWhen { .. } => self.detach(expr),
-                ScalarExpression::Tuple(_)
-                | ScalarExpression::TableFunction(_)
+                // FIXME: `(c1, c2) IS NULL` could be detached into `c1 = NULL` and `c2 = NULL`
+                ScalarExpression::Tuple(_) => None,
+                ScalarExpression::TableFunction(_)
                 | ScalarExpression::Reference { .. }
                 | ScalarExpression::Empty => unreachable!(),
             },
@@ -283,6 +315,12 @@ impl<'a> RangeDetacher<'a> {
             }
         }
         match (left_binary, right_binary) {
+            (Range::SortedRanges(left_ranges), right_binary) if op == BinaryOperator::And => {
+                Self::and_sorted_ranges_merge(left_ranges, Self::range2ranges(right_binary))
+            }
+            (left_binary, Range::SortedRanges(right_ranges)) if op == BinaryOperator::And => {
+                Self::and_sorted_ranges_merge(Self::range2ranges(left_binary), right_ranges)
+            }
             (Range::Dummy, binary) | (binary, Range::Dummy) => match op {
                 BinaryOperator::And => Some(Range::Dummy),
                 BinaryOperator::Or => Some(binary),
@@ -436,6 +474,31 @@ impl<'a> RangeDetacher<'a> {
         }
     }
 
+    /// `(c1 = 1 or c1 > 5) and (c1 < 3 or c1 = 6)` is the union of the intersections of
+    /// each pair of ranges
+    fn and_sorted_ranges_merge(left_ranges: Vec<Range>, right_ranges: Vec<Range>) -> Option<Range> {
+        let mut merged_range = Range::Dummy;
+
+        for left_range in left_ranges.iter() {
+            for right_range in right_ranges.iter() {
+                let range = Self::merge_binary(
+                    BinaryOperator::And,
+                    left_range.clone(),
+                    right_range.clone(),
+                )?;
+                merged_range = Self::merge_binary(BinaryOperator::Or, merged_range, range)?;
+            }
+        }
+        Some(merged_range)
+    }
+
+    fn range2ranges(range: Range) -> Vec<Range> {
+        match range {
+            Range::SortedRanges(ranges) => ranges,
+            range => vec![range],
+        }
+    }
+
     fn ranges2range(mut merged_ranges: Vec<Range>) -> Range {
         if merged_ranges.is_empty() {
             Range::Dummy
@@ -465,14 +528,10 @@ impl<'a> RangeDetacher<'a> {
                         max: r_max,
                     },
                 ) => {
-                    if let Some(true) =
-                        Self::bound_compared(l_max, r_min, false).map(Ordering::is_lt)
-                    {
+                    if Self::is_apart(l_max, r_min) {
                         ranges.insert(*idx, binary.unwrap());
                         return ranges;
-                    } else if let Some(true) =
-                        Self::bound_compared(l_min, r_max, true).map(Ordering::is_gt)
-                    {
+                    } else if Self::is_apart(r_max, l_min) {
                         *idx += 1;
                         continue;
                     } else {
@@ -559,19 +618,31 @@ impl<'a> RangeDetacher<'a> {
         ranges
     }
 
+    /// Whether a scope ending at `max` is strictly before one starting at `min`,
+    /// e.g. `c1 < 1` and `c1 > 1` are apart, since neither of them contains `1`
+    fn is_apart(max: &Bound<DataValue>, min: &Bound<DataValue>) -> bool {
+        match (max, min) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (
+                Bound::Included(max_val) | Bound::Excluded(max_val),
+                Bound::Included(min_val) | Bound::Excluded(min_val),
+            ) => match max_val.partial_cmp(min_val) {
+                Some(Ordering::Less) => true,
+                Some(Ordering::Equal) => {
+                    matches!((max, min), (Bound::Excluded(_), Bound::Excluded(_)))
+                }
+                _ => false,
+            },
+        }
+    }
+
     fn or_scope_merge(
         left_min: Bound<DataValue>,
         left_max: Bound<DataValue>,
         right_min: Bound<DataValue>,
         right_max: Bound<DataValue>,
     ) -> Range {
-        if matches!(
-            Self::bound_compared(&left_max, &right_min, false),
-            Some(Ordering::Less)
-        ) || matches!(
-            Self::bound_compared(&right_max, &left_min, false),
-            Some(Ordering::Less)
-        ) {
+        if Self::is_apart(&left_max, &right_min) || Self::is_apart(&right_max, &left_min) {
             let (min_1, max_1, min_2, max_2) = if let Some(true) =
                 Self::bound_compared(&left_min, &right_min, true).map(Ordering::is_lt)
             {
@@ -722,6 +793,253 @@ impl<'a> RangeDetacher<'a> {
                 max: Bound::Included(val.clone()),
             }),
             BinaryOperator::Eq | BinaryOperator::Spaceship => Some(Range::Eq(val.clone())),
+            // `c1 != NULL` is never true, e.g. `c1 NOT IN (1, NULL)`
+            BinaryOperator::NotEq if val.is_null() => Some(Range::Dummy),
+            BinaryOperator::NotEq => Some(Range::SortedRanges(vec![
+                Range::Scope {
+                    min: Bound::Unbounded,
+                    max: Bound::Excluded(val.clone()),
+                },
+                Range::Scope {
+                    min: Bound::Excluded(val),
+                    max: Bound::Unbounded,
+                },
+            ])),
+            // `c1 LIKE NULL` is never true
+            BinaryOperator::Like(_) if val.is_null() => Some(Range::Dummy),
+            BinaryOperator::Like(escape_char) if !is_flip => {
+                Self::like_prefix_range(val, escape_char)
+            }
+            _ => None,
+        }
+    }
+
+    /// `c1 LIKE 'abc%'` only matches the values starting with `abc`, which are in the scope
+    /// `['abc', 'abd')`, and a pattern without wildcards is the value itself
+    ///
+    /// Tips: a pattern starting with a wildcard has no prefix to scan
+    fn like_prefix_range(pattern: DataValue, escape_char: Option<char>) -> Option<Range> {
+        let DataValue::Utf8 {
+            value: pattern,
+            ty,
+            unit,
+        } = pattern
+        else {
+            return None;
+        };
+        let mut prefix = String::new();
+        let mut is_exact = true;
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if Some(c) == escape_char {
+                prefix.extend(chars.next());
+            } else if c == '%' || c == '_' {
+                is_exact = false;
+                break;
+            } else {
+                prefix.push(c);
+            }
+        }
+        let utf8 = |value| DataValue::Utf8 {
+            value,
+            ty: ty.clone(),
+            unit,
+        };
+        if is_exact {
+            return Some(Range::Eq(utf8(prefix)));
+        }
+        if prefix.is_empty() {
+            return None;
+        }
+        // the smallest string greater than all the strings starting with the prefix
+        let mut upper = prefix.clone();
+        let mut max = Bound::Unbounded;
+        while let Some(c) = upper.pop() {
+            if let Some(next_c) = (c as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+                upper.push(next_c);
+                max = Bound::Excluded(utf8(upper));
+                break;
+            }
+        }
+
+        Some(Range::Scope {
+            min: Bound::Included(utf8(prefix)),
+            max,
+        })
+    }
+
+    /// `c1 BETWEEN l AND h` is the scope `[l, h]`, and `c1 NOT BETWEEN l AND h` is `(-inf, l), (h, +inf)`
+    ///
+    /// Tips: a NULL bound never holds, so `c1 NOT BETWEEN l AND NULL` is only `(-inf, l)`
+    fn detach_between(
+        &mut self,
+        col: &ColumnRef,
+        left: DataValue,
+        right: DataValue,
+        negated: bool,
+    ) -> Option<Range> {
+        let (op, left_op, right_op) = if negated {
+            (BinaryOperator::Or, BinaryOperator::Lt, BinaryOperator::Gt)
+        } else {
+            (
+                BinaryOperator::And,
+                BinaryOperator::GtEq,
+                BinaryOperator::LtEq,
+            )
+        };
+        let mut new_range = |op, val: DataValue| {
+            let is_null = val.is_null();
+
+            self.new_range(op, col.clone(), val, false).map(|range| {
+                if is_null {
+                    Range::Dummy
+                } else {
+                    range
+                }
+            })
+        };
+        let left_range = new_range(left_op, left)?;
+        let right_range = new_range(right_op, right)?;
+
+        Self::merge_binary(op, left_range, right_range)
+    }
+
+    /// `COALESCE(c1, d) op v` matches the rows that `c1 op v` matches,
+    /// and also the NULL rows of `c1` when the default `d op v` holds
+    fn detach_coalesce(
+        &mut self,
+        mut op: BinaryOperator,
+        coalesce: &ScalarExpression,
+        val: DataValue,
+        is_flip: bool,
+    ) -> Option<Range> {
+        let ScalarExpression::Coalesce { exprs, ty } = coalesce else {
+            return None;
+        };
+        let (ScalarExpression::ColumnRef(col), defaults) = exprs.split_first()? else {
+            return None;
+        };
+        // a NULL column is replaced by the first non-null default
+        let mut default = NULL_VALUE.clone();
+        for expr in defaults {
+            default = expr.unpack_val()?;
+            if !default.is_null() {
+                break;
+            }
+        }
+        if is_flip {
+            op = match op {
+                BinaryOperator::Gt => BinaryOperator::Lt,
+                BinaryOperator::Lt => BinaryOperator::Gt,
+                BinaryOperator::GtEq => BinaryOperator::LtEq,
+                BinaryOperator::LtEq => BinaryOperator::GtEq,
+                source_op => source_op,
+            };
+        }
+        let range = self.new_range(op, col.clone(), val.clone(), false)?;
+
+        if default.is_null() || val.is_null() {
+            return Some(range);
+        }
+        let ordering = default.cast(ty).ok()?.partial_cmp(&val.cast(ty).ok()?)?;
+        let is_default_matched = match op {
+            BinaryOperator::Gt => ordering == Ordering::Greater,
+            BinaryOperator::GtEq => ordering != Ordering::Less,
+            BinaryOperator::Lt => ordering == Ordering::Less,
+            BinaryOperator::LtEq => ordering != Ordering::Greater,
+            BinaryOperator::Eq | BinaryOperator::Spaceship => ordering == Ordering::Equal,
+            BinaryOperator::NotEq => ordering != Ordering::Equal,
+            _ => return None,
+        };
+        if !is_default_matched {
+            return Some(range);
+        }
+        Self::merge_binary(BinaryOperator::Or, Range::Eq(NULL_VALUE.clone()), range)
+    }
+
+    /// Detaches a row-value comparison against constants, e.g. `(c1, c2) > (1, 2)`, into a
+    /// range of an index whose leading columns are the columns of the row in order.
+    ///
+    /// Tips: only the conjuncts of `expr` are searched, and the range is not always tight,
+    /// so the predicate still has to be evaluated on the scanned tuples
+    pub(crate) fn detach_row(
+        table_name: &str,
+        column_ids: &[ColumnId],
+        expr: &ScalarExpression,
+    ) -> Option<Range> {
+        let ScalarExpression::Binary {
+            left_expr,
+            right_expr,
+            op,
+            ..
+        } = expr
+        else {
+            return None;
+        };
+        if *op == BinaryOperator::And {
+            return Self::detach_row(table_name, column_ids, left_expr)
+                .or_else(|| Self::detach_row(table_name, column_ids, right_expr));
+        }
+        // the number of leading index columns the row consists of
+        let index_row_len = |expr: &ScalarExpression| {
+            let ScalarExpression::Tuple(columns) = expr else {
+                return None;
+            };
+            let is_prefix = !columns.is_empty()
+                && columns.len() <= column_ids.len()
+                && columns.iter().zip(column_ids).all(|(expr, column_id)| {
+                    matches!(expr, ScalarExpression::ColumnRef(col)
+                        if Self::_is_belong(table_name, col) && col.id() == Some(*column_id))
+                });
+            is_prefix.then_some(columns.len())
+        };
+        let (len, values, op) = if let Some(len) = index_row_len(left_expr) {
+            (len, right_expr, *op)
+        } else if let Some(len) = index_row_len(right_expr) {
+            let op = match op {
+                BinaryOperator::Gt => BinaryOperator::Lt,
+                BinaryOperator::Lt => BinaryOperator::Gt,
+                BinaryOperator::GtEq => BinaryOperator::LtEq,
+                BinaryOperator::LtEq => BinaryOperator::GtEq,
+                source_op => *source_op,
+            };
+            (len, left_expr, op)
+        } else {
+            return None;
+        };
+        let values = match values.as_ref() {
+            ScalarExpression::Tuple(exprs) => exprs
+                .iter()
+                .map(ScalarExpression::unpack_val)
+                .collect::<Option<Vec<_>>>()?,
+            expr => match expr.unpack_val()? {
+                DataValue::Tuple(values, _) => values,
+                _ => return None,
+            },
+        };
+        if values.len() != len {
+            return None;
+        }
+
+        // `is_upper` places the bound after every key sharing the row as its prefix
+        match op {
+            BinaryOperator::Gt => Some(Range::Scope {
+                min: Bound::Excluded(DataValue::Tuple(values, true)),
+                max: Bound::Unbounded,
+            }),
+            BinaryOperator::GtEq => Some(Range::Scope {
+                min: Bound::Included(DataValue::Tuple(values, false)),
+                max: Bound::Unbounded,
+            }),
+            BinaryOperator::Lt => Some(Range::Scope {
+                min: Bound::Unbounded,
+                max: Bound::Excluded(DataValue::Tuple(values, false)),
+            }),
+            BinaryOperator::LtEq => Some(Range::Scope {
+                min: Bound::Unbounded,
+                max: Bound::Included(DataValue::Tuple(values, true)),
+            }),
             _ => None,
         }
     }
@@ -770,8 +1088,12 @@ impl fmt::Display for Range {
 #[cfg(test)]
 mod test {
     use crate::binder::test::build_t1_table;
+    use crate::catalog::{ColumnCatalog, ColumnDesc, ColumnRef};
     use crate::errors::DatabaseError;
+    use crate::expression::function::scala::{ArcScalarFunctionImpl, ScalarFunction};
     use crate::expression::range_detacher::{Range, RangeDetacher};
+    use crate::expression::{BinaryOperator, ScalarExpression};
+    use crate::function::date_trunc::DateTrunc;
     use crate::optimizer::heuristic::batch::HepBatchStrategy;
     use crate::optimizer::heuristic::optimizer::HepOptimizer;
     use crate::optimizer::rule::normalization::NormalizationRuleImpl;
@@ -781,8 +1103,11 @@ mod test {
     use crate::storage::rocksdb::RocksTransaction;
     use crate::types::evaluator::tuple::TupleLtBinaryEvaluator;
     use crate::types::evaluator::BinaryEvaluator;
-    use crate::types::value::DataValue;
+    use crate::types::value::{DataValue, Utf8Type};
+    use crate::types::{ColumnId, LogicalType};
+    use sqlparser::ast::CharLengthUnits;
     use std::ops::Bound;
+    use std::sync::Arc;
 
     fn plan_filter(plan: LogicalPlan) -> Result<Option<FilterOperator>, DatabaseError> {
         let best_plan = HepOptimizer::new(plan.clone())
@@ -817,7 +1142,19 @@ mod test {
             let range =
                 RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate);
             println!("c1 != 1 => {:#?}", range);
-            assert_eq!(range, None)
+            assert_eq!(
+                range,
+                Some(Range::SortedRanges(vec![
+                    Range::Scope {
+                        min: Bound::Unbounded,
+                        max: Bound::Excluded(DataValue::Int32(1)),
+                    },
+                    Range::Scope {
+                        min: Bound::Excluded(DataValue::Int32(1)),
+                        max: Bound::Unbounded,
+                    },
+                ]))
+            )
         }
         {
             let plan = table_state.plan("select * from t1 where c1 > 1")?;
@@ -1001,6 +1338,73 @@ mod test {
                 }
             )
         }
+        {
+            let plan = table_state.plan("select * from t1 where c1 between symmetric 2 and 1")?;
+            let op = plan_filter(plan)?.unwrap();
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 between symmetric 2 and 1 => c1: {}", range);
+            assert_eq!(
+                range,
+                Range::Scope {
+                    min: Bound::Included(DataValue::Int32(1)),
+                    max: Bound::Included(DataValue::Int32(2)),
+                }
+            )
+        }
+        {
+            let plan = table_state.plan("select * from t1 where c1 between 5 and 10")?;
+            let op = plan_filter(plan)?.unwrap();
+            assert!(matches!(op.predicate, ScalarExpression::Between { .. }));
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 between 5 and 10 => c1: {}", range);
+            assert_eq!(
+                range,
+                Range::Scope {
+                    min: Bound::Included(DataValue::Int32(5)),
+                    max: Bound::Included(DataValue::Int32(10)),
+                }
+            )
+        }
+        {
+            let plan = table_state.plan("select * from t1 where c1 not between 5 and 10")?;
+            let op = plan_filter(plan)?.unwrap();
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 not between 5 and 10 => c1: {}", range);
+            assert_eq!(
+                range,
+                Range::SortedRanges(vec![
+                    Range::Scope {
+                        min: Bound::Unbounded,
+                        max: Bound::Excluded(DataValue::Int32(5)),
+                    },
+                    Range::Scope {
+                        min: Bound::Excluded(DataValue::Int32(10)),
+                        max: Bound::Unbounded,
+                    },
+                ])
+            )
+        }
+        {
+            let plan = table_state.plan("select * from t1 where c1 not between 10 and 5")?;
+            let op = plan_filter(plan)?.unwrap();
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 not between 10 and 5 => c1: {}", range);
+            assert_eq!(
+                range,
+                Range::Scope {
+                    min: Bound::Unbounded,
+                    max: Bound::Unbounded,
+                }
+            )
+        }
         // scope
         {
             let plan = table_state
@@ -1266,7 +1670,131 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_detach_coalesce_cases() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let detach = |sql: &str| -> Result<Option<Range>, DatabaseError> {
+            let plan = table_state.plan(sql)?;
+            let op = plan_filter(plan)?.unwrap();
+
+            Ok(RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate))
+        };
+        // the default is out of the range: NULL rows never match
+        assert_eq!(
+            detach("select * from t1 where coalesce(c1, 0) > 5")?,
+            Some(Range::Scope {
+                min: Bound::Excluded(DataValue::Int32(5)),
+                max: Bound::Unbounded,
+            })
+        );
+        assert_eq!(
+            detach("select * from t1 where 5 > coalesce(c1, 10)")?,
+            Some(Range::Scope {
+                min: Bound::Unbounded,
+                max: Bound::Excluded(DataValue::Int32(5)),
+            })
+        );
+        // the default is in the range: NULL rows match
+        assert_eq!(
+            detach("select * from t1 where coalesce(c1, 10) > 5")?,
+            Some(Range::SortedRanges(vec![
+                Range::Eq(DataValue::Null),
+                Range::Scope {
+                    min: Bound::Excluded(DataValue::Int32(5)),
+                    max: Bound::Unbounded,
+                },
+            ]))
+        );
+        assert_eq!(
+            detach("select * from t1 where coalesce(c1, null, 1) = 1")?,
+            Some(Range::SortedRanges(vec![
+                Range::Eq(DataValue::Null),
+                Range::Eq(DataValue::Int32(1)),
+            ]))
+        );
+        // the default is not a constant
+        assert_eq!(detach("select * from t1 where coalesce(c1, c2) > 5")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detach_preimage_cases() -> Result<(), DatabaseError> {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        let datetime = |value: &str| utf8(value).cast(&LogicalType::DateTime);
+        let column_id = ColumnId::new();
+        let mut column = ColumnCatalog::new(
+            "ts".to_string(),
+            false,
+            ColumnDesc::new(LogicalType::DateTime, None, false, None)?,
+        );
+        column.set_ref_table(Arc::new("t1".to_string()), column_id, false);
+
+        // date_trunc('day', ts) = '2022-10-12'
+        let mut expr = ScalarExpression::Binary {
+            op: BinaryOperator::Eq,
+            left_expr: Box::new(ScalarExpression::ScalaFunction(ScalarFunction {
+                args: vec![
+                    ScalarExpression::Constant(utf8("day")),
+                    ScalarExpression::ColumnRef(ColumnRef::from(column)),
+                ],
+                inner: ArcScalarFunctionImpl(DateTrunc::new(LogicalType::DateTime)),
+            })),
+            right_expr: Box::new(ScalarExpression::Constant(utf8("2022-10-12"))),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        expr.simplify()?;
+        let range = RangeDetacher::new("t1", &column_id).detach(&expr);
+        println!("date_trunc('day', ts) = '2022-10-12' => {:#?}", range);
+        assert_eq!(
+            range,
+            Some(Range::Scope {
+                min: Bound::Included(datetime("2022-10-12 00:00:00")?),
+                max: Bound::Excluded(datetime("2022-10-13 00:00:00")?),
+            })
+        );
+
+        Ok(())
+    }
+
     // Tips: `null` should be First
+    #[test]
+    fn test_detach_like_cases() {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        let detach = |pattern: &str, escape_char| {
+            RangeDetacher::like_prefix_range(utf8(pattern), escape_char)
+        };
+        let scope = |min: &str, max: Option<&str>| Range::Scope {
+            min: Bound::Included(utf8(min)),
+            max: max.map_or(Bound::Unbounded, |max| Bound::Excluded(utf8(max))),
+        };
+
+        assert_eq!(detach("Kip%", None), Some(scope("Kip", Some("Kiq"))));
+        assert_eq!(detach("Kip_QL", None), Some(scope("Kip", Some("Kiq"))));
+        assert_eq!(detach("Kip", None), Some(Range::Eq(utf8("Kip"))));
+        assert_eq!(detach("F@%c%", Some('@')), Some(scope("F%c", Some("F%d"))));
+        assert_eq!(detach("F@%", Some('@')), Some(Range::Eq(utf8("F%"))));
+        assert_eq!(detach("中文%", None), Some(scope("中文", Some("中斈"))));
+        // the last character can not be increased
+        assert_eq!(
+            detach("a\u{10FFFF}%", None),
+            Some(scope("a\u{10FFFF}", Some("b")))
+        );
+        assert_eq!(detach("\u{10FFFF}%", None), Some(scope("\u{10FFFF}", None)));
+        // no prefix to scan
+        assert_eq!(detach("%SQL", None), None);
+        assert_eq!(detach("_ip", None), None);
+    }
+
     #[test]
     fn test_detach_null_cases() -> Result<(), DatabaseError> {
         let table_state = build_t1_table()?;
@@ -1348,6 +1876,31 @@ mod test {
             println!("c1 = null and (c1 > 1 and c1 < 5) => c1: {}", range);
             assert_eq!(range, Range::Dummy)
         }
+        // between
+        {
+            let plan = table_state.plan("select * from t1 where c1 between null and 5")?;
+            let op = plan_filter(plan)?.unwrap();
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 between null and 5 => c1: {}", range);
+            assert_eq!(range, Range::Dummy)
+        }
+        {
+            let plan = table_state.plan("select * from t1 where c1 not between 5 and null")?;
+            let op = plan_filter(plan)?.unwrap();
+            let range = RangeDetacher::new("t1", table_state.column_id_by_name("c1"))
+                .detach(&op.predicate)
+                .unwrap();
+            println!("c1 not between 5 and null => c1: {}", range);
+            assert_eq!(
+                range,
+                Range::Scope {
+                    min: Bound::Unbounded,
+                    max: Bound::Excluded(DataValue::Int32(5)),
+                }
+            )
+        }
         // noteq
         {
             let plan = table_state.plan("select * from t1 where c1 != null")?;
@@ -1355,7 +1908,7 @@ mod test {
             let range =
                 RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate);
             println!("c1 != null => c1: {:#?}", range);
-            assert_eq!(range, None)
+            assert_eq!(range, Some(Range::Dummy))
         }
         {
             let plan = table_state.plan("select * from t1 where c1 = null or c1 != 1")?;
@@ -1363,7 +1916,20 @@ mod test {
             let range =
                 RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate);
             println!("c1 = null or c1 != 1 => c1: {:#?}", range);
-            assert_eq!(range, None)
+            assert_eq!(
+                range,
+                Some(Range::SortedRanges(vec![
+                    Range::Eq(DataValue::Null),
+                    Range::Scope {
+                        min: Bound::Unbounded,
+                        max: Bound::Excluded(DataValue::Int32(1)),
+                    },
+                    Range::Scope {
+                        min: Bound::Excluded(DataValue::Int32(1)),
+                        max: Bound::Unbounded,
+                    },
+                ]))
+            )
         }
         {
             let plan = table_state.plan("select * from t1 where c1 != null or c1 < 5")?;
@@ -1371,7 +1937,13 @@ mod test {
             let range =
                 RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate);
             println!("c1 != null or c1 < 5 => c1: {:#?}", range);
-            assert_eq!(range, None)
+            assert_eq!(
+                range,
+                Some(Range::Scope {
+                    min: Bound::Unbounded,
+                    max: Bound::Excluded(DataValue::Int32(5)),
+                })
+            )
         }
         {
             let plan =
@@ -1380,7 +1952,13 @@ mod test {
             let range =
                 RangeDetacher::new("t1", table_state.column_id_by_name("c1")).detach(&op.predicate);
             println!("c1 != null or (c1 > 1 and c1 < 5) => c1: {:#?}", range);
-            assert_eq!(range, None)
+            assert_eq!(
+                range,
+                Some(Range::Scope {
+                    min: Bound::Excluded(DataValue::Int32(1)),
+                    max: Bound::Excluded(DataValue::Int32(5)),
+                })
+            )
         }
         {
             let plan = table_state.plan("select * from t1 where c1 != null and c1 < 5")?;
@@ -1389,13 +1967,7 @@ mod test {
                 .detach(&op.predicate)
                 .unwrap();
             println!("c1 != null and c1 < 5 => c1: {}", range);
-            assert_eq!(
-                range,
-                Range::Scope {
-                    min: Bound::Unbounded,
-                    max: Bound::Excluded(DataValue::Int32(5)),
-                }
-            )
+            assert_eq!(range, Range::Dummy)
         }
         {
             let plan =
@@ -1405,13 +1977,7 @@ mod test {
                 .detach(&op.predicate)
                 .unwrap();
             println!("c1 != null and (c1 > 1 and c1 < 5) => c1: {}", range);
-            assert_eq!(
-                range,
-                Range::Scope {
-                    min: Bound::Excluded(DataValue::Int32(1)),
-                    max: Bound::Excluded(DataValue::Int32(5)),
-                }
-            )
+            assert_eq!(range, Range::Dummy)
         }
         {
             let plan = table_state.plan("select * from t1 where (c1 = null or (c1 < 2 and c1 > 0) or (c1 < 6 and c1 > 4)) or ((c1 < 3 and c1 > 1) or (c1 < 7 and c1 > 5))")?;
@@ -1922,7 +2488,7 @@ mod test {
             unreachable!()
         };
         assert_eq!(
-            TupleLtBinaryEvaluator.binary_eval(&min, &max),
+            TupleLtBinaryEvaluator.binary_eval(&min, &max).unwrap(),
             DataValue::Boolean(true)
         )
     }
@@ -48,8 +48,14 @@ impl ScalarExpression {
                 left_expr.exist_column(table_name, col_id)
                     || right_expr.exist_column(table_name, col_id)
             }
-            ScalarExpression::AggCall { args, .. }
-            | ScalarExpression::Tuple(args)
+            ScalarExpression::AggCall { args, filter, .. } => {
+                args.iter()
+                    .any(|expr| expr.exist_column(table_name, col_id))
+                    || filter
+                        .as_ref()
+                        .map_or(false, |filter| filter.exist_column(table_name, col_id))
+            }
+            ScalarExpression::Tuple(args)
             | ScalarExpression::ScalaFunction(ScalarFunction { args, .. })
             | ScalarExpression::TableFunction(TableFunction { args, .. })
             | ScalarExpression::Coalesce { exprs: args, .. } => args
@@ -89,6 +95,7 @@ impl ScalarExpression {
             ScalarExpression::Position { expr, in_expr } => {
                 expr.exist_column(table_name, col_id) || in_expr.exist_column(table_name, col_id)
             }
+            ScalarExpression::Extract { expr, .. } => expr.exist_column(table_name, col_id),
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -153,12 +160,18 @@ impl ScalarExpression {
         match self {
             ScalarExpression::Constant(val) => Some(val.clone()),
             ScalarExpression::Alias { expr, .. } => expr.unpack_val(),
-            ScalarExpression::TypeCast { expr, ty, .. } => {
-                expr.unpack_val().and_then(|val| val.cast(ty).ok())
+            ScalarExpression::TypeCast { expr, ty, is_try } => {
+                let value = expr.unpack_val()?.cast(ty);
+
+                if *is_try {
+                    Some(value.unwrap_or(DataValue::Null))
+                } else {
+                    value.ok()
+                }
             }
-            ScalarExpression::IsNull { expr, .. } => expr
+            ScalarExpression::IsNull { expr, negated } => expr
                 .unpack_val()
-                .map(|val| DataValue::Boolean(val.is_null())),
+                .map(|val| DataValue::Boolean(Self::eval_is_null(&val, *negated))),
             ScalarExpression::Unary {
                 expr,
                 op,
@@ -187,21 +200,23 @@ impl ScalarExpression {
             } => {
                 let mut left = left_expr.unpack_val()?;
                 let mut right = right_expr.unpack_val()?;
-                if &left.logical_type() != ty {
-                    left = left.cast(ty).ok()?;
+                let evaluation_ty = ty.clone().evaluation_type();
+                if left.logical_type() != evaluation_ty {
+                    left = left.cast(&evaluation_ty).ok()?;
                 }
-                if &right.logical_type() != ty {
-                    right = right.cast(ty).ok()?;
+                if right.logical_type() != evaluation_ty {
+                    right = right.cast(&evaluation_ty).ok()?;
                 }
                 let binary_value = if let Some(evaluator) = evaluator {
                     evaluator.0.binary_eval(&left, &right)
                 } else {
-                    EvaluatorFactory::binary_create(ty.clone(), *op)
+                    EvaluatorFactory::binary_create(evaluation_ty, *op)
                         .ok()?
                         .0
                         .binary_eval(&left, &right)
                 };
-                Some(binary_value)
+                let binary_value = binary_value.ok()?;
+                ScalarExpression::fit_decimal(binary_value, ty).ok()
             }
             _ => None,
         }
@@ -259,12 +274,10 @@ impl ScalarExpression {
                 left_expr,
                 right_expr,
                 op,
+                ty: result_ty,
                 ..
             } => {
-                let ty = LogicalType::max_logical_type(
-                    &left_expr.return_type(),
-                    &right_expr.return_type(),
-                )?;
+                let ty = ScalarExpression::binary_evaluation_type(op, left_expr, right_expr)?;
                 left_expr.constant_calculation()?;
                 right_expr.constant_calculation()?;
 
@@ -278,20 +291,36 @@ impl ScalarExpression {
                     if left_val.logical_type() != ty {
                         *left_val = left_val.clone().cast(&ty)?;
                     }
-                    if right_val.logical_type() != ty {
+                    if right_val.logical_type() != ty && !op.is_shift() {
                         *right_val = right_val.clone().cast(&ty)?;
                     }
-                    let value = evaluator.0.binary_eval(left_val, right_val);
+                    let value = ScalarExpression::fit_decimal(
+                        evaluator.0.binary_eval(left_val, right_val)?,
+                        result_ty,
+                    )?;
                     let _ = mem::replace(self, ScalarExpression::Constant(value));
                 }
             }
             ScalarExpression::Alias { expr, .. } => expr.constant_calculation()?,
-            ScalarExpression::TypeCast { expr, .. } => expr.constant_calculation()?,
+            ScalarExpression::TypeCast { expr, ty, is_try } => {
+                expr.constant_calculation()?;
+
+                // a failed `TRY_CAST` is NULL, so it folds whatever the constant is
+                if *is_try {
+                    if let ScalarExpression::Constant(value) = expr.as_ref() {
+                        let value = value.clone().cast(ty).unwrap_or(DataValue::Null);
+                        let _ = mem::replace(self, ScalarExpression::Constant(value));
+                    }
+                }
+            }
             ScalarExpression::IsNull { expr, .. } => expr.constant_calculation()?,
-            ScalarExpression::AggCall { args, .. } => {
+            ScalarExpression::AggCall { args, filter, .. } => {
                 for expr in args {
                     expr.constant_calculation()?;
                 }
+                if let Some(filter) = filter {
+                    filter.constant_calculation()?;
+                }
             }
             ScalarExpression::In { expr, args, .. } => {
                 expr.constant_calculation()?;
@@ -326,6 +355,7 @@ impl ScalarExpression {
                 expr.constant_calculation()?;
                 in_expr.constant_calculation()?;
             }
+            ScalarExpression::Extract { expr, .. } => expr.constant_calculation()?,
             ScalarExpression::Trim {
                 expr,
                 trim_what_expr,
@@ -336,11 +366,27 @@ impl ScalarExpression {
                     trim_what_expr.constant_calculation()?;
                 }
             }
-            ScalarExpression::Tuple(exprs) | ScalarExpression::Coalesce { exprs, .. } => {
+            ScalarExpression::Tuple(exprs) => {
                 for expr in exprs {
                     expr.constant_calculation()?;
                 }
             }
+            ScalarExpression::Coalesce { exprs, .. } => {
+                // the arguments after the first non-null one are never evaluated, so an
+                // argument failing to fold is kept as it is and only errors if it is reached
+                let mut len = exprs.len();
+                for (i, expr) in exprs.iter_mut().enumerate() {
+                    let mut folded = expr.clone();
+                    if folded.constant_calculation().is_ok() {
+                        *expr = folded;
+                    }
+                    if matches!(expr, ScalarExpression::Constant(value) if !value.is_null()) {
+                        len = i + 1;
+                        break;
+                    }
+                }
+                exprs.truncate(len);
+            }
             ScalarExpression::If {
                 condition,
                 left_expr,
@@ -399,6 +445,8 @@ impl ScalarExpression {
     // Tips: Indirect expressions like `ScalarExpression:：Alias` will be lost
     fn _simplify(&mut self, replaces: &mut Vec<Replace>) -> Result<(), DatabaseError> {
         match self {
+            // a bitwise operation cannot be inverted, so nothing is moved across it
+            ScalarExpression::Binary { op, .. } if op.is_bitwise() => (),
             ScalarExpression::Binary {
                 left_expr,
                 right_expr,
@@ -406,6 +454,11 @@ impl ScalarExpression {
                 ty,
                 ..
             } => {
+                if let Some(new_expr) = Self::unwrap_preimage(left_expr, right_expr, *op)? {
+                    let _ = mem::replace(self, new_expr);
+
+                    return self._simplify(replaces);
+                }
                 Self::fix_expr(replaces, left_expr, right_expr, op)?;
 
                 // `(c1 - 1) and (c1 + 2)` cannot fix!
@@ -463,16 +516,18 @@ impl ScalarExpression {
                 }
             }
             ScalarExpression::Alias { expr, .. } => expr._simplify(replaces)?,
-            ScalarExpression::TypeCast { expr, .. } => {
-                if let Some(val) = expr.unpack_val() {
+            ScalarExpression::TypeCast { .. } => {
+                // fold the cast value rather than the value being cast
+                if let Some(val) = self.unpack_val() {
                     let _ = mem::replace(self, ScalarExpression::Constant(val));
                 }
             }
-            ScalarExpression::IsNull { expr, .. } => {
+            ScalarExpression::IsNull { expr, negated } => {
                 if let Some(val) = expr.unpack_val() {
+                    let is_null = Self::eval_is_null(&val, *negated);
                     let _ = mem::replace(
                         self,
-                        ScalarExpression::Constant(DataValue::Boolean(val.is_null())),
+                        ScalarExpression::Constant(DataValue::Boolean(is_null)),
                     );
                 }
             }
@@ -484,6 +539,8 @@ impl ScalarExpression {
                 ..
             } => {
                 if let Some(value) = expr.unpack_val() {
+                    // the value moved from the other side of a comparison may be of another type
+                    let value = value.cast(ty)?;
                     let value = if let Some(evaluator) = evaluator {
                         evaluator.0.unary_eval(&value)
                     } else {
@@ -493,6 +550,12 @@ impl ScalarExpression {
                     };
                     let new_expr = ScalarExpression::Constant(value);
                     let _ = mem::replace(self, new_expr);
+                } else if *op == UnaryOperator::Not && Self::is_foldable_not(expr) {
+                    let new_expr =
+                        Self::fold_not(mem::replace(expr.as_mut(), ScalarExpression::Empty));
+                    let _ = mem::replace(self, new_expr);
+
+                    return self._simplify(replaces);
                 } else {
                     replaces.push(Replace::Unary(ReplaceUnary {
                         child_expr: expr.as_ref().clone(),
@@ -541,6 +604,20 @@ impl ScalarExpression {
 
                 let _ = mem::replace(self, new_expr);
             }
+            // `c1 BETWEEN 1 AND 2` is detached into a range as it is, see `RangeDetacher::detach`,
+            // any other form is split into the comparisons to be fixed like `c1 + 1 >= 2`
+            ScalarExpression::Between {
+                expr,
+                left_expr,
+                right_expr,
+                ..
+            } if matches!(expr.as_ref(), ScalarExpression::ColumnRef(_))
+                && [&**left_expr, &**right_expr].into_iter().all(|bound| {
+                    let ty = bound.return_type();
+
+                    bound.referenced_columns(true).is_empty()
+                        && (ty == expr.return_type() || ty == LogicalType::SqlNull)
+                }) => {}
             ScalarExpression::Between {
                 expr,
                 left_expr,
@@ -585,6 +662,159 @@ impl ScalarExpression {
         Ok(())
     }
 
+    /// `f(x) op c` is rewritten into a range over `x` when `f` provides the preimage of `c`,
+    /// e.g. `date_trunc('day', ts) = '2022-10-12'` => `ts >= '2022-10-12' and ts < '2022-10-13'`
+    fn unwrap_preimage(
+        left_expr: &ScalarExpression,
+        right_expr: &ScalarExpression,
+        op: BinaryOperator,
+    ) -> Result<Option<ScalarExpression>, DatabaseError> {
+        let (function, value, op) = match (left_expr, right_expr) {
+            (ScalarExpression::ScalaFunction(function), expr) if expr.unpack_val().is_some() => {
+                (function, expr.unpack_val(), op)
+            }
+            (expr, ScalarExpression::ScalaFunction(function)) if expr.unpack_val().is_some() => {
+                let op = match op {
+                    BinaryOperator::Gt => BinaryOperator::Lt,
+                    BinaryOperator::GtEq => BinaryOperator::LtEq,
+                    BinaryOperator::Lt => BinaryOperator::Gt,
+                    BinaryOperator::LtEq => BinaryOperator::GtEq,
+                    op => op,
+                };
+                (function, expr.unpack_val(), op)
+            }
+            _ => return Ok(None),
+        };
+        if !matches!(
+            op,
+            BinaryOperator::Eq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+        ) {
+            return Ok(None);
+        }
+        let Some(value) = value
+            .filter(|value| !value.is_null())
+            .and_then(|value| value.cast(function.inner.return_type()).ok())
+        else {
+            return Ok(None);
+        };
+        let Some((start, end)) = function.inner.preimage(&function.args, &value)? else {
+            return Ok(None);
+        };
+        let Some(arg) = function
+            .inner
+            .monotonicity()
+            .and_then(|monotonicity| monotonicity.iter().position(|m| *m == Some(true)))
+            .and_then(|i| function.args.get(i))
+        else {
+            return Ok(None);
+        };
+        let compare = |op: BinaryOperator, value: DataValue| ScalarExpression::Binary {
+            op,
+            left_expr: Box::new(arg.clone()),
+            right_expr: Box::new(ScalarExpression::Constant(value)),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        // `c` is only reachable by `f(x)` if it is the start of its range
+        let is_start = start == value;
+
+        Ok(Some(match op {
+            BinaryOperator::Eq if is_start => ScalarExpression::Binary {
+                op: BinaryOperator::And,
+                left_expr: Box::new(compare(BinaryOperator::GtEq, start)),
+                right_expr: Box::new(compare(BinaryOperator::Lt, end)),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            },
+            BinaryOperator::Lt => compare(BinaryOperator::Lt, if is_start { start } else { end }),
+            BinaryOperator::GtEq => {
+                compare(BinaryOperator::GtEq, if is_start { start } else { end })
+            }
+            BinaryOperator::LtEq => compare(BinaryOperator::Lt, end),
+            BinaryOperator::Gt => compare(BinaryOperator::GtEq, end),
+            _ => return Ok(None),
+        }))
+    }
+
+    /// `NOT NOT x` and `NOT (a op b)` can be folded away.
+    ///
+    /// Tips: the comparison flip is only applied when both sides can never be NULL,
+    /// nullable operands are conservatively left alone
+    fn is_foldable_not(expr: &ScalarExpression) -> bool {
+        match expr {
+            ScalarExpression::Unary {
+                op: UnaryOperator::Not,
+                expr,
+                ..
+            } => expr.return_type() == LogicalType::Boolean,
+            ScalarExpression::Binary {
+                op,
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                Self::negated_comparison(op).is_some()
+                    && !left_expr.is_nullable()
+                    && !right_expr.is_nullable()
+            }
+            _ => false,
+        }
+    }
+
+    fn fold_not(expr: ScalarExpression) -> ScalarExpression {
+        match expr {
+            ScalarExpression::Unary { expr, .. } => *expr,
+            ScalarExpression::Binary {
+                op,
+                left_expr,
+                right_expr,
+                ty,
+                ..
+            } => ScalarExpression::Binary {
+                op: Self::negated_comparison(&op).unwrap(),
+                left_expr,
+                right_expr,
+                evaluator: None,
+                ty,
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn negated_comparison(op: &BinaryOperator) -> Option<BinaryOperator> {
+        Some(match op {
+            BinaryOperator::Gt => BinaryOperator::LtEq,
+            BinaryOperator::GtEq => BinaryOperator::Lt,
+            BinaryOperator::Lt => BinaryOperator::GtEq,
+            BinaryOperator::LtEq => BinaryOperator::Gt,
+            BinaryOperator::Eq => BinaryOperator::NotEq,
+            BinaryOperator::NotEq => BinaryOperator::Eq,
+            _ => return None,
+        })
+    }
+
+    /// Conservatively checks whether the expression may evaluate to NULL.
+    fn is_nullable(&self) -> bool {
+        match self {
+            ScalarExpression::Constant(val) => val.is_null(),
+            ScalarExpression::ColumnRef(col) => col.nullable(),
+            ScalarExpression::Alias { expr, .. }
+            | ScalarExpression::TypeCast { expr, .. }
+            | ScalarExpression::Unary { expr, .. } => expr.is_nullable(),
+            ScalarExpression::Binary {
+                left_expr,
+                right_expr,
+                ..
+            } => left_expr.is_nullable() || right_expr.is_nullable(),
+            ScalarExpression::IsNull { .. } => false,
+            _ => true,
+        }
+    }
+
     fn is_arithmetic(op: &mut BinaryOperator) -> bool {
         matches!(
             op,
@@ -606,6 +836,11 @@ impl ScalarExpression {
         if Self::is_arithmetic(op) {
             return Ok(());
         }
+        // `NOT c1 AND c2` must not be moved across a logical operator
+        if matches!(op, BinaryOperator::And | BinaryOperator::Or) {
+            replaces.clear();
+            return Ok(());
+        }
         while let Some(replace) = replaces.pop() {
             match replace {
                 Replace::Binary(binary) => Self::fix_binary(binary, left_expr, right_expr, op),
@@ -657,7 +892,8 @@ impl ScalarExpression {
                     BinaryOperator::LtEq => BinaryOperator::GtEq,
                     source_op => source_op,
                 },
-                UnaryOperator::Not => match *op {
+                // `~` reverses the order of integers, as `~x = -x - 1`
+                UnaryOperator::Not | UnaryOperator::BitNot => match *op {
                     BinaryOperator::Gt => BinaryOperator::Lt,
                     BinaryOperator::Lt => BinaryOperator::Gt,
                     BinaryOperator::GtEq => BinaryOperator::LtEq,
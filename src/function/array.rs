@@ -0,0 +1,116 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `list[index]` returns the element of `list` at the 1-based `index`, or NULL when the list or
+/// the index is NULL or the index is out of range.
+///
+/// Tips: the return type is the element type of each list, so it is built by the binder
+/// instead of being registered
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ArrayElement {
+    summary: FunctionSummary,
+    return_ty: LogicalType,
+}
+
+impl ArrayElement {
+    pub(crate) fn new(arg_types: Vec<LogicalType>, return_ty: LogicalType) -> Arc<Self> {
+        let function_name = "array_element".to_lowercase();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            return_ty,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for ArrayElement {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let DataValue::List(mut values) = exprs[0].eval(tuples)? else {
+            return Ok(DataValue::Null);
+        };
+        let Some(index) = exprs[1].eval(tuples)?.cast(&LogicalType::Bigint)?.i64() else {
+            return Ok(DataValue::Null);
+        };
+        if index < 1 || index as usize > values.len() {
+            return Ok(DataValue::Null);
+        }
+
+        values.swap_remove(index as usize - 1).cast(&self.return_ty)
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.return_ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
+
+/// `cardinality(list)` returns the number of elements in `list`, NULL elements included.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Cardinality {
+    summary: FunctionSummary,
+}
+
+impl Cardinality {
+    pub(crate) fn new(arg_types: Vec<LogicalType>) -> Arc<Self> {
+        let function_name = "cardinality".to_lowercase();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Cardinality {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let DataValue::List(values) = exprs[0].eval(tuples)? else {
+            return Ok(DataValue::Null);
+        };
+
+        Ok(DataValue::Int32(values.len() as i32))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Integer
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
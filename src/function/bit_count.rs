@@ -0,0 +1,67 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `bit_count(integer)` returns the number of bits set in the integer, within its own width,
+/// e.g. `bit_count(cast(-1 as tinyint))` is `8`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BitCount {
+    summary: FunctionSummary,
+}
+
+impl BitCount {
+    pub(crate) fn new(ty: LogicalType) -> Arc<Self> {
+        let function_name = "bit_count".to_lowercase();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: vec![ty],
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for BitCount {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let count = match exprs[0].eval(tuples)?.cast(&self.summary.arg_types[0])? {
+            DataValue::Int8(value) => value.count_ones(),
+            DataValue::Int16(value) => value.count_ones(),
+            DataValue::Int32(value) => value.count_ones(),
+            DataValue::Int64(value) => value.count_ones(),
+            DataValue::UInt8(value) => value.count_ones(),
+            DataValue::UInt16(value) => value.count_ones(),
+            DataValue::UInt32(value) => value.count_ones(),
+            DataValue::UInt64(value) => value.count_ones(),
+            _ => return Ok(DataValue::Null),
+        };
+
+        Ok(DataValue::Int32(count as i32))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Integer
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -7,7 +7,7 @@ use crate::expression::ScalarExpression;
 use crate::types::tuple::Tuple;
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Local, NaiveDateTime};
 use serde::Deserialize;
 use serde::Serialize;
 use std::sync::Arc;
@@ -15,6 +15,8 @@ use std::sync::Arc;
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct CurrentDate {
     summary: FunctionSummary,
+    /// the date of the statement, `None` reads the clock on every evaluation (e.g. as a default)
+    today: Option<i32>,
 }
 
 impl CurrentDate {
@@ -27,6 +29,7 @@ impl CurrentDate {
                 name: function_name,
                 arg_types: Vec::new(),
             },
+            today: None,
         })
     }
 }
@@ -39,13 +42,27 @@ impl ScalarFunctionImpl for CurrentDate {
         _: &[ScalarExpression],
         _: Option<(&Tuple, &[ColumnRef])>,
     ) -> Result<DataValue, DatabaseError> {
-        Ok(DataValue::Date32(Local::now().num_days_from_ce()))
+        Ok(DataValue::Date32(
+            self.today
+                .unwrap_or_else(|| Local::now().num_days_from_ce()),
+        ))
     }
 
     fn monotonicity(&self) -> Option<FuncMonotonicity> {
         todo!()
     }
 
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn at_time(&self, now: NaiveDateTime) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Arc::new(Self {
+            summary: self.summary.clone(),
+            today: Some(now.num_days_from_ce()),
+        }))
+    }
+
     fn return_type(&self) -> &LogicalType {
         &LogicalType::Date
     }
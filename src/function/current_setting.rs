@@ -0,0 +1,97 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::setting::SettingsRef;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+const TEXT: LogicalType = LogicalType::Varchar(None, CharLengthUnits::Characters);
+
+/// `current_setting(name [, missing_ok])` reads a session setting as text.
+///
+/// Tips: an unknown setting is an error, unless `missing_ok` is true and then it is `NULL`
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CurrentSetting {
+    summary: FunctionSummary,
+    #[serde(skip)]
+    settings: SettingsRef,
+}
+
+impl CurrentSetting {
+    pub(crate) fn new(with_missing_ok: bool) -> Arc<Self> {
+        let function_name = "current_setting".to_lowercase();
+        let mut arg_types = vec![TEXT];
+        if with_missing_ok {
+            arg_types.push(LogicalType::Boolean);
+        }
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            settings: Default::default(),
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for CurrentSetting {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let name = match exprs[0].eval(tuples)? {
+            DataValue::Null => return Ok(DataValue::Null),
+            DataValue::Utf8 { value, .. } => value,
+            value => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "current_setting name: {}",
+                    value
+                )))
+            }
+        };
+        let missing_ok = match exprs.get(1) {
+            Some(expr) => expr.eval(tuples)?.bool().unwrap_or(false),
+            None => false,
+        };
+
+        match self.settings.snapshot().get(&name) {
+            Some(value) => Ok(value),
+            None if missing_ok => Ok(DataValue::Null),
+            None => Err(DatabaseError::SettingNotFound(name)),
+        }
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn with_settings(&self, settings: &SettingsRef) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Arc::new(Self {
+            summary: self.summary.clone(),
+            settings: settings.clone(),
+        }))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &TEXT
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
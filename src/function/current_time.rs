@@ -0,0 +1,72 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use chrono::{Local, NaiveDateTime, Timelike};
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `current_time` returns the local time of day the statement started at.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CurrentTime {
+    summary: FunctionSummary,
+    /// the time of the statement, `None` reads the clock on every evaluation (e.g. as a default)
+    now: Option<u32>,
+}
+
+impl CurrentTime {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "current_time".to_lowercase();
+
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: Vec::new(),
+            },
+            now: None,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for CurrentTime {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        _: &[ScalarExpression],
+        _: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::Time(self.now.unwrap_or_else(|| {
+            Local::now().naive_local().num_seconds_from_midnight()
+        })))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn at_time(&self, now: NaiveDateTime) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Arc::new(Self {
+            summary: self.summary.clone(),
+            now: Some(now.num_seconds_from_midnight()),
+        }))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Time
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
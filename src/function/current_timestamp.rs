@@ -0,0 +1,70 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use chrono::{Local, NaiveDateTime};
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `current_timestamp`/`now()` returns the local date and time the statement started at.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CurrentTimestamp {
+    summary: FunctionSummary,
+    /// the time of the statement, `None` reads the clock on every evaluation (e.g. as a default)
+    now: Option<i64>,
+}
+
+impl CurrentTimestamp {
+    pub(crate) fn new(function_name: String) -> Arc<Self> {
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: Vec::new(),
+            },
+            now: None,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for CurrentTimestamp {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        _: &[ScalarExpression],
+        _: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::Date64(self.now.unwrap_or_else(|| {
+            Local::now().naive_local().and_utc().timestamp()
+        })))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn at_time(&self, now: NaiveDateTime) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Arc::new(Self {
+            summary: self.summary.clone(),
+            now: Some(now.and_utc().timestamp()),
+        }))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::DateTime
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
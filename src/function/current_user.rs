@@ -0,0 +1,63 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// the only user of an embedded database
+pub(crate) const DEFAULT_USER: &str = "kite_sql";
+
+/// `current_user`/`session_user` returns the name of the user running the statement.
+///
+/// Tips: there are no accounts yet, so it is always [`DEFAULT_USER`]
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CurrentUser {
+    summary: FunctionSummary,
+}
+
+impl CurrentUser {
+    pub(crate) fn new(function_name: String) -> Arc<Self> {
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: Vec::new(),
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for CurrentUser {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        _: &[ScalarExpression],
+        _: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::from(DEFAULT_USER.to_string()))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
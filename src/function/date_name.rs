@@ -0,0 +1,77 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `dayname(temporal)` / `monthname(temporal)` return the English name of the day of the week /
+/// the month of the value, e.g. `Wednesday` / `October`.
+///
+/// Tips: the names do not depend on any locale
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DateName {
+    summary: FunctionSummary,
+    is_month: bool,
+}
+
+impl DateName {
+    pub(crate) fn new(is_month: bool, ty: LogicalType) -> Arc<Self> {
+        let function_name = if is_month { "monthname" } else { "dayname" }.to_string();
+        let arg_types = vec![ty];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            is_month,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for DateName {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let value = exprs[0].eval(tuples)?;
+        let date = match value {
+            DataValue::Null => return Ok(DataValue::Null),
+            DataValue::Date32(_) => value.date(),
+            DataValue::Date64(_) => value.datetime().map(|datetime| datetime.date()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            DatabaseError::InvalidValue(format!("{} cannot name: {}", self.summary.name, value))
+        })?;
+        let format = if self.is_month { "%B" } else { "%A" };
+
+        Ok(DataValue::Utf8 {
+            value: date.format(format).to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
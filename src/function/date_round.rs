@@ -0,0 +1,107 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+/// `1970-01-01` as days from CE, which is the unit of `DataValue::Date32`
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// `date_round(temporal, interval)` rounds the value to the nearest multiple of the interval,
+/// counted from the epoch origin (`1970-01-01 00:00:00`, or midnight for `Time`).
+///
+/// Tips: a value exactly halfway between two multiples is rounded up
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DateRound {
+    summary: FunctionSummary,
+}
+
+impl DateRound {
+    pub(crate) fn new(ty: LogicalType) -> Arc<Self> {
+        let function_name = "date_round".to_lowercase();
+        let arg_types = vec![ty, LogicalType::Interval];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+
+    fn round(seconds: i64, interval: i64) -> Result<i64, DatabaseError> {
+        let remainder = seconds.rem_euclid(interval);
+        let floor = seconds - remainder;
+
+        if remainder >= interval - remainder {
+            floor
+                .checked_add(interval)
+                .ok_or_else(|| DatabaseError::InvalidValue("date_round overflow".to_string()))
+        } else {
+            Ok(floor)
+        }
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for DateRound {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let value = exprs[0].eval(tuples)?;
+        let interval = match exprs[1].eval(tuples)? {
+            DataValue::Null => return Ok(DataValue::Null),
+            DataValue::Interval(interval) if interval > 0 => interval,
+            interval => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "date_round interval must be positive: {}",
+                    interval
+                )))
+            }
+        };
+
+        Ok(match value {
+            DataValue::Date64(seconds) => DataValue::Date64(Self::round(seconds, interval)?),
+            DataValue::Date32(days) => {
+                let seconds = (days as i64 - UNIX_EPOCH_DAYS_FROM_CE) * SECONDS_PER_DAY;
+                let days = Self::round(seconds, interval)?.div_euclid(SECONDS_PER_DAY)
+                    + UNIX_EPOCH_DAYS_FROM_CE;
+
+                DataValue::Date32(i32::try_from(days)?)
+            }
+            // rounding up past midnight wraps around to `00:00:00`
+            DataValue::Time(seconds) => DataValue::Time(
+                Self::round(seconds as i64, interval)?.rem_euclid(SECONDS_PER_DAY) as u32,
+            ),
+            DataValue::Null => DataValue::Null,
+            value => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "date_round cannot round: {}",
+                    value
+                )))
+            }
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        Some(vec![Some(true), None])
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.summary.arg_types[0]
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
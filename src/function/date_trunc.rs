@@ -0,0 +1,192 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Timelike};
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TruncUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TruncUnit {
+    fn parse(unit: &str) -> Result<Self, DatabaseError> {
+        Ok(match unit.to_lowercase().as_str() {
+            "second" => TruncUnit::Second,
+            "minute" => TruncUnit::Minute,
+            "hour" => TruncUnit::Hour,
+            "day" => TruncUnit::Day,
+            "week" => TruncUnit::Week,
+            "month" => TruncUnit::Month,
+            "quarter" => TruncUnit::Quarter,
+            "year" => TruncUnit::Year,
+            _ => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "date_trunc unit not recognized: {}",
+                    unit
+                )))
+            }
+        })
+    }
+
+    fn truncate(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = datetime.date();
+
+        match self {
+            TruncUnit::Second => datetime.with_nanosecond(0),
+            TruncUnit::Minute => date.and_hms_opt(datetime.hour(), datetime.minute(), 0),
+            TruncUnit::Hour => date.and_hms_opt(datetime.hour(), 0, 0),
+            TruncUnit::Day => date.and_hms_opt(0, 0, 0),
+            // weeks start on Monday as in ISO 8601
+            TruncUnit::Week => (date
+                - Duration::days(date.weekday().num_days_from_monday() as i64))
+            .and_hms_opt(0, 0, 0),
+            TruncUnit::Month => date.with_day(1)?.and_hms_opt(0, 0, 0),
+            TruncUnit::Quarter => {
+                NaiveDate::from_ymd_opt(date.year(), (date.month0() / 3) * 3 + 1, 1)?
+                    .and_hms_opt(0, 0, 0)
+            }
+            TruncUnit::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1)?.and_hms_opt(0, 0, 0),
+        }
+    }
+
+    /// the start of the unit following the truncated `datetime`
+    fn next(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self {
+            TruncUnit::Second => datetime.checked_add_signed(Duration::seconds(1)),
+            TruncUnit::Minute => datetime.checked_add_signed(Duration::minutes(1)),
+            TruncUnit::Hour => datetime.checked_add_signed(Duration::hours(1)),
+            TruncUnit::Day => datetime.checked_add_signed(Duration::days(1)),
+            TruncUnit::Week => datetime.checked_add_signed(Duration::weeks(1)),
+            TruncUnit::Month => datetime.checked_add_months(Months::new(1)),
+            TruncUnit::Quarter => datetime.checked_add_months(Months::new(3)),
+            TruncUnit::Year => datetime.checked_add_months(Months::new(12)),
+        }
+    }
+}
+
+/// `date_trunc(unit, temporal)` truncates the value down to the start of the given unit
+/// (`second`, `minute`, `hour`, `day`, `week`, `month`, `quarter` or `year`).
+///
+/// Tips: a `Date` has no time part, so units finer than `day` leave it unchanged
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DateTrunc {
+    summary: FunctionSummary,
+}
+
+impl DateTrunc {
+    pub(crate) fn new(ty: LogicalType) -> Arc<Self> {
+        let function_name = "date_trunc".to_lowercase();
+        let arg_types = vec![LogicalType::Varchar(None, CharLengthUnits::Characters), ty];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+
+    fn unit(&self, unit: &DataValue) -> Result<Option<TruncUnit>, DatabaseError> {
+        let Some(unit) = unit.utf8() else {
+            return Ok(None);
+        };
+        let unit = TruncUnit::parse(unit)?;
+
+        // a `Date` can only be truncated down to a day
+        Ok(Some(if self.summary.arg_types[1] == LogicalType::Date {
+            unit.max(TruncUnit::Day)
+        } else {
+            unit
+        }))
+    }
+
+    fn to_datetime(value: &DataValue) -> Option<NaiveDateTime> {
+        match value {
+            DataValue::Date32(_) => value.date()?.and_hms_opt(0, 0, 0),
+            DataValue::Date64(_) => value.datetime(),
+            _ => None,
+        }
+    }
+
+    fn datetime_value(&self, datetime: NaiveDateTime) -> DataValue {
+        if self.summary.arg_types[1] == LogicalType::Date {
+            DataValue::Date32(datetime.date().num_days_from_ce())
+        } else {
+            DataValue::Date64(datetime.and_utc().timestamp())
+        }
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for DateTrunc {
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let Some(unit) = self.unit(&exprs[0].eval(tuples)?)? else {
+            return Ok(DataValue::Null);
+        };
+        let value = exprs[1].eval(tuples)?;
+        if value.is_null() {
+            return Ok(DataValue::Null);
+        }
+        let truncated = Self::to_datetime(&value)
+            .and_then(|datetime| unit.truncate(datetime))
+            .ok_or_else(|| {
+                DatabaseError::InvalidValue(format!("date_trunc cannot truncate: {}", value))
+            })?;
+
+        Ok(self.datetime_value(truncated))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        Some(vec![None, Some(true)])
+    }
+
+    fn preimage(
+        &self,
+        args: &[ScalarExpression],
+        value: &DataValue,
+    ) -> Result<Option<(DataValue, DataValue)>, DatabaseError> {
+        let Some(unit) = args[0].unpack_val() else {
+            return Ok(None);
+        };
+        let Some(unit) = self.unit(&unit)? else {
+            return Ok(None);
+        };
+        let Some(start) = Self::to_datetime(value).and_then(|datetime| unit.truncate(datetime))
+        else {
+            return Ok(None);
+        };
+        let Some(end) = unit.next(start) else {
+            return Ok(None);
+        };
+
+        Ok(Some((self.datetime_value(start), self.datetime_value(end))))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.summary.arg_types[1]
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
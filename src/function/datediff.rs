@@ -0,0 +1,141 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use chrono::{Datelike, NaiveDateTime};
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl DiffUnit {
+    fn parse(unit: &str) -> Result<Self, DatabaseError> {
+        Ok(match unit.to_lowercase().as_str() {
+            "second" => DiffUnit::Second,
+            "minute" => DiffUnit::Minute,
+            "hour" => DiffUnit::Hour,
+            "day" => DiffUnit::Day,
+            "week" => DiffUnit::Week,
+            "month" => DiffUnit::Month,
+            "quarter" => DiffUnit::Quarter,
+            "year" => DiffUnit::Year,
+            _ => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "datediff unit not recognized: {}",
+                    unit
+                )))
+            }
+        })
+    }
+
+    /// the number of whole units from `start` to `end`, truncated toward zero
+    fn diff(&self, start: NaiveDateTime, end: NaiveDateTime) -> i64 {
+        let seconds = (end - start).num_seconds();
+
+        match self {
+            DiffUnit::Second => seconds,
+            DiffUnit::Minute => seconds / 60,
+            DiffUnit::Hour => seconds / 3600,
+            DiffUnit::Day => seconds / 86400,
+            DiffUnit::Week => seconds / 604800,
+            DiffUnit::Month => Self::months(start, end),
+            DiffUnit::Quarter => Self::months(start, end) / 3,
+            DiffUnit::Year => Self::months(start, end) / 12,
+        }
+    }
+
+    /// a month is only whole once the day and time of `start` are reached again,
+    /// e.g. there is no whole month from `2024-01-31` to `2024-02-29`
+    fn months(start: NaiveDateTime, end: NaiveDateTime) -> i64 {
+        let months = (end.year() as i64 * 12 + end.month0() as i64)
+            - (start.year() as i64 * 12 + start.month0() as i64);
+        let start_rest = (start.day(), start.time());
+        let end_rest = (end.day(), end.time());
+
+        if months > 0 && end_rest < start_rest {
+            months - 1
+        } else if months < 0 && end_rest > start_rest {
+            months + 1
+        } else {
+            months
+        }
+    }
+}
+
+/// `datediff(unit, start, end)` is the signed number of whole units (`second`, `minute`, `hour`,
+/// `day`, `week`, `month`, `quarter` or `year`) from `start` to `end`, negative if `end` is earlier.
+///
+/// Tips: a `Date` is compared as the start of its day
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct DateDiff {
+    summary: FunctionSummary,
+}
+
+impl DateDiff {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "datediff".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::DateTime,
+            LogicalType::DateTime,
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for DateDiff {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let unit = exprs[0].eval(tuples)?;
+        let Some(unit) = unit.utf8() else {
+            return Ok(DataValue::Null);
+        };
+        let unit = DiffUnit::parse(unit)?;
+        let start = exprs[1].eval(tuples)?.cast(&LogicalType::DateTime)?;
+        let end = exprs[2].eval(tuples)?.cast(&LogicalType::DateTime)?;
+        let (Some(start), Some(end)) = (start.datetime(), end.datetime()) else {
+            return Ok(DataValue::Null);
+        };
+
+        Ok(DataValue::Int64(unit.diff(start, end)))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Bigint
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
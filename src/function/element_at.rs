@@ -0,0 +1,85 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `element_at(keys, values, key)` returns the element of `values` at the position of the first
+/// `key` in `keys`, or NULL when `key` is NULL or missing.
+///
+/// Tips: `keys` and `values` are lists of the same length, so it is built by the binder for
+/// the types of each call instead of being registered
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ElementAt {
+    summary: FunctionSummary,
+    key_ty: LogicalType,
+    return_ty: LogicalType,
+}
+
+impl ElementAt {
+    pub(crate) fn new(
+        arg_types: Vec<LogicalType>,
+        key_ty: LogicalType,
+        return_ty: LogicalType,
+    ) -> Arc<Self> {
+        let function_name = "element_at".to_lowercase();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            key_ty,
+            return_ty,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for ElementAt {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let key = exprs[2].eval(tuples)?;
+        if key.is_null() {
+            return Ok(DataValue::Null);
+        }
+        let key = key.cast(&self.key_ty)?;
+        let (DataValue::Tuple(keys, _), DataValue::Tuple(values, _)) =
+            (exprs[0].eval(tuples)?, exprs[1].eval(tuples)?)
+        else {
+            return Ok(DataValue::Null);
+        };
+        if keys.len() != values.len() {
+            return Err(DatabaseError::ValuesLenMismatch(keys.len(), values.len()));
+        }
+
+        for (candidate, value) in keys.into_iter().zip(values) {
+            if !candidate.is_null() && candidate.cast(&self.key_ty)? == key {
+                return value.cast(&self.return_ty);
+            }
+        }
+        Ok(DataValue::Null)
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.return_ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
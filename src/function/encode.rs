@@ -0,0 +1,283 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Hex,
+    Base64,
+    Escape,
+}
+
+impl Format {
+    fn parse(function_name: &str, format: &str) -> Result<Self, DatabaseError> {
+        Ok(match format.to_lowercase().as_str() {
+            "hex" => Format::Hex,
+            "base64" => Format::Base64,
+            "escape" => Format::Escape,
+            _ => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "{} format not recognized: {}",
+                    function_name, format
+                )))
+            }
+        })
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        let mut text = String::with_capacity(bytes.len() * 2);
+
+        match self {
+            Format::Hex => {
+                for byte in bytes {
+                    text.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+                    text.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+                }
+            }
+            Format::Base64 => {
+                for chunk in bytes.chunks(3) {
+                    let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+                        group | (*byte as u32) << (16 - i * 8)
+                    });
+
+                    for i in 0..4 {
+                        if i <= chunk.len() {
+                            let index = (group >> (18 - i * 6)) & 0x3f;
+                            text.push(BASE64_CHARS[index as usize] as char);
+                        } else {
+                            text.push('=');
+                        }
+                    }
+                }
+            }
+            // as PostgreSQL: `\` is doubled, zero and high-bit bytes become `\nnn` in octal
+            Format::Escape => {
+                for byte in bytes {
+                    match byte {
+                        b'\\' => text.push_str("\\\\"),
+                        0 | 0x80..=0xff => text.push_str(&format!("\\{:03o}", byte)),
+                        _ => text.push(*byte as char),
+                    }
+                }
+            }
+        }
+        text
+    }
+
+    fn decode(&self, text: &str) -> Result<Vec<u8>, DatabaseError> {
+        let malformed =
+            || DatabaseError::InvalidValue(format!("decode invalid {:?} input: {}", self, text));
+
+        match self {
+            Format::Hex => {
+                let digits = text
+                    .bytes()
+                    .filter(|byte| !byte.is_ascii_whitespace())
+                    .map(|byte| (byte as char).to_digit(16).ok_or_else(malformed))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if digits.len() % 2 != 0 {
+                    return Err(malformed());
+                }
+
+                Ok(digits
+                    .chunks(2)
+                    .map(|pair| (pair[0] << 4 | pair[1]) as u8)
+                    .collect())
+            }
+            Format::Base64 => {
+                let chars = text
+                    .bytes()
+                    .filter(|byte| !byte.is_ascii_whitespace())
+                    .collect::<Vec<_>>();
+                if chars.len() % 4 != 0 {
+                    return Err(malformed());
+                }
+                let mut bytes = Vec::with_capacity(chars.len() / 4 * 3);
+
+                for (i, quad) in chars.chunks(4).enumerate() {
+                    let is_last = i == chars.len() / 4 - 1;
+                    let padding = quad.iter().rev().take_while(|char| **char == b'=').count();
+                    if padding > 2 || (padding > 0 && !is_last) {
+                        return Err(malformed());
+                    }
+                    let mut group = 0u32;
+
+                    for char in &quad[..4 - padding] {
+                        let index = BASE64_CHARS
+                            .iter()
+                            .position(|base64_char| base64_char == char)
+                            .ok_or_else(malformed)?;
+                        group = group << 6 | index as u32;
+                    }
+                    group <<= padding * 6;
+                    bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+                }
+                Ok(bytes)
+            }
+            Format::Escape => {
+                let chars = text.as_bytes();
+                let mut bytes = Vec::with_capacity(chars.len());
+                let mut i = 0;
+
+                while i < chars.len() {
+                    if chars[i] != b'\\' {
+                        bytes.push(chars[i]);
+                        i += 1;
+                    } else if chars.get(i + 1) == Some(&b'\\') {
+                        bytes.push(b'\\');
+                        i += 2;
+                    } else {
+                        let octal = chars
+                            .get(i + 1..i + 4)
+                            .and_then(|octal| std::str::from_utf8(octal).ok())
+                            .and_then(|octal| u8::from_str_radix(octal, 8).ok())
+                            .ok_or_else(malformed)?;
+                        bytes.push(octal);
+                        i += 4;
+                    }
+                }
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+fn format_arg(
+    function_name: &str,
+    exprs: &[ScalarExpression],
+    tuples: Option<(&Tuple, &[ColumnRef])>,
+) -> Result<Option<Format>, DatabaseError> {
+    let format = exprs[1].eval(tuples)?;
+
+    format
+        .utf8()
+        .map(|format| Format::parse(function_name, format))
+        .transpose()
+}
+
+/// `encode(bytea, format)` renders the bytes as text in the `hex`, `base64` or `escape` format
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Encode {
+    summary: FunctionSummary,
+}
+
+impl Encode {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "encode".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Bytea,
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Encode {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let Some(format) = format_arg(&self.summary.name, exprs, tuples)? else {
+            return Ok(DataValue::Null);
+        };
+        let value = exprs[0].eval(tuples)?;
+        let Some(bytes) = value.bytea() else {
+            return Ok(DataValue::Null);
+        };
+
+        Ok(DataValue::Utf8 {
+            value: format.encode(bytes),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.summary.arg_types[1]
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
+
+/// `decode(text, format)` parses text in the `hex`, `base64` or `escape` format back into bytes
+///
+/// Tips: whitespace is ignored in `hex` and `base64`, any other malformed input is an error
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Decode {
+    summary: FunctionSummary,
+}
+
+impl Decode {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "decode".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Decode {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let Some(format) = format_arg(&self.summary.name, exprs, tuples)? else {
+            return Ok(DataValue::Null);
+        };
+        let value = exprs[0].eval(tuples)?;
+        let Some(text) = value.utf8() else {
+            return Ok(DataValue::Null);
+        };
+
+        Ok(DataValue::Bytea(format.decode(text)?))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Bytea
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
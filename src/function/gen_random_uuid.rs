@@ -0,0 +1,62 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `gen_random_uuid()` returns a random (version 4) UUID, a new one on every call.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct GenRandomUuid {
+    summary: FunctionSummary,
+}
+
+impl GenRandomUuid {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "gen_random_uuid".to_lowercase();
+
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: Vec::new(),
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for GenRandomUuid {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        _: &[ScalarExpression],
+        _: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        // the version nibble is `4` and the variant bits are `10`, see RFC 9562
+        let bits = rand::random::<u128>() & !(0xF << 76) & !(0x3 << 62);
+
+        Ok(DataValue::Uuid(bits | (0x4 << 76) | (0x2 << 62)))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Uuid
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,124 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::setting::SettingsRef;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// `greatest(a, b, ..)` / `least(a, b, ..)` return the largest / smallest of their arguments,
+/// which are cast to a common type.
+///
+/// Tips: any NULL argument makes the result NULL, unless the setting
+/// `greatest_least_ignore_nulls` is true when the function is bound and then only all NULL
+/// arguments do
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct GreatestLeast {
+    summary: FunctionSummary,
+    is_greatest: bool,
+    return_ty: LogicalType,
+    ignore_nulls: bool,
+}
+
+impl GreatestLeast {
+    pub(crate) fn new(is_greatest: bool) -> Arc<Self> {
+        Self::with_arg_types(false, is_greatest, vec![], LogicalType::SqlNull)
+    }
+
+    fn with_arg_types(
+        ignore_nulls: bool,
+        is_greatest: bool,
+        arg_types: Vec<LogicalType>,
+        return_ty: LogicalType,
+    ) -> Arc<Self> {
+        let function_name = if is_greatest { "greatest" } else { "least" }.to_string();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            is_greatest,
+            return_ty,
+            ignore_nulls,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for GreatestLeast {
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let expected = if self.is_greatest {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+        let mut result = DataValue::Null;
+
+        for expr in exprs {
+            let value = expr.eval(tuples)?.cast(&self.return_ty)?;
+            if value.is_null() {
+                if self.ignore_nulls {
+                    continue;
+                }
+                return Ok(DataValue::Null);
+            }
+            if result.is_null() || value.partial_cmp(&result) == Some(expected) {
+                result = value;
+            }
+        }
+        Ok(result)
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn with_settings(&self, settings: &SettingsRef) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Self::with_arg_types(
+            settings.snapshot().greatest_least_ignore_nulls(),
+            self.is_greatest,
+            self.summary.arg_types.clone(),
+            self.return_ty.clone(),
+        ))
+    }
+
+    fn variadic(
+        &self,
+        arg_types: &[LogicalType],
+    ) -> Result<Option<Arc<dyn ScalarFunctionImpl>>, DatabaseError> {
+        if arg_types.is_empty() {
+            return Ok(None);
+        }
+        let return_ty = arg_types
+            .iter()
+            .try_fold(LogicalType::SqlNull, |ty, arg_ty| {
+                LogicalType::max_logical_type(&ty, arg_ty)
+            })?;
+
+        Ok(Some(Self::with_arg_types(
+            self.ignore_nulls,
+            self.is_greatest,
+            vec![return_ty.clone(); arg_types.len()],
+            return_ty,
+        )))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.return_ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
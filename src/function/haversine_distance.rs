@@ -0,0 +1,106 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// mean radius of the earth in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// `haversine_distance(lat1, lon1, lat2, lon2)` returns the great-circle distance in kilometers
+/// between two points given in degrees, treating the earth as a sphere.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct HaversineDistance {
+    summary: FunctionSummary,
+}
+
+impl HaversineDistance {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "haversine_distance".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Double,
+            LogicalType::Double,
+            LogicalType::Double,
+            LogicalType::Double,
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+
+    fn check_coordinate(name: &str, value: f64, limit: f64) -> Result<(), DatabaseError> {
+        if !(-limit..=limit).contains(&value) {
+            return Err(DatabaseError::InvalidValue(format!(
+                "haversine_distance {} must be between -{} and {}: {}",
+                name, limit, limit, value
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for HaversineDistance {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            let Some(value) = value.cast(ty)?.double() else {
+                return Ok(DataValue::Null);
+            };
+            values.push(value);
+        }
+        let [lat1, lon1, lat2, lon2] = values[..] else {
+            return Ok(DataValue::Null);
+        };
+        for (name, value, limit) in [
+            ("latitude", lat1, 90.0),
+            ("longitude", lon1, 180.0),
+            ("latitude", lat2, 90.0),
+            ("longitude", lon2, 180.0),
+        ] {
+            Self::check_coordinate(name, value, limit)?;
+        }
+        let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+        let half_chord = ((lat2 - lat1) / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * ((lon2 - lon1).to_radians() / 2.0).sin().powi(2);
+        // rounding can push the antipodal case just above 1
+        let central_angle = 2.0 * half_chord.sqrt().min(1.0).asin();
+
+        Ok(DataValue::Float64(OrderedFloat(
+            EARTH_RADIUS_KM * central_angle,
+        )))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Double
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
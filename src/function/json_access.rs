@@ -0,0 +1,98 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `json -> key` returns the field `key` of a JSON object, or the element at the 0-based `key`
+/// of a JSON array, as JSON. `json ->> key` returns it as text, where strings are unquoted and
+/// a JSON `null` is NULL. A missing field or element is NULL.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct JsonAccess {
+    summary: FunctionSummary,
+    is_text: bool,
+}
+
+impl JsonAccess {
+    pub(crate) fn new(arg_types: Vec<LogicalType>, is_text: bool) -> Arc<Self> {
+        let function_name = if is_text { "->>" } else { "->" }.to_string();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            is_text,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for JsonAccess {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let json = exprs[0].eval(tuples)?.cast(&LogicalType::Json)?;
+        let key = exprs[1].eval(tuples)?;
+        let (Some(json), false) = (json.json(), key.is_null()) else {
+            return Ok(DataValue::Null);
+        };
+        let json: Value = serde_json::from_str(json)
+            .map_err(|err| DatabaseError::InvalidValue(format!("invalid json: {}", err)))?;
+
+        let value = if key.logical_type().is_numeric() {
+            let Some(index) = key.cast(&LogicalType::Bigint)?.i64() else {
+                return Ok(DataValue::Null);
+            };
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| json.get(index))
+        } else {
+            let key = key.cast(&LogicalType::Varchar(None, CharLengthUnits::Characters))?;
+            let Some(key) = key.utf8() else {
+                return Ok(DataValue::Null);
+            };
+            json.get(key)
+        };
+        Ok(match value {
+            None => DataValue::Null,
+            Some(value) if !self.is_text => DataValue::Json(value.to_string()),
+            Some(Value::Null) => DataValue::Null,
+            Some(value) => DataValue::Utf8 {
+                value: match value {
+                    Value::String(string) => string.clone(),
+                    value => value.to_string(),
+                },
+                ty: Utf8Type::Variable(None),
+                unit: CharLengthUnits::Characters,
+            },
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        if self.is_text {
+            &LogicalType::Varchar(None, CharLengthUnits::Characters)
+        } else {
+            &LogicalType::Json
+        }
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,146 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `json_extract(json, path)` returns the value at `path` of the JSON document `json`, where
+/// `path` is like `$.a.b[0]` or `$["a"]`. Strings are returned unquoted and the other values as
+/// JSON text, a missing value or a JSON `null` is NULL.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct JsonExtract {
+    summary: FunctionSummary,
+}
+
+enum PathElement {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonExtract {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "json_extract".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+
+    fn parse_path(path: &str) -> Result<Vec<PathElement>, DatabaseError> {
+        let fn_invalid = || DatabaseError::InvalidValue(format!("invalid json path: {}", path));
+        let mut chars = path.trim().chars().peekable();
+        let mut elements = Vec::new();
+
+        if chars.next() != Some('$') {
+            return Err(fn_invalid());
+        }
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    let mut key = String::new();
+                    while let Some(c) = chars.next_if(|c| *c != '.' && *c != '[') {
+                        key.push(c);
+                    }
+                    if key.is_empty() {
+                        return Err(fn_invalid());
+                    }
+                    elements.push(PathElement::Key(key));
+                }
+                '[' => {
+                    let is_key = chars.next_if_eq(&'"').is_some();
+                    let terminator = if is_key { '"' } else { ']' };
+                    let mut inner = String::new();
+                    while let Some(c) = chars.next_if(|c| *c != terminator) {
+                        inner.push(c);
+                    }
+                    if chars.next() != Some(terminator) || is_key && chars.next() != Some(']') {
+                        return Err(fn_invalid());
+                    }
+                    elements.push(if is_key {
+                        PathElement::Key(inner)
+                    } else {
+                        PathElement::Index(inner.trim().parse().map_err(|_| fn_invalid())?)
+                    });
+                }
+                _ => return Err(fn_invalid()),
+            }
+        }
+        Ok(elements)
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for JsonExtract {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(json), Some(path)) = (values[0].utf8(), values[1].utf8()) else {
+            return Ok(DataValue::Null);
+        };
+        let path = Self::parse_path(path)?;
+        let json: Value = serde_json::from_str(json)
+            .map_err(|err| DatabaseError::InvalidValue(format!("invalid json: {}", err)))?;
+
+        let mut value = &json;
+        for element in path {
+            let next = match element {
+                PathElement::Key(key) => value.get(key),
+                PathElement::Index(i) => value.get(i),
+            };
+            let Some(next) = next else {
+                return Ok(DataValue::Null);
+            };
+            value = next;
+        }
+        let value = match value {
+            Value::Null => return Ok(DataValue::Null),
+            Value::String(string) => string.clone(),
+            value => value.to_string(),
+        };
+
+        Ok(DataValue::Utf8 {
+            value,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
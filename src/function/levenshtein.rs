@@ -0,0 +1,135 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::mem;
+use std::sync::Arc;
+
+/// `levenshtein(a, b [, max_distance])` returns the number of single character insertions,
+/// deletions and substitutions that turn `a` into `b`.
+///
+/// Tips: with `max_distance` the computation stops as soon as the distance must exceed it and
+/// returns `max_distance + 1`, a negative `max_distance` is treated as 0
+///
+/// Tips: like PostgreSQL, an argument longer than 255 characters is an error
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Levenshtein {
+    summary: FunctionSummary,
+}
+
+impl Levenshtein {
+    pub(crate) fn new(with_max_distance: bool) -> Arc<Self> {
+        let function_name = "levenshtein".to_lowercase();
+        let mut arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        if with_max_distance {
+            arg_types.push(LogicalType::Integer);
+        }
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+/// the maximum number of characters of an argument, which bounds the time of the computation
+const MAX_LENGTH: usize = 255;
+
+/// Only two rows of the DP matrix are kept, each as long as the shorter string,
+/// and neither string is copied.
+fn distance(a: &str, b: &str, max_distance: Option<usize>) -> usize {
+    let (mut long, mut short) = ((a, a.chars().count()), (b, b.chars().count()));
+    if long.1 < short.1 {
+        mem::swap(&mut long, &mut short);
+    }
+    let ((long, long_len), (short, short_len)) = (long, short);
+    let exceeded = max_distance.map(|max| max + 1);
+    if let Some(max) = max_distance {
+        if long_len - short_len > max {
+            return max + 1;
+        }
+    }
+
+    let mut prev = (0..=short_len).collect::<Vec<_>>();
+    let mut cur = vec![0; short_len + 1];
+
+    for (i, long_char) in long.chars().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+
+        for (j, short_char) in short.chars().enumerate() {
+            let substitution = prev[j] + usize::from(long_char != short_char);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+            row_min = row_min.min(cur[j + 1]);
+        }
+        // the distance never decreases from one row to the next
+        if let Some(exceeded) = exceeded.filter(|exceeded| row_min >= *exceeded) {
+            return exceeded;
+        }
+        mem::swap(&mut prev, &mut cur);
+    }
+    let distance = prev[short_len];
+
+    exceeded.map_or(distance, |exceeded| distance.min(exceeded))
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Levenshtein {
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(a), Some(b)) = (values[0].utf8(), values[1].utf8()) else {
+            return Ok(DataValue::Null);
+        };
+        if [a, b]
+            .iter()
+            .any(|value| value.chars().nth(MAX_LENGTH).is_some())
+        {
+            return Err(DatabaseError::InvalidValue(format!(
+                "levenshtein argument exceeds the maximum length of {} characters",
+                MAX_LENGTH
+            )));
+        }
+        let max_distance = values
+            .get(2)
+            .and_then(DataValue::i32)
+            .map(|max| max.max(0) as usize);
+
+        Ok(DataValue::Int32(distance(a, b, max_distance) as i32))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Integer
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,330 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ordered_float::OrderedFloat;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum MathKind {
+    Abs,
+    Ceil,
+    Floor,
+    Round,
+    Power,
+    Sqrt,
+    Exp,
+    Ln,
+    Log,
+}
+
+impl MathKind {
+    fn name(&self) -> &'static str {
+        match self {
+            MathKind::Abs => "abs",
+            MathKind::Ceil => "ceil",
+            MathKind::Floor => "floor",
+            MathKind::Round => "round",
+            MathKind::Power => "power",
+            MathKind::Sqrt => "sqrt",
+            MathKind::Exp => "exp",
+            MathKind::Ln => "ln",
+            MathKind::Log => "log",
+        }
+    }
+
+    fn accepts(&self, args_len: usize) -> bool {
+        match self {
+            MathKind::Round | MathKind::Log => matches!(args_len, 1 | 2),
+            MathKind::Power => args_len == 2,
+            _ => args_len == 1,
+        }
+    }
+
+    /// whether the result has the type of the (first) argument rather than double
+    fn keeps_type(&self) -> bool {
+        matches!(
+            self,
+            MathKind::Abs | MathKind::Ceil | MathKind::Floor | MathKind::Round
+        )
+    }
+}
+
+/// The math functions of numbers:
+/// - `abs(x)`, `ceil(x)`, `floor(x)` and `round(x [, digits])` return the type of `x`,
+///   a negative `digits` rounds to the left of the decimal point (e.g. `round(1250, -2)` is `1300`)
+///   and halves are rounded away from zero
+/// - `power(x, y)`, `sqrt(x)`, `exp(x)`, `ln(x)`, `log(x)` (base 10) and `log(b, x)` return double
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Math {
+    summary: FunctionSummary,
+    kind: MathKind,
+    return_ty: LogicalType,
+}
+
+impl Math {
+    pub(crate) fn new(kind: MathKind) -> Arc<Self> {
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: kind.name().to_string(),
+                arg_types: vec![],
+            },
+            kind,
+            return_ty: LogicalType::SqlNull,
+        })
+    }
+
+    fn double(value: &DataValue) -> Result<f64, DatabaseError> {
+        value.double().ok_or(DatabaseError::InvalidType)
+    }
+
+    fn abs(value: DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match value {
+            DataValue::Int8(v) => {
+                DataValue::Int8(v.checked_abs().ok_or(DatabaseError::NumericOverflow)?)
+            }
+            DataValue::Int16(v) => {
+                DataValue::Int16(v.checked_abs().ok_or(DatabaseError::NumericOverflow)?)
+            }
+            DataValue::Int32(v) => {
+                DataValue::Int32(v.checked_abs().ok_or(DatabaseError::NumericOverflow)?)
+            }
+            DataValue::Int64(v) => {
+                DataValue::Int64(v.checked_abs().ok_or(DatabaseError::NumericOverflow)?)
+            }
+            DataValue::Float32(v) => DataValue::Float32(OrderedFloat(v.abs())),
+            DataValue::Float64(v) => DataValue::Float64(OrderedFloat(v.abs())),
+            DataValue::Decimal(v) => DataValue::Decimal(v.abs()),
+            // unsigned
+            value => value,
+        })
+    }
+
+    fn ceil_or_floor(value: DataValue, is_ceil: bool) -> DataValue {
+        match value {
+            DataValue::Float32(v) => {
+                DataValue::Float32(OrderedFloat(if is_ceil { v.ceil() } else { v.floor() }))
+            }
+            DataValue::Float64(v) => {
+                DataValue::Float64(OrderedFloat(if is_ceil { v.ceil() } else { v.floor() }))
+            }
+            DataValue::Decimal(v) => DataValue::Decimal(if is_ceil { v.ceil() } else { v.floor() }),
+            // integers
+            value => value,
+        }
+    }
+
+    fn round(value: DataValue, digits: i32) -> Result<DataValue, DatabaseError> {
+        // rounds an integer to the multiple of `10^-digits`
+        macro_rules! round_integer {
+            ($compute_type:path, $v:expr) => {{
+                if digits >= 0 {
+                    return Ok($compute_type($v));
+                }
+                let Some(unit) = 10i128.checked_pow(digits.unsigned_abs()) else {
+                    return Ok($compute_type(0));
+                };
+                let v = $v as i128;
+                let rounded = (v.abs() + unit / 2) / unit * unit * v.signum();
+
+                $compute_type(
+                    rounded
+                        .try_into()
+                        .map_err(|_| DatabaseError::NumericOverflow)?,
+                )
+            }};
+        }
+        let round_f64 = |v: f64| {
+            let unit = 10f64.powi(digits.abs().min(f64::MAX_10_EXP));
+            let rounded = if digits >= 0 {
+                (v * unit).round() / unit
+            } else {
+                (v / unit).round() * unit
+            };
+            // `v * 10^digits` overflows when `v` has fewer digits than asked for
+            if rounded.is_finite() {
+                rounded
+            } else {
+                v
+            }
+        };
+
+        Ok(match value {
+            DataValue::Int8(v) => round_integer!(DataValue::Int8, v),
+            DataValue::Int16(v) => round_integer!(DataValue::Int16, v),
+            DataValue::Int32(v) => round_integer!(DataValue::Int32, v),
+            DataValue::Int64(v) => round_integer!(DataValue::Int64, v),
+            DataValue::UInt8(v) => round_integer!(DataValue::UInt8, v),
+            DataValue::UInt16(v) => round_integer!(DataValue::UInt16, v),
+            DataValue::UInt32(v) => round_integer!(DataValue::UInt32, v),
+            DataValue::UInt64(v) => round_integer!(DataValue::UInt64, v),
+            DataValue::Float32(v) => DataValue::Float32(OrderedFloat(round_f64(v.0 as f64) as f32)),
+            DataValue::Float64(v) => DataValue::Float64(OrderedFloat(round_f64(v.0))),
+            DataValue::Decimal(v) => {
+                if digits >= 0 {
+                    DataValue::Decimal(v.round_dp_with_strategy(
+                        digits.unsigned_abs(),
+                        RoundingStrategy::MidpointAwayFromZero,
+                    ))
+                } else {
+                    let Some(unit) = 10i64.checked_pow(digits.unsigned_abs()).map(Decimal::from)
+                    else {
+                        return Ok(DataValue::Decimal(Decimal::ZERO));
+                    };
+                    let rounded = v
+                        .checked_div(unit)
+                        .ok_or(DatabaseError::NumericOverflow)?
+                        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero);
+
+                    DataValue::Decimal(
+                        rounded
+                            .checked_mul(unit)
+                            .ok_or(DatabaseError::NumericOverflow)?,
+                    )
+                }
+            }
+            value => value,
+        })
+    }
+
+    fn log(base: f64, value: f64) -> Result<f64, DatabaseError> {
+        if base <= 0.0 || value <= 0.0 {
+            return Err(DatabaseError::InvalidValue(format!(
+                "cannot take logarithm of a non-positive number: log({}, {})",
+                base, value
+            )));
+        }
+        if base == 1.0 {
+            return Err(DatabaseError::DivisionByZero);
+        }
+        // the dedicated functions are exact for the powers of their bases
+        Ok(if base == std::f64::consts::E {
+            value.ln()
+        } else if base == 10.0 {
+            value.log10()
+        } else if base == 2.0 {
+            value.log2()
+        } else {
+            value.log(base)
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Math {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let value = values.remove(0);
+        let result = match self.kind {
+            MathKind::Abs => return Self::abs(value),
+            MathKind::Ceil => return Ok(Self::ceil_or_floor(value, true)),
+            MathKind::Floor => return Ok(Self::ceil_or_floor(value, false)),
+            MathKind::Round => {
+                let digits = values.first().and_then(DataValue::i32).unwrap_or(0);
+
+                return Self::round(value, digits);
+            }
+            MathKind::Power => {
+                let (x, y) = (Self::double(&value)?, Self::double(&values[0])?);
+                if x == 0.0 && y < 0.0 {
+                    return Err(DatabaseError::DivisionByZero);
+                }
+                if x < 0.0 && y.fract() != 0.0 {
+                    return Err(DatabaseError::InvalidValue(format!(
+                        "a negative number raised to a non-integer power: power({}, {})",
+                        x, y
+                    )));
+                }
+                x.powf(y)
+            }
+            MathKind::Sqrt => {
+                let x = Self::double(&value)?;
+                if x < 0.0 {
+                    return Err(DatabaseError::InvalidValue(format!(
+                        "cannot take square root of a negative number: sqrt({})",
+                        x
+                    )));
+                }
+                x.sqrt()
+            }
+            MathKind::Exp => Self::double(&value)?.exp(),
+            MathKind::Ln => Self::log(std::f64::consts::E, Self::double(&value)?)?,
+            MathKind::Log => match values.first() {
+                Some(x) => Self::log(Self::double(&value)?, Self::double(x)?)?,
+                None => Self::log(10.0, Self::double(&value)?)?,
+            },
+        };
+        if !result.is_finite() {
+            return Err(DatabaseError::NumericOverflow);
+        }
+        Ok(DataValue::Float64(OrderedFloat(result)))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn variadic(
+        &self,
+        arg_types: &[LogicalType],
+    ) -> Result<Option<Arc<dyn ScalarFunctionImpl>>, DatabaseError> {
+        if !self.kind.accepts(arg_types.len())
+            || arg_types
+                .iter()
+                .any(|ty| !ty.is_numeric() && !matches!(ty, LogicalType::SqlNull))
+        {
+            return Ok(None);
+        }
+        let (arg_types, return_ty) = if self.kind.keeps_type() {
+            let ty = arg_types[0].clone();
+            let mut types = vec![ty.clone()];
+            // the digits of `round`
+            types.extend(arg_types[1..].iter().map(|_| LogicalType::Integer));
+
+            (types, ty)
+        } else {
+            (
+                vec![LogicalType::Double; arg_types.len()],
+                LogicalType::Double,
+            )
+        };
+
+        Ok(Some(Arc::new(Self {
+            summary: FunctionSummary {
+                name: self.summary.name.clone(),
+                arg_types,
+            },
+            kind: self.kind,
+            return_ty,
+        })))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.return_ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
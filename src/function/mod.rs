@@ -1,5 +1,35 @@
+pub(crate) mod array;
+pub(crate) mod bit_count;
 pub(crate) mod char_length;
 pub(crate) mod current_date;
+pub(crate) mod current_setting;
+pub(crate) mod current_time;
+pub(crate) mod current_timestamp;
+pub(crate) mod current_user;
+pub(crate) mod date_name;
+pub(crate) mod date_round;
+pub(crate) mod date_trunc;
+pub(crate) mod datediff;
+pub(crate) mod element_at;
+pub(crate) mod encode;
+pub(crate) mod gen_random_uuid;
+pub(crate) mod greatest_least;
+pub(crate) mod haversine_distance;
+pub(crate) mod json_access;
+pub(crate) mod json_extract;
+pub(crate) mod levenshtein;
 pub(crate) mod lower;
+pub(crate) mod math;
+pub(crate) mod number_format;
 pub(crate) mod numbers;
+pub(crate) mod pad;
+pub(crate) mod replace;
+pub(crate) mod safe_cast;
+pub(crate) mod set_config;
+pub(crate) mod soundex;
+pub(crate) mod substring;
+pub(crate) mod try_divide;
 pub(crate) mod upper;
+pub(crate) mod uuid_v7;
+pub(crate) mod vector_distance;
+pub(crate) mod width_bucket;
@@ -0,0 +1,351 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::str::FromStr;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntegerToken {
+    /// `9`, a digit whose leading zero is dropped
+    Digit,
+    /// `0`, a digit whose leading zero is kept
+    Zero,
+    /// `,` or `G`, only output after the first digit
+    Group,
+}
+
+/// The format mask of `to_char` and `to_number`, made of:
+/// - `9`: a digit, leading zeros are dropped except for the units digit
+/// - `0`: a digit, leading zeros are kept
+/// - `,` or `G`: a grouping separator before the decimal point
+/// - `.` or `D`: the decimal point
+/// - `S`: a `+`/`-` sign, only as the first or the last character
+///
+/// Tips: without `S`, only negative numbers carry a leading `-`
+#[derive(Debug)]
+struct NumberFormat {
+    integer: Vec<IntegerToken>,
+    fraction_digits: usize,
+    has_point: bool,
+    leading_sign: bool,
+    trailing_sign: bool,
+}
+
+impl NumberFormat {
+    fn parse(function_name: &str, mask: &str) -> Result<Self, DatabaseError> {
+        let unsupported = || {
+            DatabaseError::InvalidValue(format!("{} format not supported: {}", function_name, mask))
+        };
+        let chars = mask.chars().collect::<Vec<_>>();
+        let mut format = NumberFormat {
+            integer: Vec::new(),
+            fraction_digits: 0,
+            has_point: false,
+            leading_sign: false,
+            trailing_sign: false,
+        };
+
+        for (i, char) in chars.iter().enumerate() {
+            match char.to_ascii_uppercase() {
+                '9' | '0' if format.has_point => format.fraction_digits += 1,
+                '9' => format.integer.push(IntegerToken::Digit),
+                '0' => format.integer.push(IntegerToken::Zero),
+                ',' | 'G' if !format.has_point => format.integer.push(IntegerToken::Group),
+                '.' | 'D' if !format.has_point => format.has_point = true,
+                'S' if i == 0 => format.leading_sign = true,
+                'S' if i == chars.len() - 1 && !format.leading_sign => format.trailing_sign = true,
+                _ => return Err(unsupported()),
+            }
+        }
+        if format.integer_digits() + format.fraction_digits == 0 {
+            return Err(unsupported());
+        }
+        Ok(format)
+    }
+
+    fn integer_digits(&self) -> usize {
+        self.integer
+            .iter()
+            .filter(|token| !matches!(token, IntegerToken::Group))
+            .count()
+    }
+
+    fn has_sign(&self) -> bool {
+        self.leading_sign || self.trailing_sign
+    }
+
+    fn to_char(&self, value: Decimal) -> Option<String> {
+        let rounded = value.round_dp_with_strategy(
+            self.fraction_digits as u32,
+            RoundingStrategy::MidpointAwayFromZero,
+        );
+        let is_negative = rounded.is_sign_negative() && !rounded.is_zero();
+        let digits = rounded.abs().to_string();
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let integer = match integer.trim_start_matches('0') {
+            "" if self.integer_digits() > 0 => "0",
+            integer => integer,
+        };
+        let integer_digits = self.integer_digits();
+        if integer.len() > integer_digits {
+            return None;
+        }
+        let mut padded = "0".repeat(integer_digits - integer.len());
+        padded.push_str(integer);
+
+        let sign = match (is_negative, self.has_sign()) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+        let mut text = String::with_capacity(self.integer.len() + self.fraction_digits + 2);
+        if !self.trailing_sign {
+            text.push_str(sign);
+        }
+        let mut digits = padded.chars();
+        let mut is_started = false;
+
+        for token in self.integer.iter() {
+            match token {
+                IntegerToken::Group => {
+                    if is_started {
+                        text.push(',');
+                    }
+                }
+                IntegerToken::Digit | IntegerToken::Zero => {
+                    let digit = digits.next()?;
+                    is_started |= digit != '0'
+                        || matches!(token, IntegerToken::Zero)
+                        || digits.as_str().is_empty();
+                    if is_started {
+                        text.push(digit);
+                    }
+                }
+            }
+        }
+        if self.has_point {
+            text.push('.');
+            text.push_str(fraction);
+            text.push_str(&"0".repeat(self.fraction_digits - fraction.len()));
+        }
+        if self.trailing_sign {
+            text.push_str(sign);
+        }
+        Some(text)
+    }
+
+    fn to_number(&self, text: &str) -> Option<Decimal> {
+        let mut text = text.trim();
+        let mut is_negative = false;
+
+        if self.leading_sign || !self.has_sign() {
+            match text.chars().next() {
+                Some(sign @ ('-' | '+')) => {
+                    is_negative = sign == '-';
+                    text = &text[1..];
+                }
+                _ if self.leading_sign => return None,
+                _ => (),
+            }
+        }
+        if self.trailing_sign {
+            match text.chars().last() {
+                Some(sign @ ('-' | '+')) => {
+                    is_negative = sign == '-';
+                    text = &text[..text.len() - 1];
+                }
+                _ => return None,
+            }
+        }
+        let (integer, fraction) = match text.split_once('.') {
+            Some(_) if !self.has_point => return None,
+            Some((integer, fraction)) => (integer, fraction),
+            None => (text, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if fraction.len() > self.fraction_digits || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        // the integer part is right-aligned with the mask, so that every `,` must meet a group
+        let mut tokens = self.integer.iter().rev();
+        let mut digits = String::with_capacity(integer.len());
+
+        for char in integer.chars().rev() {
+            match char {
+                ',' => {
+                    if tokens.next() != Some(&IntegerToken::Group) {
+                        return None;
+                    }
+                }
+                '0'..='9' => {
+                    tokens.find(|token| !matches!(token, IntegerToken::Group))?;
+                    digits.push(char);
+                }
+                _ => return None,
+            }
+        }
+        let digits = digits.chars().rev().collect::<String>();
+        let integer = if digits.is_empty() { "0" } else { &digits };
+        let mut number = if fraction.is_empty() {
+            Decimal::from_str(integer)
+        } else {
+            Decimal::from_str(&format!("{}.{}", integer, fraction))
+        }
+        .ok()?;
+        number.set_sign_negative(is_negative);
+
+        Some(number)
+    }
+}
+
+fn format_arg(
+    function_name: &str,
+    exprs: &[ScalarExpression],
+    tuples: Option<(&Tuple, &[ColumnRef])>,
+) -> Result<Option<(String, NumberFormat)>, DatabaseError> {
+    let mask = exprs[1].eval(tuples)?;
+
+    mask.utf8()
+        .map(|mask| Ok((mask.to_string(), NumberFormat::parse(function_name, mask)?)))
+        .transpose()
+}
+
+/// `to_char(number, format)` renders the number as text with the format mask of [`NumberFormat`]
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ToChar {
+    summary: FunctionSummary,
+}
+
+impl ToChar {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "to_char".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Decimal(None, None),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for ToChar {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let Some((mask, format)) = format_arg(&self.summary.name, exprs, tuples)? else {
+            return Ok(DataValue::Null);
+        };
+        let value = exprs[0].eval(tuples)?;
+        if value.is_null() {
+            return Ok(DataValue::Null);
+        }
+        let DataValue::Decimal(number) = value.cast(&self.summary.arg_types[0])? else {
+            return Ok(DataValue::Null);
+        };
+        let text = format.to_char(number).ok_or_else(|| {
+            DatabaseError::InvalidValue(format!(
+                "to_char value {} does not fit format {}",
+                number, mask
+            ))
+        })?;
+
+        Ok(DataValue::Utf8 {
+            value: text,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.summary.arg_types[1]
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
+
+/// `to_number(text, format)` parses text written with the format mask of [`NumberFormat`]
+/// back into a decimal
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ToNumber {
+    summary: FunctionSummary,
+}
+
+impl ToNumber {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "to_number".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for ToNumber {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let Some((mask, format)) = format_arg(&self.summary.name, exprs, tuples)? else {
+            return Ok(DataValue::Null);
+        };
+        let value = exprs[0].eval(tuples)?;
+        let Some(text) = value.utf8() else {
+            return Ok(DataValue::Null);
+        };
+        let number = format.to_number(text).ok_or_else(|| {
+            DatabaseError::InvalidValue(format!(
+                "to_number value {} does not match format {}",
+                text, mask
+            ))
+        })?;
+
+        Ok(DataValue::Decimal(number))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Decimal(None, None)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
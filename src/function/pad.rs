@@ -0,0 +1,96 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `lpad(str, len, fill)` / `rpad(str, len, fill)` extend `str` to `len` characters by
+/// repeating `fill` on the left / right, `str` is truncated to `len` characters if it is
+/// already longer, and a non-positive `len` returns an empty string.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Pad {
+    summary: FunctionSummary,
+    is_left: bool,
+}
+
+impl Pad {
+    pub(crate) fn new(is_left: bool) -> Arc<Self> {
+        let function_name = if is_left { "lpad" } else { "rpad" }.to_string();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Integer,
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            is_left,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Pad {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(string), Some(len), Some(fill)) =
+            (values[0].utf8(), values[1].i32(), values[2].utf8())
+        else {
+            return Ok(DataValue::Null);
+        };
+        let len = len.max(0) as usize;
+        let mut value = string.chars().take(len).collect::<String>();
+        let pad_len = len - value.chars().count();
+
+        if pad_len > 0 && !fill.is_empty() {
+            let pad = fill.chars().cycle().take(pad_len);
+
+            value = if self.is_left {
+                pad.chain(value.chars()).collect()
+            } else {
+                value.chars().chain(pad).collect()
+            };
+        }
+
+        Ok(DataValue::Utf8 {
+            value,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,85 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `replace(str, from, to)` replaces every occurrence of `from` in `str` with `to`,
+/// an empty `from` leaves `str` unchanged.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Replace {
+    summary: FunctionSummary,
+}
+
+impl Replace {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "replace".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Replace {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(string), Some(from), Some(to)) =
+            (values[0].utf8(), values[1].utf8(), values[2].utf8())
+        else {
+            return Ok(DataValue::Null);
+        };
+        let value = if from.is_empty() {
+            string.to_string()
+        } else {
+            string.replace(from, to)
+        };
+
+        Ok(DataValue::Utf8 {
+            value,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,77 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// `safe_cast(expr)` is the `CAST` of the select list when the setting `lenient_cast` is true,
+/// a value that fails to convert is NULL and counted into `coerced_nulls` of the statement.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SafeCast {
+    summary: FunctionSummary,
+    ty: LogicalType,
+    #[serde(skip)]
+    coerced_nulls: Arc<AtomicUsize>,
+}
+
+impl SafeCast {
+    pub(crate) fn new(
+        arg_type: LogicalType,
+        ty: LogicalType,
+        coerced_nulls: Arc<AtomicUsize>,
+    ) -> Arc<Self> {
+        let function_name = "safe_cast".to_lowercase();
+
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: vec![arg_type],
+            },
+            ty,
+            coerced_nulls,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for SafeCast {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        match exprs[0].eval(tuples)?.cast(&self.ty) {
+            Ok(value) => Ok(value),
+            Err(_) => {
+                self.coerced_nulls.fetch_add(1, Ordering::SeqCst);
+                Ok(DataValue::Null)
+            }
+        }
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    // counts into the statement, so it is never folded while planning
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,98 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::setting::SettingsRef;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+const TEXT: LogicalType = LogicalType::Varchar(None, CharLengthUnits::Characters);
+
+/// `set_config(name, value, is_local)` changes a session setting like `SET name = value`,
+/// and returns the new value as text.
+///
+/// Tips: a setting changed within a transaction is dropped when it ends, `is_local` must be false
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct SetConfig {
+    summary: FunctionSummary,
+    #[serde(skip)]
+    settings: SettingsRef,
+}
+
+impl SetConfig {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "set_config".to_lowercase();
+        let arg_types = vec![TEXT, TEXT, LogicalType::Boolean];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            settings: Default::default(),
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for SetConfig {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let name = match exprs[0].eval(tuples)? {
+            DataValue::Utf8 { value, .. } => value,
+            value => {
+                return Err(DatabaseError::InvalidValue(format!(
+                    "set_config name: {}",
+                    value
+                )))
+            }
+        };
+        let value = exprs[1].eval(tuples)?;
+        if exprs[2].eval(tuples)?.bool().unwrap_or(false) {
+            return Err(DatabaseError::UnsupportedStmt(
+                "set_config with is_local = true".to_string(),
+            ));
+        }
+        self.settings
+            .set(&name, (!value.is_null()).then_some(value))?;
+
+        Ok(self
+            .settings
+            .snapshot()
+            .get(&name)
+            .unwrap_or(DataValue::Null))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn with_settings(&self, settings: &SettingsRef) -> Option<Arc<dyn ScalarFunctionImpl>> {
+        Some(Arc::new(Self {
+            summary: self.summary.clone(),
+            settings: settings.clone(),
+        }))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &TEXT
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,119 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+const SOUNDEX_LEN: usize = 4;
+
+/// `soundex(str)` returns the American Soundex code of `str`: its first letter followed by
+/// three digits for the sounds of the next consonants, padded with `0`.
+///
+/// Tips: characters other than ASCII letters are skipped, so a string without any letter
+/// returns an empty string
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Soundex {
+    summary: FunctionSummary,
+}
+
+impl Soundex {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "soundex".to_lowercase();
+        let arg_types = vec![LogicalType::Varchar(None, CharLengthUnits::Characters)];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+/// `None` for `H` and `W`, which do not separate two consonants of the same code,
+/// and `Some('0')` for the vowels, which do.
+fn code(letter: char) -> Option<char> {
+    match letter {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        'H' | 'W' => None,
+        _ => Some('0'),
+    }
+}
+
+fn soundex(string: &str) -> String {
+    let mut letters = string
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase());
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+    let mut value = String::with_capacity(SOUNDEX_LEN);
+    let mut last_code = code(first);
+    value.push(first);
+
+    for letter in letters {
+        let Some(letter_code) = code(letter) else {
+            continue;
+        };
+        if letter_code != '0' && Some(letter_code) != last_code {
+            value.push(letter_code);
+            if value.len() == SOUNDEX_LEN {
+                break;
+            }
+        }
+        last_code = Some(letter_code);
+    }
+    while value.len() < SOUNDEX_LEN {
+        value.push('0');
+    }
+    value
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Soundex {
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let value = exprs[0].eval(tuples)?;
+        if value.is_null() {
+            return Ok(DataValue::Null);
+        }
+        let value = value.cast(&self.summary.arg_types[0])?;
+        let Some(string) = value.utf8() else {
+            return Ok(DataValue::Null);
+        };
+
+        Ok(DataValue::Utf8 {
+            value: soundex(string),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,82 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::{DataValue, Utf8Type};
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use sqlparser::ast::CharLengthUnits;
+use std::sync::Arc;
+
+/// `substring(str, start, len)` returns the `len` characters of `str` beginning at the 1-based
+/// position `start`, as in Postgres a `start` before the first character still counts
+/// towards `len`.
+///
+/// Tips: `SUBSTRING(..)` is parsed into `ScalarExpression::SubString` whichever syntax it is written in,
+/// so this function is only called as `substr(str, start, len)`
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Substring {
+    summary: FunctionSummary,
+}
+
+impl Substring {
+    pub(crate) fn new(function_name: String) -> Arc<Self> {
+        let arg_types = vec![
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            LogicalType::Integer,
+            LogicalType::Integer,
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for Substring {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(string), Some(start), Some(len)) =
+            (values[0].utf8(), values[1].i32(), values[2].i32())
+        else {
+            return Ok(DataValue::Null);
+        };
+        Ok(DataValue::Utf8 {
+            value: ScalarExpression::eval_substring(string, start, Some(len))?,
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        })
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Varchar(None, CharLengthUnits::Characters)
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
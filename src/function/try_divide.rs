@@ -0,0 +1,114 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::{BinaryOperator, ScalarExpression};
+use crate::types::evaluator::{BinaryEvaluatorBox, EvaluatorFactory};
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `try_divide(a, b)` is `a / b` with the same result type, but returns NULL instead of
+/// infinity or a division error when `b` is zero.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct TryDivide {
+    summary: FunctionSummary,
+    return_ty: LogicalType,
+    evaluator: Option<BinaryEvaluatorBox>,
+}
+
+impl TryDivide {
+    pub(crate) fn new() -> Arc<Self> {
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: "try_divide".to_string(),
+                arg_types: vec![],
+            },
+            return_ty: LogicalType::SqlNull,
+            evaluator: None,
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for TryDivide {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let evaluator = self
+            .evaluator
+            .as_ref()
+            .ok_or(DatabaseError::EvaluatorNotFound)?;
+        let left = exprs[0].eval(tuples)?;
+        let right = exprs[1].eval(tuples)?;
+
+        if left.is_null()
+            || right.is_null()
+            || right == DataValue::Int8(0).cast(&self.summary.arg_types[1])?
+        {
+            return Ok(DataValue::Null);
+        }
+        evaluator.binary_eval(&left, &right)?.cast(&self.return_ty)
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn variadic(
+        &self,
+        arg_types: &[LogicalType],
+    ) -> Result<Option<Arc<dyn ScalarFunctionImpl>>, DatabaseError> {
+        let [left_ty, right_ty] = arg_types else {
+            return Ok(None);
+        };
+        if [left_ty, right_ty]
+            .iter()
+            .any(|ty| !ty.is_numeric() && !matches!(ty, LogicalType::SqlNull))
+        {
+            return Ok(None);
+        }
+        let arg_ty = LogicalType::max_logical_type(left_ty, right_ty)?;
+        if !arg_ty.is_numeric() {
+            return Ok(None);
+        }
+        // the same as the result type of `/`
+        let return_ty =
+            LogicalType::decimal_arithmetic_type(&BinaryOperator::Divide, left_ty, right_ty)
+                .unwrap_or_else(|| {
+                    if matches!(arg_ty, LogicalType::Decimal(_, _)) {
+                        arg_ty.clone()
+                    } else {
+                        LogicalType::Double
+                    }
+                });
+        let evaluator = EvaluatorFactory::binary_create(
+            arg_ty.clone().evaluation_type(),
+            BinaryOperator::Divide,
+        )?;
+
+        Ok(Some(Arc::new(Self {
+            summary: FunctionSummary {
+                name: self.summary.name.clone(),
+                arg_types: vec![arg_ty; 2],
+            },
+            return_ty,
+            evaluator: Some(evaluator),
+        })))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &self.return_ty
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
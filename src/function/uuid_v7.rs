@@ -0,0 +1,90 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use chrono::Utc;
+use parking_lot::{const_mutex, Mutex};
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+const RAND_BITS: u32 = 74;
+
+// the last uuid generated in the process without the version and the variant bits,
+// i.e. the 48-bit unix milliseconds followed by the 74 random bits
+static LAST_UUID: Mutex<u128> = const_mutex(0);
+
+/// `uuid_v7()` returns a time-ordered (version 7) UUID, whose first 48 bits are the unix
+/// milliseconds of the call and the rest random, a new one on every call.
+///
+/// Tips: the UUIDs generated by the process are increasing, those within the same
+/// millisecond count up from the previous one
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct UuidV7 {
+    summary: FunctionSummary,
+}
+
+impl UuidV7 {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "uuid_v7".to_lowercase();
+
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types: Vec::new(),
+            },
+        })
+    }
+
+    /// generates the uuid of `millis` following `last`, the last one generated
+    fn generate(last: &mut u128, millis: u64) -> u128 {
+        let mut value =
+            ((millis as u128) << RAND_BITS) | (rand::random::<u128>() & ((1 << RAND_BITS) - 1));
+        if value <= *last {
+            value = *last + 1;
+        }
+        *last = value;
+
+        // the version nibble `7` follows the timestamp and the variant bits `10` split the
+        // random bits into 12 and 62, see RFC 9562
+        let rand_a = (value >> 62) & 0xFFF;
+        let rand_b = value & ((1 << 62) - 1);
+        ((value >> RAND_BITS) << 80) | (0x7 << 76) | (rand_a << 64) | (0x2 << 62) | rand_b
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for UuidV7 {
+    fn eval(
+        &self,
+        _: &[ScalarExpression],
+        _: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let millis = Utc::now().timestamp_millis().max(0) as u64;
+
+        let mut last = LAST_UUID.lock();
+
+        Ok(DataValue::Uuid(Self::generate(&mut last, millis)))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Uuid
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -0,0 +1,130 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ordered_float::OrderedFloat;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `cosine_similarity(a, b)` / `l2_distance(a, b)` return the cosine of the angle / the
+/// euclidean distance between two numeric lists of the same length, e.g. `array[1, 2, 3]`.
+///
+/// Tips: a NULL list or element makes the result NULL, and so does a list of zeros for
+/// `cosine_similarity`, whose angle is undefined
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct VectorDistance {
+    summary: FunctionSummary,
+    is_cosine: bool,
+}
+
+impl VectorDistance {
+    pub(crate) fn new(is_cosine: bool) -> Arc<Self> {
+        Self::with_arg_types(is_cosine, vec![])
+    }
+
+    fn with_arg_types(is_cosine: bool, arg_types: Vec<LogicalType>) -> Arc<Self> {
+        let function_name = if is_cosine {
+            "cosine_similarity"
+        } else {
+            "l2_distance"
+        }
+        .to_string();
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+            is_cosine,
+        })
+    }
+
+    fn vector(value: DataValue) -> Result<Option<Vec<f64>>, DatabaseError> {
+        let DataValue::Tuple(values, _) = value else {
+            return Ok(None);
+        };
+        let mut vector = Vec::with_capacity(values.len());
+
+        for value in values {
+            let Some(element) = value.cast(&LogicalType::Double)?.double() else {
+                return Ok(None);
+            };
+            vector.push(element);
+        }
+        Ok(Some(vector))
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for VectorDistance {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let (value_a, value_b) = (exprs[0].eval(tuples)?, exprs[1].eval(tuples)?);
+        if let (DataValue::Tuple(a, _), DataValue::Tuple(b, _)) = (&value_a, &value_b) {
+            if a.len() != b.len() {
+                return Err(DatabaseError::ValuesLenMismatch(a.len(), b.len()));
+            }
+        }
+        let (Some(a), Some(b)) = (Self::vector(value_a)?, Self::vector(value_b)?) else {
+            return Ok(DataValue::Null);
+        };
+        let pairs = a.iter().zip(b.iter());
+
+        let result = if self.is_cosine {
+            let (dot, norm_a, norm_b) = pairs
+                .fold((0.0, 0.0, 0.0), |(dot, norm_a, norm_b), (x, y)| {
+                    (dot + x * y, norm_a + x * x, norm_b + y * y)
+                });
+            if norm_a == 0.0 || norm_b == 0.0 {
+                return Ok(DataValue::Null);
+            }
+            // rounding may push the cosine of (anti)parallel vectors slightly out of [-1, 1]
+            (dot / (norm_a.sqrt() * norm_b.sqrt())).clamp(-1.0, 1.0)
+        } else {
+            pairs.map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+        };
+
+        Ok(DataValue::Float64(OrderedFloat(result)))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        None
+    }
+
+    fn variadic(
+        &self,
+        arg_types: &[LogicalType],
+    ) -> Result<Option<Arc<dyn ScalarFunctionImpl>>, DatabaseError> {
+        let is_vector = |ty: &LogicalType| match ty {
+            LogicalType::Tuple(types) => types
+                .iter()
+                .all(|ty| ty.is_numeric() || ty == &LogicalType::SqlNull),
+            ty => ty == &LogicalType::SqlNull,
+        };
+        if arg_types.len() != 2 || !arg_types.iter().all(is_vector) {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::with_arg_types(
+            self.is_cosine,
+            arg_types.to_vec(),
+        )))
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Double
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
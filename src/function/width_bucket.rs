@@ -0,0 +1,102 @@
+use crate::catalog::ColumnRef;
+use crate::errors::DatabaseError;
+use crate::expression::function::scala::FuncMonotonicity;
+use crate::expression::function::scala::ScalarFunctionImpl;
+use crate::expression::function::FunctionSummary;
+use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// `width_bucket(value, low, high, count)` divides `[low, high)` into `count` equal-width buckets
+/// and returns the 1-based bucket of `value`, `0` if it is below `low` and `count + 1`
+/// if it is at or above `high`.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct WidthBucket {
+    summary: FunctionSummary,
+}
+
+impl WidthBucket {
+    pub(crate) fn new() -> Arc<Self> {
+        let function_name = "width_bucket".to_lowercase();
+        let arg_types = vec![
+            LogicalType::Double,
+            LogicalType::Double,
+            LogicalType::Double,
+            LogicalType::Integer,
+        ];
+        Arc::new(Self {
+            summary: FunctionSummary {
+                name: function_name,
+                arg_types,
+            },
+        })
+    }
+}
+
+#[typetag::serde]
+impl ScalarFunctionImpl for WidthBucket {
+    #[allow(unused_variables, clippy::redundant_closure_call)]
+    fn eval(
+        &self,
+        exprs: &[ScalarExpression],
+        tuples: Option<(&Tuple, &[ColumnRef])>,
+    ) -> Result<DataValue, DatabaseError> {
+        let mut values = Vec::with_capacity(exprs.len());
+
+        for (expr, ty) in exprs.iter().zip(self.summary.arg_types.iter()) {
+            let value = expr.eval(tuples)?;
+            if value.is_null() {
+                return Ok(DataValue::Null);
+            }
+            values.push(value.cast(ty)?);
+        }
+        let (Some(value), Some(low), Some(high), Some(count)) = (
+            values[0].double(),
+            values[1].double(),
+            values[2].double(),
+            values[3].i32(),
+        ) else {
+            return Ok(DataValue::Null);
+        };
+        if low >= high {
+            return Err(DatabaseError::InvalidValue(format!(
+                "width_bucket lower bound must be less than upper bound: {} >= {}",
+                low, high
+            )));
+        }
+        if count <= 0 {
+            return Err(DatabaseError::InvalidValue(format!(
+                "width_bucket count must be greater than zero: {}",
+                count
+            )));
+        }
+        let bucket = if value < low {
+            0
+        } else if value >= high {
+            count
+                .checked_add(1)
+                .ok_or_else(|| DatabaseError::InvalidValue("width_bucket overflow".to_string()))?
+        } else {
+            // guard against rounding up into the overflow bucket just below `high`
+            (((value - low) / (high - low) * count as f64) as i32 + 1).min(count)
+        };
+
+        Ok(DataValue::Int32(bucket))
+    }
+
+    fn monotonicity(&self) -> Option<FuncMonotonicity> {
+        Some(vec![Some(true), None, None, None])
+    }
+
+    fn return_type(&self) -> &LogicalType {
+        &LogicalType::Integer
+    }
+
+    fn summary(&self) -> &FunctionSummary {
+        &self.summary
+    }
+}
@@ -109,6 +109,7 @@ mod optimizer;
 pub mod parser;
 pub mod planner;
 pub mod serdes;
+pub mod setting;
 pub mod storage;
 pub mod types;
 pub(crate) mod utils;
@@ -94,14 +94,15 @@ impl HistogramBuilder {
             let mut bytes = BumpBytes::new_in(&self.arena);
 
             value.memcomparable_encode(&mut bytes)?;
-            self.values
-                .as_mut()
-                .unwrap()
-                .put((self.value_index, value.clone()));
+            let values = self.values.as_mut().unwrap();
+            // NULLs are not put into `values`, so the sort key points to the position in it
+            let offset = values.len();
+
+            values.put((self.value_index, value.clone()));
             self.sort_keys
                 .as_mut()
                 .unwrap()
-                .push((self.value_index, unsafe { mem::transmute::<_, _>(bytes) }))
+                .push((offset, unsafe { mem::transmute::<_, _>(bytes) }))
         }
 
         self.value_index += 1;
@@ -109,6 +110,11 @@ impl HistogramBuilder {
         Ok(())
     }
 
+    /// The number of the non-NULL values appended
+    pub fn values_len(&self) -> usize {
+        self.values.as_ref().map_or(0, |values| values.len())
+    }
+
     pub fn build(
         mut self,
         number_of_buckets: usize,
@@ -215,7 +221,7 @@ fn is_under(
                 BinaryOperator::LtEq
             },
         )?;
-        let value = evaluator.0.binary_eval(value, target);
+        let value = evaluator.0.binary_eval(value, target)?;
         Ok::<bool, DatabaseError>(matches!(value, DataValue::Boolean(true)))
     };
 
@@ -240,7 +246,7 @@ fn is_above(
                 BinaryOperator::Gt
             },
         )?;
-        let value = evaluator.0.binary_eval(value, target);
+        let value = evaluator.0.binary_eval(value, target)?;
         Ok::<bool, DatabaseError>(matches!(value, DataValue::Boolean(true)))
     };
     Ok(match target {
@@ -329,7 +335,18 @@ impl Histogram {
                     }
                     _ => unreachable!(),
                 },
-                LogicalType::Date | LogicalType::DateTime | LogicalType::Time => match value {
+                LogicalType::Bytea => value.bytea().map(|bytes| {
+                    // the 8 bytes after the common prefix, right padded with 0
+                    (0..8).fold(0u64, |val, i| {
+                        (val << 8) + bytes.get(prefix_len + i).copied().unwrap_or(0) as u64
+                    }) as f64
+                }),
+                LogicalType::List(_) | LogicalType::Json => None,
+                LogicalType::Uuid => value.uuid().map(|value| value as f64),
+                LogicalType::Date
+                | LogicalType::DateTime
+                | LogicalType::Time
+                | LogicalType::Interval => match value {
                     DataValue::Date32(value) => DataValue::Int32(*value)
                         .cast(&LogicalType::Double)?
                         .double(),
@@ -339,6 +356,9 @@ impl Histogram {
                     DataValue::Time(value) => DataValue::UInt32(*value)
                         .cast(&LogicalType::Double)?
                         .double(),
+                    DataValue::Interval(value) => DataValue::Int64(*value)
+                        .cast(&LogicalType::Double)?
+                        .double(),
                     _ => unreachable!(),
                 },
 
@@ -525,6 +545,8 @@ mod tests {
             value_ty: LogicalType::Integer,
             name: "pk_c1".to_string(),
             ty: IndexType::PrimaryKey { is_multiple: false },
+            expr: None,
+            predicate: None,
         }
     }
 
@@ -0,0 +1,83 @@
+use crate::optimizer::core::cm_sketch::FastHasher;
+use std::hash::{Hash, Hasher};
+
+const PRECISION: u32 = 12;
+const NUM_OF_REGISTERS: usize = 1 << PRECISION;
+
+// https://algo.inria.fr/flajolet/Publications/FlFuGaMe07.pdf
+#[derive(Debug, Clone)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        HyperLogLog {
+            registers: vec![0; NUM_OF_REGISTERS],
+        }
+    }
+}
+
+impl HyperLogLog {
+    pub fn add<K: ?Sized + Hash>(&mut self, key: &K) {
+        let mut hasher = FastHasher::new();
+        key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let index = (hash >> (64 - PRECISION)) as usize;
+        // the position of the first 1 bit after the register bits, the sentinel caps it
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() as u8 + 1;
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn estimate(&self) -> usize {
+        let m = NUM_OF_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let (sum, zeros) = self
+            .registers
+            .iter()
+            .fold((0.0, 0usize), |(sum, zeros), register| {
+                (
+                    sum + 2f64.powi(-(*register as i32)),
+                    zeros + (*register == 0) as usize,
+                )
+            });
+        let estimate = alpha * m * m / sum;
+
+        // small cardinalities are counted more precisely by the empty registers
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::optimizer::core::hyper_log_log::HyperLogLog;
+
+    #[test]
+    fn test_estimate() {
+        for (len, error) in [(0, 0.0), (10, 0.0), (1_000, 0.02), (100_000, 0.05)] {
+            let mut sketch = HyperLogLog::default();
+
+            for i in 0..len {
+                sketch.add(&i);
+                // repeats do not count
+                sketch.add(&i);
+            }
+            let estimate = sketch.estimate() as f64;
+
+            assert!(
+                (estimate - len as f64).abs() <= len as f64 * error,
+                "{}: {}",
+                len,
+                estimate
+            );
+        }
+    }
+}
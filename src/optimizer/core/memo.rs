@@ -204,6 +204,8 @@ mod tests {
                     value_ty: LogicalType::Integer,
                     name: "pk_index".to_string(),
                     ty: IndexType::PrimaryKey { is_multiple: false },
+                    expr: None,
+                    predicate: None,
                 }),
                 range: Some(Range::SortedRanges(vec![
                     Range::Eq(DataValue::Int32(2)),
@@ -212,6 +214,8 @@ mod tests {
                         max: Bound::Unbounded,
                     }
                 ])),
+                covered: false,
+                order: None,
             }))
         );
 
@@ -1,6 +1,8 @@
 pub(crate) mod cm_sketch;
 pub(crate) mod histogram;
+pub(crate) mod hyper_log_log;
 pub(crate) mod memo;
 pub(crate) mod pattern;
 pub(crate) mod rule;
 pub(crate) mod statistics_meta;
+pub(crate) mod table_statistics;
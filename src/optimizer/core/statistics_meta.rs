@@ -3,6 +3,7 @@ use crate::errors::DatabaseError;
 use crate::expression::range_detacher::Range;
 use crate::optimizer::core::cm_sketch::CountMinSketch;
 use crate::optimizer::core::histogram::Histogram;
+use crate::optimizer::core::table_statistics::TableStatistics;
 use crate::serdes::{ReferenceSerialization, ReferenceTables};
 use crate::storage::{StatisticsMetaCache, Transaction};
 use crate::types::index::IndexId;
@@ -42,6 +43,10 @@ impl<'a, T: Transaction> StatisticMetaLoader<'a, T> {
             Ok(None)
         }
     }
+
+    pub fn load_table(&self, table_name: &str) -> Result<Option<TableStatistics>, DatabaseError> {
+        self.tx.table_statistics(table_name)
+    }
 }
 
 #[derive(Debug, ReferenceSerialization)]
@@ -126,6 +131,8 @@ mod tests {
             value_ty: LogicalType::Integer,
             name: "pk_c1".to_string(),
             ty: IndexType::PrimaryKey { is_multiple: false },
+            expr: None,
+            predicate: None,
         };
 
         let mut builder = HistogramBuilder::new(&index, Some(15));
@@ -0,0 +1,163 @@
+use crate::catalog::{ColumnRef, TableName};
+use crate::errors::DatabaseError;
+use crate::optimizer::core::hyper_log_log::HyperLogLog;
+use crate::types::value::DataValue;
+use crate::types::ColumnId;
+use chrono::{DateTime, Utc};
+use kite_sql_serde_macros::ReferenceSerialization;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, ReferenceSerialization)]
+pub struct ColumnStatistics {
+    column_id: ColumnId,
+    null_count: usize,
+    distinct_count: usize,
+    // NULL when the column has no value other than NULL
+    min: DataValue,
+    max: DataValue,
+}
+
+impl ColumnStatistics {
+    pub fn column_id(&self) -> ColumnId {
+        self.column_id
+    }
+
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// estimated by HyperLogLog, NULL is not counted
+    pub fn distinct_count(&self) -> usize {
+        self.distinct_count
+    }
+
+    pub fn min(&self) -> &DataValue {
+        &self.min
+    }
+
+    pub fn max(&self) -> &DataValue {
+        &self.max
+    }
+}
+
+/// Table-wide statistics collected by `ANALYZE`, they are not updated by later writes
+#[derive(Debug, Clone, PartialEq, ReferenceSerialization)]
+pub struct TableStatistics {
+    table_name: TableName,
+    row_count: usize,
+    columns: Vec<ColumnStatistics>,
+    // milliseconds since the Unix epoch
+    analyzed_at: i64,
+}
+
+impl TableStatistics {
+    pub fn table_name(&self) -> &TableName {
+        &self.table_name
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn column(&self, column_id: &ColumnId) -> Option<&ColumnStatistics> {
+        self.columns
+            .iter()
+            .find(|column| &column.column_id == column_id)
+    }
+
+    pub fn columns(&self) -> &[ColumnStatistics] {
+        &self.columns
+    }
+
+    pub fn analyzed_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.analyzed_at).unwrap_or_default()
+    }
+}
+
+struct ColumnStatisticsBuilder {
+    column_id: ColumnId,
+    null_count: usize,
+    sketch: HyperLogLog,
+    min: Option<DataValue>,
+    max: Option<DataValue>,
+}
+
+pub struct TableStatisticsBuilder {
+    table_name: TableName,
+    row_count: usize,
+    columns: Vec<ColumnStatisticsBuilder>,
+}
+
+impl TableStatisticsBuilder {
+    pub fn new(table_name: TableName, columns: &[ColumnRef]) -> Result<Self, DatabaseError> {
+        let columns = columns
+            .iter()
+            .map(|column| {
+                Ok(ColumnStatisticsBuilder {
+                    column_id: column.id().ok_or_else(|| {
+                        DatabaseError::ColumnIdNotFound(column.name().to_string())
+                    })?,
+                    null_count: 0,
+                    sketch: HyperLogLog::default(),
+                    min: None,
+                    max: None,
+                })
+            })
+            .collect::<Result<Vec<_>, DatabaseError>>()?;
+
+        Ok(TableStatisticsBuilder {
+            table_name,
+            row_count: 0,
+            columns,
+        })
+    }
+
+    /// `values` are in the order of the columns given to `new`
+    pub fn append(&mut self, values: &[DataValue]) {
+        self.row_count += 1;
+
+        for (builder, value) in self.columns.iter_mut().zip(values) {
+            if value.is_null() {
+                builder.null_count += 1;
+                continue;
+            }
+            builder.sketch.add(value);
+
+            if builder
+                .min
+                .as_ref()
+                .is_none_or(|min| value.partial_cmp(min) == Some(Ordering::Less))
+            {
+                builder.min = Some(value.clone());
+            }
+            if builder
+                .max
+                .as_ref()
+                .is_none_or(|max| value.partial_cmp(max) == Some(Ordering::Greater))
+            {
+                builder.max = Some(value.clone());
+            }
+        }
+    }
+
+    pub fn build(self) -> TableStatistics {
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|builder| ColumnStatistics {
+                column_id: builder.column_id,
+                null_count: builder.null_count,
+                distinct_count: builder.sketch.estimate(),
+                min: builder.min.unwrap_or(DataValue::Null),
+                max: builder.max.unwrap_or(DataValue::Null),
+            })
+            .collect();
+
+        TableStatistics {
+            table_name: self.table_name,
+            row_count: self.row_count,
+            columns,
+            analyzed_at: Utc::now().timestamp_millis(),
+        }
+    }
+}
@@ -1,4 +1,11 @@
+use crate::catalog::ColumnRef;
 use crate::optimizer::core::memo::Memo;
+use crate::planner::operator::except::ExceptOperator;
+use crate::planner::operator::intersect::IntersectOperator;
+use crate::planner::operator::join::JoinType;
+use crate::planner::operator::table_scan::TableScanOperator;
+use crate::planner::operator::union::UnionOperator;
+use crate::planner::operator::values::ValuesOperator;
 use crate::planner::operator::Operator;
 use crate::planner::{Childrens, LogicalPlan};
 use fixedbitset::FixedBitSet;
@@ -173,6 +180,72 @@ impl HepGraph {
             .map(|edge| edge.target())
     }
 
+    /// The columns output by the node, as [`LogicalPlan::output_schema`] but over the graph.
+    ///
+    /// Tips: operators that do not output rows of a query return no columns
+    pub fn output_columns(&self, node_id: HepNodeId) -> Vec<ColumnRef> {
+        match self.operator(node_id) {
+            Operator::Filter(_) | Operator::Sort(_) | Operator::Limit(_) => self
+                .eldest_child_at(node_id)
+                .map(|child_id| self.output_columns(child_id))
+                .unwrap_or_default(),
+            Operator::Window(op) => {
+                let mut columns = self
+                    .eldest_child_at(node_id)
+                    .map(|child_id| self.output_columns(child_id))
+                    .unwrap_or_default();
+                columns.extend(op.calls.iter().map(|call| call.output.clone()));
+                columns
+            }
+            Operator::Aggregate(op) => op
+                .agg_calls
+                .iter()
+                .chain(op.groupby_exprs.iter())
+                .map(|expr| expr.output_column())
+                .collect_vec(),
+            Operator::Join(op) => {
+                if matches!(op.join_type, JoinType::LeftSemi | JoinType::LeftAnti) {
+                    return self
+                        .eldest_child_at(node_id)
+                        .map(|child_id| self.output_columns(child_id))
+                        .unwrap_or_default();
+                }
+                self.children_at(node_id)
+                    .flat_map(|child_id| self.output_columns(child_id))
+                    .collect_vec()
+            }
+            Operator::Project(op) => op
+                .exprs
+                .iter()
+                .map(|expr| expr.output_column())
+                .collect_vec(),
+            Operator::TableScan(TableScanOperator {
+                projection: Some(exprs),
+                ..
+            }) => exprs.iter().map(|expr| expr.output_column()).collect_vec(),
+            Operator::TableScan(op) => op
+                .columns
+                .iter()
+                .map(|(_, column)| column.clone())
+                .collect_vec(),
+            Operator::FunctionScan(op) => op.table_function.output_schema().to_vec(),
+            Operator::Values(ValuesOperator { schema_ref, .. })
+            | Operator::Union(UnionOperator {
+                left_schema_ref: schema_ref,
+                ..
+            })
+            | Operator::Except(ExceptOperator {
+                left_schema_ref: schema_ref,
+                ..
+            })
+            | Operator::Intersect(IntersectOperator {
+                left_schema_ref: schema_ref,
+                ..
+            }) => schema_ref.to_vec(),
+            _ => vec![],
+        }
+    }
+
     pub fn into_plan(mut self, memo: Option<&Memo>) -> Option<LogicalPlan> {
         self.build_childrens(self.root_index, memo)
     }
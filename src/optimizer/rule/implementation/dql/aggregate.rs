@@ -11,7 +11,7 @@ use std::sync::LazyLock;
 static GROUP_BY_AGGREGATE_PATTERN: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     predicate: |op| {
         if let Operator::Aggregate(op) = op {
-            return !op.groupby_exprs.is_empty();
+            return !op.groupby_exprs.is_empty() || !op.grouping_sets.is_empty();
         }
         false
     },
@@ -21,7 +21,7 @@ static GROUP_BY_AGGREGATE_PATTERN: LazyLock<Pattern> = LazyLock::new(|| Pattern
 static SIMPLE_AGGREGATE_PATTERN: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     predicate: |op| {
         if let Operator::Aggregate(op) = op {
-            return op.groupby_exprs.is_empty();
+            return op.groupby_exprs.is_empty() && op.grouping_sets.is_empty();
         }
         false
     },
@@ -1,13 +1,22 @@
 use crate::errors::DatabaseError;
+use crate::expression::range_detacher::Range;
 use crate::optimizer::core::memo::{Expression, GroupExpression};
 use crate::optimizer::core::pattern::{Pattern, PatternChildrenPredicate};
 use crate::optimizer::core::rule::{ImplementationRule, MatchPattern};
 use crate::optimizer::core::statistics_meta::StatisticMetaLoader;
+use crate::optimizer::core::table_statistics::TableStatistics;
+use crate::planner::operator::table_scan::TableScanOperator;
 use crate::planner::operator::{Operator, PhysicalOption};
 use crate::storage::Transaction;
-use crate::types::index::IndexType;
+use crate::types::index::{IndexInfo, IndexType};
+use std::collections::Bound;
 use std::sync::LazyLock;
 
+/// Row count assumed for a table that has not been analyzed
+const DEFAULT_ROW_COUNT: usize = 1000;
+/// Without statistics an equality on a non-unique index is assumed to keep one row in this many
+const DEFAULT_EQ_DIVISOR: usize = 200;
+
 static TABLE_SCAN_PATTERN: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     predicate: |op| matches!(op, Operator::TableScan(_)),
     children: PatternChildrenPredicate::None,
@@ -30,18 +39,21 @@ impl<T: Transaction> ImplementationRule<T> for SeqScanImplementation {
         group_expr: &mut GroupExpression,
     ) -> Result<(), DatabaseError> {
         if let Operator::TableScan(scan_op) = op {
-            let cost = scan_op
+            // the sort has been pushed into an index, see `PushSortIntoScan`
+            if scan_op
                 .index_infos
                 .iter()
-                .find(|index_info| index_info.meta.column_ids == scan_op.primary_keys)
-                .map(|index_info| loader.load(&scan_op.table_name, index_info.meta.id))
-                .transpose()?
-                .flatten()
-                .map(|statistics_meta| statistics_meta.histogram().values_len());
-
+                .any(|index_info| index_info.order.is_some())
+            {
+                return Ok(());
+            }
             group_expr.append_expr(Expression {
                 op: PhysicalOption::SeqScan,
-                cost,
+                cost: Some(row_count(
+                    scan_op,
+                    loader,
+                    loader.load_table(&scan_op.table_name)?.as_ref(),
+                )?),
             });
 
             Ok(())
@@ -67,29 +79,53 @@ impl<T: Transaction> ImplementationRule<T> for IndexScanImplementation {
         group_expr: &mut GroupExpression,
     ) -> Result<(), DatabaseError> {
         if let Operator::TableScan(scan_op) = op {
+            let table_statistics = loader.load_table(&scan_op.table_name)?;
+            let table_row_count = row_count(scan_op, loader, table_statistics.as_ref())?;
+            let is_ordered = scan_op
+                .index_infos
+                .iter()
+                .any(|index_info| index_info.order.is_some());
+
             for index_info in scan_op.index_infos.iter() {
-                if index_info.range.is_none() {
+                let Some(range) = &index_info.range else {
+                    continue;
+                };
+                if is_ordered && index_info.order.is_none() {
                     continue;
                 }
-                let mut cost = None;
-
-                if let Some(range) = &index_info.range {
-                    if let Some(statistics_meta) =
-                        loader.load(&scan_op.table_name, index_info.meta.id)?
-                    {
-                        let mut row_count = statistics_meta.collect_count(range)?;
-
-                        if !matches!(index_info.meta.ty, IndexType::PrimaryKey { .. }) {
-                            // need to return table query(non-covering index)
-                            row_count *= 2;
-                        }
-                        cost = Some(row_count);
+                let covered = index_info.meta.is_covering(
+                    &scan_op.primary_keys,
+                    scan_op.columns.iter().map(|(_, column)| column),
+                );
+                let mut row_count = match loader.load(&scan_op.table_name, index_info.meta.id)? {
+                    Some(statistics_meta) => statistics_meta.collect_count(range)?,
+                    None => {
+                        let distinct_count =
+                            match (&table_statistics, &index_info.meta.column_ids[..]) {
+                                (Some(table_statistics), [column_id])
+                                    if index_info.meta.expr.is_none()
+                                        && index_info.meta.predicate.is_none() =>
+                                {
+                                    table_statistics
+                                        .column(column_id)
+                                        .map(|column| column.distinct_count())
+                                }
+                                _ => None,
+                            };
+                        estimate_count(range, &index_info.meta.ty, table_row_count, distinct_count)
                     }
-                }
+                };
 
+                if !matches!(index_info.meta.ty, IndexType::PrimaryKey { .. }) && !covered {
+                    // need to return table query(non-covering index)
+                    row_count *= 2;
+                }
                 group_expr.append_expr(Expression {
-                    op: PhysicalOption::IndexScan(index_info.clone()),
-                    cost,
+                    op: PhysicalOption::IndexScan(IndexInfo {
+                        covered,
+                        ..index_info.clone()
+                    }),
+                    cost: Some(row_count),
                 })
             }
 
@@ -99,3 +135,55 @@ impl<T: Transaction> ImplementationRule<T> for IndexScanImplementation {
         }
     }
 }
+
+fn row_count<T: Transaction>(
+    scan_op: &TableScanOperator,
+    loader: &StatisticMetaLoader<'_, T>,
+    table_statistics: Option<&TableStatistics>,
+) -> Result<usize, DatabaseError> {
+    if let Some(table_statistics) = table_statistics {
+        return Ok(table_statistics.row_count());
+    }
+    Ok(scan_op
+        .index_infos
+        .iter()
+        .find(|index_info| index_info.meta.column_ids == scan_op.primary_keys)
+        .map(|index_info| loader.load(&scan_op.table_name, index_info.meta.id))
+        .transpose()?
+        .flatten()
+        .map(|statistics_meta| statistics_meta.histogram().values_len())
+        .unwrap_or(DEFAULT_ROW_COUNT))
+}
+
+/// Estimates the rows in the range of an index that has no histogram
+///
+/// Tips: an equality on a primary key or unique index matches at most one row,
+/// any other equality keeps one row per distinct value of the column when the table was analyzed,
+/// and each bounded side of a scope is assumed to keep half of the rows
+fn estimate_count(
+    range: &Range,
+    index_ty: &IndexType,
+    row_count: usize,
+    distinct_count: Option<usize>,
+) -> usize {
+    match range {
+        Range::Eq(_) => match index_ty {
+            IndexType::PrimaryKey { .. } | IndexType::Unique => 1,
+            IndexType::Normal | IndexType::Composite => (row_count
+                / distinct_count
+                    .filter(|count| *count > 0)
+                    .unwrap_or(DEFAULT_EQ_DIVISOR))
+            .max(1),
+        },
+        Range::Scope { min, max } => [min, max]
+            .into_iter()
+            .filter(|bound| !matches!(bound, Bound::Unbounded))
+            .fold(row_count, |count, _| count / 2),
+        Range::Dummy => 0,
+        Range::SortedRanges(ranges) => ranges
+            .iter()
+            .map(|range| estimate_count(range, index_ty, row_count, distinct_count))
+            .sum::<usize>()
+            .min(row_count),
+    }
+}
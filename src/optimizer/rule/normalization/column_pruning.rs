@@ -1,7 +1,7 @@
 use crate::catalog::ColumnSummary;
 use crate::errors::DatabaseError;
 use crate::expression::agg::AggKind;
-use crate::expression::ScalarExpression;
+use crate::expression::{AliasType, ScalarExpression};
 use crate::optimizer::core::pattern::{Pattern, PatternChildrenPredicate};
 use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
 use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
@@ -37,6 +37,17 @@ impl ColumnPruning {
             if column_references.contains(expr.output_column().summary()) {
                 return true;
             }
+            // an aliased column (e.g. `t1.c1 as x.c1`) is only referenced by its alias, its source
+            // column may be referenced on behalf of another alias of the same table (e.g. self join)
+            if matches!(
+                expr,
+                ScalarExpression::Alias {
+                    alias: AliasType::Expr(_),
+                    ..
+                }
+            ) {
+                return false;
+            }
             expr.referenced_columns(false)
                 .iter()
                 .any(|column| column_references.contains(column.summary()))
@@ -69,6 +80,7 @@ impl ColumnPruning {
                             kind: AggKind::Count,
                             args: vec![ScalarExpression::Constant(value)],
                             ty: LogicalType::Integer,
+                            filter: None,
                         })
                     }
                 }
@@ -103,10 +115,13 @@ impl ColumnPruning {
                 }
             }
             Operator::Sort(_)
+            | Operator::Window(_)
             | Operator::Limit(_)
             | Operator::Join(_)
             | Operator::Filter(_)
-            | Operator::Union(_) => {
+            | Operator::Union(_)
+            | Operator::Except(_)
+            | Operator::Intersect(_) => {
                 let temp_columns = operator.referenced_columns(false);
                 // why?
                 let mut column_references = column_references;
@@ -223,7 +238,7 @@ mod tests {
         let join_op = best_plan.childrens.pop_only();
         match &join_op.operator {
             Operator::Join(op) => match &op.on {
-                JoinCondition::On { on, filter } => {
+                JoinCondition::On { on, filter, .. } => {
                     assert_eq!(on.len(), 1);
                     assert!(filter.is_none());
                 }
@@ -247,4 +262,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_column_pruning_self_join() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan("select x.c1 from t1 x join t1 y on x.c1 = y.c2")?;
+
+        let best_plan = HepOptimizer::new(plan.clone())
+            .batch(
+                "test_column_pruning_self_join".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![NormalizationRuleImpl::ColumnPruning],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        let join_op = best_plan.childrens.pop_only();
+        assert!(matches!(join_op.operator, Operator::Join(_)));
+
+        for (alias_plan, column_name) in join_op.childrens.iter().zip(["c1", "c2"]) {
+            match &alias_plan.operator {
+                Operator::Project(op) => assert_eq!(op.exprs.len(), 1),
+                _ => unreachable!("Should be a project operator"),
+            }
+            match alias_plan.childrens.as_ref() {
+                Childrens::Only(scan_plan) => match &scan_plan.operator {
+                    Operator::TableScan(op) => {
+                        assert_eq!(op.columns.len(), 1);
+                        assert_eq!(op.columns[0].1.name(), column_name);
+                    }
+                    _ => unreachable!("Should be a scan operator"),
+                },
+                _ => unreachable!("Should be a single child"),
+            }
+        }
+
+        Ok(())
+    }
 }
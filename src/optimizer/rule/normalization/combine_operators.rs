@@ -25,20 +25,33 @@ static COMBINE_FILTERS_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     }]),
 });
 
+// Tips: the groups of different grouping sets may have the same keys
 static COLLAPSE_GROUP_BY_AGG: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     predicate: |op| match op {
-        Operator::Aggregate(agg_op) => !agg_op.groupby_exprs.is_empty(),
+        Operator::Aggregate(agg_op) => {
+            !agg_op.groupby_exprs.is_empty() && agg_op.grouping_sets.is_empty()
+        }
         _ => false,
     },
     children: PatternChildrenPredicate::Predicate(vec![Pattern {
         predicate: |op| match op {
-            Operator::Aggregate(agg_op) => !agg_op.groupby_exprs.is_empty(),
+            Operator::Aggregate(agg_op) => {
+                !agg_op.groupby_exprs.is_empty() && agg_op.grouping_sets.is_empty()
+            }
             _ => false,
         },
         children: PatternChildrenPredicate::None,
     }]),
 });
 
+static COMBINE_PROJECT_INTO_SCAN_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| matches!(op, Operator::Project(_)),
+    children: PatternChildrenPredicate::Predicate(vec![Pattern {
+        predicate: |op| matches!(op, Operator::TableScan(_)),
+        children: PatternChildrenPredicate::None,
+    }]),
+});
+
 /// Combine two adjacent project operators into one.
 pub struct CollapseProject;
 
@@ -137,6 +150,52 @@ impl NormalizationRule for CollapseGroupByAgg {
     }
 }
 
+/// Fuse a project operator into the table scan below it, so that the expressions are computed
+/// while the tuples are read instead of by a separate operator.
+///
+/// Tips: a project of plain columns computes nothing and is kept
+pub struct CombineProjectIntoScan;
+
+impl MatchPattern for CombineProjectIntoScan {
+    fn pattern(&self) -> &Pattern {
+        &COMBINE_PROJECT_INTO_SCAN_RULE
+    }
+}
+
+impl NormalizationRule for CombineProjectIntoScan {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        if let Operator::Project(op) = graph.operator(node_id).clone() {
+            if op
+                .exprs
+                .iter()
+                .all(|expr| matches!(expr.unpack_alias_ref(), ScalarExpression::ColumnRef(_)))
+            {
+                return Ok(());
+            }
+            if let Some(child_id) = graph.eldest_child_at(node_id) {
+                if let Operator::TableScan(scan_op) = graph.operator_mut(child_id) {
+                    let is_fusible = scan_op.projection.is_none()
+                        && op
+                            .exprs
+                            .iter()
+                            .flat_map(|expr| expr.referenced_columns(true))
+                            .all(|column| {
+                                scan_op.columns.iter().any(|(_, scan_column)| {
+                                    scan_column.summary() == column.summary()
+                                })
+                            });
+                    if is_fusible {
+                        scan_op.projection = Some(op.exprs);
+                        graph.remove_node(node_id, false);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::binder::test::build_t1_table;
@@ -261,4 +320,37 @@ mod tests {
         }
         unreachable!("Should be a agg operator")
     }
+
+    #[test]
+    fn test_combine_project_into_scan() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan("select c1 + 1, c2 from t1")?;
+
+        let optimizer = HepOptimizer::new(plan.clone()).batch(
+            "test_combine_project_into_scan".to_string(),
+            HepBatchStrategy::once_topdown(),
+            vec![NormalizationRuleImpl::CombineProjectIntoScan],
+        );
+
+        let best_plan = optimizer.find_best::<RocksTransaction>(None)?;
+
+        if let Operator::TableScan(op) = &best_plan.operator {
+            assert_eq!(op.projection.as_ref().map(Vec::len), Some(2));
+            assert!(matches!(best_plan.childrens.as_ref(), Childrens::None));
+        } else {
+            unreachable!("Should be a scan operator")
+        }
+
+        let plan = table_state.plan("select c1, c2 from t1")?;
+        let optimizer = HepOptimizer::new(plan).batch(
+            "test_combine_project_into_scan".to_string(),
+            HepBatchStrategy::once_topdown(),
+            vec![NormalizationRuleImpl::CombineProjectIntoScan],
+        );
+        let best_plan = optimizer.find_best::<RocksTransaction>(None)?;
+
+        assert!(matches!(best_plan.operator, Operator::Project(_)));
+
+        Ok(())
+    }
 }
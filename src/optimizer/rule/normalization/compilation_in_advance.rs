@@ -3,8 +3,10 @@ use crate::expression::ScalarExpression;
 use crate::optimizer::core::pattern::{Pattern, PatternChildrenPredicate};
 use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
 use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
+use crate::planner::operator::insert::{OnConflict, OnConflictAction};
 use crate::planner::operator::join::JoinCondition;
 use crate::planner::operator::Operator;
+use itertools::Itertools;
 use std::sync::LazyLock;
 
 static EXPRESSION_REMAPPER_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
@@ -39,12 +41,20 @@ impl ExpressionRemapper {
             left_len = output_exprs.len();
             output_exprs.append(&mut second_output_exprs);
         }
+        // the right side of a set operation is remapped by its own output
+        if let Operator::Union(_) | Operator::Except(_) | Operator::Intersect(_) =
+            graph.operator(node_id)
+        {
+            if let Some(child_id) = graph.youngest_child_at(node_id) {
+                Self::_apply(&mut Vec::new(), child_id, graph)?;
+            }
+        }
         let operator = graph.operator_mut(node_id);
 
         match operator {
             Operator::Join(op) => {
                 match &mut op.on {
-                    JoinCondition::On { on, filter } => {
+                    JoinCondition::On { on, filter, .. } => {
                         for (left_expr, right_expr) in on {
                             left_expr.try_reference(&output_exprs[0..left_len]);
                             right_expr.try_reference(&output_exprs[left_len..]);
@@ -53,6 +63,14 @@ impl ExpressionRemapper {
                             expr.try_reference(output_exprs);
                         }
                     }
+                    JoinCondition::Dependent { params, filter } => {
+                        for (outer_expr, _) in params {
+                            outer_expr.try_reference(&output_exprs[0..left_len]);
+                        }
+                        if let Some(expr) = filter {
+                            expr.try_reference(output_exprs);
+                        }
+                    }
                     JoinCondition::None => {}
                 }
 
@@ -76,18 +94,43 @@ impl ExpressionRemapper {
                     sort_field.expr.try_reference(output_exprs);
                 }
             }
+            Operator::Window(op) => {
+                for call in op.calls.iter_mut() {
+                    for expr in call.exprs_mut() {
+                        expr.try_reference(output_exprs);
+                    }
+                }
+                // the results are appended to the input
+                output_exprs.extend(
+                    op.calls
+                        .iter()
+                        .map(|call| ScalarExpression::ColumnRef(call.output.clone())),
+                );
+            }
             Operator::FunctionScan(op) => {
                 for expr in op.table_function.args.iter_mut() {
                     expr.try_reference(output_exprs);
                 }
             }
+            Operator::TableScan(op) => {
+                if let Some(exprs) = op.projection.as_mut() {
+                    let column_exprs = op
+                        .columns
+                        .iter()
+                        .map(|(_, column)| ScalarExpression::ColumnRef(column.clone()))
+                        .collect_vec();
+
+                    for expr in exprs.iter_mut() {
+                        expr.try_reference(&column_exprs);
+                    }
+                }
+            }
             Operator::Update(op) => {
                 for (_, expr) in op.value_exprs.iter_mut() {
                     expr.try_reference(output_exprs);
                 }
             }
             Operator::Dummy
-            | Operator::TableScan(_)
             | Operator::Limit(_)
             | Operator::Values(_)
             | Operator::Show
@@ -106,7 +149,9 @@ impl ExpressionRemapper {
             | Operator::Truncate(_)
             | Operator::CopyFromFile(_)
             | Operator::CopyToFile(_)
-            | Operator::Union(_) => (),
+            | Operator::Union(_)
+            | Operator::Except(_)
+            | Operator::Intersect(_) => (),
         }
         if let Some(exprs) = operator.output_exprs() {
             *output_exprs = exprs;
@@ -140,8 +185,12 @@ impl EvaluatorBind {
         if let Some(child_id) = graph.eldest_child_at(node_id) {
             Self::_apply(child_id, graph)?;
         }
-        // for join
-        if let Operator::Join(_) = graph.operator(node_id) {
+        // for join and set operations
+        if let Operator::Join(_)
+        | Operator::Union(_)
+        | Operator::Except(_)
+        | Operator::Intersect(_) = graph.operator(node_id)
+        {
             if let Some(child_id) = graph.youngest_child_at(node_id) {
                 Self::_apply(child_id, graph)?;
             }
@@ -151,7 +200,7 @@ impl EvaluatorBind {
         match operator {
             Operator::Join(op) => {
                 match &mut op.on {
-                    JoinCondition::On { on, filter } => {
+                    JoinCondition::On { on, filter, .. } => {
                         for (left_expr, right_expr) in on {
                             left_expr.bind_evaluator()?;
                             right_expr.bind_evaluator()?;
@@ -160,6 +209,14 @@ impl EvaluatorBind {
                             expr.bind_evaluator()?;
                         }
                     }
+                    JoinCondition::Dependent { params, filter } => {
+                        for (outer_expr, _) in params {
+                            outer_expr.bind_evaluator()?;
+                        }
+                        if let Some(expr) = filter {
+                            expr.bind_evaluator()?;
+                        }
+                    }
                     JoinCondition::None => {}
                 }
 
@@ -183,24 +240,52 @@ impl EvaluatorBind {
                     sort_field.expr.bind_evaluator()?;
                 }
             }
+            Operator::Window(op) => {
+                for call in op.calls.iter_mut() {
+                    for expr in call.exprs_mut() {
+                        expr.bind_evaluator()?;
+                    }
+                }
+            }
             Operator::FunctionScan(op) => {
                 for expr in op.table_function.args.iter_mut() {
                     expr.bind_evaluator()?;
                 }
             }
+            Operator::TableScan(op) => {
+                for expr in op.projection.iter_mut().flatten() {
+                    expr.bind_evaluator()?;
+                }
+            }
             Operator::Update(op) => {
                 for (_, expr) in op.value_exprs.iter_mut() {
                     expr.bind_evaluator()?;
                 }
             }
+            Operator::Insert(op) => {
+                if let Some(OnConflict {
+                    action:
+                        OnConflictAction::DoUpdate {
+                            value_exprs,
+                            predicate,
+                        },
+                    ..
+                }) = op.on_conflict.as_mut()
+                {
+                    for (_, expr) in value_exprs.iter_mut() {
+                        expr.bind_evaluator()?;
+                    }
+                    if let Some(predicate) = predicate {
+                        predicate.bind_evaluator()?;
+                    }
+                }
+            }
             Operator::Dummy
-            | Operator::TableScan(_)
             | Operator::Limit(_)
             | Operator::Values(_)
             | Operator::Show
             | Operator::Explain
             | Operator::Describe(_)
-            | Operator::Insert(_)
             | Operator::Delete(_)
             | Operator::Analyze(_)
             | Operator::AddColumn(_)
@@ -213,7 +298,9 @@ impl EvaluatorBind {
             | Operator::Truncate(_)
             | Operator::CopyFromFile(_)
             | Operator::CopyToFile(_)
-            | Operator::Union(_) => (),
+            | Operator::Union(_)
+            | Operator::Except(_)
+            | Operator::Intersect(_) => (),
         }
 
         Ok(())
@@ -0,0 +1,129 @@
+use crate::errors::DatabaseError;
+use crate::expression::ScalarExpression;
+use crate::optimizer::core::pattern::{Pattern, PatternChildrenPredicate};
+use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
+use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
+use crate::planner::operator::Operator;
+use crate::types::index::IndexType;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+static ELIMINATE_REDUNDANT_DISTINCT_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| match op {
+        Operator::Aggregate(agg_op) => agg_op.is_distinct && agg_op.agg_calls.is_empty(),
+        _ => false,
+    },
+    children: PatternChildrenPredicate::None,
+});
+
+/// Remove the `DISTINCT` whose columns contain a primary key or unique index of the only table
+/// below it, as every row is already distinct.
+///
+/// Tips: a unique index holds at most one NULL, so the nullable columns are also distinct
+pub struct EliminateRedundantDistinct;
+
+impl MatchPattern for EliminateRedundantDistinct {
+    fn pattern(&self) -> &Pattern {
+        &ELIMINATE_REDUNDANT_DISTINCT_RULE
+    }
+}
+
+impl NormalizationRule for EliminateRedundantDistinct {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        let Operator::Aggregate(op) = graph.operator(node_id) else {
+            return Ok(());
+        };
+        let mut child_id = graph.eldest_child_at(node_id);
+        let mut is_redundant = false;
+
+        // only the operators that keep the rows of the table as they are can be skipped
+        while let Some(id) = child_id {
+            match graph.operator(id) {
+                Operator::Filter(_) | Operator::Sort(_) | Operator::Limit(_) => {
+                    child_id = graph.eldest_child_at(id);
+                }
+                Operator::TableScan(scan_op) => {
+                    let column_ids = op
+                        .groupby_exprs
+                        .iter()
+                        .filter_map(|expr| match expr.unpack_alias_ref() {
+                            ScalarExpression::ColumnRef(column)
+                                if column.table_name() == Some(&scan_op.table_name) =>
+                            {
+                                column.id()
+                            }
+                            _ => None,
+                        })
+                        .collect::<HashSet<_>>();
+                    is_redundant = scan_op.index_infos.iter().any(|index_info| {
+                        matches!(
+                            index_info.meta.ty,
+                            IndexType::PrimaryKey { .. } | IndexType::Unique
+                        ) && index_info.meta.expr.is_none()
+                            && index_info.meta.predicate.is_none()
+                            && index_info
+                                .meta
+                                .column_ids
+                                .iter()
+                                .all(|column_id| column_ids.contains(column_id))
+                    });
+                    break;
+                }
+                _ => break,
+            }
+        }
+        if is_redundant {
+            graph.remove_node(node_id, false);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binder::test::build_t1_table;
+    use crate::errors::DatabaseError;
+    use crate::optimizer::heuristic::batch::HepBatchStrategy;
+    use crate::optimizer::heuristic::optimizer::HepOptimizer;
+    use crate::optimizer::rule::normalization::NormalizationRuleImpl;
+    use crate::planner::operator::Operator;
+    use crate::planner::LogicalPlan;
+    use crate::storage::rocksdb::RocksTransaction;
+
+    fn has_distinct(plan: &LogicalPlan) -> bool {
+        matches!(plan.operator, Operator::Aggregate(_)) || plan.childrens.iter().any(has_distinct)
+    }
+
+    #[test]
+    fn test_eliminate_redundant_distinct() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+
+        for (sql, expected) in [
+            ("select distinct c3 from t2", false),
+            (
+                "select distinct c4, c3 from t2 where c4 > 1 order by c4",
+                false,
+            ),
+            // unique
+            ("select distinct c2 from t1", false),
+            ("select distinct c4 from t2", true),
+            ("select distinct c3 + 1 from t2", true),
+            ("select distinct c3 from t2 group by c3", true),
+            ("select distinct c3 from t1, t2", true),
+        ] {
+            let plan = table_state.plan(sql)?;
+            let best_plan = HepOptimizer::new(plan)
+                .batch(
+                    "test_eliminate_redundant_distinct".to_string(),
+                    HepBatchStrategy::once_topdown(),
+                    vec![NormalizationRuleImpl::EliminateRedundantDistinct],
+                )
+                .find_best::<RocksTransaction>(None)?;
+
+            assert_eq!(has_distinct(&best_plan), expected, "{}", sql);
+        }
+
+        Ok(())
+    }
+}
@@ -5,25 +5,32 @@ use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
 use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
 use crate::optimizer::rule::normalization::column_pruning::ColumnPruning;
 use crate::optimizer::rule::normalization::combine_operators::{
-    CollapseGroupByAgg, CollapseProject, CombineFilter,
+    CollapseGroupByAgg, CollapseProject, CombineFilter, CombineProjectIntoScan,
 };
 use crate::optimizer::rule::normalization::compilation_in_advance::{
     EvaluatorBind, ExpressionRemapper,
 };
+use crate::optimizer::rule::normalization::distinct_elimination::EliminateRedundantDistinct;
 use crate::optimizer::rule::normalization::pushdown_limit::{
-    LimitProjectTranspose, PushLimitIntoScan, PushLimitThroughJoin,
+    LimitProjectTranspose, PushLimitIntoScan, PushLimitIntoSort, PushLimitThroughJoin,
+    PushLimitThroughUnion,
 };
 use crate::optimizer::rule::normalization::pushdown_predicates::PushPredicateIntoScan;
 use crate::optimizer::rule::normalization::pushdown_predicates::PushPredicateThroughJoin;
 use crate::optimizer::rule::normalization::simplification::ConstantCalculation;
 use crate::optimizer::rule::normalization::simplification::SimplifyFilter;
+use crate::optimizer::rule::normalization::sort_elimination::{
+    EliminateRedundantSort, PushSortIntoScan,
+};
 
 mod column_pruning;
 mod combine_operators;
 mod compilation_in_advance;
+mod distinct_elimination;
 mod pushdown_limit;
 mod pushdown_predicates;
 mod simplification;
+mod sort_elimination;
 
 #[derive(Debug, Copy, Clone)]
 pub enum NormalizationRuleImpl {
@@ -32,10 +39,18 @@ pub enum NormalizationRuleImpl {
     CollapseProject,
     CollapseGroupByAgg,
     CombineFilter,
+    CombineProjectIntoScan,
+    // Eliminate operators
+    EliminateRedundantDistinct,
+    EliminateRedundantSort,
     // PushDown limit
     LimitProjectTranspose,
     PushLimitThroughJoin,
+    PushLimitThroughUnion,
     PushLimitIntoTableScan,
+    PushLimitIntoSort,
+    // PushDown sort
+    PushSortIntoTableScan,
     // PushDown predicates
     PushPredicateThroughJoin,
     // Tips: need to be used with `SimplifyFilter`
@@ -55,9 +70,17 @@ impl MatchPattern for NormalizationRuleImpl {
             NormalizationRuleImpl::CollapseProject => CollapseProject.pattern(),
             NormalizationRuleImpl::CollapseGroupByAgg => CollapseGroupByAgg.pattern(),
             NormalizationRuleImpl::CombineFilter => CombineFilter.pattern(),
+            NormalizationRuleImpl::CombineProjectIntoScan => CombineProjectIntoScan.pattern(),
+            NormalizationRuleImpl::EliminateRedundantDistinct => {
+                EliminateRedundantDistinct.pattern()
+            }
+            NormalizationRuleImpl::EliminateRedundantSort => EliminateRedundantSort.pattern(),
             NormalizationRuleImpl::LimitProjectTranspose => LimitProjectTranspose.pattern(),
             NormalizationRuleImpl::PushLimitThroughJoin => PushLimitThroughJoin.pattern(),
+            NormalizationRuleImpl::PushLimitThroughUnion => PushLimitThroughUnion.pattern(),
             NormalizationRuleImpl::PushLimitIntoTableScan => PushLimitIntoScan.pattern(),
+            NormalizationRuleImpl::PushLimitIntoSort => PushLimitIntoSort.pattern(),
+            NormalizationRuleImpl::PushSortIntoTableScan => PushSortIntoScan.pattern(),
             NormalizationRuleImpl::PushPredicateThroughJoin => PushPredicateThroughJoin.pattern(),
             NormalizationRuleImpl::PushPredicateIntoScan => PushPredicateIntoScan.pattern(),
             NormalizationRuleImpl::SimplifyFilter => SimplifyFilter.pattern(),
@@ -75,15 +98,29 @@ impl NormalizationRule for NormalizationRuleImpl {
             NormalizationRuleImpl::CollapseProject => CollapseProject.apply(node_id, graph),
             NormalizationRuleImpl::CollapseGroupByAgg => CollapseGroupByAgg.apply(node_id, graph),
             NormalizationRuleImpl::CombineFilter => CombineFilter.apply(node_id, graph),
+            NormalizationRuleImpl::CombineProjectIntoScan => {
+                CombineProjectIntoScan.apply(node_id, graph)
+            }
+            NormalizationRuleImpl::EliminateRedundantDistinct => {
+                EliminateRedundantDistinct.apply(node_id, graph)
+            }
+            NormalizationRuleImpl::EliminateRedundantSort => {
+                EliminateRedundantSort.apply(node_id, graph)
+            }
             NormalizationRuleImpl::LimitProjectTranspose => {
                 LimitProjectTranspose.apply(node_id, graph)
             }
             NormalizationRuleImpl::PushLimitThroughJoin => {
                 PushLimitThroughJoin.apply(node_id, graph)
             }
+            NormalizationRuleImpl::PushLimitThroughUnion => {
+                PushLimitThroughUnion.apply(node_id, graph)
+            }
             NormalizationRuleImpl::PushLimitIntoTableScan => {
                 PushLimitIntoScan.apply(node_id, graph)
             }
+            NormalizationRuleImpl::PushLimitIntoSort => PushLimitIntoSort.apply(node_id, graph),
+            NormalizationRuleImpl::PushSortIntoTableScan => PushSortIntoScan.apply(node_id, graph),
             NormalizationRuleImpl::PushPredicateThroughJoin => {
                 PushPredicateThroughJoin.apply(node_id, graph)
             }
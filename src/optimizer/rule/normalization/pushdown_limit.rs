@@ -4,6 +4,7 @@ use crate::optimizer::core::pattern::PatternChildrenPredicate;
 use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
 use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
 use crate::planner::operator::join::JoinType;
+use crate::planner::operator::limit::LimitOperator;
 use crate::planner::operator::Operator;
 use itertools::Itertools;
 use std::sync::LazyLock;
@@ -24,6 +25,14 @@ static PUSH_LIMIT_THROUGH_JOIN_RULE: LazyLock<Pattern> = LazyLock::new(|| Patter
     }]),
 });
 
+static PUSH_LIMIT_THROUGH_UNION_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| matches!(op, Operator::Limit(_)),
+    children: PatternChildrenPredicate::Predicate(vec![Pattern {
+        predicate: |op| matches!(op, Operator::Union(_)),
+        children: PatternChildrenPredicate::None,
+    }]),
+});
+
 static PUSH_LIMIT_INTO_TABLE_SCAN_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
     predicate: |op| matches!(op, Operator::Limit(_)),
     children: PatternChildrenPredicate::Predicate(vec![Pattern {
@@ -32,6 +41,14 @@ static PUSH_LIMIT_INTO_TABLE_SCAN_RULE: LazyLock<Pattern> = LazyLock::new(|| Pat
     }]),
 });
 
+static PUSH_LIMIT_INTO_SORT_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| matches!(op, Operator::Limit(_)),
+    children: PatternChildrenPredicate::Predicate(vec![Pattern {
+        predicate: |op| matches!(op, Operator::Sort(_)),
+        children: PatternChildrenPredicate::None,
+    }]),
+});
+
 pub struct LimitProjectTranspose;
 
 impl MatchPattern for LimitProjectTranspose {
@@ -94,6 +111,73 @@ impl NormalizationRule for PushLimitThroughJoin {
     }
 }
 
+/// Add extra limits below `UNION ALL`, each side only needs the first `offset + limit` tuples.
+///
+/// Tips: the `Limit` is kept, and `UNION` is skipped because its deduplication may need more
+/// tuples of each side
+pub struct PushLimitThroughUnion;
+
+impl PushLimitThroughUnion {
+    /// whether the side already yields at most `limit` tuples, after the extra limit has been
+    /// moved below its projections or into its scan
+    fn is_limited(graph: &HepGraph, mut node_id: HepNodeId, limit: usize) -> bool {
+        loop {
+            match graph.operator(node_id) {
+                Operator::Limit(op) => {
+                    return op.offset.is_none() && op.limit.is_some_and(|l| l <= limit)
+                }
+                Operator::TableScan(op) => {
+                    return op.limit.0.is_none() && op.limit.1.is_some_and(|l| l <= limit)
+                }
+                Operator::Project(_) => match graph.eldest_child_at(node_id) {
+                    Some(child_id) => node_id = child_id,
+                    None => return false,
+                },
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl MatchPattern for PushLimitThroughUnion {
+    fn pattern(&self) -> &Pattern {
+        &PUSH_LIMIT_THROUGH_UNION_RULE
+    }
+}
+
+impl NormalizationRule for PushLimitThroughUnion {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        if let Operator::Limit(LimitOperator {
+            offset,
+            limit: Some(limit),
+        }) = graph.operator(node_id)
+        {
+            let union_limit = offset.unwrap_or(0).saturating_add(*limit);
+
+            if let Some(child_id) = graph.eldest_child_at(node_id) {
+                if !matches!(graph.operator(child_id), Operator::Union(op) if op.is_all) {
+                    return Ok(());
+                }
+                for grandson_id in graph.children_at(child_id).collect_vec() {
+                    if Self::is_limited(graph, grandson_id, union_limit) {
+                        continue;
+                    }
+                    graph.add_node(
+                        child_id,
+                        Some(grandson_id),
+                        Operator::Limit(LimitOperator {
+                            offset: None,
+                            limit: Some(union_limit),
+                        }),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Push down `Limit` past a `Scan`.
 pub struct PushLimitIntoScan;
 
@@ -124,6 +208,41 @@ impl NormalizationRule for PushLimitIntoScan {
     }
 }
 
+/// Push down `Limit` into the `Sort` directly below it,
+/// so that the sort only keeps the first `offset + limit` tuples (Top-N).
+///
+/// Tips: the `Limit` is kept to skip the `offset`
+pub struct PushLimitIntoSort;
+
+impl MatchPattern for PushLimitIntoSort {
+    fn pattern(&self) -> &Pattern {
+        &PUSH_LIMIT_INTO_SORT_RULE
+    }
+}
+
+impl NormalizationRule for PushLimitIntoSort {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        if let Operator::Limit(LimitOperator {
+            offset,
+            limit: Some(limit),
+        }) = graph.operator(node_id)
+        {
+            let sort_limit = offset.unwrap_or(0).saturating_add(*limit);
+
+            if let Some(child_id) = graph.eldest_child_at(node_id) {
+                if let Operator::Sort(sort_op) = graph.operator_mut(child_id) {
+                    if sort_op.limit.is_none_or(|limit| limit > sort_limit) {
+                        sort_op.limit = Some(sort_limit);
+                        graph.version += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::binder::test::build_t1_table;
@@ -132,6 +251,7 @@ mod tests {
     use crate::optimizer::heuristic::optimizer::HepOptimizer;
     use crate::optimizer::rule::normalization::NormalizationRuleImpl;
     use crate::planner::operator::Operator;
+    use crate::planner::LogicalPlan;
     use crate::storage::rocksdb::RocksTransaction;
 
     #[test]
@@ -193,6 +313,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_push_limit_through_union() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let optimize = |plan: LogicalPlan| {
+            HepOptimizer::new(plan)
+                .batch(
+                    "test_push_limit_through_union".to_string(),
+                    HepBatchStrategy::fix_point_topdown(10),
+                    vec![
+                        NormalizationRuleImpl::LimitProjectTranspose,
+                        NormalizationRuleImpl::PushLimitThroughUnion,
+                    ],
+                )
+                .find_best::<RocksTransaction>(None)
+        };
+
+        let best_plan = optimize(
+            table_state.plan("select c1 from t1 union all select c3 from t2 limit 2 offset 1")?,
+        )?;
+        if let Operator::Limit(op) = &best_plan.operator {
+            assert_eq!((op.offset, op.limit), (Some(1), Some(2)))
+        } else {
+            unreachable!("Should be a limit operator")
+        }
+        let union_op = best_plan.childrens.pop_only();
+        let (left, right) = union_op.childrens.pop_twins();
+        for side in [left, right] {
+            // the extra limit is moved below the projection of each side
+            let limit_op = side.childrens.pop_only();
+            if let Operator::Limit(op) = &limit_op.operator {
+                assert_eq!((op.offset, op.limit), (None, Some(3)))
+            } else {
+                unreachable!("Should be a limit operator")
+            }
+            assert!(matches!(
+                limit_op.childrens.pop_only().operator,
+                Operator::TableScan(_)
+            ));
+        }
+
+        // `offset + limit` saturates rather than overflows
+        let mut plan =
+            table_state.plan("select c1 from t1 union all select c3 from t2 limit 2 offset 1")?;
+        if let Operator::Limit(op) = &mut plan.operator {
+            op.offset = Some(usize::MAX);
+        } else {
+            unreachable!("Should be a limit operator")
+        }
+        let union_op = optimize(plan)?.childrens.pop_only();
+        let (left, right) = union_op.childrens.pop_twins();
+        for side in [left, right] {
+            if let Operator::Limit(op) = &side.childrens.pop_only().operator {
+                assert_eq!((op.offset, op.limit), (None, Some(usize::MAX)))
+            } else {
+                unreachable!("Should be a limit operator")
+            }
+        }
+
+        let best_plan =
+            optimize(table_state.plan("select c1 from t1 union select c3 from t2 limit 2")?)?;
+        let union_op = best_plan.childrens.pop_only();
+        let (left, right) = union_op.childrens.pop_twins();
+        for side in [left, right] {
+            assert!(matches!(
+                side.childrens.pop_only().operator,
+                Operator::TableScan(_)
+            ));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_push_limit_into_table_scan() -> Result<(), DatabaseError> {
         let table_state = build_t1_table()?;
@@ -218,4 +410,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_push_limit_into_sort() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan("select * from t1 order by c2 limit 2 offset 3")?;
+
+        let best_plan = HepOptimizer::new(plan.clone())
+            .batch(
+                "test_push_limit_into_sort".to_string(),
+                HepBatchStrategy::fix_point_topdown(10),
+                vec![
+                    NormalizationRuleImpl::LimitProjectTranspose,
+                    NormalizationRuleImpl::PushLimitIntoSort,
+                ],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        let limit_op = best_plan.childrens.pop_only();
+        if let Operator::Limit(op) = &limit_op.operator {
+            assert_eq!((op.offset, op.limit), (Some(3), Some(2)))
+        } else {
+            unreachable!("Should be a limit operator")
+        }
+        let sort_op = limit_op.childrens.pop_only();
+        if let Operator::Sort(op) = &sort_op.operator {
+            assert_eq!(op.limit, Some(5))
+        } else {
+            unreachable!("Should be a sort operator")
+        }
+
+        // `offset + limit` saturates rather than overflows
+        let mut plan = table_state.plan("select * from t1 order by c2 limit 2 offset 3")?;
+        if let Operator::Limit(op) = &mut plan.operator {
+            op.offset = Some(usize::MAX);
+        } else {
+            unreachable!("Should be a limit operator")
+        }
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_push_limit_into_sort".to_string(),
+                HepBatchStrategy::fix_point_topdown(10),
+                vec![
+                    NormalizationRuleImpl::LimitProjectTranspose,
+                    NormalizationRuleImpl::PushLimitIntoSort,
+                ],
+            )
+            .find_best::<RocksTransaction>(None)?;
+        let sort_op = best_plan.childrens.pop_only().childrens.pop_only();
+        if let Operator::Sort(op) = &sort_op.operator {
+            assert_eq!(op.limit, Some(usize::MAX))
+        } else {
+            unreachable!("Should be a sort operator")
+        }
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::catalog::ColumnRef;
+use crate::catalog::{ColumnCatalog, ColumnRef};
 use crate::errors::DatabaseError;
 use crate::expression::range_detacher::{Range, RangeDetacher};
 use crate::expression::{BinaryOperator, ScalarExpression};
@@ -11,7 +11,7 @@ use crate::planner::operator::join::JoinType;
 use crate::planner::operator::Operator;
 use crate::types::index::{IndexInfo, IndexMetaRef, IndexType};
 use crate::types::value::DataValue;
-use crate::types::LogicalType;
+use crate::types::{ColumnId, LogicalType};
 use itertools::Itertools;
 use std::mem;
 use std::ops::Bound;
@@ -101,7 +101,6 @@ impl MatchPattern for PushPredicateThroughJoin {
 }
 
 impl NormalizationRule for PushPredicateThroughJoin {
-    // TODO: pushdown_predicates need to consider output columns
     fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
         let child_id = match graph.eldest_child_at(node_id) {
             Some(child_id) => child_id,
@@ -111,6 +110,7 @@ impl NormalizationRule for PushPredicateThroughJoin {
             if !matches!(
                 child_op.join_type,
                 JoinType::Inner
+                    | JoinType::Cross
                     | JoinType::LeftOuter
                     | JoinType::LeftSemi
                     | JoinType::LeftAnti
@@ -120,8 +120,8 @@ impl NormalizationRule for PushPredicateThroughJoin {
             }
 
             let join_childs = graph.children_at(child_id).collect_vec();
-            let left_columns = graph.operator(join_childs[0]).referenced_columns(true);
-            let right_columns = graph.operator(join_childs[1]).referenced_columns(true);
+            let left_columns = graph.output_columns(join_childs[0]);
+            let right_columns = graph.output_columns(join_childs[1]);
 
             let mut new_ops = (None, None, None);
 
@@ -130,13 +130,13 @@ impl NormalizationRule for PushPredicateThroughJoin {
 
                 let (left_filters, rest): (Vec<_>, Vec<_>) = filter_exprs
                     .into_iter()
-                    .partition(|f| is_subset_cols(&f.referenced_columns(true), &left_columns));
+                    .partition(|f| is_subset_cols(&f.referenced_input_columns(), &left_columns));
                 let (right_filters, common_filters): (Vec<_>, Vec<_>) = rest
                     .into_iter()
-                    .partition(|f| is_subset_cols(&f.referenced_columns(true), &right_columns));
+                    .partition(|f| is_subset_cols(&f.referenced_input_columns(), &right_columns));
 
                 let replace_filters = match child_op.join_type {
-                    JoinType::Inner => {
+                    JoinType::Inner | JoinType::Cross => {
                         if !left_filters.is_empty() {
                             if let Some(left_filter_op) = reduce_filters(left_filters, op.having) {
                                 new_ops.0 = Some(Operator::Filter(left_filter_op));
@@ -183,6 +183,11 @@ impl NormalizationRule for PushPredicateThroughJoin {
                     }
                 }
             }
+            // nothing can be pushed down, keep the graph unchanged so that the batch
+            // moves on to the filters below
+            if new_ops.0.is_none() && new_ops.1.is_none() {
+                return Ok(());
+            }
 
             if let Some(left_op) = new_ops.0 {
                 graph.add_node(child_id, Some(join_childs[0]), left_op);
@@ -217,11 +222,14 @@ impl NormalizationRule for PushPredicateIntoScan {
             if let Some(child_id) = graph.eldest_child_at(node_id) {
                 if let Operator::TableScan(child_op) = graph.operator_mut(child_id) {
                     //FIXME: now only support `unique` and `primary key`
-                    for IndexInfo { meta, range } in &mut child_op.index_infos {
-                        if range.is_some() {
+                    for IndexInfo { meta, range, .. } in &mut child_op.index_infos {
+                        if range.is_some() || !Self::is_implied(&op, meta) {
                             continue;
                         }
                         *range = match meta.ty {
+                            IndexType::Unique | IndexType::Normal if meta.expr.is_some() => {
+                                Self::expr_range(&op, meta)
+                            }
                             IndexType::PrimaryKey { is_multiple: false }
                             | IndexType::Unique
                             | IndexType::Normal => {
@@ -242,6 +250,97 @@ impl NormalizationRule for PushPredicateIntoScan {
 }
 
 impl PushPredicateIntoScan {
+    /// A partial index only holds the rows its predicate passes, so it can only be scanned when
+    /// each condition of its predicate is also a condition of the filter,
+    /// e.g. `a = 1 AND b IS NULL` for the index `(a) WHERE b IS NULL`
+    fn is_implied(op: &FilterOperator, meta: &IndexMetaRef) -> bool {
+        let Some(index_predicate) = &meta.predicate else {
+            return true;
+        };
+        let predicates = split_conjunctive_predicates(&op.predicate);
+
+        split_conjunctive_predicates(index_predicate)
+            .iter()
+            .all(|index_predicate| predicates.contains(index_predicate))
+    }
+
+    /// Detach the range of an expression index by reading the indexed expression in the
+    /// predicate as a column of the index, e.g. `json_extract(j, '$.id') = 'a1'`
+    fn expr_range(op: &FilterOperator, meta: &IndexMetaRef) -> Option<Range> {
+        let index_expr = meta.expr.as_ref()?;
+        let column_id = ColumnId::new();
+        let mut column = ColumnCatalog::clone(&index_expr.output_column());
+        column.set_ref_table(meta.table_name.clone(), column_id, false);
+
+        let mut predicate = op.predicate.clone();
+        Self::replace_index_expr(&mut predicate, index_expr, &ColumnRef::from(column));
+        let range = RangeDetacher::new(meta.table_name.as_str(), &column_id).detach(&predicate)?;
+
+        // the values must be encoded as the values of the index
+        Self::is_typed_range(&range, &meta.value_ty).then_some(range)
+    }
+
+    fn replace_index_expr(
+        expr: &mut ScalarExpression,
+        index_expr: &ScalarExpression,
+        column: &ColumnRef,
+    ) {
+        if expr == index_expr {
+            *expr = ScalarExpression::ColumnRef(column.clone());
+            return;
+        }
+        match expr {
+            ScalarExpression::Alias { expr, .. }
+            | ScalarExpression::TypeCast { expr, .. }
+            | ScalarExpression::Unary { expr, .. }
+            | ScalarExpression::IsNull { expr, .. }
+            | ScalarExpression::Extract { expr, .. } => {
+                Self::replace_index_expr(expr, index_expr, column)
+            }
+            ScalarExpression::Binary {
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                Self::replace_index_expr(left_expr, index_expr, column);
+                Self::replace_index_expr(right_expr, index_expr, column);
+            }
+            ScalarExpression::In { expr, args, .. } => {
+                Self::replace_index_expr(expr, index_expr, column);
+                for arg in args {
+                    Self::replace_index_expr(arg, index_expr, column);
+                }
+            }
+            ScalarExpression::Between {
+                expr,
+                left_expr,
+                right_expr,
+                ..
+            } => {
+                Self::replace_index_expr(expr, index_expr, column);
+                Self::replace_index_expr(left_expr, index_expr, column);
+                Self::replace_index_expr(right_expr, index_expr, column);
+            }
+            _ => (),
+        }
+    }
+
+    fn is_typed_range(range: &Range, ty: &LogicalType) -> bool {
+        let fn_is_typed = |value: &DataValue| value.is_null() || &value.logical_type() == ty;
+
+        match range {
+            Range::Scope { min, max } => [min, max].into_iter().all(|bound| match bound {
+                Bound::Included(value) | Bound::Excluded(value) => fn_is_typed(value),
+                Bound::Unbounded => true,
+            }),
+            Range::Eq(value) => fn_is_typed(value),
+            Range::Dummy => true,
+            Range::SortedRanges(ranges) => {
+                ranges.iter().all(|range| Self::is_typed_range(range, ty))
+            }
+        }
+    }
+
     fn composite_range(op: &FilterOperator, meta: &mut IndexMetaRef) -> Option<Range> {
         let mut res = None;
         let mut eq_ranges = Vec::with_capacity(meta.column_ids.len());
@@ -266,6 +365,13 @@ impl PushPredicateIntoScan {
                 res = range.combining_eqs(&eq_ranges);
             }
         }
+        if res.is_none() {
+            return RangeDetacher::detach_row(
+                meta.table_name.as_str(),
+                &meta.column_ids,
+                &op.predicate,
+            );
+        }
         res.map(|range| {
             if range.only_eq() && apply_column_count != meta.column_ids.len() {
                 fn eq_to_scope(range: Range) -> Range {
@@ -481,4 +587,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_push_predicate_through_nested_join() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan(
+            "select * from t1 join t2 on c1 = c3 join t1 as t3 on c4 = t3.c1 \
+            where t1.c2 > 1 and t3.c2 < 2 and t1.c2 + c4 > t3.c2",
+        )?;
+
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_push_predicate_through_join".to_string(),
+                HepBatchStrategy::fix_point_topdown(10),
+                vec![NormalizationRuleImpl::PushPredicateThroughJoin],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        assert_eq!(
+            best_plan.explain(0),
+            "Projection [t1.c1, t1.c2, (t1.c1) as (t3.c1), (t1.c2) as (t3.c2), t2.c3, t2.c4]
+  Filter ((t1.c2 + t2.c4) > c2), Is Having: false
+    Inner Join On t2.c4 = c1
+      Inner Join On t1.c1 = t2.c3
+        Filter (t1.c2 > 1), Is Having: false
+          TableScan t1 -> [c1, c2]
+        TableScan t2 -> [c3, c4]
+      Filter (c2 < 2), Is Having: false
+        Projection [(t1.c1) as (t3.c1), (t1.c2) as (t3.c2)]
+          TableScan t1 -> [c1, c2]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_predicate_through_cross_join() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan("select * from t1, t2 where c1 = c3 and c2 > 1 and c4 < 2")?;
+
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_push_predicate_through_join".to_string(),
+                HepBatchStrategy::fix_point_topdown(10),
+                vec![NormalizationRuleImpl::PushPredicateThroughJoin],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        assert_eq!(
+            best_plan.explain(0),
+            "Projection [t1.c1, t1.c2, t2.c3, t2.c4]
+  Filter (t1.c1 = t2.c3), Is Having: false
+    Cross Join Nothing
+      Filter (t1.c2 > 1), Is Having: false
+        TableScan t1 -> [c1, c2]
+      Filter (t2.c4 < 2), Is Having: false
+        TableScan t2 -> [c3, c4]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_predicate_through_nested_left_join() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan(
+            "select * from t1 left join t2 on c1 = c3 left join t1 as t3 on c4 = t3.c1 \
+            where t1.c2 > 1 and c4 < 2 and t3.c2 < 3",
+        )?;
+
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_push_predicate_through_join".to_string(),
+                HepBatchStrategy::fix_point_topdown(10),
+                vec![NormalizationRuleImpl::PushPredicateThroughJoin],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        assert_eq!(
+            best_plan.explain(0),
+            "Projection [t1.c1, t1.c2, (t1.c1) as (t3.c1), (t1.c2) as (t3.c2), t2.c3, t2.c4]
+  Filter (c2 < 3), Is Having: false
+    LeftOuter Join On t2.c4 = c1
+      Filter (t2.c4 < 2), Is Having: false
+        LeftOuter Join On t1.c1 = t2.c3
+          Filter (t1.c2 > 1), Is Having: false
+            TableScan t1 -> [c1, c2]
+          TableScan t2 -> [c3, c4]
+      Projection [(t1.c1) as (t3.c1), (t1.c2) as (t3.c2)]
+        TableScan t1 -> [c1, c2]"
+        );
+
+        Ok(())
+    }
 }
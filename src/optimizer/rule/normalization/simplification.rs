@@ -37,7 +37,7 @@ impl ConstantCalculation {
                 op.predicate.constant_calculation()?;
             }
             Operator::Join(op) => {
-                if let JoinCondition::On { on, filter } = &mut op.on {
+                if let JoinCondition::On { on, filter, .. } = &mut op.on {
                     for (left_expr, right_expr) in on {
                         left_expr.constant_calculation()?;
                         right_expr.constant_calculation()?;
@@ -177,6 +177,79 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_constant_calculation_try_cast() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan = table_state.plan(
+            "select try_cast('a' as int), try_cast('4' as int), try_cast(c1 as int) from t1",
+        )?;
+
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_simplification".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![NormalizationRuleImpl::ConstantCalculation],
+            )
+            .find_best::<RocksTransaction>(None)?;
+        if let Operator::Project(project_op) = best_plan.operator {
+            assert_eq!(
+                project_op.exprs[0],
+                ScalarExpression::Constant(DataValue::Null)
+            );
+            assert_eq!(
+                project_op.exprs[1],
+                ScalarExpression::Constant(DataValue::Int32(4))
+            );
+            assert!(matches!(
+                project_op.exprs[2],
+                ScalarExpression::TypeCast { is_try: true, .. }
+            ));
+        } else {
+            unreachable!();
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_calculation_coalesce() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan =
+            table_state.plan("select coalesce(null, 1 + 1, 1 % 0), coalesce(c1, 1 % 0) from t1")?;
+
+        let best_plan = HepOptimizer::new(plan)
+            .batch(
+                "test_simplification".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![NormalizationRuleImpl::ConstantCalculation],
+            )
+            .find_best::<RocksTransaction>(None)?;
+        if let Operator::Project(project_op) = best_plan.operator {
+            // the arguments after the first non-null constant are dropped
+            let ScalarExpression::Coalesce { exprs, .. } = &project_op.exprs[0] else {
+                unreachable!();
+            };
+            assert_eq!(exprs.len(), 2);
+            assert_eq!(exprs[1], ScalarExpression::Constant(DataValue::Int32(2)));
+            // the division by zero is left to the runtime
+            let ScalarExpression::Coalesce { exprs, .. } = &project_op.exprs[1] else {
+                unreachable!();
+            };
+            assert_eq!(exprs.len(), 2);
+            assert!(matches!(
+                exprs[1],
+                ScalarExpression::Binary {
+                    op: BinaryOperator::Modulo,
+                    ..
+                }
+            ));
+        } else {
+            unreachable!();
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_simplify_filter_single_column() -> Result<(), DatabaseError> {
         let table_state = build_t1_table()?;
@@ -497,12 +570,157 @@ mod test {
     fn test_simplify_filter_column_not_in() -> Result<(), DatabaseError> {
         let table_state = build_t1_table()?;
         let plan_1 = table_state.plan("select * from t1 where c1 not in (1, 2, 3)")?;
+        let scope = |min, max| Range::Scope { min, max };
 
         assert_eq!(
             plan_filter(&plan_1, table_state.column_id_by_name("c1"))?,
-            None
+            Some(Range::SortedRanges(vec![
+                scope(Bound::Unbounded, Bound::Excluded(DataValue::Int32(1))),
+                scope(
+                    Bound::Excluded(DataValue::Int32(1)),
+                    Bound::Excluded(DataValue::Int32(2))
+                ),
+                scope(
+                    Bound::Excluded(DataValue::Int32(2)),
+                    Bound::Excluded(DataValue::Int32(3))
+                ),
+                scope(Bound::Excluded(DataValue::Int32(3)), Bound::Unbounded),
+            ]))
+        );
+        // `c1 != NULL` is never true
+        let plan_2 = table_state.plan("select * from t1 where c1 not in (1, null)")?;
+
+        assert_eq!(
+            plan_filter(&plan_2, table_state.column_id_by_name("c1"))?,
+            Some(Range::Dummy)
+        );
+
+        Ok(())
+    }
+
+    fn simplified_predicate(plan: &LogicalPlan) -> Result<ScalarExpression, DatabaseError> {
+        let best_plan = HepOptimizer::new(plan.clone())
+            .batch(
+                "test_simplify_filter".to_string(),
+                HepBatchStrategy::once_topdown(),
+                vec![NormalizationRuleImpl::SimplifyFilter],
+            )
+            .find_best::<RocksTransaction>(None)?;
+
+        let filter_op = best_plan.childrens.pop_only();
+        if let Operator::Filter(filter_op) = filter_op.operator {
+            Ok(filter_op.predicate)
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_simplify_filter_double_negation() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        let plan_1 = table_state.plan("select * from t1 where not not c2 > 1")?;
+        let plan_2 = table_state.plan("select * from t1 where c2 > 1")?;
+
+        // NOT NOT (c2 > 1) => c2 > 1
+        assert_eq!(
+            simplified_predicate(&plan_1)?,
+            simplified_predicate(&plan_2)?
+        );
+        assert_eq!(
+            plan_filter(&plan_1, table_state.column_id_by_name("c2"))?,
+            Some(Range::Scope {
+                min: Bound::Excluded(DataValue::Int32(1)),
+                max: Bound::Unbounded,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simplify_filter_not_comparison() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+        // c1 is not nullable: NOT (c1 > 1) => c1 <= 1
+        let plan_1 = table_state.plan("select * from t1 where not c1 > 1")?;
+        let plan_2 = table_state.plan("select * from t1 where c1 <= 1")?;
+        // NOT (c1 = 1) => c1 != 1
+        let plan_3 = table_state.plan("select * from t1 where not c1 = 1")?;
+        let plan_4 = table_state.plan("select * from t1 where c1 != 1")?;
+
+        assert_eq!(
+            simplified_predicate(&plan_1)?,
+            simplified_predicate(&plan_2)?
+        );
+        assert_eq!(
+            plan_filter(&plan_1, table_state.column_id_by_name("c1"))?,
+            Some(Range::Scope {
+                min: Bound::Unbounded,
+                max: Bound::Included(DataValue::Int32(1)),
+            })
+        );
+        assert_eq!(
+            simplified_predicate(&plan_3)?,
+            simplified_predicate(&plan_4)?
         );
 
+        // nullable columns are left alone
+        let nullable_col = ColumnRef::from(ColumnCatalog::new(
+            "c3".to_string(),
+            true,
+            ColumnDesc::new(LogicalType::Integer, None, false, None)?,
+        ));
+        let mut expr = ScalarExpression::Unary {
+            op: UnaryOperator::Not,
+            expr: Box::new(ScalarExpression::Binary {
+                op: BinaryOperator::Gt,
+                left_expr: Box::new(ScalarExpression::ColumnRef(nullable_col)),
+                right_expr: Box::new(ScalarExpression::Constant(DataValue::Int32(1))),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            }),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        let origin_expr = expr.clone();
+        expr.simplify()?;
+        assert_eq!(expr, origin_expr);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simplify_filter_not_beside_logical_operator() -> Result<(), DatabaseError> {
+        let column = |name: &str, nullable| {
+            Ok::<_, DatabaseError>(ColumnRef::from(ColumnCatalog::new(
+                name.to_string(),
+                nullable,
+                ColumnDesc::new(LogicalType::Integer, None, false, None)?,
+            )))
+        };
+        let eq = |column, value| ScalarExpression::Binary {
+            op: BinaryOperator::Eq,
+            left_expr: Box::new(ScalarExpression::ColumnRef(column)),
+            right_expr: Box::new(ScalarExpression::Constant(DataValue::Int32(value))),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        // NOT (c3 = 2) AND c1 = 2, the NOT of the nullable c3 stays on its own side
+        let mut expr = ScalarExpression::Binary {
+            op: BinaryOperator::And,
+            left_expr: Box::new(ScalarExpression::Unary {
+                op: UnaryOperator::Not,
+                expr: Box::new(eq(column("c3", true)?, 2)),
+                evaluator: None,
+                ty: LogicalType::Boolean,
+            }),
+            right_expr: Box::new(eq(column("c1", false)?, 2)),
+            evaluator: None,
+            ty: LogicalType::Boolean,
+        };
+        let origin_expr = expr.clone();
+        expr.simplify()?;
+        assert_eq!(expr, origin_expr);
+
         Ok(())
     }
 }
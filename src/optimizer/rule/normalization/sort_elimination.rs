@@ -0,0 +1,318 @@
+use crate::errors::DatabaseError;
+use crate::expression::agg::AggKind;
+use crate::expression::range_detacher::Range;
+use crate::expression::ScalarExpression;
+use crate::optimizer::core::pattern::{Pattern, PatternChildrenPredicate};
+use crate::optimizer::core::rule::{MatchPattern, NormalizationRule};
+use crate::optimizer::heuristic::graph::{HepGraph, HepNodeId};
+use crate::planner::operator::join::JoinType;
+use crate::planner::operator::sort::SortField;
+use crate::planner::operator::table_scan::TableScanOperator;
+use crate::planner::operator::Operator;
+use crate::types::index::{IndexInfo, IndexOrder};
+use std::collections::Bound;
+use std::sync::LazyLock;
+
+static ELIMINATE_REDUNDANT_SORT_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| match op {
+        Operator::Sort(sort_op) => sort_op.limit.is_none(),
+        _ => false,
+    },
+    children: PatternChildrenPredicate::None,
+});
+
+static PUSH_SORT_INTO_SCAN_RULE: LazyLock<Pattern> = LazyLock::new(|| Pattern {
+    predicate: |op| matches!(op, Operator::Sort(_)),
+    children: PatternChildrenPredicate::None,
+});
+
+/// Remove the `ORDER BY` whose order can not be observed by its consumer, such as an aggregation,
+/// an insertion or the right side of a semi/anti join.
+///
+/// Tips: `LIMIT` depends on the order, so a sort below it is always kept
+pub struct EliminateRedundantSort;
+
+impl MatchPattern for EliminateRedundantSort {
+    fn pattern(&self) -> &Pattern {
+        &ELIMINATE_REDUNDANT_SORT_RULE
+    }
+}
+
+impl EliminateRedundantSort {
+    fn is_unordered_consumer(graph: &HepGraph, node_id: HepNodeId, child_id: HepNodeId) -> bool {
+        match graph.operator(node_id) {
+            // ties of `max_by`/`min_by` are resolved by the order of the input
+            Operator::Aggregate(op) => op.agg_calls.iter().all(|call| {
+                !matches!(
+                    call.unpack_alias_ref(),
+                    ScalarExpression::AggCall {
+                        kind: AggKind::MaxBy | AggKind::MinBy,
+                        ..
+                    }
+                )
+            }),
+            Operator::Insert(_) => true,
+            Operator::Join(op) => {
+                matches!(op.join_type, JoinType::LeftSemi | JoinType::LeftAnti)
+                    && graph.youngest_child_at(node_id) == Some(child_id)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl NormalizationRule for EliminateRedundantSort {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        let mut child_id = node_id;
+        let mut is_redundant = false;
+
+        // the operators that handle rows one by one pass the order through as it is
+        while let Some(parent_id) = graph.parent_id(child_id) {
+            match graph.operator(parent_id) {
+                Operator::Project(_) | Operator::Filter(_) => child_id = parent_id,
+                _ => {
+                    is_redundant = Self::is_unordered_consumer(graph, parent_id, child_id);
+                    break;
+                }
+            }
+        }
+        if is_redundant {
+            graph.remove_node(node_id, false);
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove the `ORDER BY` whose keys are a prefix of the columns of an index on the scanned table,
+/// the index is read in its order instead, or backwards when all the keys are `DESC`.
+///
+/// Tips: an index keeps NULL before any other value, so the NULLs of a nullable key must be
+/// placed the same way unless the range of the index leaves them out. The sort is kept when a
+/// predicate has been pushed into another index, whose range is likely cheaper to read
+pub struct PushSortIntoScan;
+
+impl MatchPattern for PushSortIntoScan {
+    fn pattern(&self) -> &Pattern {
+        &PUSH_SORT_INTO_SCAN_RULE
+    }
+}
+
+impl PushSortIntoScan {
+    fn index_order(
+        sort_fields: &[SortField],
+        scan_op: &TableScanOperator,
+        index_info: &IndexInfo,
+    ) -> Option<IndexOrder> {
+        let column_ids = &index_info.meta.column_ids;
+        let asc = sort_fields.first()?.asc;
+
+        // the range of a partial index is only detached when the filter implies its predicate
+        if sort_fields.len() > column_ids.len()
+            || index_info.meta.expr.is_some()
+            || index_info.meta.predicate.is_some() && index_info.range.is_none()
+        {
+            return None;
+        }
+        for (i, (sort_field, column_id)) in sort_fields.iter().zip(column_ids).enumerate() {
+            let ScalarExpression::ColumnRef(column) = sort_field.expr.unpack_alias_ref() else {
+                return None;
+            };
+            if sort_field.asc != asc
+                || column.id() != Some(*column_id)
+                || !scan_op
+                    .columns
+                    .iter()
+                    .any(|(_, scan_column)| scan_column.summary() == column.summary())
+            {
+                return None;
+            }
+            let is_null_free = i == 0
+                && column_ids.len() == 1
+                && index_info.range.as_ref().is_some_and(Self::is_null_free);
+            // the NULLs are read first in order and last backwards
+            if column.nullable() && sort_field.nulls_first != asc && !is_null_free {
+                return None;
+            }
+        }
+
+        Some(if asc {
+            IndexOrder::Asc
+        } else {
+            IndexOrder::Desc
+        })
+    }
+
+    fn is_null_free(range: &Range) -> bool {
+        match range {
+            Range::Scope { min, .. } => match min {
+                Bound::Included(value) => !value.is_null(),
+                Bound::Excluded(_) => true,
+                Bound::Unbounded => false,
+            },
+            Range::Eq(value) => !value.is_null(),
+            Range::Dummy => true,
+            Range::SortedRanges(ranges) => ranges.first().is_none_or(Self::is_null_free),
+        }
+    }
+}
+
+impl NormalizationRule for PushSortIntoScan {
+    fn apply(&self, node_id: HepNodeId, graph: &mut HepGraph) -> Result<(), DatabaseError> {
+        let Operator::Sort(sort_op) = graph.operator(node_id) else {
+            return Ok(());
+        };
+        let mut scan_id = graph.eldest_child_at(node_id);
+
+        // the filters keep the order of the scan
+        while let Some(child_id) = scan_id {
+            if !matches!(graph.operator(child_id), Operator::Filter(_)) {
+                break;
+            }
+            scan_id = graph.eldest_child_at(child_id);
+        }
+        let Some(scan_id) = scan_id else {
+            return Ok(());
+        };
+        let Operator::TableScan(scan_op) = graph.operator(scan_id) else {
+            return Ok(());
+        };
+        let Some((position, order)) = scan_op
+            .index_infos
+            .iter()
+            .enumerate()
+            .filter_map(|(i, index_info)| {
+                Self::index_order(&sort_op.sort_fields, scan_op, index_info).map(|order| (i, order))
+            })
+            .min_by_key(|(i, _)| scan_op.index_infos[*i].range.is_none())
+        else {
+            return Ok(());
+        };
+        if scan_op
+            .index_infos
+            .iter()
+            .enumerate()
+            .any(|(i, index_info)| i != position && index_info.range.is_some())
+        {
+            return Ok(());
+        }
+        if let Operator::TableScan(scan_op) = graph.operator_mut(scan_id) {
+            let index_info = &mut scan_op.index_infos[position];
+
+            index_info.range.get_or_insert(Range::Scope {
+                min: Bound::Unbounded,
+                max: Bound::Unbounded,
+            });
+            index_info.order = Some(order);
+        }
+        graph.remove_node(node_id, false);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::binder::test::build_t1_table;
+    use crate::errors::DatabaseError;
+    use crate::optimizer::heuristic::batch::HepBatchStrategy;
+    use crate::optimizer::heuristic::optimizer::HepOptimizer;
+    use crate::optimizer::rule::normalization::NormalizationRuleImpl;
+    use crate::planner::operator::Operator;
+    use crate::planner::LogicalPlan;
+    use crate::storage::rocksdb::RocksTransaction;
+    use crate::types::index::IndexOrder;
+
+    fn has_sort(plan: &LogicalPlan) -> bool {
+        matches!(plan.operator, Operator::Sort(_)) || plan.childrens.iter().any(has_sort)
+    }
+
+    #[test]
+    fn test_eliminate_redundant_sort() -> Result<(), DatabaseError> {
+        let table_state = build_t1_table()?;
+
+        for (sql, expected) in [
+            ("select count(*) from (select * from t1 order by c2)", false),
+            (
+                "select c1, sum(c2) from (select * from t1 order by c2) where c1 > 1 group by c1",
+                false,
+            ),
+            (
+                "select c3 from t2 where c3 in (select c1 from t1 order by c2)",
+                false,
+            ),
+            ("select * into t2 from t1 order by c1", false),
+            ("select * from t1 order by c2", true),
+            (
+                "select count(*) from (select * from t1 order by c2 limit 1)",
+                true,
+            ),
+            (
+                "select * from (select * from t1 order by c2) where c1 > 1",
+                true,
+            ),
+            (
+                "select max_by(c1, c2) from (select * from t1 order by c2)",
+                true,
+            ),
+        ] {
+            let plan = table_state.plan(sql)?;
+            let best_plan = HepOptimizer::new(plan)
+                .batch(
+                    "test_eliminate_redundant_sort".to_string(),
+                    HepBatchStrategy::once_topdown(),
+                    vec![NormalizationRuleImpl::EliminateRedundantSort],
+                )
+                .find_best::<RocksTransaction>(None)?;
+
+            assert_eq!(has_sort(&best_plan), expected, "{}", sql);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_sort_into_scan() -> Result<(), DatabaseError> {
+        fn scan_order(plan: &LogicalPlan) -> Option<(String, IndexOrder)> {
+            if let Operator::TableScan(op) = &plan.operator {
+                return op.index_infos.iter().find_map(|index_info| {
+                    index_info
+                        .order
+                        .map(|order| (index_info.meta.name.clone(), order))
+                });
+            }
+            plan.childrens.iter().find_map(scan_order)
+        }
+        let table_state = build_t1_table()?;
+
+        for (sql, expected) in [
+            (
+                "select * from t1 order by c1",
+                Some(("pk_index", IndexOrder::Asc)),
+            ),
+            (
+                "select c2 from t1 where c2 > 1 order by c2 desc",
+                Some(("uk_c2_index", IndexOrder::Desc)),
+            ),
+            ("select * from t1 order by c1, c2", None),
+            ("select * from t1 order by c2 asc, c1 desc", None),
+            ("select c1 + 1 as c from t1 order by c", None),
+            ("select * from t1 join t2 on c1 = c3 order by c1", None),
+        ] {
+            let plan = table_state.plan(sql)?;
+            let best_plan = HepOptimizer::new(plan)
+                .batch(
+                    "test_push_sort_into_scan".to_string(),
+                    HepBatchStrategy::once_topdown(),
+                    vec![NormalizationRuleImpl::PushSortIntoTableScan],
+                )
+                .find_best::<RocksTransaction>(None)?;
+            let expected = expected.map(|(name, order)| (name.to_string(), order));
+
+            assert_eq!(has_sort(&best_plan), expected.is_none(), "{}", sql);
+            assert_eq!(scan_order(&best_plan), expected, "{}", sql);
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,10 @@
+use itertools::Itertools;
+use sqlparser::ast::{Expr, Ident, Statement};
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::ParserError;
-use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+use sqlparser::tokenizer::{Token, TokenWithLocation, Tokenizer, Word};
+use sqlparser::{dialect::PostgreSqlDialect, parser::Parser};
+use std::collections::HashSet;
 
 const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 
@@ -16,5 +21,394 @@ const DIALECT: PostgreSqlDialect = PostgreSqlDialect {};
 /// println!("{:?}", ast);
 /// ```
 pub fn parse_sql<S: AsRef<str>>(sql: S) -> Result<Vec<Statement>, ParserError> {
-    Parser::parse_sql(&DIALECT, sql.as_ref())
+    Parser::new(&DIALECT)
+        .with_tokens_with_locations(tokenize(sql.as_ref())?)
+        .parse_statements()
+}
+
+/// Parse a string to a collection of statements like [`parse_sql`], with the predicate of each
+/// partial index that `sqlparser` can not parse as a part of `CREATE INDEX`.
+pub(crate) fn parse_sql_with_index_predicates<S: AsRef<str>>(
+    sql: S,
+) -> Result<Vec<(Statement, Option<Expr>)>, ParserError> {
+    let (tokens, predicates) = split_partial_index_predicates(tokenize(sql.as_ref())?);
+    let mut predicates = predicates
+        .into_iter()
+        .map(|tokens| tokens.map(parse_index_predicate).transpose())
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter();
+    let statements = Parser::new(&DIALECT)
+        .with_tokens_with_locations(tokens)
+        .parse_statements()?;
+
+    Ok(statements
+        .into_iter()
+        .map(|statement| {
+            let predicate = matches!(statement, Statement::CreateIndex { .. })
+                .then(|| predicates.next().flatten())
+                .flatten();
+            (statement, predicate)
+        })
+        .collect())
+}
+
+fn tokenize(sql: &str) -> Result<Vec<TokenWithLocation>, ParserError> {
+    let tokens = Tokenizer::new(&DIALECT, sql).tokenize_with_location()?;
+    let mut edits = TokenEdits::default();
+
+    for rewrite in SYNTAX_REWRITES {
+        rewrite(&tokens, &mut edits);
+    }
+    Ok(edits.apply(tokens))
+}
+
+/// The rewrites of the syntaxes `sqlparser` cannot parse into the equivalent ones it can.
+const SYNTAX_REWRITES: &[fn(&[TokenWithLocation], &mut TokenEdits)] = &[
+    wrap_quantified_subqueries,
+    wrap_in_values,
+    wrap_empty_grouping_sets,
+    unwrap_within_group,
+    wrap_between_symmetric,
+    call_niladic_functions,
+];
+
+/// the tokens inserted before the token at a position, the positions of the tokens removed
+/// and the keywords the words at a position are read as
+#[derive(Default)]
+struct TokenEdits {
+    inserts: Vec<(usize, Token)>,
+    removes: HashSet<usize>,
+    renames: Vec<(usize, Keyword)>,
+}
+
+impl TokenEdits {
+    fn insert(&mut self, pos: usize, token: Token) {
+        self.inserts.push((pos, token));
+    }
+
+    fn apply(self, mut tokens: Vec<TokenWithLocation>) -> Vec<TokenWithLocation> {
+        for (pos, keyword) in self.renames {
+            if let Token::Word(word) = &mut tokens[pos].token {
+                word.keyword = keyword;
+            }
+        }
+        if self.inserts.is_empty() && self.removes.is_empty() {
+            return tokens;
+        }
+        let mut inserts = self.inserts;
+        // stable, so the tokens inserted at the same position keep their order
+        inserts.sort_by_key(|(pos, _)| *pos);
+        let mut inserts = inserts.into_iter().peekable();
+        let mut result = Vec::with_capacity(tokens.len() + inserts.len());
+
+        for (i, token) in tokens.into_iter().enumerate() {
+            while let Some((_, inserted)) = inserts.next_if(|(pos, _)| *pos == i) {
+                result.push(TokenWithLocation::wrap(inserted));
+            }
+            if !self.removes.contains(&i) {
+                result.push(token);
+            }
+        }
+        result.extend(inserts.map(|(_, inserted)| TokenWithLocation::wrap(inserted)));
+        result
+    }
+}
+
+fn non_whitespaces(tokens: &[TokenWithLocation]) -> Vec<usize> {
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !matches!(token.token, Token::Whitespace(_)))
+        .map(|(i, _)| i)
+        .collect_vec()
+}
+
+fn is_keyword(token: &Token, keywords: &[Keyword]) -> bool {
+    matches!(token, Token::Word(word) if keywords.contains(&word.keyword))
+}
+
+/// the position of the `)` closing the `(` at `l_paren`
+fn closing_paren(tokens: &[TokenWithLocation], l_paren: usize) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate().skip(l_paren) {
+        match token.token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => continue,
+        }
+        if depth == 0 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// `sqlparser` only accepts an expression inside `ANY (..)`/`ALL (..)`,
+/// so `a > ALL (SELECT ..)` is rewritten into `a > ALL ((SELECT ..))` to parse it as a subquery.
+fn wrap_quantified_subqueries(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(4) {
+        let [op, quantifier, l_paren, select] = [0, 1, 2, 3].map(|i| &tokens[window[i]].token);
+
+        if matches!(
+            op,
+            Token::Eq
+                | Token::DoubleEq
+                | Token::Neq
+                | Token::Lt
+                | Token::Gt
+                | Token::LtEq
+                | Token::GtEq
+        ) && is_keyword(quantifier, &[Keyword::ANY, Keyword::ALL])
+            && l_paren == &Token::LParen
+            && is_keyword(select, &[Keyword::SELECT, Keyword::WITH])
+        {
+            if let Some(r_paren) = closing_paren(tokens, window[2]) {
+                edits.insert(window[3], Token::LParen);
+                edits.insert(r_paren, Token::RParen);
+            }
+        }
+    }
+}
+
+/// `sqlparser` only parses a subquery after `IN (` when it starts with `SELECT` or `WITH`,
+/// so `x IN (VALUES (1), (2))` is rewritten into `x IN (SELECT * FROM (VALUES (1), (2)))`
+/// for the binder to use the rows of the `VALUES` as a literal `IN` list.
+fn wrap_in_values(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(3) {
+        let [in_, l_paren, values] = [0, 1, 2].map(|i| &tokens[window[i]].token);
+
+        if is_keyword(in_, &[Keyword::IN])
+            && l_paren == &Token::LParen
+            && is_keyword(values, &[Keyword::VALUES])
+        {
+            if let Some(r_paren) = closing_paren(tokens, window[1]) {
+                edits.insert(window[2], Token::make_keyword("SELECT"));
+                edits.insert(window[2], Token::Mul);
+                edits.insert(window[2], Token::make_keyword("FROM"));
+                edits.insert(window[2], Token::LParen);
+                edits.insert(r_paren, Token::RParen);
+            }
+        }
+    }
+}
+
+/// `sqlparser` cannot parse the empty grouping set of `GROUP BY ()`,
+/// so it is rewritten into the equivalent `GROUP BY GROUPING SETS (())`.
+fn wrap_empty_grouping_sets(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(4) {
+        let [group, by, l_paren, r_paren] = [0, 1, 2, 3].map(|i| &tokens[window[i]].token);
+
+        if is_keyword(group, &[Keyword::GROUP])
+            && is_keyword(by, &[Keyword::BY])
+            && l_paren == &Token::LParen
+            && r_paren == &Token::RParen
+        {
+            edits.insert(window[2], Token::make_keyword("GROUPING"));
+            edits.insert(window[2], Token::make_keyword("SETS"));
+            edits.insert(window[2], Token::LParen);
+            edits.insert(window[3] + 1, Token::RParen);
+        }
+    }
+}
+
+/// `sqlparser` only parses `WITHIN GROUP` after `LISTAGG` and `ARRAY_AGG`,
+/// so the ordered-set aggregate `f(..) WITHIN GROUP (ORDER BY ..)` is rewritten into
+/// `f(.. ORDER BY ..)`, whose `ORDER BY` the binder only accepts from `PERCENTILE_CONT`.
+fn unwrap_within_group(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(5) {
+        let [r_paren, within, group, l_paren, order] =
+            [0, 1, 2, 3, 4].map(|i| &tokens[window[i]].token);
+
+        if r_paren == &Token::RParen
+            && is_keyword(within, &[Keyword::WITHIN])
+            && is_keyword(group, &[Keyword::GROUP])
+            && l_paren == &Token::LParen
+            && is_keyword(order, &[Keyword::ORDER])
+        {
+            edits.removes.extend(&window[..4]);
+        }
+    }
+}
+
+/// `sqlparser` cannot parse `BETWEEN [A]SYMMETRIC`, so the lower bound of
+/// `x BETWEEN SYMMETRIC a AND b` is wrapped into `x BETWEEN SYMMETRIC(a) AND b`,
+/// which is parsed as a call for the binder to unwrap, and `ASYMMETRIC` is dropped
+/// as it is the default.
+///
+/// The name of the call is quoted with backticks, which the PostgreSQL tokenizer never reads
+/// as an identifier, so a function named `symmetric` is still an ordinary lower bound,
+/// see `is_symmetric_bound`.
+fn wrap_between_symmetric(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(2) {
+        let [between, symmetric] = [0, 1].map(|i| &tokens[window[i]].token);
+
+        if !is_keyword(between, &[Keyword::BETWEEN]) {
+            continue;
+        }
+        if is_keyword(symmetric, &[Keyword::ASYMMETRIC]) {
+            edits.removes.insert(window[1]);
+            continue;
+        }
+        if !is_keyword(symmetric, &[Keyword::SYMMETRIC]) {
+            continue;
+        }
+        // `AND` binds looser than `BETWEEN`, so the first one outside of parentheses, `CASE`s and
+        // the `BETWEEN`s within the lower bound is its own
+        let mut depth = 0;
+        let mut betweens = 0;
+        for (and, token) in tokens.iter().enumerate().skip(window[1] + 1) {
+            match &token.token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                token if is_keyword(token, &[Keyword::CASE]) => depth += 1,
+                token if is_keyword(token, &[Keyword::END]) => depth -= 1,
+                token if depth == 0 && is_keyword(token, &[Keyword::BETWEEN]) => betweens += 1,
+                token if depth == 0 && betweens > 0 && is_keyword(token, &[Keyword::AND]) => {
+                    betweens -= 1
+                }
+                token if depth == 0 && is_keyword(token, &[Keyword::AND]) => {
+                    edits.removes.insert(window[1]);
+                    edits.insert(
+                        window[1],
+                        Token::Word(Word {
+                            value: SYMMETRIC_BOUND.to_string(),
+                            quote_style: Some('`'),
+                            keyword: Keyword::NoKeyword,
+                        }),
+                    );
+                    edits.insert(window[1] + 1, Token::LParen);
+                    edits.insert(and, Token::RParen);
+                    break;
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+const SYMMETRIC_BOUND: &str = "symmetric";
+
+/// Whether the name of a call is the one wrapping the lower bound of `BETWEEN SYMMETRIC`,
+/// see `wrap_between_symmetric`.
+pub(crate) fn is_symmetric_bound(ident: &Ident) -> bool {
+    ident.quote_style == Some('`') && ident.value == SYMMETRIC_BOUND
+}
+
+/// `sqlparser` parses the keywords `CURRENT_USER` and `SESSION_USER` without parentheses only,
+/// so the keyword of the ones called as `current_user()` is cleared to parse them as an ordinary
+/// function, which the binder resolves the same as the keyword.
+fn call_niladic_functions(tokens: &[TokenWithLocation], edits: &mut TokenEdits) {
+    for window in non_whitespaces(tokens).windows(2) {
+        if is_keyword(
+            &tokens[window[0]].token,
+            &[Keyword::CURRENT_USER, Keyword::SESSION_USER],
+        ) && tokens[window[1]].token == Token::LParen
+        {
+            edits.renames.push((window[0], Keyword::NoKeyword));
+        }
+    }
+}
+
+/// the predicate of a partial index runs to the end of its statement
+fn parse_index_predicate(tokens: Vec<TokenWithLocation>) -> Result<Expr, ParserError> {
+    let mut parser = Parser::new(&DIALECT).with_tokens_with_locations(tokens);
+    let predicate = parser.parse_expr()?;
+
+    match parser.peek_token() {
+        token if token.token == Token::EOF => Ok(predicate),
+        token => parser.expected("end of the index predicate", token),
+    }
+}
+
+/// `sqlparser` cannot parse the predicate of a partial index, so the `WHERE ..` after the columns
+/// of `CREATE INDEX i ON t (a) WHERE b IS NULL` is split out of the statement into the tokens of
+/// its predicate, one for each `CREATE INDEX` in order.
+fn split_partial_index_predicates(
+    tokens: Vec<TokenWithLocation>,
+) -> (Vec<TokenWithLocation>, Vec<Option<Vec<TokenWithLocation>>>) {
+    let non_whitespaces = non_whitespaces(&tokens);
+    // (the `WHERE` after the columns, the end of the predicate) of each `CREATE INDEX` in order
+    let mut predicates = Vec::new();
+
+    for (pos, i) in non_whitespaces.iter().enumerate() {
+        if !is_keyword(&tokens[*i].token, &[Keyword::CREATE]) {
+            continue;
+        }
+        let is_index = match non_whitespaces.get(pos + 1) {
+            Some(next) if is_keyword(&tokens[*next].token, &[Keyword::UNIQUE]) => non_whitespaces
+                .get(pos + 2)
+                .is_some_and(|next| is_keyword(&tokens[*next].token, &[Keyword::INDEX])),
+            Some(next) => is_keyword(&tokens[*next].token, &[Keyword::INDEX]),
+            None => false,
+        };
+        if !is_index {
+            continue;
+        }
+        predicates.push(None);
+        let mut depth = 0;
+        let mut r_paren = None;
+        for (j, token) in non_whitespaces[pos..]
+            .iter()
+            .map(|j| (*j, &tokens[*j].token))
+        {
+            match token {
+                Token::LParen => depth += 1,
+                Token::RParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        r_paren = Some(j);
+                        break;
+                    }
+                }
+                Token::SemiColon => break,
+                _ => (),
+            }
+        }
+        let Some(r_paren) = r_paren else {
+            continue;
+        };
+        let Some(r_pos) = non_whitespaces.iter().position(|j| *j == r_paren) else {
+            continue;
+        };
+        let Some(where_) = non_whitespaces
+            .get(r_pos + 1)
+            .filter(|j| is_keyword(&tokens[**j].token, &[Keyword::WHERE]))
+        else {
+            continue;
+        };
+        let mut depth = 0;
+        let mut end = tokens.len();
+        for (j, token) in tokens.iter().enumerate().skip(where_ + 1) {
+            match &token.token {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => {
+                    end = j;
+                    break;
+                }
+                _ => (),
+            }
+        }
+        *predicates.last_mut().unwrap() = Some((*where_, end));
+    }
+    if predicates.iter().all(Option::is_none) {
+        return (tokens, predicates.into_iter().map(|_| None).collect());
+    }
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut predicate_tokens = predicates
+        .iter()
+        .map(|predicate| predicate.map(|_| Vec::new()))
+        .collect_vec();
+
+    for (i, token) in tokens.into_iter().enumerate() {
+        match predicates.iter().position(
+            |predicate| matches!(predicate, Some((where_, end)) if (*where_..*end).contains(&i)),
+        ) {
+            Some(pos) if predicates[pos].is_some_and(|(where_, _)| where_ == i) => (),
+            Some(pos) => predicate_tokens[pos].as_mut().unwrap().push(token),
+            None => result.push(token),
+        }
+    }
+    (result, predicate_tokens)
 }
@@ -1,13 +1,21 @@
 pub mod operator;
 
 use crate::catalog::{ColumnCatalog, ColumnRef, TableName};
-use crate::planner::operator::join::JoinType;
+use crate::expression::ScalarExpression;
+use crate::planner::operator::except::ExceptOperator;
+use crate::planner::operator::intersect::IntersectOperator;
+use crate::planner::operator::join::{JoinCondition, JoinType};
+use crate::planner::operator::table_scan::TableScanOperator;
 use crate::planner::operator::union::UnionOperator;
 use crate::planner::operator::values::ValuesOperator;
+use crate::planner::operator::window::WindowCall;
 use crate::planner::operator::{Operator, PhysicalOption};
+use crate::setting::SettingsRef;
 use crate::types::tuple::{Schema, SchemaRef};
+use chrono::NaiveDateTime;
 use itertools::Itertools;
 use kite_sql_serde_macros::ReferenceSerialization;
+use std::iter;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -110,6 +118,58 @@ impl LogicalPlan {
         }
     }
 
+    /// Fixes the functions of the plan reading the clock at `now`,
+    /// see [`ScalarExpression::at_time`].
+    pub(crate) fn at_time(&mut self, now: NaiveDateTime) {
+        self.for_each_expr_mut(&mut |expr| expr.at_time(now))
+    }
+
+    /// Binds the functions of the plan reading or changing the session settings to `settings`,
+    /// see [`ScalarExpression::with_settings`].
+    pub(crate) fn with_settings(&mut self, settings: &SettingsRef) {
+        self.for_each_expr_mut(&mut |expr| expr.with_settings(settings))
+    }
+
+    fn for_each_expr_mut(&mut self, fn_apply: &mut impl FnMut(&mut ScalarExpression)) {
+        let exprs: Box<dyn Iterator<Item = &mut ScalarExpression> + '_> = match &mut self.operator {
+            Operator::Aggregate(op) => {
+                Box::new(op.groupby_exprs.iter_mut().chain(&mut op.agg_calls))
+            }
+            Operator::Filter(op) => Box::new(iter::once(&mut op.predicate)),
+            Operator::Join(op) => match &mut op.on {
+                JoinCondition::On { on, filter, .. } => Box::new(
+                    on.iter_mut()
+                        .flat_map(|(left_expr, right_expr)| [left_expr, right_expr])
+                        .chain(filter),
+                ),
+                JoinCondition::Dependent { params, filter } => Box::new(
+                    params
+                        .iter_mut()
+                        .map(|(outer_expr, _)| outer_expr)
+                        .chain(filter),
+                ),
+                JoinCondition::None => Box::new(iter::empty()),
+            },
+            Operator::Project(op) => Box::new(op.exprs.iter_mut()),
+            Operator::Sort(op) => Box::new(op.sort_fields.iter_mut().map(|field| &mut field.expr)),
+            Operator::Window(op) => Box::new(op.calls.iter_mut().flat_map(WindowCall::exprs_mut)),
+            Operator::FunctionScan(op) => Box::new(op.table_function.args.iter_mut()),
+            Operator::TableScan(op) => Box::new(op.projection.iter_mut().flatten()),
+            _ => Box::new(iter::empty()),
+        };
+        for expr in exprs {
+            fn_apply(expr);
+        }
+        match self.childrens.as_mut() {
+            Childrens::None => (),
+            Childrens::Only(child) => child.for_each_expr_mut(fn_apply),
+            Childrens::Twins { left, right } => {
+                left.for_each_expr_mut(fn_apply);
+                right.for_each_expr_mut(fn_apply);
+            }
+        }
+    }
+
     pub fn referenced_table(&self) -> Vec<TableName> {
         fn collect_table(plan: &LogicalPlan, results: &mut Vec<TableName>) {
             if let Operator::TableScan(op) = &plan.operator {
@@ -133,6 +193,18 @@ impl LogicalPlan {
             Operator::Filter(_) | Operator::Sort(_) | Operator::Limit(_) => {
                 childrens_iter.next().unwrap().output_schema_direct()
             }
+            Operator::Window(op) => {
+                let mut columns = childrens_iter
+                    .next()
+                    .unwrap()
+                    .output_schema_direct()
+                    .columns()
+                    .cloned()
+                    .collect_vec();
+                columns.extend(op.calls.iter().map(|call| call.output.clone()));
+
+                SchemaOutput::Schema(columns)
+            }
             Operator::Aggregate(op) => SchemaOutput::Schema(
                 op.agg_calls
                     .iter()
@@ -159,6 +231,10 @@ impl LogicalPlan {
                     .map(|expr| expr.output_column())
                     .collect_vec(),
             ),
+            Operator::TableScan(TableScanOperator {
+                projection: Some(exprs),
+                ..
+            }) => SchemaOutput::Schema(exprs.iter().map(|expr| expr.output_column()).collect_vec()),
             Operator::TableScan(op) => SchemaOutput::Schema(
                 op.columns
                     .iter()
@@ -172,6 +248,14 @@ impl LogicalPlan {
             | Operator::Union(UnionOperator {
                 left_schema_ref: schema_ref,
                 ..
+            })
+            | Operator::Except(ExceptOperator {
+                left_schema_ref: schema_ref,
+                ..
+            })
+            | Operator::Intersect(IntersectOperator {
+                left_schema_ref: schema_ref,
+                ..
             }) => SchemaOutput::SchemaRef(schema_ref.clone()),
             Operator::Dummy => SchemaOutput::Schema(vec![]),
             Operator::Show => SchemaOutput::Schema(vec![ColumnRef::from(
@@ -9,6 +9,9 @@ use std::fmt::Formatter;
 pub struct AggregateOperator {
     pub groupby_exprs: Vec<ScalarExpression>,
     pub agg_calls: Vec<ScalarExpression>,
+    /// the positions of the `groupby_exprs` grouped by each grouping set of `ROLLUP`, `CUBE` or
+    /// `GROUPING SETS`, empty when grouping by all the `groupby_exprs` at once
+    pub grouping_sets: Vec<Vec<usize>>,
     pub is_distinct: bool,
 }
 
@@ -17,12 +20,14 @@ impl AggregateOperator {
         children: LogicalPlan,
         agg_calls: Vec<ScalarExpression>,
         groupby_exprs: Vec<ScalarExpression>,
+        grouping_sets: Vec<Vec<usize>>,
         is_distinct: bool,
     ) -> LogicalPlan {
         LogicalPlan::new(
             Operator::Aggregate(Self {
                 groupby_exprs,
                 agg_calls,
+                grouping_sets,
                 is_distinct,
             }),
             Childrens::Only(children),
@@ -47,6 +52,19 @@ impl fmt::Display for AggregateOperator {
                 .join(", ");
             write!(f, " -> Group By [{}]", groupbys)?;
         }
+        if !self.grouping_sets.is_empty() {
+            let sets = self
+                .grouping_sets
+                .iter()
+                .map(|set| {
+                    format!(
+                        "({})",
+                        set.iter().map(|i| &self.groupby_exprs[*i]).join(", ")
+                    )
+                })
+                .join(", ");
+            write!(f, " -> Grouping Sets [{}]", sets)?;
+        }
 
         Ok(())
     }
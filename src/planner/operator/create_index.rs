@@ -1,4 +1,5 @@
 use crate::catalog::{ColumnRef, TableName};
+use crate::expression::ScalarExpression;
 use crate::types::index::IndexType;
 use itertools::Itertools;
 use kite_sql_serde_macros::ReferenceSerialization;
@@ -8,25 +9,35 @@ use std::fmt::Formatter;
 #[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
 pub struct CreateIndexOperator {
     pub table_name: TableName,
-    /// List of columns of the index
+    /// List of columns of the index, or the columns referenced by `expr`
     pub columns: Vec<ColumnRef>,
     pub index_name: String,
     pub if_not_exists: bool,
     pub ty: IndexType,
+    /// the indexed expression of an expression index
+    pub expr: Option<ScalarExpression>,
+    /// the predicate of a partial index, e.g. `WHERE a IS NULL`
+    pub predicate: Option<ScalarExpression>,
 }
 
 impl fmt::Display for CreateIndexOperator {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let columns = self
-            .columns
-            .iter()
-            .map(|column| column.name().to_string())
-            .join(", ");
+        let columns = if let Some(expr) = &self.expr {
+            format!("{}", expr)
+        } else {
+            self.columns
+                .iter()
+                .map(|column| column.name().to_string())
+                .join(", ")
+        };
         write!(
             f,
             "Create Index On {} -> [{}], If Not Exists: {}",
             self.table_name, columns, self.if_not_exists
         )?;
+        if let Some(predicate) = &self.predicate {
+            write!(f, ", Where: {}", predicate)?;
+        }
 
         Ok(())
     }
@@ -0,0 +1,56 @@
+use crate::planner::operator::Operator;
+use crate::planner::{Childrens, LogicalPlan};
+use crate::types::tuple::SchemaRef;
+use itertools::Itertools;
+use kite_sql_serde_macros::ReferenceSerialization;
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
+pub struct ExceptOperator {
+    pub left_schema_ref: SchemaRef,
+    // mainly use `left_schema` as output and `right_schema` for `column pruning`
+    pub _right_schema_ref: SchemaRef,
+    pub is_all: bool,
+}
+
+impl ExceptOperator {
+    pub fn build(
+        left_schema_ref: SchemaRef,
+        right_schema_ref: SchemaRef,
+        is_all: bool,
+        left_plan: LogicalPlan,
+        right_plan: LogicalPlan,
+    ) -> LogicalPlan {
+        LogicalPlan::new(
+            Operator::Except(ExceptOperator {
+                left_schema_ref,
+                _right_schema_ref: right_schema_ref,
+                is_all,
+            }),
+            Childrens::Twins {
+                left: left_plan,
+                right: right_plan,
+            },
+        )
+    }
+}
+
+impl fmt::Display for ExceptOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let schema = self
+            .left_schema_ref
+            .iter()
+            .map(|column| column.name().to_string())
+            .join(", ");
+
+        write!(
+            f,
+            "Except{}: [{}]",
+            if self.is_all { " All" } else { "" },
+            schema
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,13 +1,64 @@
-use crate::catalog::TableName;
+use crate::catalog::{ColumnCatalog, ColumnRef, TableCatalog, TableName};
+use crate::expression::ScalarExpression;
+use crate::types::index::IndexId;
+use itertools::Itertools;
 use kite_sql_serde_macros::ReferenceSerialization;
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::Arc;
+
+/// the name the `DO UPDATE` of `ON CONFLICT` refers to the row proposed for insertion by
+pub(crate) const EXCLUDED_TABLE: &str = "excluded";
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
 pub struct InsertOperator {
     pub table_name: TableName,
     pub is_overwrite: bool,
     pub is_mapping_by_name: bool,
+    /// the defaults of the columns of the table at the time of the statement, which fill the
+    /// columns omitted by the inserted rows, see [`ColumnCatalog::statement_default`]
+    ///
+    /// [`ColumnCatalog::statement_default`]: crate::catalog::ColumnCatalog::statement_default
+    pub defaults: Vec<Option<ScalarExpression>>,
+    pub on_conflict: Option<OnConflict>,
+}
+
+/// `ON CONFLICT (..) DO ..`, a row is in conflict when the primary key or the unique index
+/// `index_id` already holds its value
+#[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
+pub struct OnConflict {
+    pub index_id: IndexId,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
+pub enum OnConflictAction {
+    DoNothing,
+    /// the assignments and the predicate are evaluated on the existing row followed by the
+    /// row proposed for insertion, see [`OnConflict::excluded_schema`]
+    DoUpdate {
+        value_exprs: Vec<(ColumnRef, ScalarExpression)>,
+        predicate: Option<ScalarExpression>,
+    },
+}
+
+impl OnConflict {
+    /// The columns of `table` as the columns of the row proposed for insertion,
+    /// i.e. `excluded.*`
+    pub(crate) fn excluded_schema(table: &TableCatalog) -> Vec<ColumnRef> {
+        table
+            .columns()
+            .map(|column| {
+                let mut excluded_column = ColumnCatalog::clone(column);
+                excluded_column.set_ref_table(
+                    Arc::new(EXCLUDED_TABLE.to_string()),
+                    column.id().unwrap(),
+                    true,
+                );
+                ColumnRef::from(excluded_column)
+            })
+            .collect_vec()
+    }
 }
 
 impl fmt::Display for InsertOperator {
@@ -17,7 +68,25 @@ impl fmt::Display for InsertOperator {
             "Insert {}, Is Overwrite: {}, Is Mapping By Name: {}",
             self.table_name, self.is_overwrite, self.is_mapping_by_name
         )?;
+        if let Some(on_conflict) = &self.on_conflict {
+            write!(f, ", On Conflict: {}", on_conflict)?;
+        }
 
         Ok(())
     }
 }
+
+impl fmt::Display for OnConflict {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.action {
+            OnConflictAction::DoNothing => write!(f, "Do Nothing"),
+            OnConflictAction::DoUpdate { value_exprs, .. } => {
+                let values = value_exprs
+                    .iter()
+                    .map(|(column, expr)| format!("{} -> {}", column.full_name(), expr))
+                    .join(", ");
+                write!(f, "Do Update [{}]", values)
+            }
+        }
+    }
+}
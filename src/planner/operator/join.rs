@@ -1,4 +1,5 @@
 use super::Operator;
+use crate::catalog::ColumnRef;
 use crate::expression::ScalarExpression;
 use crate::planner::{Childrens, LogicalPlan};
 use itertools::Itertools;
@@ -23,6 +24,18 @@ pub enum JoinCondition {
         on: Vec<(ScalarExpression, ScalarExpression)>,
         /// Filters applied during join (non-equi conditions)
         filter: Option<ScalarExpression>,
+        /// Whether `NULL` join columns match each other, e.g. `a IS NOT DISTINCT FROM b`
+        null_equals_null: bool,
+    },
+    /// The right side is a correlated subquery executed once for each left tuple, with its
+    /// parameter columns bound to the values of the outer expressions, see [`Values`]
+    ///
+    /// [`Values`]: crate::planner::operator::values::ValuesOperator
+    Dependent {
+        /// Pairs of (outer expression, parameter column)
+        params: Vec<(ScalarExpression, ColumnRef)>,
+        /// Filters applied during join, only for `LeftSemi` and `LeftAnti`
+        filter: Option<ScalarExpression>,
     },
     None,
 }
@@ -74,11 +87,16 @@ impl fmt::Display for JoinOperator {
 impl fmt::Display for JoinCondition {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            JoinCondition::On { on, filter } => {
+            JoinCondition::On {
+                on,
+                filter,
+                null_equals_null,
+            } => {
                 if !on.is_empty() {
+                    let op = if *null_equals_null { "<=>" } else { "=" };
                     let on = on
                         .iter()
-                        .map(|(v1, v2)| format!("{} = {}", v1, v2))
+                        .map(|(v1, v2)| format!("{} {} {}", v1, op, v2))
                         .join(" AND ");
 
                     write!(f, " On {}", on)?;
@@ -87,6 +105,17 @@ impl fmt::Display for JoinCondition {
                     write!(f, " Where {}", filter)?;
                 }
             }
+            JoinCondition::Dependent { params, filter } => {
+                let params = params
+                    .iter()
+                    .map(|(expr, param)| format!("{} := {}", param.full_name(), expr))
+                    .join(", ");
+
+                write!(f, " Dependent {}", params)?;
+                if let Some(filter) = filter {
+                    write!(f, " Where {}", filter)?;
+                }
+            }
             JoinCondition::None => {
                 write!(f, " Nothing")?;
             }
@@ -10,9 +10,11 @@ pub mod delete;
 pub mod describe;
 pub mod drop_table;
 pub mod drop_view;
+pub mod except;
 pub mod filter;
 pub mod function_scan;
 pub mod insert;
+pub mod intersect;
 pub mod join;
 pub mod limit;
 pub mod project;
@@ -22,6 +24,7 @@ pub mod truncate;
 pub mod union;
 pub mod update;
 pub mod values;
+pub mod window;
 
 use self::{
     aggregate::AggregateOperator, alter_table::add_column::AddColumnOperator,
@@ -41,13 +44,16 @@ use crate::planner::operator::delete::DeleteOperator;
 use crate::planner::operator::describe::DescribeOperator;
 use crate::planner::operator::drop_table::DropTableOperator;
 use crate::planner::operator::drop_view::DropViewOperator;
+use crate::planner::operator::except::ExceptOperator;
 use crate::planner::operator::function_scan::FunctionScanOperator;
 use crate::planner::operator::insert::InsertOperator;
+use crate::planner::operator::intersect::IntersectOperator;
 use crate::planner::operator::join::JoinCondition;
 use crate::planner::operator::truncate::TruncateOperator;
 use crate::planner::operator::union::UnionOperator;
 use crate::planner::operator::update::UpdateOperator;
 use crate::planner::operator::values::ValuesOperator;
+use crate::planner::operator::window::WindowOperator;
 use crate::types::index::IndexInfo;
 use itertools::Itertools;
 use kite_sql_serde_macros::ReferenceSerialization;
@@ -65,12 +71,15 @@ pub enum Operator {
     TableScan(TableScanOperator),
     FunctionScan(FunctionScanOperator),
     Sort(SortOperator),
+    Window(WindowOperator),
     Limit(LimitOperator),
     Values(ValuesOperator),
     Show,
     Explain,
     Describe(DescribeOperator),
     Union(UnionOperator),
+    Except(ExceptOperator),
+    Intersect(IntersectOperator),
     // DML
     Insert(InsertOperator),
     Update(UpdateOperator),
@@ -132,18 +141,26 @@ impl Operator {
             ),
             Operator::Filter(_) | Operator::Join(_) => None,
             Operator::Project(op) => Some(op.exprs.clone()),
-            Operator::TableScan(op) => Some(
+            Operator::TableScan(op) => Some(op.projection.clone().unwrap_or_else(|| {
                 op.columns
                     .iter()
                     .cloned()
                     .map(|(_, column)| ScalarExpression::ColumnRef(column))
-                    .collect_vec(),
-            ),
-            Operator::Sort(_) | Operator::Limit(_) => None,
+                    .collect_vec()
+            })),
+            Operator::Sort(_) | Operator::Window(_) | Operator::Limit(_) => None,
             Operator::Values(ValuesOperator { schema_ref, .. })
             | Operator::Union(UnionOperator {
                 left_schema_ref: schema_ref,
                 ..
+            })
+            | Operator::Except(ExceptOperator {
+                left_schema_ref: schema_ref,
+                ..
+            })
+            | Operator::Intersect(IntersectOperator {
+                left_schema_ref: schema_ref,
+                ..
             }) => Some(
                 schema_ref
                     .iter()
@@ -191,7 +208,7 @@ impl Operator {
             Operator::Join(op) => {
                 let mut exprs = Vec::new();
 
-                if let JoinCondition::On { on, filter } = &op.on {
+                if let JoinCondition::On { on, filter, .. } = &op.on {
                     for (left_expr, right_expr) in on {
                         exprs.append(&mut left_expr.referenced_columns(only_column_ref));
                         exprs.append(&mut right_expr.referenced_columns(only_column_ref));
@@ -201,6 +218,14 @@ impl Operator {
                         exprs.append(&mut filter_expr.referenced_columns(only_column_ref));
                     }
                 }
+                if let JoinCondition::Dependent { params, filter } = &op.on {
+                    for (outer_expr, _) in params {
+                        exprs.append(&mut outer_expr.referenced_columns(only_column_ref));
+                    }
+                    if let Some(filter_expr) = filter {
+                        exprs.append(&mut filter_expr.referenced_columns(only_column_ref));
+                    }
+                }
                 exprs
             }
             Operator::Project(op) => op
@@ -226,10 +251,27 @@ impl Operator {
                 .map(|field| &field.expr)
                 .flat_map(|expr| expr.referenced_columns(only_column_ref))
                 .collect_vec(),
+            Operator::Window(op) => op
+                .calls
+                .iter()
+                .flat_map(|call| call.exprs())
+                .flat_map(|expr| expr.referenced_columns(only_column_ref))
+                .collect_vec(),
             Operator::Values(ValuesOperator { schema_ref, .. }) => Vec::clone(schema_ref),
             Operator::Union(UnionOperator {
                 left_schema_ref,
                 _right_schema_ref,
+                ..
+            })
+            | Operator::Except(ExceptOperator {
+                left_schema_ref,
+                _right_schema_ref,
+                ..
+            })
+            | Operator::Intersect(IntersectOperator {
+                left_schema_ref,
+                _right_schema_ref,
+                ..
             }) => left_schema_ref
                 .iter()
                 .chain(_right_schema_ref.iter())
@@ -269,6 +311,7 @@ impl fmt::Display for Operator {
             Operator::TableScan(op) => write!(f, "{}", op),
             Operator::FunctionScan(op) => write!(f, "{}", op),
             Operator::Sort(op) => write!(f, "{}", op),
+            Operator::Window(op) => write!(f, "{}", op),
             Operator::Limit(op) => write!(f, "{}", op),
             Operator::Values(op) => write!(f, "{}", op),
             Operator::Show => write!(f, "Show Tables"),
@@ -289,6 +332,8 @@ impl fmt::Display for Operator {
             Operator::CopyFromFile(op) => write!(f, "{}", op),
             Operator::CopyToFile(op) => write!(f, "{}", op),
             Operator::Union(op) => write!(f, "{}", op),
+            Operator::Except(op) => write!(f, "{}", op),
+            Operator::Intersect(op) => write!(f, "{}", op),
         }
     }
 }
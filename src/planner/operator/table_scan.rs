@@ -1,5 +1,6 @@
 use super::Operator;
 use crate::catalog::{ColumnRef, TableCatalog, TableName};
+use crate::expression::ScalarExpression;
 use crate::planner::{Childrens, LogicalPlan};
 use crate::storage::Bounds;
 use crate::types::index::IndexInfo;
@@ -20,6 +21,9 @@ pub struct TableScanOperator {
     // Support push down predicate.
     // If pre_where is simple predicate, for example:  a > 1 then can calculate directly when read data.
     pub(crate) index_infos: Vec<IndexInfo>,
+    // Support fusing projection.
+    // The scan outputs these expressions computed on `columns` instead of `columns` themselves.
+    pub(crate) projection: Option<Vec<ScalarExpression>>,
 }
 
 impl TableScanOperator {
@@ -41,6 +45,8 @@ impl TableScanOperator {
             .map(|meta| IndexInfo {
                 meta: meta.clone(),
                 range: None,
+                covered: false,
+                order: None,
             })
             .collect_vec();
 
@@ -51,6 +57,7 @@ impl TableScanOperator {
                 primary_keys,
                 columns,
                 limit: (None, None),
+                projection: None,
             }),
             Childrens::None,
         )
@@ -77,6 +84,11 @@ impl fmt::Display for TableScanOperator {
         if let Some(offset) = offset {
             write!(f, ", Offset: {}", offset)?;
         }
+        if let Some(exprs) = &self.projection {
+            let exprs = exprs.iter().map(|expr| format!("{}", expr)).join(", ");
+
+            write!(f, ", Projection [{}]", exprs)?;
+        }
 
         Ok(())
     }
@@ -11,12 +11,14 @@ pub struct UnionOperator {
     pub left_schema_ref: SchemaRef,
     // mainly use `left_schema` as output and `right_schema` for `column pruning`
     pub _right_schema_ref: SchemaRef,
+    pub is_all: bool,
 }
 
 impl UnionOperator {
     pub fn build(
         left_schema_ref: SchemaRef,
         right_schema_ref: SchemaRef,
+        is_all: bool,
         left_plan: LogicalPlan,
         right_plan: LogicalPlan,
     ) -> LogicalPlan {
@@ -24,6 +26,7 @@ impl UnionOperator {
             Operator::Union(UnionOperator {
                 left_schema_ref,
                 _right_schema_ref: right_schema_ref,
+                is_all,
             }),
             Childrens::Twins {
                 left: left_plan,
@@ -41,7 +44,12 @@ impl fmt::Display for UnionOperator {
             .map(|column| column.name().to_string())
             .join(", ");
 
-        write!(f, "Union: [{}]", schema)?;
+        write!(
+            f,
+            "Union{}: [{}]",
+            if self.is_all { " All" } else { "" },
+            schema
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,100 @@
+use crate::catalog::ColumnRef;
+use crate::expression::ScalarExpression;
+use crate::planner::operator::sort::SortField;
+use crate::planner::operator::Operator;
+use crate::planner::{Childrens, LogicalPlan};
+use itertools::Itertools;
+use kite_sql_serde_macros::ReferenceSerialization;
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, ReferenceSerialization)]
+pub enum WindowKind {
+    RowNumber,
+    /// the peers get the same rank, which leaves gaps after them
+    Rank,
+    /// the peers get the same rank without gaps
+    DenseRank,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
+pub struct WindowCall {
+    pub kind: WindowKind,
+    pub partition_by: Vec<ScalarExpression>,
+    pub order_by: Vec<SortField>,
+    /// the column of the results appended to the input
+    pub output: ColumnRef,
+}
+
+/// Appends the result column of each window function call to the tuples of its input.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, ReferenceSerialization)]
+pub struct WindowOperator {
+    pub calls: Vec<WindowCall>,
+}
+
+impl WindowOperator {
+    pub fn build(calls: Vec<WindowCall>, children: LogicalPlan) -> LogicalPlan {
+        LogicalPlan::new(
+            Operator::Window(WindowOperator { calls }),
+            Childrens::Only(children),
+        )
+    }
+}
+
+impl WindowCall {
+    pub fn exprs(&self) -> impl Iterator<Item = &ScalarExpression> {
+        self.partition_by
+            .iter()
+            .chain(self.order_by.iter().map(|field| &field.expr))
+    }
+
+    pub fn exprs_mut(&mut self) -> impl Iterator<Item = &mut ScalarExpression> {
+        self.partition_by
+            .iter_mut()
+            .chain(self.order_by.iter_mut().map(|field| &mut field.expr))
+    }
+}
+
+impl fmt::Display for WindowKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            WindowKind::RowNumber => write!(f, "row_number"),
+            WindowKind::Rank => write!(f, "rank"),
+            WindowKind::DenseRank => write!(f, "dense_rank"),
+        }
+    }
+}
+
+impl fmt::Display for WindowCall {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}() over (", self.kind)?;
+        if !self.partition_by.is_empty() {
+            let partition_by = self
+                .partition_by
+                .iter()
+                .map(|expr| format!("{}", expr))
+                .join(", ");
+            write!(f, "partition by {}", partition_by)?;
+            if !self.order_by.is_empty() {
+                write!(f, " ")?;
+            }
+        }
+        if !self.order_by.is_empty() {
+            let order_by = self
+                .order_by
+                .iter()
+                .map(|field| format!("{}", field))
+                .join(", ");
+            write!(f, "order by {}", order_by)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl fmt::Display for WindowOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let calls = self.calls.iter().map(|call| format!("{}", call)).join(", ");
+
+        write!(f, "Window [{}]", calls)
+    }
+}
@@ -9,6 +9,7 @@ pub(crate) mod test {
     use crate::serdes::{ReferenceSerialization, ReferenceTables};
     use crate::storage::rocksdb::RocksTransaction;
     use crate::types::value::DataValue;
+    use rust_decimal::Decimal;
     use std::io::{Cursor, Seek, SeekFrom};
 
     #[test]
@@ -17,7 +18,7 @@ pub(crate) mod test {
         let source_1 = DataValue::Int32(32);
         let source_2 = DataValue::Null;
         let source_3 = DataValue::Null;
-        let source_4 = DataValue::Null;
+        let source_4 = DataValue::Decimal(Decimal::new(15, 1));
         let source_5 = DataValue::Tuple(vec![DataValue::Null, DataValue::Int32(42)], false);
 
         let mut reference_tables = ReferenceTables::new();
@@ -0,0 +1,146 @@
+use crate::errors::DatabaseError;
+use crate::types::value::DataValue;
+use crate::types::LogicalType;
+use ahash::HashMap;
+use parking_lot::RwLock;
+use std::sync::{Arc, LazyLock};
+
+pub const FLOAT_PRECISION: &str = "float_precision";
+pub const GREATEST_LEAST_IGNORE_NULLS: &str = "greatest_least_ignore_nulls";
+pub const LENIENT_CAST: &str = "lenient_cast";
+
+pub(crate) static DEFAULT_SETTINGS: LazyLock<Settings> = LazyLock::new(Settings::default);
+
+/// Session variables, changed by `SET <name> = <value>` or `set_config(name, value, is_local)`
+///
+/// Tips: like PostgreSQL, names containing a `.` (e.g. `myapp.user`) are custom variables,
+/// which can hold any text
+#[derive(Debug, Default, Clone)]
+pub struct Settings {
+    float_precision: Option<usize>,
+    greatest_least_ignore_nulls: bool,
+    lenient_cast: bool,
+    customs: HashMap<String, String>,
+}
+
+impl Settings {
+    /// The number of digits after the decimal point when displaying floats,
+    /// `None` displays the shortest representation that round-trips
+    pub fn float_precision(&self) -> Option<usize> {
+        self.float_precision
+    }
+
+    /// Whether `greatest`/`least` skip their NULL arguments,
+    /// by default any NULL argument makes the result NULL
+    pub fn greatest_least_ignore_nulls(&self) -> bool {
+        self.greatest_least_ignore_nulls
+    }
+
+    /// Whether the `CAST`s of the select list yield NULL for the values they fail to convert
+    /// instead of aborting the query, the values coerced to NULL are counted by
+    /// [`ResultIter::coerced_nulls`](crate::db::ResultIter::coerced_nulls)
+    pub fn lenient_cast(&self) -> bool {
+        self.lenient_cast
+    }
+
+    /// Reads the setting of `name` as text, `None` if there is no such setting
+    pub fn get(&self, name: &str) -> Option<DataValue> {
+        let name = name.to_lowercase();
+
+        match name.as_str() {
+            FLOAT_PRECISION => Some(
+                self.float_precision
+                    .map(|precision| DataValue::from(precision.to_string()))
+                    .unwrap_or(DataValue::Null),
+            ),
+            GREATEST_LEAST_IGNORE_NULLS => Some(DataValue::from(
+                self.greatest_least_ignore_nulls.to_string(),
+            )),
+            LENIENT_CAST => Some(DataValue::from(self.lenient_cast.to_string())),
+            _ => self
+                .customs
+                .get(&name)
+                .map(|value| DataValue::from(value.clone())),
+        }
+    }
+
+    /// Changes the setting of `name`, `None` resets it to the default
+    pub fn set(&mut self, name: &str, value: Option<DataValue>) -> Result<(), DatabaseError> {
+        let name = name.to_lowercase();
+
+        match name.as_str() {
+            FLOAT_PRECISION => {
+                self.float_precision = value
+                    .map(|value| Self::unsigned(&name, value))
+                    .transpose()?
+                    .flatten();
+            }
+            GREATEST_LEAST_IGNORE_NULLS => {
+                self.greatest_least_ignore_nulls = value
+                    .map(|value| Self::boolean(&name, value))
+                    .transpose()?
+                    .flatten()
+                    .unwrap_or(false);
+            }
+            LENIENT_CAST => {
+                self.lenient_cast = value
+                    .map(|value| Self::boolean(&name, value))
+                    .transpose()?
+                    .flatten()
+                    .unwrap_or(false);
+            }
+            _ if name.contains('.') => match value.filter(|value| !value.is_null()) {
+                Some(value) => {
+                    self.customs.insert(name, value.to_string());
+                }
+                None => {
+                    self.customs.remove(&name);
+                }
+            },
+            _ => return Err(DatabaseError::SettingNotFound(name)),
+        }
+        Ok(())
+    }
+
+    fn boolean(name: &str, value: DataValue) -> Result<Option<bool>, DatabaseError> {
+        match value.clone().cast(&LogicalType::Boolean) {
+            Ok(DataValue::Null) => Ok(None),
+            Ok(DataValue::Boolean(value)) => Ok(Some(value)),
+            _ => Err(DatabaseError::InvalidValue(format!(
+                "{} must be a boolean: {}",
+                name, value
+            ))),
+        }
+    }
+
+    fn unsigned(name: &str, value: DataValue) -> Result<Option<usize>, DatabaseError> {
+        match value.clone().cast(&LogicalType::UInteger) {
+            Ok(DataValue::Null) => Ok(None),
+            Ok(DataValue::UInt32(value)) => Ok(Some(value as usize)),
+            _ => Err(DatabaseError::InvalidValue(format!(
+                "{} must be a non-negative integer: {}",
+                name, value
+            ))),
+        }
+    }
+}
+
+/// The settings of a session, shared with the functions bound within it,
+/// see [`ScalarFunctionImpl::with_settings`](crate::expression::function::scala::ScalarFunctionImpl::with_settings)
+#[derive(Debug, Default, Clone)]
+pub struct SettingsRef(Arc<RwLock<Arc<Settings>>>);
+
+impl SettingsRef {
+    /// A session of its own that starts with the current settings
+    pub(crate) fn fork(&self) -> Self {
+        SettingsRef(Arc::new(RwLock::new(self.snapshot())))
+    }
+
+    pub(crate) fn snapshot(&self) -> Arc<Settings> {
+        self.0.read().clone()
+    }
+
+    pub(crate) fn set(&self, name: &str, value: Option<DataValue>) -> Result<(), DatabaseError> {
+        Arc::make_mut(&mut self.0.write()).set(name, value)
+    }
+}
@@ -7,10 +7,12 @@ use crate::catalog::{
 };
 use crate::errors::DatabaseError;
 use crate::expression::range_detacher::Range;
+use crate::expression::ScalarExpression;
 use crate::optimizer::core::statistics_meta::{StatisticMetaLoader, StatisticsMeta};
+use crate::optimizer::core::table_statistics::TableStatistics;
 use crate::serdes::ReferenceTables;
-use crate::storage::table_codec::{BumpBytes, Bytes, TableCodec};
-use crate::types::index::{Index, IndexId, IndexMetaRef, IndexType};
+use crate::storage::table_codec::{BumpBytes, Bytes, TableCodec, STORAGE_VERSION};
+use crate::types::index::{Index, IndexId, IndexMeta, IndexMetaRef, IndexType};
 use crate::types::tuple::{Tuple, TupleId};
 use crate::types::value::DataValue;
 use crate::types::{ColumnId, LogicalType};
@@ -90,6 +92,7 @@ pub trait Transaction: Sized {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn read_by_index<'a>(
         &'a self,
         table_cache: &'a TableCache,
@@ -97,7 +100,8 @@ pub trait Transaction: Sized {
         (offset_option, limit_option): Bounds,
         columns: Vec<(usize, ColumnRef)>,
         index_meta: IndexMetaRef,
-        ranges: Vec<Range>,
+        mut ranges: Vec<Range>,
+        is_reverse: bool,
     ) -> Result<IndexIter<'a, Self>, DatabaseError> {
         debug_assert!(columns.is_sorted_by_key(|(i, _)| i));
         debug_assert!(columns.iter().map(|(i, _)| i).all_unique());
@@ -116,7 +120,12 @@ pub trait Transaction: Sized {
             tuple_columns.push(column);
             projections.push(projection);
         }
+        let covered_columns =
+            CoveredColumn::from_projections(&index_meta, pk_indices, &projections, &tuple_columns);
         let inner = IndexImplEnum::instance(index_meta.ty);
+        if is_reverse {
+            ranges.reverse();
+        }
 
         Ok(IndexIter {
             offset,
@@ -125,10 +134,12 @@ pub trait Transaction: Sized {
             params: IndexImplParams {
                 tuple_schema_ref: Arc::new(tuple_columns),
                 projections,
+                covered_columns,
                 index_meta,
                 table_name,
                 table_types,
                 tx: self,
+                is_reverse,
             },
             inner,
             ranges: ranges.into_iter(),
@@ -136,6 +147,7 @@ pub trait Transaction: Sized {
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_index_meta(
         &mut self,
         table_cache: &TableCache,
@@ -143,9 +155,11 @@ pub trait Transaction: Sized {
         index_name: String,
         column_ids: Vec<ColumnId>,
         ty: IndexType,
+        expr: Option<ScalarExpression>,
+        predicate: Option<ScalarExpression>,
     ) -> Result<IndexId, DatabaseError> {
         if let Some(mut table) = self.table(table_cache, table_name.clone())?.cloned() {
-            let index_meta = table.add_index_meta(index_name, column_ids, ty)?;
+            let index_meta = table.add_index_meta(index_name, column_ids, ty, expr, predicate)?;
             let (key, value) =
                 unsafe { &*self.table_codec() }.encode_index_meta(table_name, index_meta)?;
             self.set(key, value)?;
@@ -183,6 +197,19 @@ pub trait Transaction: Sized {
         Ok(())
     }
 
+    /// Writes the entries of `batch` in key order, so that a bulk insertion builds each index
+    /// sequentially instead of scattering its writes over it.
+    fn add_index_batch(&mut self, batch: IndexBatch) -> Result<(), DatabaseError> {
+        let mut entries = batch.entries;
+        entries.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+
+        for (key, value) in entries {
+            self.set(key, value)?;
+        }
+
+        Ok(())
+    }
+
     fn del_index(
         &mut self,
         table_name: &str,
@@ -226,6 +253,55 @@ pub trait Transaction: Sized {
         Ok(())
     }
 
+    /// The tuple of `table` with the primary key `tuple_id`, with all the columns
+    fn get_tuple(
+        &self,
+        table: &TableCatalog,
+        tuple_id: &TupleId,
+    ) -> Result<Option<Tuple>, DatabaseError> {
+        let key = unsafe { &*self.table_codec() }.encode_tuple_key(&table.name, tuple_id)?;
+        let projections = (0..table.columns_len()).collect_vec();
+
+        self.get(&key)?
+            .map(|bytes| {
+                TableCodec::decode_tuple(
+                    &table.types(),
+                    table.primary_keys_indices(),
+                    &projections,
+                    table.schema_ref(),
+                    &bytes,
+                )
+            })
+            .transpose()
+    }
+
+    /// The primary key of the stored tuple holding the value of `index`, which is of the primary
+    /// key or a unique index, `tuple_id` being the primary key of the tuple looking for it
+    fn unique_tuple_id(
+        &self,
+        table_name: &str,
+        index: &Index,
+        tuple_id: &TupleId,
+    ) -> Result<Option<TupleId>, DatabaseError> {
+        let table_codec = unsafe { &*self.table_codec() };
+
+        match index.ty {
+            IndexType::PrimaryKey { .. } => {
+                let key = table_codec.encode_tuple_key(table_name, tuple_id)?;
+
+                Ok(self.get(&key)?.map(|_| tuple_id.clone()))
+            }
+            IndexType::Unique => {
+                let key = table_codec.encode_index_key(table_name, index, None)?;
+
+                self.get(&key)?
+                    .map(|bytes| TableCodec::decode_index(&bytes).map(|(tuple_id, _)| tuple_id))
+                    .transpose()
+            }
+            IndexType::Normal | IndexType::Composite => Err(DatabaseError::InvalidIndex),
+        }
+    }
+
     fn add_column(
         &mut self,
         table_cache: &TableCache,
@@ -234,7 +310,7 @@ pub trait Transaction: Sized {
         if_not_exists: bool,
     ) -> Result<ColumnId, DatabaseError> {
         if let Some(mut table) = self.table(table_cache, table_name.clone())?.cloned() {
-            if !column.nullable() && column.default_value()?.is_none() {
+            if !column.nullable() && column.default_value(None, None)?.is_none() {
                 return Err(DatabaseError::NeedNullAbleOrDefault);
             }
 
@@ -255,6 +331,8 @@ pub trait Transaction: Sized {
                     format!("uk_{}", column.name()),
                     vec![col_id],
                     IndexType::Unique,
+                    None,
+                    None,
                 )?;
                 let (key, value) =
                     unsafe { &*self.table_codec() }.encode_index_meta(table_name, meta_ref)?;
@@ -444,6 +522,7 @@ pub trait Transaction: Sized {
         let (statistics_min, statistics_max) =
             unsafe { &*self.table_codec() }.statistics_bound(table_name);
         self._drop_data(statistics_min, statistics_max)?;
+        self.remove(&unsafe { &*self.table_codec() }.encode_table_statistics_key(table_name))?;
 
         Ok(())
     }
@@ -544,6 +623,23 @@ pub trait Transaction: Sized {
         Ok(())
     }
 
+    fn save_table_statistics(&mut self, statistics: &TableStatistics) -> Result<(), DatabaseError> {
+        let (key, value) = unsafe { &*self.table_codec() }.encode_table_statistics(statistics)?;
+        self.set(key, value)?;
+
+        Ok(())
+    }
+
+    fn table_statistics(&self, table_name: &str) -> Result<Option<TableStatistics>, DatabaseError>
+    where
+        Self: Sized,
+    {
+        let key = unsafe { &*self.table_codec() }.encode_table_statistics_key(table_name);
+        self.get(&key)?
+            .map(|bytes| TableCodec::decode_table_statistics::<Self>(&bytes))
+            .transpose()
+    }
+
     fn meta_loader<'a>(
         &'a self,
         meta_cache: &'a StatisticsMetaCache,
@@ -602,6 +698,105 @@ pub trait Transaction: Sized {
         Ok(())
     }
 
+    /// Rewrites the data written by an older storage version into the current format.
+    fn upgrade(&mut self, table_cache: &TableCache) -> Result<(), DatabaseError> {
+        let version = self
+            .get(&unsafe { &*self.table_codec() }.encode_version_key())?
+            .map(|bytes| TableCodec::decode_version(&bytes))
+            .transpose()?
+            .unwrap_or(0);
+
+        if version == STORAGE_VERSION {
+            return Ok(());
+        }
+        if version > STORAGE_VERSION {
+            return Err(DatabaseError::InvalidValue(format!(
+                "storage version {} is newer than the supported version {}",
+                version, STORAGE_VERSION
+            )));
+        }
+        // the index metas are read by every table, so they are rewritten first
+        if version < 2 {
+            for TableMeta { table_name } in self.table_metas()? {
+                self.rewrite_index_metas_v1(&table_name)?;
+            }
+        }
+        if version < 1 {
+            for TableMeta { table_name } in self.table_metas()? {
+                self.rebuild_single_column_indexes(table_cache, table_name)?;
+            }
+        }
+        let (key, value) = unsafe { &*self.table_codec() }.encode_version();
+        self.set(key, value)
+    }
+
+    fn rewrite_index_metas_v1(&mut self, table_name: &str) -> Result<(), DatabaseError> {
+        let (min, max) = unsafe { &*self.table_codec() }.index_meta_bound(table_name);
+        let mut iter = self.range(Bound::Included(min), Bound::Included(max))?;
+        let mut index_metas = Vec::new();
+
+        while let Some((_, value)) = iter.try_next()? {
+            index_metas.push(TableCodec::decode_index_meta_v1::<Self>(&value)?);
+        }
+        drop(iter);
+
+        for index_meta in index_metas {
+            let (key, value) =
+                unsafe { &*self.table_codec() }.encode_index_meta(table_name, &index_meta)?;
+            self.set(key, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn rebuild_single_column_indexes(
+        &mut self,
+        table_cache: &TableCache,
+        table_name: TableName,
+    ) -> Result<(), DatabaseError> {
+        let table = self
+            .table(table_cache, table_name.clone())?
+            .cloned()
+            .ok_or(DatabaseError::TableNotFound)?;
+
+        for index_meta in table.indexes() {
+            if matches!(index_meta.ty, IndexType::PrimaryKey { .. })
+                || index_meta.column_ids.len() != 1
+            {
+                continue;
+            }
+            let (min, max) =
+                unsafe { &*self.table_codec() }.index_bound(&table_name, &index_meta.id)?;
+            self._drop_data(min, max)?;
+
+            let columns = table.schema_ref().iter().cloned().enumerate().collect_vec();
+            let Some(position) = columns
+                .iter()
+                .position(|(_, column)| column.id() == Some(index_meta.column_ids[0]))
+            else {
+                continue;
+            };
+            let mut iter = self.read(table_cache, table_name.clone(), (None, None), columns)?;
+            let mut entries = Vec::new();
+
+            while let Some(mut tuple) = iter.next_tuple()? {
+                let tuple_id = tuple
+                    .id()
+                    .cloned()
+                    .ok_or(DatabaseError::PrimaryKeyNotFound)?;
+                entries.push((tuple_id, tuple.values.swap_remove(position)));
+            }
+            drop(iter);
+
+            for (tuple_id, value) in entries {
+                let index = Index::new(index_meta.id, &value, index_meta.ty);
+                self.add_index(&table_name, index, &tuple_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn create_index_meta_from_column(
         &mut self,
         table: &mut TableCatalog,
@@ -620,8 +815,13 @@ pub trait Transaction: Sized {
             } else {
                 continue;
             };
-            let meta_ref =
-                table.add_index_meta(format!("uk_{}_index", col.name()), vec![col_id], index_ty)?;
+            let meta_ref = table.add_index_meta(
+                format!("uk_{}_index", col.name()),
+                vec![col_id],
+                index_ty,
+                None,
+                None,
+            )?;
             let (key, value) =
                 unsafe { &*self.table_codec() }.encode_index_meta(&table_name, meta_ref)?;
             self.set(key, value)?;
@@ -634,7 +834,13 @@ pub trait Transaction: Sized {
         let pk_index_ty = IndexType::PrimaryKey {
             is_multiple: primary_keys.len() != 1,
         };
-        let meta_ref = table.add_index_meta("pk_index".to_string(), primary_keys, pk_index_ty)?;
+        let meta_ref = table.add_index_meta(
+            "pk_index".to_string(),
+            primary_keys,
+            pk_index_ty,
+            None,
+            None,
+        )?;
         let (key, value) =
             unsafe { &*self.table_codec() }.encode_index_meta(&table_name, meta_ref)?;
         self.set(key, value)?;
@@ -654,9 +860,57 @@ pub trait Transaction: Sized {
         max: Bound<BumpBytes<'a>>,
     ) -> Result<Self::IterType<'a>, DatabaseError>;
 
+    /// Same as [`Transaction::range`], but yields the keys from the largest to the smallest
+    fn rev_range<'a>(
+        &'a self,
+        min: Bound<BumpBytes<'a>>,
+        max: Bound<BumpBytes<'a>>,
+    ) -> Result<Self::IterType<'a>, DatabaseError>;
+
+    /// Record the uncommitted writes so far, savepoints are kept as a stack
+    fn set_savepoint(&mut self);
+
+    /// Undo the writes since the most recent savepoint and remove it
+    fn rollback_to_savepoint(&mut self) -> Result<(), DatabaseError>;
+
     fn commit(self) -> Result<(), DatabaseError>;
 }
 
+/// The secondary index entries encoded by a bulk insertion, see [`Transaction::add_index_batch`]
+#[derive(Default)]
+pub struct IndexBatch<'a> {
+    entries: Vec<(BumpBytes<'a>, BumpBytes<'a>)>,
+}
+
+impl IndexBatch<'_> {
+    pub(crate) const CAPACITY: usize = 8192;
+
+    /// Tips: unique entries are written at once by `add_index`, so that a duplicated value is
+    /// rejected before its row is appended
+    pub(crate) fn push<T: Transaction>(
+        &mut self,
+        transaction: &mut T,
+        table_name: &str,
+        index: Index,
+        tuple_id: &TupleId,
+    ) -> Result<(), DatabaseError> {
+        match index.ty {
+            IndexType::PrimaryKey { .. } => return Ok(()),
+            IndexType::Unique => return transaction.add_index(table_name, index, tuple_id),
+            _ => (),
+        }
+        let (key, value) =
+            unsafe { &*transaction.table_codec() }.encode_index(table_name, &index, tuple_id)?;
+        self.entries.push((key, value));
+
+        Ok(())
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.entries.len() >= Self::CAPACITY
+    }
+}
+
 trait IndexImpl<'bytes, T: Transaction + 'bytes> {
     fn index_lookup(
         &self,
@@ -703,17 +957,72 @@ struct UniqueIndexImpl;
 struct NormalIndexImpl;
 struct CompositeIndexImpl;
 
+/// Where a projected column is read from when the index entry covers the scan
+#[derive(Debug, Clone, Copy)]
+enum CoveredColumn {
+    /// the position in the indexed values
+    Index(usize),
+    /// the position in the primary key
+    PrimaryKey(usize),
+}
+
+impl CoveredColumn {
+    /// `None` if any projected column is neither indexed nor part of the primary key
+    fn from_projections(
+        index_meta: &IndexMeta,
+        pk_indices: &PrimaryKeyIndices,
+        projections: &[usize],
+        columns: &[ColumnRef],
+    ) -> Option<Vec<CoveredColumn>> {
+        if matches!(index_meta.ty, IndexType::PrimaryKey { .. }) || index_meta.expr.is_some() {
+            return None;
+        }
+        projections
+            .iter()
+            .zip(columns)
+            .map(|(projection, column)| {
+                let column_id = column.id();
+
+                index_meta
+                    .column_ids
+                    .iter()
+                    .position(|id| Some(*id) == column_id)
+                    .map(CoveredColumn::Index)
+                    .or_else(|| {
+                        pk_indices
+                            .iter()
+                            .position(|i| i == projection)
+                            .map(CoveredColumn::PrimaryKey)
+                    })
+            })
+            .collect()
+    }
+
+    fn read(&self, index_value: &DataValue, tuple_id: &TupleId) -> DataValue {
+        let (value, i) = match self {
+            CoveredColumn::Index(i) => (index_value, *i),
+            CoveredColumn::PrimaryKey(i) => (tuple_id, *i),
+        };
+        match value {
+            DataValue::Tuple(values, _) => values[i].clone(),
+            value => value.clone(),
+        }
+    }
+}
+
 struct IndexImplParams<'a, T: Transaction> {
     tuple_schema_ref: Arc<Vec<ColumnRef>>,
     projections: Vec<usize>,
+    covered_columns: Option<Vec<CoveredColumn>>,
 
     index_meta: IndexMetaRef,
     table_name: &'a str,
     table_types: Vec<LogicalType>,
     tx: &'a T,
+    is_reverse: bool,
 }
 
-impl<T: Transaction> IndexImplParams<'_, T> {
+impl<'a, T: Transaction> IndexImplParams<'a, T> {
     #[inline]
     pub(crate) fn value_ty(&self) -> &LogicalType {
         &self.index_meta.value_ty
@@ -724,6 +1033,18 @@ impl<T: Transaction> IndexImplParams<'_, T> {
         self.tx.table_codec()
     }
 
+    fn range(
+        &self,
+        min: Bound<BumpBytes<'a>>,
+        max: Bound<BumpBytes<'a>>,
+    ) -> Result<T::IterType<'a>, DatabaseError> {
+        if self.is_reverse {
+            self.tx.rev_range(min, max)
+        } else {
+            self.tx.range(min, max)
+        }
+    }
+
     pub(crate) fn try_cast(&self, mut val: DataValue) -> Result<DataValue, DatabaseError> {
         let value_ty = self.value_ty();
 
@@ -826,6 +1147,10 @@ impl<'bytes, T: Transaction + 'bytes> IndexImpl<'bytes, T> for PrimaryKeyIndexIm
         pk_indices: &PrimaryKeyIndices,
         params: &IndexImplParams<'a, T>,
     ) -> Result<IndexResult<'a, T>, DatabaseError> {
+        // primary keys are never NULL
+        if value.is_null() {
+            return Ok(IndexResult::Tuple(None));
+        }
         let tuple = params
             .tx
             .get(&unsafe { &*params.table_codec() }.encode_tuple_key(params.table_name, value)?)?
@@ -857,7 +1182,17 @@ fn secondary_index_lookup<T: Transaction>(
     pk_indices: &PrimaryKeyIndices,
     params: &IndexImplParams<T>,
 ) -> Result<Tuple, DatabaseError> {
-    let tuple_id = TableCodec::decode_index(bytes)?;
+    let (tuple_id, index_value) = TableCodec::decode_index(bytes)?;
+
+    // a covering index answers from its entry without reading the tuple
+    if let (Some(covered_columns), Some(index_value)) = (&params.covered_columns, index_value) {
+        let values = covered_columns
+            .iter()
+            .map(|column| column.read(&index_value, &tuple_id))
+            .collect_vec();
+
+        return Ok(Tuple::new(Some(pk_indices.clone()), values));
+    }
     params
         .get_tuple_by_id(pk_indices, &tuple_id)?
         .ok_or(DatabaseError::TupleIdNotFound(tuple_id))
@@ -882,10 +1217,7 @@ impl<'bytes, T: Transaction + 'bytes> IndexImpl<'bytes, T> for UniqueIndexImpl {
         let Some(bytes) = params.tx.get(&self.bound_key(params, value, false)?)? else {
             return Ok(IndexResult::Tuple(None));
         };
-        let tuple_id = TableCodec::decode_index(&bytes)?;
-        let tuple = params
-            .get_tuple_by_id(pk_indices, &tuple_id)?
-            .ok_or(DatabaseError::TupleIdNotFound(tuple_id))?;
+        let tuple = secondary_index_lookup(&bytes, pk_indices, params)?;
         Ok(IndexResult::Tuple(Some(tuple)))
     }
 
@@ -920,9 +1252,7 @@ impl<'bytes, T: Transaction + 'bytes> IndexImpl<'bytes, T> for NormalIndexImpl {
         let min = self.bound_key(params, value, false)?;
         let max = self.bound_key(params, value, true)?;
 
-        let iter = params
-            .tx
-            .range(Bound::Included(min), Bound::Included(max))?;
+        let iter = params.range(Bound::Included(min), Bound::Included(max))?;
         Ok(IndexResult::Scope(iter))
     }
 
@@ -961,9 +1291,7 @@ impl<'bytes, T: Transaction + 'bytes> IndexImpl<'bytes, T> for CompositeIndexImp
         let min = self.bound_key(params, value, false)?;
         let max = self.bound_key(params, value, true)?;
 
-        let iter = params
-            .tx
-            .range(Bound::Included(min), Bound::Included(max))?;
+        let iter = params.range(Bound::Included(min), Bound::Included(max))?;
         Ok(IndexResult::Scope(iter))
     }
 
@@ -1085,9 +1413,19 @@ impl<T: Transaction> Iter for IndexIter<'_, T> {
                         continue;
                     };
                     match binary {
-                        Range::Scope { min, max } => {
+                        Range::Scope { mut min, max } => {
                             let table_name = self.params.table_name;
                             let index_meta = &self.params.index_meta;
+                            let is_null = |bound: &Bound<DataValue>| matches!(bound, Bound::Included(val) | Bound::Excluded(val) if val.is_null());
+                            // primary keys are never NULL, which sorts before any other value
+                            if matches!(index_meta.ty, IndexType::PrimaryKey { .. }) {
+                                if is_null(&max) {
+                                    continue;
+                                }
+                                if is_null(&min) {
+                                    min = Bound::Unbounded;
+                                }
+                            }
                             let bound_encode =
                                 |bound: Bound<DataValue>,
                                  is_upper: bool|
@@ -1127,20 +1465,21 @@ impl<T: Transaction> Iter for IndexIter<'_, T> {
                             let mut encode_max = bound_encode(max, true)?;
                             check_bound(&mut encode_max, bound_max);
 
-                            let iter = self.params.tx.range(encode_min, encode_max)?;
+                            let iter = self.params.range(encode_min, encode_max)?;
                             self.state = IndexIterState::Range(iter);
                         }
                         Range::Eq(mut val) => {
                             val = self.params.try_cast(val)?;
 
                             match self.inner.eq_to_res(&val, self.pk_indices, &self.params)? {
-                                IndexResult::Tuple(tuple) => {
+                                IndexResult::Tuple(Some(tuple)) => {
                                     if Self::offset_move(&mut self.offset) {
                                         continue;
                                     }
                                     Self::limit_sub(&mut self.limit);
-                                    return Ok(tuple);
+                                    return Ok(Some(tuple));
                                 }
+                                IndexResult::Tuple(None) => (),
                                 IndexResult::Scope(iter) => {
                                     self.state = IndexIterState::Range(iter);
                                 }
@@ -1186,11 +1525,13 @@ mod test {
     use crate::errors::DatabaseError;
     use crate::expression::range_detacher::Range;
     use crate::storage::rocksdb::{RocksStorage, RocksTransaction};
+    use crate::storage::table_codec::Bytes;
     use crate::storage::table_codec::TableCodec;
     use crate::storage::{
-        IndexIter, InnerIter, Iter, StatisticsMetaCache, Storage, TableCache, Transaction,
+        IndexBatch, IndexIter, InnerIter, Iter, StatisticsMetaCache, Storage, TableCache,
+        Transaction,
     };
-    use crate::types::index::{Index, IndexMeta, IndexType};
+    use crate::types::index::{Index, IndexId, IndexMeta, IndexType};
     use crate::types::tuple::Tuple;
     use crate::types::value::DataValue;
     use crate::types::{ColumnId, LogicalType};
@@ -1457,6 +1798,8 @@ mod test {
             "i1".to_string(),
             vec![c3_column_id],
             IndexType::Normal,
+            None,
+            None,
         )?;
         let _ = transaction.add_index_meta(
             &table_cache,
@@ -1464,6 +1807,8 @@ mod test {
             "i2".to_string(),
             vec![c3_column_id, c2_column_id],
             IndexType::Composite,
+            None,
+            None,
         )?;
 
         let fn_assert = |transaction: &mut RocksTransaction,
@@ -1532,11 +1877,14 @@ mod test {
                     value_ty: LogicalType::Integer,
                     name: "i1".to_string(),
                     ty: IndexType::Normal,
+                    expr: None,
+                    predicate: None,
                 }),
                 vec![Range::Scope {
                     min: Bound::Unbounded,
                     max: Bound::Unbounded,
                 }],
+                false,
             )
         }
 
@@ -1558,6 +1906,8 @@ mod test {
             "i1".to_string(),
             vec![c3_column_id],
             IndexType::Normal,
+            None,
+            None,
         )?;
 
         let tuples = build_tuples();
@@ -1627,6 +1977,243 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_index_batch() -> Result<(), DatabaseError> {
+        fn index_entries<T: Transaction>(
+            transaction: &T,
+            index_id: IndexId,
+        ) -> Result<Vec<(Bytes, Bytes)>, DatabaseError> {
+            let table_codec = TableCodec::default();
+            let (min, max) = table_codec.index_bound("t1", &index_id)?;
+            let mut iter = transaction.range(Bound::Included(min), Bound::Included(max))?;
+            let mut entries = Vec::new();
+
+            while let Some(entry) = iter.try_next()? {
+                entries.push(entry);
+            }
+            Ok(entries)
+        }
+
+        let tuples = build_tuples();
+        let mut entries = Vec::with_capacity(2);
+
+        // the entries built in bulk are the same as the ones added one by one
+        for is_batch in [false, true] {
+            let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+            let storage = RocksStorage::new(temp_dir.path())?;
+            let mut transaction = storage.transaction()?;
+            let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+            build_table(&table_cache, &mut transaction)?;
+            let c3_column_id = *transaction
+                .table(&table_cache, Arc::new("t1".to_string()))?
+                .unwrap()
+                .get_column_id_by_name("c3")
+                .unwrap();
+            let index_id = transaction.add_index_meta(
+                &table_cache,
+                &Arc::new("t1".to_string()),
+                "i1".to_string(),
+                vec![c3_column_id],
+                IndexType::Normal,
+                None,
+                None,
+            )?;
+            let mut batch = IndexBatch::default();
+
+            for tuple in tuples.iter().rev() {
+                let index = Index::new(index_id, &tuple.values[2], IndexType::Normal);
+                let tuple_id = tuple.values[0].clone();
+
+                if is_batch {
+                    batch.push(&mut transaction, "t1", index, &tuple_id)?;
+                } else {
+                    transaction.add_index("t1", index, &tuple_id)?;
+                }
+            }
+            transaction.add_index_batch(batch)?;
+            entries.push(index_entries(&transaction, index_id)?);
+        }
+        assert_eq!(entries[0].len(), 3);
+        assert_eq!(entries[0], entries[1]);
+
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let storage = RocksStorage::new(temp_dir.path())?;
+        let mut transaction = storage.transaction()?;
+        let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+        build_table(&table_cache, &mut transaction)?;
+        let c3_column_id = *transaction
+            .table(&table_cache, Arc::new("t1".to_string()))?
+            .unwrap()
+            .get_column_id_by_name("c3")
+            .unwrap();
+        let index_id = transaction.add_index_meta(
+            &table_cache,
+            &Arc::new("t1".to_string()),
+            "u1".to_string(),
+            vec![c3_column_id],
+            IndexType::Unique,
+            None,
+            None,
+        )?;
+        let mut batch = IndexBatch::default();
+
+        // unique entries are checked against the stored ones when pushed
+        for tuple in [&tuples[0], &tuples[1], &tuples[0]] {
+            let index = Index::new(index_id, &tuple.values[2], IndexType::Unique);
+            batch.push(&mut transaction, "t1", index, &tuple.values[0])?;
+        }
+        // `tuples[0]` and `tuples[2]` share the same value of `c3`
+        let index = Index::new(index_id, &tuples[2].values[2], IndexType::Unique);
+        assert!(matches!(
+            batch.push(&mut transaction, "t1", index, &tuples[2].values[0]),
+            Err(DatabaseError::DuplicateUniqueValue)
+        ));
+        transaction.add_index_batch(batch)?;
+        assert_eq!(index_entries(&transaction, index_id)?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_covering_index_read() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let storage = RocksStorage::new(temp_dir.path())?;
+        let mut transaction = storage.transaction()?;
+        let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+        build_table(&table_cache, &mut transaction)?;
+        let t1_table = transaction
+            .table(&table_cache, Arc::new("t1".to_string()))?
+            .unwrap()
+            .clone();
+        let c2_column_id = *t1_table.get_column_id_by_name("c2").unwrap();
+        let c3_column_id = *t1_table.get_column_id_by_name("c3").unwrap();
+        let column_ids = vec![c3_column_id, c2_column_id];
+
+        let index_id = transaction.add_index_meta(
+            &table_cache,
+            &Arc::new("t1".to_string()),
+            "i2".to_string(),
+            column_ids.clone(),
+            IndexType::Composite,
+            None,
+            None,
+        )?;
+        let tuples = build_tuples();
+        for tuple in tuples.iter() {
+            let value = DataValue::Tuple(
+                vec![tuple.values[2].clone(), tuple.values[1].clone()],
+                false,
+            );
+            transaction.add_index(
+                "t1",
+                Index::new(index_id, &value, IndexType::Composite),
+                &tuple.values[0],
+            )?;
+        }
+        // only the index entries are left, so the reads below never touch a tuple
+        for tuple in tuples.iter() {
+            transaction.remove_tuple("t1", &tuple.values[0])?;
+        }
+        let columns = t1_table
+            .columns()
+            .enumerate()
+            .filter(|(i, _)| *i != 1)
+            .map(|(i, column)| (i, column.clone()))
+            .collect::<Vec<_>>();
+        let mut index_iter = transaction.read_by_index(
+            &table_cache,
+            Arc::new("t1".to_string()),
+            (None, None),
+            columns,
+            Arc::new(IndexMeta {
+                id: index_id,
+                column_ids,
+                table_name: Arc::new("t1".to_string()),
+                pk_ty: LogicalType::Integer,
+                value_ty: LogicalType::Tuple(vec![LogicalType::Integer, LogicalType::Boolean]),
+                name: "i2".to_string(),
+                ty: IndexType::Composite,
+                expr: None,
+                predicate: None,
+            }),
+            vec![Range::Scope {
+                min: Bound::Included(DataValue::Tuple(vec![DataValue::Int32(0)], false)),
+                max: Bound::Included(DataValue::Tuple(vec![DataValue::Int32(0)], true)),
+            }],
+            false,
+        )?;
+
+        assert_eq!(
+            index_iter.next_tuple()?.unwrap().values,
+            vec![DataValue::Int32(2), DataValue::Int32(0)]
+        );
+        assert_eq!(
+            index_iter.next_tuple()?.unwrap().values,
+            vec![DataValue::Int32(0), DataValue::Int32(0)]
+        );
+        assert!(index_iter.next_tuple()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_primary_key_read_missing_and_null() -> Result<(), DatabaseError> {
+        let temp_dir = TempDir::new().expect("unable to create temporary working directory");
+        let storage = RocksStorage::new(temp_dir.path())?;
+        let mut transaction = storage.transaction()?;
+        let table_cache = Arc::new(SharedLruCache::new(4, 1, RandomState::new())?);
+
+        build_table(&table_cache, &mut transaction)?;
+        let tuples = build_tuples();
+        for tuple in tuples.iter().cloned() {
+            transaction.append_tuple(
+                "t1",
+                tuple,
+                &[
+                    LogicalType::Integer,
+                    LogicalType::Boolean,
+                    LogicalType::Integer,
+                ],
+                false,
+            )?;
+        }
+        let pk_index_meta = transaction
+            .table(&table_cache, Arc::new("t1".to_string()))?
+            .unwrap()
+            .indexes[0]
+            .clone();
+        let mut index_iter = transaction.read_by_index(
+            &table_cache,
+            Arc::new("t1".to_string()),
+            (None, None),
+            full_columns(),
+            pk_index_meta,
+            vec![
+                Range::Eq(DataValue::Null),
+                Range::Eq(DataValue::Int32(5)),
+                Range::Eq(DataValue::Int32(2)),
+                Range::Scope {
+                    min: Bound::Unbounded,
+                    max: Bound::Included(DataValue::Null),
+                },
+                Range::Scope {
+                    min: Bound::Included(DataValue::Null),
+                    max: Bound::Excluded(DataValue::Int32(1)),
+                },
+            ],
+            false,
+        )?;
+
+        assert_eq!(index_iter.next_tuple()?.unwrap(), tuples[2]);
+        assert_eq!(index_iter.next_tuple()?.unwrap(), tuples[0]);
+        assert!(index_iter.next_tuple()?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_column_add_drop() -> Result<(), DatabaseError> {
         let temp_dir = TempDir::new().expect("unable to create temporary working directory");
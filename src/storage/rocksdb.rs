@@ -2,7 +2,8 @@ use crate::errors::DatabaseError;
 use crate::storage::table_codec::{BumpBytes, Bytes, TableCodec};
 use crate::storage::{InnerIter, Storage, Transaction};
 use rocksdb::{
-    DBIteratorWithThreadMode, Direction, IteratorMode, OptimisticTransactionDB, SliceTransform,
+    DBIteratorWithThreadMode, Direction, IteratorMode, OptimisticTransactionDB, ReadOptions,
+    SliceTransform,
 };
 use std::collections::Bound;
 use std::path::PathBuf;
@@ -115,11 +116,56 @@ impl<'txn> Transaction for RocksTransaction<'txn> {
             let mut iter = self.tx.prefix_iterator(&min_bytes[..len]);
             iter.set_mode(lower);
 
-            return Ok(RocksIter { upper: max, iter });
+            return Ok(RocksIter {
+                lower: Bound::Unbounded,
+                upper: max,
+                is_reverse: false,
+                iter,
+            });
         }
         let iter = self.tx.iterator(lower);
 
-        Ok(RocksIter { upper: max, iter })
+        Ok(RocksIter {
+            lower: Bound::Unbounded,
+            upper: max,
+            is_reverse: false,
+            iter,
+        })
+    }
+
+    #[inline]
+    fn rev_range<'a>(
+        &'a self,
+        min: Bound<BumpBytes<'a>>,
+        max: Bound<BumpBytes<'a>>,
+    ) -> Result<Self::IterType<'a>, DatabaseError> {
+        // the prefix seek can not move backwards across the prefixes
+        let mut options = ReadOptions::default();
+        options.set_total_order_seek(true);
+
+        let upper = match &max {
+            Bound::Included(bytes) | Bound::Excluded(bytes) => {
+                IteratorMode::From(bytes, Direction::Reverse)
+            }
+            Bound::Unbounded => IteratorMode::End,
+        };
+        let iter = self.tx.iterator_opt(upper, options);
+
+        Ok(RocksIter {
+            lower: min,
+            upper: max,
+            is_reverse: true,
+            iter,
+        })
+    }
+
+    fn set_savepoint(&mut self) {
+        self.tx.set_savepoint();
+    }
+
+    fn rollback_to_savepoint(&mut self) -> Result<(), DatabaseError> {
+        self.tx.rollback_to_savepoint()?;
+        Ok(())
     }
 
     fn commit(self) -> Result<(), DatabaseError> {
@@ -129,21 +175,36 @@ impl<'txn> Transaction for RocksTransaction<'txn> {
 }
 
 pub struct RocksIter<'txn, 'iter> {
+    lower: Bound<BumpBytes<'iter>>,
     upper: Bound<BumpBytes<'iter>>,
+    is_reverse: bool,
     iter: DBIteratorWithThreadMode<'iter, rocksdb::Transaction<'txn, OptimisticTransactionDB>>,
 }
 
 impl InnerIter for RocksIter<'_, '_> {
     #[inline]
     fn try_next(&mut self) -> Result<Option<(Bytes, Bytes)>, DatabaseError> {
-        if let Some(result) = self.iter.by_ref().next() {
+        for result in self.iter.by_ref() {
             let (key, value) = result?;
             let upper_bound_check = match &self.upper {
                 Bound::Included(ref upper) => key.as_ref() <= upper.as_slice(),
                 Bound::Excluded(ref upper) => key.as_ref() < upper.as_slice(),
                 Bound::Unbounded => true,
             };
-            if !upper_bound_check {
+            if self.is_reverse {
+                // the reverse seek stops at the excluded upper bound itself
+                if !upper_bound_check {
+                    continue;
+                }
+                let lower_bound_check = match &self.lower {
+                    Bound::Included(ref lower) => key.as_ref() >= lower.as_slice(),
+                    Bound::Excluded(ref lower) => key.as_ref() > lower.as_slice(),
+                    Bound::Unbounded => true,
+                };
+                if !lower_bound_check {
+                    return Ok(None);
+                }
+            } else if !upper_bound_check {
                 return Ok(None);
             }
             return Ok(Some((Vec::from(key), Vec::from(value))));
@@ -279,6 +340,7 @@ mod test {
             params: IndexImplParams {
                 tuple_schema_ref: table.schema_ref().clone(),
                 projections: vec![0],
+                covered_columns: None,
                 index_meta: Arc::new(IndexMeta {
                     id: 0,
                     column_ids: vec![*a_column_id],
@@ -287,10 +349,13 @@ mod test {
                     value_ty: LogicalType::Integer,
                     name: "pk_a".to_string(),
                     ty: IndexType::PrimaryKey { is_multiple: false },
+                    expr: None,
+                    predicate: None,
                 }),
                 table_name: &table.name,
                 table_types: table.types(),
                 tx: &transaction,
+                is_reverse: false,
             },
             ranges: vec![
                 Range::Eq(DataValue::Int32(0)),
@@ -342,6 +407,7 @@ mod test {
                     min: Bound::Excluded(DataValue::Int32(0)),
                     max: Bound::Unbounded,
                 }],
+                false,
             )
             .unwrap();
 
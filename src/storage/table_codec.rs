@@ -1,9 +1,10 @@
 use crate::catalog::view::View;
 use crate::catalog::{ColumnRef, ColumnRelation, PrimaryKeyIndices, TableMeta};
 use crate::errors::DatabaseError;
+use crate::optimizer::core::table_statistics::TableStatistics;
 use crate::serdes::{ReferenceSerialization, ReferenceTables};
 use crate::storage::{TableCache, Transaction};
-use crate::types::index::{Index, IndexId, IndexMeta, IndexType};
+use crate::types::index::{Index, IndexId, IndexMeta, IndexMetaV1, IndexType};
 use crate::types::tuple::{Schema, Tuple, TupleId};
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
@@ -15,10 +16,20 @@ use std::sync::LazyLock;
 
 pub(crate) const BOUND_MIN_TAG: u8 = u8::MIN;
 pub(crate) const BOUND_MAX_TAG: u8 = u8::MAX;
+const NULL_TAG: u8 = 0;
+const NOTNULL_TAG: u8 = 1;
+/// The format of the keys written by this version, the data of an older version is rewritten by
+/// `Transaction::upgrade` when the database is opened
+///
+/// 1. NULL tag in the keys of single column indexes
+/// 2. the index metas are prefixed with the position of their reference tables and carry the
+///    expression and the predicate of the index
+pub(crate) const STORAGE_VERSION: u32 = 2;
 
 static ROOT_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| b"Root".to_vec());
 static VIEW_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| b"View".to_vec());
 static HASH_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| b"Hash".to_vec());
+static VERSION_BYTES: LazyLock<Vec<u8>> = LazyLock::new(|| b"Version".to_vec());
 static EMPTY_REFERENCE_TABLES: LazyLock<ReferenceTables> = LazyLock::new(ReferenceTables::new);
 
 pub type Bytes = Vec<u8>;
@@ -35,6 +46,7 @@ enum CodecType {
     IndexMeta,
     Index,
     Statistics,
+    TableStatistics,
     View,
     Tuple,
     Root,
@@ -82,6 +94,7 @@ impl TableCodec {
                 | LogicalType::UBigint
                 | LogicalType::Char(..)
                 | LogicalType::Varchar(..)
+                | LogicalType::Uuid
         ) {
             return Err(DatabaseError::InvalidType);
         }
@@ -91,7 +104,7 @@ impl TableCodec {
     /// TableName + Type
     ///
     /// Tips:
-    /// 1. Root & View & Hash full key = key_prefix
+    /// 1. Root & View & Hash & TableStatistics full key = key_prefix
     /// 2. hash table name makes it 4 as a fixed length, and [prefix_extractor](https://github.com/facebook/rocksdb/wiki/Prefix-Seek#defining-a-prefix) can be enabled in rocksdb
     fn key_prefix(&self, ty: CodecType, name: &str) -> BumpBytes {
         let mut table_bytes = BumpBytes::new_in(&self.arena);
@@ -110,6 +123,9 @@ impl TableCodec {
             CodecType::Statistics => {
                 table_bytes.push(b'4');
             }
+            CodecType::TableStatistics => {
+                table_bytes.push(b'5');
+            }
             CodecType::Tuple => {
                 table_bytes.push(b'8');
             }
@@ -304,26 +320,59 @@ impl TableCodec {
         key_prefix.write_all(&[BOUND_MIN_TAG])?;
         key_prefix.write_all(&index_meta.id.to_be_bytes()[..])?;
 
+        // the columns of an expression index refer to the table by the reference tables
+        let mut reference_tables = ReferenceTables::new();
         let mut value_bytes = BumpBytes::new_in(&self.arena);
-        index_meta.encode(&mut value_bytes, true, &mut ReferenceTables::new())?;
+        value_bytes.resize(4, 0u8);
+
+        let reference_tables_pos = {
+            index_meta.encode(&mut value_bytes, true, &mut reference_tables)?;
+            let pos = value_bytes.len();
+            reference_tables.to_raw(&mut value_bytes)?;
+            pos
+        };
+        value_bytes[..4].copy_from_slice(&(reference_tables_pos as u32).to_le_bytes());
 
         Ok((key_prefix, value_bytes))
     }
 
     pub fn decode_index_meta<T: Transaction>(bytes: &[u8]) -> Result<IndexMeta, DatabaseError> {
-        IndexMeta::decode::<T, _>(&mut Cursor::new(bytes), None, &EMPTY_REFERENCE_TABLES)
+        let mut cursor = Cursor::new(bytes);
+        let reference_tables_pos = {
+            let mut bytes = [0u8; 4];
+            cursor.read_exact(&mut bytes)?;
+            u32::from_le_bytes(bytes) as u64
+        };
+        if reference_tables_pos < 4 || reference_tables_pos > bytes.len() as u64 {
+            return Err(DatabaseError::InvalidValue(
+                "index meta of an older storage version".to_string(),
+            ));
+        }
+        cursor.seek(SeekFrom::Start(reference_tables_pos))?;
+        let reference_tables = ReferenceTables::from_raw(&mut cursor)?;
+        cursor.seek(SeekFrom::Start(4))?;
+
+        IndexMeta::decode::<T, _>(&mut cursor, None, &reference_tables)
+    }
+
+    /// Decodes the index meta written by the storage versions before `2`
+    pub fn decode_index_meta_v1<T: Transaction>(bytes: &[u8]) -> Result<IndexMeta, DatabaseError> {
+        IndexMetaV1::decode::<T, _>(&mut Cursor::new(bytes), None, &EMPTY_REFERENCE_TABLES)
+            .map(IndexMeta::from)
     }
 
     /// NonUnique Index:
     /// Key: {TableName}{INDEX_TAG}{BOUND_MIN_TAG}{IndexID}{BOUND_MIN_TAG}{DataValue1}{BOUND_MIN_TAG}{DataValue2} .. {TupleId}
-    /// Value: TupleID
+    /// Value: TupleID{DataValue}
     ///
     /// Unique Index:
     /// Key: {TableName}{INDEX_TAG}{BOUND_MIN_TAG}{IndexID}{BOUND_MIN_TAG}{DataValue}
-    /// Value: TupleID
+    /// Value: TupleID{DataValue}
     ///
     /// Tips: The unique index has only one ColumnID and one corresponding DataValue,
     /// so it can be positioned directly.
+    /// The key cannot be decoded back into values, so the value also keeps the indexed values
+    /// for a scan covered by the index to skip reading the tuple.
     pub fn encode_index(
         &self,
         name: &str,
@@ -334,6 +383,7 @@ impl TableCodec {
         let mut bytes = BumpBytes::new_in(&self.arena);
 
         bincode::serialize_into(&mut bytes, tuple_id)?;
+        bincode::serialize_into(&mut bytes, index.value)?;
 
         Ok((key, bytes))
     }
@@ -349,6 +399,14 @@ impl TableCodec {
         key_prefix.extend_from_slice(&index.id.to_be_bytes());
         key_prefix.push(BOUND_MIN_TAG);
 
+        // NULL is encoded as nothing, the tag keeps it before and apart from the other values
+        if !matches!(index.value, DataValue::Tuple(..)) {
+            key_prefix.push(if index.value.is_null() {
+                NULL_TAG
+            } else {
+                NOTNULL_TAG
+            });
+        }
         index.value.memcomparable_encode(&mut key_prefix)?;
         if is_upper {
             key_prefix.push(BOUND_MAX_TAG)
@@ -373,8 +431,17 @@ impl TableCodec {
         Ok(key_prefix)
     }
 
-    pub fn decode_index(bytes: &[u8]) -> Result<TupleId, DatabaseError> {
-        Ok(bincode::deserialize_from(&mut Cursor::new(bytes))?)
+    /// Tips: the indexed values are `None` for the entries written before they were kept
+    pub fn decode_index(bytes: &[u8]) -> Result<(TupleId, Option<DataValue>), DatabaseError> {
+        let mut cursor = Cursor::new(bytes);
+        let tuple_id = bincode::deserialize_from(&mut cursor)?;
+        let value = if (cursor.position() as usize) < bytes.len() {
+            Some(bincode::deserialize_from(&mut cursor)?)
+        } else {
+            None
+        };
+
+        Ok((tuple_id, value))
     }
 
     /// Key: {TableName}{COLUMN_TAG}{BOUND_MIN_TAG}{ColumnId}
@@ -444,6 +511,31 @@ impl TableCodec {
         Ok(String::from_utf8(bytes.to_vec())?)
     }
 
+    /// Key: {TableName}{TABLE_STATISTICS_TAG}
+    /// Value: TableStatistics
+    pub fn encode_table_statistics(
+        &self,
+        statistics: &TableStatistics,
+    ) -> Result<(BumpBytes, BumpBytes), DatabaseError> {
+        let key = self.encode_table_statistics_key(statistics.table_name());
+
+        let mut bytes = BumpBytes::new_in(&self.arena);
+        statistics.encode(&mut bytes, true, &mut ReferenceTables::new())?;
+        Ok((key, bytes))
+    }
+
+    pub fn encode_table_statistics_key(&self, table_name: &str) -> BumpBytes {
+        self.key_prefix(CodecType::TableStatistics, table_name)
+    }
+
+    pub fn decode_table_statistics<T: Transaction>(
+        bytes: &[u8],
+    ) -> Result<TableStatistics, DatabaseError> {
+        let mut bytes = Cursor::new(bytes);
+
+        TableStatistics::decode::<T, _>(&mut bytes, None, &EMPTY_REFERENCE_TABLES)
+    }
+
     /// Key: View{BOUND_MIN_TAG}{ViewName}
     /// Value: View
     pub fn encode_view(&self, view: &View) -> Result<(BumpBytes, BumpBytes), DatabaseError> {
@@ -518,6 +610,32 @@ impl TableCodec {
             BumpBytes::new_in(&self.arena),
         )
     }
+
+    /// Key: Version
+    /// Value: StorageVersion
+    ///
+    /// Tips: a database without the key is written by the version `0`
+    pub fn encode_version(&self) -> (BumpBytes, BumpBytes) {
+        let mut bytes = BumpBytes::new_in(&self.arena);
+        bytes.extend_from_slice(&STORAGE_VERSION.to_be_bytes());
+
+        (self.encode_version_key(), bytes)
+    }
+
+    pub fn encode_version_key(&self) -> BumpBytes {
+        let mut key = BumpBytes::new_in(&self.arena);
+        key.extend_from_slice(&VERSION_BYTES);
+
+        key
+    }
+
+    pub fn decode_version(bytes: &[u8]) -> Result<u32, DatabaseError> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| DatabaseError::InvalidValue("storage version".to_string()))?;
+
+        Ok(u32::from_be_bytes(bytes))
+    }
 }
 
 #[cfg(test)]
@@ -630,6 +748,8 @@ mod tests {
             value_ty: LogicalType::Integer,
             name: "index_1".to_string(),
             ty: IndexType::PrimaryKey { is_multiple: false },
+            expr: None,
+            predicate: None,
         };
         let (_, bytes) = table_codec.encode_index_meta(&"T1".to_string(), &index_meta)?;
 
@@ -652,7 +772,14 @@ mod tests {
         let tuple_id = DataValue::Int32(0);
         let (_, bytes) = table_codec.encode_index(&table_catalog.name, &index, &tuple_id)?;
 
-        assert_eq!(TableCodec::decode_index(&bytes)?, tuple_id);
+        assert_eq!(
+            TableCodec::decode_index(&bytes)?,
+            (tuple_id.clone(), Some(DataValue::Int32(0)))
+        );
+        // the entries written before the indexed values were kept
+        let mut bytes = Vec::new();
+        bincode::serialize_into(&mut bytes, &tuple_id)?;
+        assert_eq!(TableCodec::decode_index(&bytes)?, (tuple_id, None));
 
         Ok(())
     }
@@ -816,6 +943,8 @@ mod tests {
                 value_ty: LogicalType::Integer,
                 name: format!("{}_index", index_id),
                 ty: IndexType::PrimaryKey { is_multiple: false },
+                expr: None,
+                predicate: None,
             };
 
             let (key, _) = table_codec
@@ -1,3 +1,4 @@
+use crate::errors::DatabaseError;
 use crate::types::evaluator::DataValue;
 use crate::types::evaluator::{BinaryEvaluator, UnaryEvaluator};
 use serde::{Deserialize, Serialize};
@@ -26,8 +27,8 @@ impl UnaryEvaluator for BooleanNotUnaryEvaluator {
 }
 #[typetag::serde]
 impl BinaryEvaluator for BooleanAndBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Boolean(v1), DataValue::Boolean(v2)) => DataValue::Boolean(*v1 && *v2),
             (DataValue::Boolean(false), DataValue::Null)
             | (DataValue::Null, DataValue::Boolean(false)) => DataValue::Boolean(false),
@@ -35,14 +36,14 @@ impl BinaryEvaluator for BooleanAndBinaryEvaluator {
             | (DataValue::Boolean(true), DataValue::Null)
             | (DataValue::Null, DataValue::Boolean(true)) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 
 #[typetag::serde]
 impl BinaryEvaluator for BooleanOrBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Boolean(v1), DataValue::Boolean(v2)) => DataValue::Boolean(*v1 || *v2),
             (DataValue::Boolean(true), DataValue::Null)
             | (DataValue::Null, DataValue::Boolean(true)) => DataValue::Boolean(true),
@@ -50,32 +51,32 @@ impl BinaryEvaluator for BooleanOrBinaryEvaluator {
             | (DataValue::Boolean(false), DataValue::Null)
             | (DataValue::Null, DataValue::Boolean(false)) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 
 #[typetag::serde]
 impl BinaryEvaluator for BooleanEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Boolean(v1), DataValue::Boolean(v2)) => DataValue::Boolean(*v1 == *v2),
             (DataValue::Null, DataValue::Boolean(_))
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 
 #[typetag::serde]
 impl BinaryEvaluator for BooleanNotEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Boolean(v1), DataValue::Boolean(v2)) => DataValue::Boolean(*v1 != *v2),
             (DataValue::Null, DataValue::Boolean(_))
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
@@ -1,3 +1,4 @@
+use crate::errors::DatabaseError;
 use crate::numeric_binary_evaluator_definition;
 use crate::types::evaluator::BinaryEvaluator;
 use crate::types::evaluator::DataValue;
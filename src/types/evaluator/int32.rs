@@ -1,9 +1,15 @@
+use crate::errors::DatabaseError;
 use crate::types::evaluator::DataValue;
 use crate::types::evaluator::{BinaryEvaluator, UnaryEvaluator};
-use crate::{numeric_binary_evaluator_definition, numeric_unary_evaluator_definition};
+use crate::{
+    integer_binary_evaluator_definition, integer_unary_evaluator_definition,
+    numeric_binary_evaluator_definition, numeric_unary_evaluator_definition,
+};
 use paste::paste;
 use serde::{Deserialize, Serialize};
 use std::hint;
 
 numeric_unary_evaluator_definition!(Int32, DataValue::Int32);
+integer_unary_evaluator_definition!(Int32, DataValue::Int32);
 numeric_binary_evaluator_definition!(Int32, DataValue::Int32);
+integer_binary_evaluator_definition!(Int32, DataValue::Int32, i32);
@@ -8,6 +8,7 @@ pub mod int16;
 pub mod int32;
 pub mod int64;
 pub mod int8;
+pub mod interval;
 pub mod null;
 pub mod time;
 pub mod tuple;
@@ -16,6 +17,7 @@ pub mod uint32;
 pub mod uint64;
 pub mod uint8;
 pub mod utf8;
+pub mod uuid;
 
 use crate::errors::DatabaseError;
 use crate::expression::{BinaryOperator, UnaryOperator};
@@ -29,7 +31,8 @@ use crate::types::evaluator::int16::*;
 use crate::types::evaluator::int32::*;
 use crate::types::evaluator::int64::*;
 use crate::types::evaluator::int8::*;
-use crate::types::evaluator::null::NullBinaryEvaluator;
+use crate::types::evaluator::interval::*;
+use crate::types::evaluator::null::{NullBinaryEvaluator, NullSafeEqBinaryEvaluator};
 use crate::types::evaluator::time::*;
 use crate::types::evaluator::tuple::{
     TupleEqBinaryEvaluator, TupleGtBinaryEvaluator, TupleGtEqBinaryEvaluator,
@@ -44,6 +47,7 @@ use crate::types::evaluator::utf8::{
     Utf8EqBinaryEvaluator, Utf8GtBinaryEvaluator, Utf8GtEqBinaryEvaluator, Utf8LtBinaryEvaluator,
     Utf8LtEqBinaryEvaluator, Utf8NotEqBinaryEvaluator, Utf8StringConcatBinaryEvaluator,
 };
+use crate::types::evaluator::uuid::*;
 use crate::types::value::DataValue;
 use crate::types::LogicalType;
 use paste::paste;
@@ -54,7 +58,7 @@ use std::sync::Arc;
 
 #[typetag::serde(tag = "binary")]
 pub trait BinaryEvaluator: Send + Sync + Debug {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue;
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError>;
 }
 
 #[typetag::serde(tag = "unary")]
@@ -66,7 +70,11 @@ pub trait UnaryEvaluator: Send + Sync + Debug {
 pub struct BinaryEvaluatorBox(pub Arc<dyn BinaryEvaluator>);
 
 impl BinaryEvaluatorBox {
-    pub fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
+    pub fn binary_eval(
+        &self,
+        left: &DataValue,
+        right: &DataValue,
+    ) -> Result<DataValue, DatabaseError> {
         self.0.binary_eval(left, right)
     }
 }
@@ -136,6 +144,48 @@ macro_rules! numeric_binary_evaluator {
     };
 }
 
+macro_rules! integer_binary_evaluator {
+    ($value_type:ident, $op:expr, $ty:expr) => {
+        paste! {
+            match $op {
+                BinaryOperator::BitAnd => Ok(BinaryEvaluatorBox(Arc::new([<$value_type BitAndBinaryEvaluator>]))),
+                BinaryOperator::BitOr => Ok(BinaryEvaluatorBox(Arc::new([<$value_type BitOrBinaryEvaluator>]))),
+                BinaryOperator::BitXor => Ok(BinaryEvaluatorBox(Arc::new([<$value_type BitXorBinaryEvaluator>]))),
+                BinaryOperator::ShiftLeft => Ok(BinaryEvaluatorBox(Arc::new([<$value_type ShiftLeftBinaryEvaluator>]))),
+                BinaryOperator::ShiftRight => Ok(BinaryEvaluatorBox(Arc::new([<$value_type ShiftRightBinaryEvaluator>]))),
+                _ => numeric_binary_evaluator!($value_type, $op, $ty),
+            }
+        }
+    };
+}
+
+macro_rules! integer_unary_evaluator {
+    ($value_type:ident, $op:expr, $ty:expr) => {
+        paste! {
+            match $op {
+                UnaryOperator::BitNot => Ok(UnaryEvaluatorBox(Arc::new([<$value_type BitNotUnaryEvaluator>]))),
+                _ => numeric_unary_evaluator!($value_type, $op, $ty),
+            }
+        }
+    };
+}
+
+macro_rules! unsigned_unary_evaluator {
+    ($value_type:ident, $op:expr, $ty:expr) => {
+        paste! {
+            match $op {
+                UnaryOperator::BitNot => Ok(UnaryEvaluatorBox(Arc::new([<$value_type BitNotUnaryEvaluator>]))),
+                _ => {
+                    return Err(DatabaseError::UnsupportedUnaryOperator(
+                        $ty,
+                        $op,
+                    ))
+                }
+            }
+        }
+    };
+}
+
 macro_rules! numeric_unary_evaluator {
     ($value_type:ident, $op:expr, $ty:expr) => {
         paste! {
@@ -161,10 +211,16 @@ impl EvaluatorFactory {
         op: UnaryOperator,
     ) -> Result<UnaryEvaluatorBox, DatabaseError> {
         match ty {
-            LogicalType::Tinyint => numeric_unary_evaluator!(Int8, op, LogicalType::Tinyint),
-            LogicalType::Smallint => numeric_unary_evaluator!(Int16, op, LogicalType::Smallint),
-            LogicalType::Integer => numeric_unary_evaluator!(Int32, op, LogicalType::Integer),
-            LogicalType::Bigint => numeric_unary_evaluator!(Int64, op, LogicalType::Bigint),
+            LogicalType::Tinyint => integer_unary_evaluator!(Int8, op, LogicalType::Tinyint),
+            LogicalType::Smallint => integer_unary_evaluator!(Int16, op, LogicalType::Smallint),
+            LogicalType::Integer => integer_unary_evaluator!(Int32, op, LogicalType::Integer),
+            LogicalType::Bigint => integer_unary_evaluator!(Int64, op, LogicalType::Bigint),
+            LogicalType::UTinyint => unsigned_unary_evaluator!(UInt8, op, LogicalType::UTinyint),
+            LogicalType::USmallint => {
+                unsigned_unary_evaluator!(UInt16, op, LogicalType::USmallint)
+            }
+            LogicalType::UInteger => unsigned_unary_evaluator!(UInt32, op, LogicalType::UInteger),
+            LogicalType::UBigint => unsigned_unary_evaluator!(UInt64, op, LogicalType::UBigint),
             LogicalType::Boolean => match op {
                 UnaryOperator::Not => Ok(UnaryEvaluatorBox(Arc::new(BooleanNotUnaryEvaluator))),
                 _ => Err(DatabaseError::UnsupportedUnaryOperator(ty, op)),
@@ -178,20 +234,28 @@ impl EvaluatorFactory {
         ty: LogicalType,
         op: BinaryOperator,
     ) -> Result<BinaryEvaluatorBox, DatabaseError> {
+        if let BinaryOperator::Spaceship = op {
+            let eq_evaluator = Self::binary_create(ty, BinaryOperator::Eq)?;
+
+            return Ok(BinaryEvaluatorBox(Arc::new(NullSafeEqBinaryEvaluator(
+                eq_evaluator,
+            ))));
+        }
         match ty {
-            LogicalType::Tinyint => numeric_binary_evaluator!(Int8, op, LogicalType::Tinyint),
-            LogicalType::Smallint => numeric_binary_evaluator!(Int16, op, LogicalType::Smallint),
-            LogicalType::Integer => numeric_binary_evaluator!(Int32, op, LogicalType::Integer),
-            LogicalType::Bigint => numeric_binary_evaluator!(Int64, op, LogicalType::Bigint),
-            LogicalType::UTinyint => numeric_binary_evaluator!(UInt8, op, LogicalType::UTinyint),
-            LogicalType::USmallint => numeric_binary_evaluator!(UInt16, op, LogicalType::USmallint),
-            LogicalType::UInteger => numeric_binary_evaluator!(UInt32, op, LogicalType::UInteger),
-            LogicalType::UBigint => numeric_binary_evaluator!(UInt64, op, LogicalType::UBigint),
+            LogicalType::Tinyint => integer_binary_evaluator!(Int8, op, LogicalType::Tinyint),
+            LogicalType::Smallint => integer_binary_evaluator!(Int16, op, LogicalType::Smallint),
+            LogicalType::Integer => integer_binary_evaluator!(Int32, op, LogicalType::Integer),
+            LogicalType::Bigint => integer_binary_evaluator!(Int64, op, LogicalType::Bigint),
+            LogicalType::UTinyint => integer_binary_evaluator!(UInt8, op, LogicalType::UTinyint),
+            LogicalType::USmallint => integer_binary_evaluator!(UInt16, op, LogicalType::USmallint),
+            LogicalType::UInteger => integer_binary_evaluator!(UInt32, op, LogicalType::UInteger),
+            LogicalType::UBigint => integer_binary_evaluator!(UInt64, op, LogicalType::UBigint),
             LogicalType::Float => numeric_binary_evaluator!(Float32, op, LogicalType::Float),
             LogicalType::Double => numeric_binary_evaluator!(Float64, op, LogicalType::Double),
             LogicalType::Date => numeric_binary_evaluator!(Date, op, LogicalType::Date),
             LogicalType::DateTime => numeric_binary_evaluator!(DateTime, op, LogicalType::DateTime),
             LogicalType::Time => numeric_binary_evaluator!(Time, op, LogicalType::Time),
+            LogicalType::Interval => numeric_binary_evaluator!(Interval, op, LogicalType::Interval),
             LogicalType::Decimal(_, _) => numeric_binary_evaluator!(Decimal, op, ty),
             LogicalType::Boolean => match op {
                 BinaryOperator::And => Ok(BinaryEvaluatorBox(Arc::new(BooleanAndBinaryEvaluator))),
@@ -229,6 +293,18 @@ impl EvaluatorFactory {
             },
             LogicalType::SqlNull => Ok(BinaryEvaluatorBox(Arc::new(NullBinaryEvaluator))),
             LogicalType::Invalid => Err(DatabaseError::InvalidType),
+            LogicalType::Uuid => match op {
+                BinaryOperator::Gt
+                | BinaryOperator::GtEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Eq
+                | BinaryOperator::NotEq => numeric_binary_evaluator!(Uuid, op, LogicalType::Uuid),
+                _ => Err(DatabaseError::UnsupportedBinaryOperator(ty, op)),
+            },
+            LogicalType::Bytea | LogicalType::List(_) | LogicalType::Json => {
+                Err(DatabaseError::UnsupportedBinaryOperator(ty, op))
+            }
             LogicalType::Tuple(_) => match op {
                 BinaryOperator::Eq => Ok(BinaryEvaluatorBox(Arc::new(TupleEqBinaryEvaluator))),
                 BinaryOperator::NotEq => {
@@ -273,6 +349,127 @@ macro_rules! numeric_unary_evaluator_definition {
     };
 }
 
+/// The amount of `value << amount`/`value >> amount`, any integer that is not negative.
+pub(crate) fn shift_amount(amount: &DataValue) -> Result<Option<u32>, DatabaseError> {
+    if amount.is_null() {
+        return Ok(None);
+    }
+    if !amount.logical_type().is_signed_numeric() && !amount.logical_type().is_unsigned_numeric() {
+        return Err(DatabaseError::InvalidType);
+    }
+    let Some(amount) = amount.clone().cast(&LogicalType::Bigint)?.i64() else {
+        return Ok(None);
+    };
+    if amount < 0 {
+        return Err(DatabaseError::InvalidValue(format!(
+            "negative shift amount: {}",
+            amount
+        )));
+    }
+    Ok(Some(u32::try_from(amount).unwrap_or(u32::MAX)))
+}
+
+/// `&`, `|`, `#` and the shifts of integers of the width of `$native`, whose right side of a
+/// shift is the amount of any integer type, see [`shift_amount`]
+///
+/// Tips: shifting by the width or more shifts every bit out, which leaves `0`,
+/// or `-1` for `>>` of a negative integer
+#[macro_export]
+macro_rules! integer_binary_evaluator_definition {
+    ($value_type:ident, $compute_type:path, $native:ty) => {
+        paste! {
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type BitAndBinaryEvaluator>];
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type BitOrBinaryEvaluator>];
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type BitXorBinaryEvaluator>];
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type ShiftLeftBinaryEvaluator>];
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type ShiftRightBinaryEvaluator>];
+
+            #[typetag::serde]
+            impl BinaryEvaluator for [<$value_type BitAndBinaryEvaluator>] {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
+                        ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 & *v2),
+                        ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    })
+                }
+            }
+            #[typetag::serde]
+            impl BinaryEvaluator for [<$value_type BitOrBinaryEvaluator>] {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
+                        ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 | *v2),
+                        ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    })
+                }
+            }
+            #[typetag::serde]
+            impl BinaryEvaluator for [<$value_type BitXorBinaryEvaluator>] {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
+                        ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 ^ *v2),
+                        ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    })
+                }
+            }
+            #[typetag::serde]
+            impl BinaryEvaluator for [<$value_type ShiftLeftBinaryEvaluator>] {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    let amount = $crate::types::evaluator::shift_amount(right)?;
+                    Ok(match (left, amount) {
+                        ($compute_type(v1), Some(amount)) => $compute_type(v1.checked_shl(amount).unwrap_or(0)),
+                        ($compute_type(_), None) | (DataValue::Null, _) => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    })
+                }
+            }
+            #[typetag::serde]
+            impl BinaryEvaluator for [<$value_type ShiftRightBinaryEvaluator>] {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    let amount = $crate::types::evaluator::shift_amount(right)?;
+                    Ok(match (left, amount) {
+                        ($compute_type(v1), Some(amount)) => $compute_type(
+                            // the sign bit shifted twice fills a signed integer and empties an unsigned one
+                            v1.checked_shr(amount).unwrap_or((*v1 >> (<$native>::BITS - 1)) >> 1),
+                        ),
+                        ($compute_type(_), None) | (DataValue::Null, _) => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    })
+                }
+            }
+        }
+    };
+}
+
+/// `~value`, the bitwise complement of an integer
+#[macro_export]
+macro_rules! integer_unary_evaluator_definition {
+    ($value_type:ident, $compute_type:path) => {
+        paste! {
+            #[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+            pub struct [<$value_type BitNotUnaryEvaluator>];
+
+            #[typetag::serde]
+            impl UnaryEvaluator for [<$value_type BitNotUnaryEvaluator>] {
+                fn unary_eval(&self, value: &DataValue) -> DataValue {
+                    match value {
+                        $compute_type(value) => $compute_type(!value),
+                        DataValue::Null => DataValue::Null,
+                        _ => unsafe { hint::unreachable_unchecked() },
+                    }
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! numeric_binary_evaluator_definition {
     ($value_type:ident, $compute_type:path) => {
@@ -302,112 +499,114 @@ macro_rules! numeric_binary_evaluator_definition {
 
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type PlusBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 + *v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type MinusBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 - *v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type MultiplyBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 * *v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type DivideBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Float64(ordered_float::OrderedFloat(*v1 as f64 / *v2 as f64)),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type GtBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 > v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type GtEqBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 >= v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type LtBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 < v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type LtEqBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 <= v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type EqBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 == v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type NotEqBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
                         ($compute_type(v1), $compute_type(v2)) => DataValue::Boolean(v1 != v2),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
             #[typetag::serde]
             impl BinaryEvaluator for [<$value_type ModBinaryEvaluator>] {
-                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-                    match (left, right) {
-                        ($compute_type(v1), $compute_type(v2)) => $compute_type(*v1 % *v2),
+                fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+                    Ok(match (left, right) {
+                        ($compute_type(_), $compute_type(0)) => return Err(DatabaseError::DivisionByZero),
+                        // `MIN % -1` overflows although the remainder is 0
+                        ($compute_type(v1), $compute_type(v2)) => $compute_type(v1.wrapping_rem(*v2)),
                         ($compute_type(_), DataValue::Null) | (DataValue::Null, $compute_type(_)) | (DataValue::Null, DataValue::Null) => DataValue::Null,
                         _ => unsafe { hint::unreachable_unchecked() },
-                    }
+                    })
                 }
             }
         }
@@ -417,7 +616,7 @@ macro_rules! numeric_binary_evaluator_definition {
 #[cfg(test)]
 mod test {
     use crate::errors::DatabaseError;
-    use crate::expression::BinaryOperator;
+    use crate::expression::{BinaryOperator, UnaryOperator};
     use crate::serdes::{ReferenceSerialization, ReferenceTables};
     use crate::storage::rocksdb::RocksTransaction;
     use crate::types::evaluator::boolean::{BooleanNotEqBinaryEvaluator, BooleanNotUnaryEvaluator};
@@ -425,8 +624,10 @@ mod test {
     use crate::types::value::{DataValue, Utf8Type};
     use crate::types::LogicalType;
     use ordered_float::OrderedFloat;
+    use rust_decimal::Decimal;
     use sqlparser::ast::CharLengthUnits;
     use std::io::{Cursor, Seek, SeekFrom};
+    use std::str::FromStr;
     use std::sync::Arc;
 
     #[test]
@@ -435,16 +636,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Plus)?;
         let plus_i32_1 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let plus_i32_2 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int32(1), &DataValue::Null)?;
         let plus_i32_3 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int32(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int32(1))?;
         let plus_i32_4 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1));
+            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1))?;
 
         assert_eq!(plus_i32_1, plus_i32_2);
         assert_eq!(plus_i32_2, plus_i32_3);
@@ -454,16 +655,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Bigint, BinaryOperator::Plus)?;
         let plus_i64_1 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let plus_i64_2 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int64(1), &DataValue::Null)?;
         let plus_i64_3 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int64(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int64(1))?;
         let plus_i64_4 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1));
+            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1))?;
 
         assert_eq!(plus_i64_1, plus_i64_2);
         assert_eq!(plus_i64_2, plus_i64_3);
@@ -473,17 +674,17 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Double, BinaryOperator::Plus)?;
         let plus_f64_1 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let plus_f64_2 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null);
+            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null)?;
         let plus_f64_3 = plus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)));
+            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)))?;
         let plus_f64_4 = plus_evaluator.0.binary_eval(
             &DataValue::Float64(OrderedFloat(1.0)),
             &DataValue::Float64(OrderedFloat(1.0)),
-        );
+        )?;
 
         assert_eq!(plus_f64_1, plus_f64_2);
         assert_eq!(plus_f64_2, plus_f64_3);
@@ -498,16 +699,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Minus)?;
         let minus_i32_1 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let minus_i32_2 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int32(1), &DataValue::Null)?;
         let minus_i32_3 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int32(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int32(1))?;
         let minus_i32_4 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1));
+            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1))?;
 
         assert_eq!(minus_i32_1, minus_i32_2);
         assert_eq!(minus_i32_2, minus_i32_3);
@@ -517,16 +718,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Bigint, BinaryOperator::Minus)?;
         let minus_i64_1 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let minus_i64_2 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int64(1), &DataValue::Null)?;
         let minus_i64_3 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int64(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int64(1))?;
         let minus_i64_4 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1));
+            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1))?;
 
         assert_eq!(minus_i64_1, minus_i64_2);
         assert_eq!(minus_i64_2, minus_i64_3);
@@ -536,17 +737,17 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Double, BinaryOperator::Minus)?;
         let minus_f64_1 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let minus_f64_2 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null);
+            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null)?;
         let minus_f64_3 = minus_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)));
+            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)))?;
         let minus_f64_4 = minus_evaluator.0.binary_eval(
             &DataValue::Float64(OrderedFloat(1.0)),
             &DataValue::Float64(OrderedFloat(1.0)),
-        );
+        )?;
 
         assert_eq!(minus_f64_1, minus_f64_2);
         assert_eq!(minus_f64_2, minus_f64_3);
@@ -561,16 +762,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Multiply)?;
         let multiply_i32_1 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let multiply_i32_2 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int32(1), &DataValue::Null)?;
         let multiply_i32_3 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int32(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int32(1))?;
         let multiply_i32_4 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1));
+            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1))?;
 
         assert_eq!(multiply_i32_1, multiply_i32_2);
         assert_eq!(multiply_i32_2, multiply_i32_3);
@@ -580,16 +781,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Bigint, BinaryOperator::Multiply)?;
         let multiply_i64_1 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let multiply_i64_2 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int64(1), &DataValue::Null)?;
         let multiply_i64_3 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int64(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int64(1))?;
         let multiply_i64_4 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1));
+            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1))?;
 
         assert_eq!(multiply_i64_1, multiply_i64_2);
         assert_eq!(multiply_i64_2, multiply_i64_3);
@@ -599,17 +800,17 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Double, BinaryOperator::Multiply)?;
         let multiply_f64_1 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let multiply_f64_2 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null);
+            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null)?;
         let multiply_f64_3 = multiply_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)));
+            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)))?;
         let multiply_f64_4 = multiply_evaluator.0.binary_eval(
             &DataValue::Float64(OrderedFloat(1.0)),
             &DataValue::Float64(OrderedFloat(1.0)),
-        );
+        )?;
 
         assert_eq!(multiply_f64_1, multiply_f64_2);
         assert_eq!(multiply_f64_2, multiply_f64_3);
@@ -624,16 +825,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Divide)?;
         let divide_i32_1 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let divide_i32_2 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int32(1), &DataValue::Null)?;
         let divide_i32_3 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int32(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int32(1))?;
         let divide_i32_4 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1));
+            .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1))?;
 
         assert_eq!(divide_i32_1, divide_i32_2);
         assert_eq!(divide_i32_2, divide_i32_3);
@@ -643,16 +844,16 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Bigint, BinaryOperator::Divide)?;
         let divide_i64_1 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let divide_i64_2 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Null);
+            .binary_eval(&DataValue::Int64(1), &DataValue::Null)?;
         let divide_i64_3 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Int64(1));
+            .binary_eval(&DataValue::Null, &DataValue::Int64(1))?;
         let divide_i64_4 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1));
+            .binary_eval(&DataValue::Int64(1), &DataValue::Int64(1))?;
 
         assert_eq!(divide_i64_1, divide_i64_2);
         assert_eq!(divide_i64_2, divide_i64_3);
@@ -662,17 +863,17 @@ mod test {
             EvaluatorFactory::binary_create(LogicalType::Double, BinaryOperator::Divide)?;
         let divide_f64_1 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Null);
+            .binary_eval(&DataValue::Null, &DataValue::Null)?;
         let divide_f64_2 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null);
+            .binary_eval(&DataValue::Float64(OrderedFloat(1.0)), &DataValue::Null)?;
         let divide_f64_3 = divide_evaluator
             .0
-            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)));
+            .binary_eval(&DataValue::Null, &DataValue::Float64(OrderedFloat(1.0)))?;
         let divide_f64_4 = divide_evaluator.0.binary_eval(
             &DataValue::Float64(OrderedFloat(1.0)),
             &DataValue::Float64(OrderedFloat(1.0)),
-        );
+        )?;
 
         assert_eq!(divide_f64_1, divide_f64_2);
         assert_eq!(divide_f64_2, divide_f64_3);
@@ -681,20 +882,65 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_binary_op_decimal_checked() -> Result<(), DatabaseError> {
+        let decimal = |value: &str| DataValue::Decimal(Decimal::from_str(value).unwrap());
+        let fn_eval = |op: BinaryOperator, left: &DataValue, right: &DataValue| {
+            EvaluatorFactory::binary_create(LogicalType::Decimal(None, None), op)?
+                .0
+                .binary_eval(left, right)
+        };
+
+        assert_eq!(
+            fn_eval(BinaryOperator::Plus, &decimal("0.1"), &decimal("0.2"))?,
+            decimal("0.3")
+        );
+        assert_eq!(
+            fn_eval(BinaryOperator::Multiply, &decimal("1.5"), &DataValue::Null)?,
+            DataValue::Null
+        );
+        assert!(matches!(
+            fn_eval(
+                BinaryOperator::Plus,
+                &DataValue::Decimal(Decimal::MAX),
+                &decimal("1")
+            ),
+            Err(DatabaseError::NumericOverflow)
+        ));
+        assert!(matches!(
+            fn_eval(
+                BinaryOperator::Multiply,
+                &DataValue::Decimal(Decimal::MAX),
+                &decimal("2")
+            ),
+            Err(DatabaseError::NumericOverflow)
+        ));
+        assert!(matches!(
+            fn_eval(BinaryOperator::Divide, &decimal("1"), &decimal("0.00")),
+            Err(DatabaseError::DivisionByZero)
+        ));
+        assert!(matches!(
+            fn_eval(BinaryOperator::Modulo, &decimal("1"), &decimal("0")),
+            Err(DatabaseError::DivisionByZero)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_binary_op_i32_compare() -> Result<(), DatabaseError> {
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Gt)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(0),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(0),)?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Lt)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(0),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(0),)?,
             DataValue::Boolean(false)
         );
         let evaluator =
@@ -702,7 +948,7 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),)?,
             DataValue::Boolean(true)
         );
         let evaluator =
@@ -710,7 +956,7 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),)?,
             DataValue::Boolean(true)
         );
         let evaluator =
@@ -718,28 +964,28 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),)?,
             DataValue::Boolean(false)
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Eq)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),)?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Gt)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(0),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(0),)?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Lt)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(0),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(0),)?,
             DataValue::Null
         );
         let evaluator =
@@ -747,7 +993,7 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(1),)?,
             DataValue::Null
         );
         let evaluator =
@@ -755,7 +1001,7 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(1),)?,
             DataValue::Null
         );
         let evaluator =
@@ -763,21 +1009,43 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(1),)?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Eq)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Int32(1),),
+                .binary_eval(&DataValue::Null, &DataValue::Int32(1),)?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Eq)?;
         assert_eq!(
-            evaluator.0.binary_eval(&DataValue::Null, &DataValue::Null,),
+            evaluator
+                .0
+                .binary_eval(&DataValue::Null, &DataValue::Null,)?,
             DataValue::Null
         );
+        let evaluator =
+            EvaluatorFactory::binary_create(LogicalType::Integer, BinaryOperator::Spaceship)?;
+        assert_eq!(
+            evaluator
+                .0
+                .binary_eval(&DataValue::Null, &DataValue::Null,)?,
+            DataValue::Boolean(true)
+        );
+        assert_eq!(
+            evaluator
+                .0
+                .binary_eval(&DataValue::Null, &DataValue::Int32(1),)?,
+            DataValue::Boolean(false)
+        );
+        assert_eq!(
+            evaluator
+                .0
+                .binary_eval(&DataValue::Int32(1), &DataValue::Int32(1),)?,
+            DataValue::Boolean(true)
+        );
 
         Ok(())
     }
@@ -788,56 +1056,347 @@ mod test {
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(true), &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Boolean(true), &DataValue::Boolean(true),)?,
             DataValue::Boolean(true)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(true),)?,
             DataValue::Boolean(false)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(false),),
+                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(false),)?,
             DataValue::Boolean(false)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Null, &DataValue::Boolean(true),)?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(LogicalType::Boolean, BinaryOperator::Or)?;
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(true), &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Boolean(true), &DataValue::Boolean(true),)?,
             DataValue::Boolean(true)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(true),)?,
             DataValue::Boolean(true)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(false),),
+                .binary_eval(&DataValue::Boolean(false), &DataValue::Boolean(false),)?,
             DataValue::Boolean(false)
         );
         assert_eq!(
             evaluator
                 .0
-                .binary_eval(&DataValue::Null, &DataValue::Boolean(true),),
+                .binary_eval(&DataValue::Null, &DataValue::Boolean(true),)?,
             DataValue::Boolean(true)
         );
 
         Ok(())
     }
 
+    #[test]
+    fn test_unary_op_bit_not() -> Result<(), DatabaseError> {
+        let evaluator =
+            EvaluatorFactory::unary_create(LogicalType::Integer, UnaryOperator::BitNot)?;
+        assert_eq!(
+            evaluator.0.unary_eval(&DataValue::Int32(0)),
+            DataValue::Int32(-1)
+        );
+        assert_eq!(
+            evaluator.0.unary_eval(&DataValue::Int32(-6)),
+            DataValue::Int32(5)
+        );
+        assert_eq!(evaluator.0.unary_eval(&DataValue::Null), DataValue::Null);
+        let evaluator =
+            EvaluatorFactory::unary_create(LogicalType::UTinyint, UnaryOperator::BitNot)?;
+        assert_eq!(
+            evaluator.0.unary_eval(&DataValue::UInt8(0)),
+            DataValue::UInt8(255)
+        );
+
+        for ty in [
+            LogicalType::Double,
+            LogicalType::Boolean,
+            LogicalType::Decimal(None, None),
+        ] {
+            assert!(matches!(
+                EvaluatorFactory::unary_create(ty, UnaryOperator::BitNot),
+                Err(DatabaseError::UnsupportedUnaryOperator(..))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_op_modulo() -> Result<(), DatabaseError> {
+        let fn_eval = |ty: LogicalType, left: DataValue, right: DataValue| {
+            EvaluatorFactory::binary_create(ty, BinaryOperator::Modulo)?
+                .0
+                .binary_eval(&left, &right)
+        };
+
+        // the remainder has the sign of the dividend
+        assert_eq!(
+            fn_eval(
+                LogicalType::Integer,
+                DataValue::Int32(-7),
+                DataValue::Int32(3)
+            )?,
+            DataValue::Int32(-1)
+        );
+        assert_eq!(
+            fn_eval(
+                LogicalType::Integer,
+                DataValue::Int32(7),
+                DataValue::Int32(-3)
+            )?,
+            DataValue::Int32(1)
+        );
+        assert_eq!(
+            fn_eval(
+                LogicalType::Bigint,
+                DataValue::Int64(i64::MIN),
+                DataValue::Int64(-1)
+            )?,
+            DataValue::Int64(0)
+        );
+        assert_eq!(
+            fn_eval(
+                LogicalType::Double,
+                DataValue::Float64((-7.5).into()),
+                DataValue::Float64(2.0.into())
+            )?,
+            DataValue::Float64((-1.5).into())
+        );
+        assert_eq!(
+            fn_eval(LogicalType::Integer, DataValue::Null, DataValue::Int32(0))?,
+            DataValue::Null
+        );
+        for (ty, left, right) in [
+            (
+                LogicalType::Integer,
+                DataValue::Int32(-7),
+                DataValue::Int32(0),
+            ),
+            (
+                LogicalType::UTinyint,
+                DataValue::UInt8(7),
+                DataValue::UInt8(0),
+            ),
+            (
+                LogicalType::Double,
+                DataValue::Float64(7.0.into()),
+                DataValue::Float64((-0.0).into()),
+            ),
+            (
+                LogicalType::Float,
+                DataValue::Float32(7.0.into()),
+                DataValue::Float32(0.0.into()),
+            ),
+        ] {
+            assert!(matches!(
+                fn_eval(ty, left, right),
+                Err(DatabaseError::DivisionByZero)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_op_bitwise() -> Result<(), DatabaseError> {
+        let fn_eval = |ty: LogicalType, op: BinaryOperator, left: DataValue, right: DataValue| {
+            EvaluatorFactory::binary_create(ty, op)?
+                .0
+                .binary_eval(&left, &right)
+        };
+
+        for (op, value) in [
+            (BinaryOperator::BitAnd, 8),
+            (BinaryOperator::BitOr, 14),
+            (BinaryOperator::BitXor, 6),
+        ] {
+            assert_eq!(
+                fn_eval(
+                    LogicalType::Integer,
+                    op,
+                    DataValue::Int32(12),
+                    DataValue::Int32(10)
+                )?,
+                DataValue::Int32(value)
+            );
+            assert_eq!(
+                fn_eval(
+                    LogicalType::Integer,
+                    op,
+                    DataValue::Int32(12),
+                    DataValue::Null
+                )?,
+                DataValue::Null
+            );
+        }
+        assert_eq!(
+            fn_eval(
+                LogicalType::Tinyint,
+                BinaryOperator::BitXor,
+                DataValue::Int8(-1),
+                DataValue::Int8(0x0F)
+            )?,
+            DataValue::Int8(-16)
+        );
+
+        // the amount of a shift is any integer, the width is the one of the left side
+        let shift = |op: BinaryOperator, left: DataValue, amount: DataValue| {
+            fn_eval(left.logical_type(), op, left, amount)
+        };
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int8(1),
+                DataValue::Int32(7)
+            )?,
+            DataValue::Int8(i8::MIN)
+        );
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int32(1),
+                DataValue::Int64(31)
+            )?,
+            DataValue::Int32(i32::MIN)
+        );
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int64(1),
+                DataValue::Int8(40)
+            )?,
+            DataValue::Int64(1 << 40)
+        );
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftRight,
+                DataValue::Int32(-16),
+                DataValue::Int32(2)
+            )?,
+            DataValue::Int32(-4)
+        );
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftRight,
+                DataValue::UInt8(0x80),
+                DataValue::Int32(7)
+            )?,
+            DataValue::UInt8(1)
+        );
+        // at and beyond the width every bit is shifted out
+        for amount in [8, 9, 200] {
+            let amount = DataValue::Int32(amount);
+
+            assert_eq!(
+                shift(
+                    BinaryOperator::ShiftLeft,
+                    DataValue::Int8(1),
+                    amount.clone()
+                )?,
+                DataValue::Int8(0)
+            );
+            assert_eq!(
+                shift(
+                    BinaryOperator::ShiftRight,
+                    DataValue::Int8(i8::MAX),
+                    amount.clone()
+                )?,
+                DataValue::Int8(0)
+            );
+            assert_eq!(
+                shift(
+                    BinaryOperator::ShiftRight,
+                    DataValue::Int8(i8::MIN),
+                    amount.clone()
+                )?,
+                DataValue::Int8(-1)
+            );
+            assert_eq!(
+                shift(
+                    BinaryOperator::ShiftRight,
+                    DataValue::UInt8(u8::MAX),
+                    amount
+                )?,
+                DataValue::UInt8(0)
+            );
+        }
+        for amount in [32, 64] {
+            assert_eq!(
+                shift(
+                    BinaryOperator::ShiftLeft,
+                    DataValue::Int32(1),
+                    DataValue::Int32(amount)
+                )?,
+                DataValue::Int32(0)
+            );
+        }
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int64(1),
+                DataValue::Int32(64)
+            )?,
+            DataValue::Int64(0)
+        );
+        assert_eq!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int32(1),
+                DataValue::Null
+            )?,
+            DataValue::Null
+        );
+        assert!(matches!(
+            shift(
+                BinaryOperator::ShiftLeft,
+                DataValue::Int32(1),
+                DataValue::Int32(-1)
+            ),
+            Err(DatabaseError::InvalidValue(_))
+        ));
+
+        for ty in [
+            LogicalType::Double,
+            LogicalType::Boolean,
+            LogicalType::Decimal(None, None),
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+        ] {
+            for op in [
+                BinaryOperator::BitAnd,
+                BinaryOperator::BitOr,
+                BinaryOperator::BitXor,
+                BinaryOperator::ShiftLeft,
+                BinaryOperator::ShiftRight,
+            ] {
+                assert!(matches!(
+                    EvaluatorFactory::binary_create(ty.clone(), op),
+                    Err(DatabaseError::UnsupportedBinaryOperator(..))
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_binary_op_utf8_compare() -> Result<(), DatabaseError> {
         let evaluator = EvaluatorFactory::binary_create(
@@ -856,7 +1415,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(false)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -875,7 +1434,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -894,7 +1453,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -913,7 +1472,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -932,7 +1491,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(false)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -951,7 +1510,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Boolean(true)
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -966,7 +1525,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -981,7 +1540,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -996,7 +1555,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -1011,7 +1570,7 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
             DataValue::Null
         );
         let evaluator = EvaluatorFactory::binary_create(
@@ -1026,7 +1585,57 @@ mod test {
                     ty: Utf8Type::Variable(None),
                     unit: CharLengthUnits::Characters,
                 },
-            ),
+            )?,
+            DataValue::Null
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_op_utf8_like() -> Result<(), DatabaseError> {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        let like = |value: &str, pattern: &str, escape_char| -> Result<_, DatabaseError> {
+            let evaluator = EvaluatorFactory::binary_create(
+                LogicalType::Varchar(None, CharLengthUnits::Characters),
+                BinaryOperator::Like(escape_char),
+            )?;
+            evaluator.0.binary_eval(&utf8(value), &utf8(pattern))
+        };
+
+        assert_eq!(like("KipSQL", "Kip%", None)?, DataValue::Boolean(true));
+        assert_eq!(like("KipSQL", "kip%", None)?, DataValue::Boolean(false));
+        assert_eq!(like("KipSQL", "Kip_QL", None)?, DataValue::Boolean(true));
+        assert_eq!(like("KipSQL", "Kip_", None)?, DataValue::Boolean(false));
+        // the whole value is matched rather than a part of it
+        assert_eq!(like("xKipSQL", "Kip%", None)?, DataValue::Boolean(false));
+        assert_eq!(like("KipSQL", "SQL", None)?, DataValue::Boolean(false));
+        // characters of regular expressions only match themselves
+        assert_eq!(like("a.c", "a.c", None)?, DataValue::Boolean(true));
+        assert_eq!(like("abc", "a.c", None)?, DataValue::Boolean(false));
+        assert_eq!(like("a(b", "a(%", None)?, DataValue::Boolean(true));
+        assert_eq!(like("a\nb", "a%b", None)?, DataValue::Boolean(true));
+        // the escape character makes the wildcards match themselves
+        assert_eq!(like("F%ck", "F@%ck", Some('@'))?, DataValue::Boolean(true));
+        assert_eq!(like("Fuck", "F@%ck", Some('@'))?, DataValue::Boolean(false));
+        assert_eq!(like("a_b", "a\\_b", Some('\\'))?, DataValue::Boolean(true));
+        assert_eq!(like("acb", "a\\_b", Some('\\'))?, DataValue::Boolean(false));
+        assert_eq!(like("a@b", "a@@b", Some('@'))?, DataValue::Boolean(true));
+
+        let evaluator = EvaluatorFactory::binary_create(
+            LogicalType::Varchar(None, CharLengthUnits::Characters),
+            BinaryOperator::NotLike(None),
+        )?;
+        assert_eq!(
+            evaluator.0.binary_eval(&utf8("KipSQL"), &utf8("Kip%"))?,
+            DataValue::Boolean(false)
+        );
+        assert_eq!(
+            evaluator.0.binary_eval(&utf8("KipSQL"), &DataValue::Null)?,
             DataValue::Null
         );
 
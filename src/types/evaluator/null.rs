@@ -1,5 +1,6 @@
-use crate::types::evaluator::BinaryEvaluator;
+use crate::errors::DatabaseError;
 use crate::types::evaluator::DataValue;
+use crate::types::evaluator::{BinaryEvaluator, BinaryEvaluatorBox};
 use serde::{Deserialize, Serialize};
 
 /// Tips:
@@ -9,7 +10,23 @@ pub struct NullBinaryEvaluator;
 
 #[typetag::serde]
 impl BinaryEvaluator for NullBinaryEvaluator {
-    fn binary_eval(&self, _: &DataValue, _: &DataValue) -> DataValue {
-        DataValue::Null
+    fn binary_eval(&self, _: &DataValue, _: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(DataValue::Null)
+    }
+}
+
+/// Tips:
+/// - `NULL <=> NULL` is true and `NULL <=> x` is false, others compare as `=`
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+pub struct NullSafeEqBinaryEvaluator(pub BinaryEvaluatorBox);
+
+#[typetag::serde]
+impl BinaryEvaluator for NullSafeEqBinaryEvaluator {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        match (left.is_null(), right.is_null()) {
+            (true, true) => Ok(DataValue::Boolean(true)),
+            (true, false) | (false, true) => Ok(DataValue::Boolean(false)),
+            (false, false) => self.0.binary_eval(left, right),
+        }
     }
 }
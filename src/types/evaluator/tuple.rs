@@ -1,3 +1,4 @@
+use crate::errors::DatabaseError;
 use crate::types::evaluator::BinaryEvaluator;
 use crate::types::evaluator::DataValue;
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,8 @@ fn tuple_cmp(
 
     while order == Ordering::Equal {
         order = match (v1_iter.next(), v2_iter.next()) {
+            // the order is unknown once an undecided pair has a NULL
+            (Some(v1), Some(v2)) if v1.is_null() || v2.is_null() => return None,
             (Some(v1), Some(v2)) => v1.partial_cmp(v2)?,
             (Some(_), None) => {
                 if *v2_is_upper {
@@ -48,34 +51,55 @@ fn tuple_cmp(
     Some(order)
 }
 
+/// rows are unequal if any pair of fields is, otherwise a NULL field makes the result unknown
+fn tuple_eq(v1: &[DataValue], v2: &[DataValue]) -> Option<bool> {
+    if v1.len() != v2.len() {
+        return Some(false);
+    }
+    let mut has_null = false;
+
+    for (v1, v2) in v1.iter().zip(v2.iter()) {
+        if v1.is_null() || v2.is_null() {
+            has_null = true;
+        } else if v1 != v2 {
+            return Some(false);
+        }
+    }
+    (!has_null).then_some(true)
+}
+
 #[typetag::serde]
 impl BinaryEvaluator for TupleEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Tuple(v1, ..), DataValue::Tuple(v2, ..)) => DataValue::Boolean(*v1 == *v2),
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (DataValue::Tuple(v1, ..), DataValue::Tuple(v2, ..)) => tuple_eq(v1, v2)
+                .map(DataValue::Boolean)
+                .unwrap_or(DataValue::Null),
             (DataValue::Null, DataValue::Boolean(_))
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for TupleNotEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Tuple(v1, ..), DataValue::Tuple(v2, ..)) => DataValue::Boolean(*v1 != *v2),
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (DataValue::Tuple(v1, ..), DataValue::Tuple(v2, ..)) => tuple_eq(v1, v2)
+                .map(|is_eq| DataValue::Boolean(!is_eq))
+                .unwrap_or(DataValue::Null),
             (DataValue::Null, DataValue::Boolean(_))
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for TupleGtBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Tuple(v1, is_upper1), DataValue::Tuple(v2, is_upper2)) => {
                 tuple_cmp((v1, is_upper1), (v2, is_upper2))
                     .map(|order| DataValue::Boolean(order.is_gt()))
@@ -85,13 +109,13 @@ impl BinaryEvaluator for TupleGtBinaryEvaluator {
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for TupleGtEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Tuple(v1, is_upper1), DataValue::Tuple(v2, is_upper2)) => {
                 tuple_cmp((v1, is_upper1), (v2, is_upper2))
                     .map(|order| DataValue::Boolean(order.is_ge()))
@@ -101,13 +125,13 @@ impl BinaryEvaluator for TupleGtEqBinaryEvaluator {
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for TupleLtBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Tuple(v1, is_upper1), DataValue::Tuple(v2, is_upper2)) => {
                 tuple_cmp((v1, is_upper1), (v2, is_upper2))
                     .map(|order| DataValue::Boolean(order.is_lt()))
@@ -117,13 +141,13 @@ impl BinaryEvaluator for TupleLtBinaryEvaluator {
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for TupleLtEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Tuple(v1, is_upper1), DataValue::Tuple(v2, is_upper2)) => {
                 tuple_cmp((v1, is_upper1), (v2, is_upper2))
                     .map(|order| DataValue::Boolean(order.is_le()))
@@ -133,6 +157,6 @@ impl BinaryEvaluator for TupleLtEqBinaryEvaluator {
             | (DataValue::Boolean(_), DataValue::Null)
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
@@ -1,8 +1,14 @@
-use crate::numeric_binary_evaluator_definition;
-use crate::types::evaluator::BinaryEvaluator;
+use crate::errors::DatabaseError;
 use crate::types::evaluator::DataValue;
+use crate::types::evaluator::{BinaryEvaluator, UnaryEvaluator};
+use crate::{
+    integer_binary_evaluator_definition, integer_unary_evaluator_definition,
+    numeric_binary_evaluator_definition,
+};
 use paste::paste;
 use serde::{Deserialize, Serialize};
 use std::hint;
 
+integer_unary_evaluator_definition!(UInt8, DataValue::UInt8);
 numeric_binary_evaluator_definition!(UInt8, DataValue::UInt8);
+integer_binary_evaluator_definition!(UInt8, DataValue::UInt8, u8);
@@ -1,3 +1,4 @@
+use crate::errors::DatabaseError;
 use crate::types::evaluator::BinaryEvaluator;
 use crate::types::evaluator::DataValue;
 use crate::types::value::Utf8Type;
@@ -29,94 +30,147 @@ pub struct Utf8NotLikeBinaryEvaluator {
     pub(crate) escape_char: Option<char>,
 }
 
+/// Compares like [`DataValue`]'s `PartialEq` and `PartialOrd`, see [`Utf8Type::unpadded`]
+fn blank_padded<'a>(left: (&'a str, &Utf8Type), right: (&'a str, &Utf8Type)) -> (&'a str, &'a str) {
+    (left.1.unpadded(left.0), right.1.unpadded(right.0))
+}
+
 #[typetag::serde]
 impl BinaryEvaluator for Utf8GtBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 > v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8GtEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 >= v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8LtBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 < v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8LtEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 <= v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8EqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 == v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8NotEqBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
-            (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
+            (
+                DataValue::Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                DataValue::Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => {
+                let (v1, v2) = blank_padded((v1, ty1), (v2, ty2));
                 DataValue::Boolean(v1 != v2)
             }
             (DataValue::Utf8 { .. }, DataValue::Null)
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8StringConcatBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Utf8 { value: v1, .. }, DataValue::Utf8 { value: v2, .. }) => {
                 DataValue::Utf8 {
                     value: v1.clone() + v2,
@@ -128,13 +182,13 @@ impl BinaryEvaluator for Utf8StringConcatBinaryEvaluator {
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8LikeBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Utf8 { value, .. }, DataValue::Utf8 { value: pattern, .. }) => {
                 DataValue::Boolean(string_like(value, pattern, self.escape_char))
             }
@@ -142,13 +196,13 @@ impl BinaryEvaluator for Utf8LikeBinaryEvaluator {
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 #[typetag::serde]
 impl BinaryEvaluator for Utf8NotLikeBinaryEvaluator {
-    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> DataValue {
-        match (left, right) {
+    fn binary_eval(&self, left: &DataValue, right: &DataValue) -> Result<DataValue, DatabaseError> {
+        Ok(match (left, right) {
             (DataValue::Utf8 { value, .. }, DataValue::Utf8 { value: pattern, .. }) => {
                 DataValue::Boolean(!string_like(value, pattern, self.escape_char))
             }
@@ -156,25 +210,29 @@ impl BinaryEvaluator for Utf8NotLikeBinaryEvaluator {
             | (DataValue::Null, DataValue::Utf8 { .. })
             | (DataValue::Null, DataValue::Null) => DataValue::Null,
             _ => unsafe { hint::unreachable_unchecked() },
-        }
+        })
     }
 }
 
+/// `%` matches any sequence of characters and `_` matches any single character, the escape
+/// character makes the next character match itself
 fn string_like(value: &str, pattern: &str, escape_char: Option<char>) -> bool {
-    let mut regex_pattern = String::new();
-    let mut chars = pattern.chars().peekable();
+    let mut regex_pattern = String::from("(?s)^");
+    let mut chars = pattern.chars();
+    let mut buf = [0; 4];
     while let Some(c) = chars.next() {
         if matches!(escape_char.map(|escape_c| escape_c == c), Some(true)) {
             if let Some(next_char) = chars.next() {
-                regex_pattern.push(next_char);
+                regex_pattern.push_str(&regex::escape(next_char.encode_utf8(&mut buf)));
             }
         } else if c == '%' {
             regex_pattern.push_str(".*");
         } else if c == '_' {
             regex_pattern.push('.');
         } else {
-            regex_pattern.push(c);
+            regex_pattern.push_str(&regex::escape(c.encode_utf8(&mut buf)));
         }
     }
+    regex_pattern.push('$');
     Regex::new(&regex_pattern).unwrap().is_match(value)
 }
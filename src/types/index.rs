@@ -1,7 +1,8 @@
-use crate::catalog::{TableCatalog, TableName};
+use crate::catalog::{ColumnRef, TableCatalog, TableName};
 use crate::errors::DatabaseError;
 use crate::expression::range_detacher::Range;
 use crate::expression::ScalarExpression;
+use crate::types::tuple::Tuple;
 use crate::types::value::DataValue;
 use crate::types::{ColumnId, LogicalType};
 use kite_sql_serde_macros::ReferenceSerialization;
@@ -24,24 +25,128 @@ pub enum IndexType {
 pub struct IndexInfo {
     pub(crate) meta: IndexMetaRef,
     pub(crate) range: Option<Range>,
+    /// the index holds every scanned column, so the tuples are not read
+    pub(crate) covered: bool,
+    /// the tuples must be read in the order of the index, see `PushSortIntoScan`
+    pub(crate) order: Option<IndexOrder>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, ReferenceSerialization)]
+pub enum IndexOrder {
+    Asc,
+    /// read the index backwards
+    Desc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, ReferenceSerialization)]
 pub struct IndexMeta {
     pub id: IndexId,
+    /// the indexed columns, or the columns referenced by `expr` for an expression index
     pub column_ids: Vec<ColumnId>,
     pub table_name: TableName,
     pub pk_ty: LogicalType,
     pub value_ty: LogicalType,
     pub name: String,
     pub ty: IndexType,
+    /// the indexed expression of an expression index, e.g. `json_extract(j, '$.id')`,
+    /// kept without evaluators to be matched with the predicates, see `PushPredicateIntoScan`
+    pub expr: Option<ScalarExpression>,
+    /// the predicate of a partial index, only the rows it passes are indexed, e.g. `a IS NULL`,
+    /// kept without evaluators like `expr`
+    pub predicate: Option<ScalarExpression>,
+}
+
+/// The index meta written by the storage versions before `2`, which has neither the expression
+/// nor the predicate and is encoded without the reference tables, see `Transaction::upgrade`
+#[derive(Debug, Clone, Eq, PartialEq, ReferenceSerialization)]
+pub(crate) struct IndexMetaV1 {
+    pub(crate) id: IndexId,
+    pub(crate) column_ids: Vec<ColumnId>,
+    pub(crate) table_name: TableName,
+    pub(crate) pk_ty: LogicalType,
+    pub(crate) value_ty: LogicalType,
+    pub(crate) name: String,
+    pub(crate) ty: IndexType,
+}
+
+impl From<IndexMetaV1> for IndexMeta {
+    fn from(
+        IndexMetaV1 {
+            id,
+            column_ids,
+            table_name,
+            pk_ty,
+            value_ty,
+            name,
+            ty,
+        }: IndexMetaV1,
+    ) -> Self {
+        IndexMeta {
+            id,
+            column_ids,
+            table_name,
+            pk_ty,
+            value_ty,
+            name,
+            ty,
+            expr: None,
+            predicate: None,
+        }
+    }
 }
 
 impl IndexMeta {
+    /// Whether the entries of a secondary index hold all of `columns`,
+    /// as they keep the indexed values and the primary key
+    pub(crate) fn is_covering<'a>(
+        &self,
+        primary_keys: &[ColumnId],
+        mut columns: impl Iterator<Item = &'a ColumnRef>,
+    ) -> bool {
+        !matches!(self.ty, IndexType::PrimaryKey { .. })
+            && self.expr.is_none()
+            && columns.all(|column| {
+                column.id().is_some_and(|column_id| {
+                    self.column_ids.contains(&column_id) || primary_keys.contains(&column_id)
+                })
+            })
+    }
+
+    /// The predicate of a partial index bound to be evaluated, see [`IndexMeta::is_indexed`]
+    pub(crate) fn predicate_expr(&self) -> Result<Option<ScalarExpression>, DatabaseError> {
+        self.predicate
+            .as_ref()
+            .map(|predicate| {
+                let mut predicate = predicate.clone();
+                predicate.bind_evaluator()?;
+
+                Ok(predicate)
+            })
+            .transpose()
+    }
+
+    /// Whether `tuple` has an entry in the index, which the predicate of a partial index decides
+    pub(crate) fn is_indexed(
+        predicate: Option<&ScalarExpression>,
+        tuple: &Tuple,
+        schema: &[ColumnRef],
+    ) -> Result<bool, DatabaseError> {
+        match predicate {
+            Some(predicate) => predicate.eval(Some((tuple, schema)))?.is_true(),
+            None => Ok(true),
+        }
+    }
+
     pub(crate) fn column_exprs(
         &self,
         table: &TableCatalog,
     ) -> Result<Vec<ScalarExpression>, DatabaseError> {
+        if let Some(expr) = &self.expr {
+            let mut expr = expr.clone();
+            expr.bind_evaluator()?;
+
+            return Ok(vec![expr]);
+        }
         let mut exprs = Vec::with_capacity(self.column_ids.len());
 
         for column_id in self.column_ids.iter() {
@@ -78,6 +183,14 @@ impl fmt::Display for IndexInfo {
         } else {
             write!(f, "EMPTY")?;
         }
+        if self.covered {
+            write!(f, ", Covered")?;
+        }
+        match self.order {
+            Some(IndexOrder::Asc) => write!(f, ", Order By Asc")?,
+            Some(IndexOrder::Desc) => write!(f, ", Order By Desc")?,
+            None => (),
+        }
 
         Ok(())
     }
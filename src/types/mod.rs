@@ -11,12 +11,16 @@ use std::any::TypeId;
 use std::cmp;
 
 use crate::errors::DatabaseError;
+use crate::expression::BinaryOperator;
 use kite_sql_serde_macros::ReferenceSerialization;
 use sqlparser::ast::{CharLengthUnits, ExactNumberInfo, TimezoneInfo};
 use ulid::Ulid;
 
 pub type ColumnId = Ulid;
 
+/// The most digits a `Decimal` is guaranteed to hold
+pub const MAX_DECIMAL_PRECISION: u8 = 28;
+
 /// Sqlrs type conversion:
 /// sqlparser::ast::DataType -> LogicalType -> arrow::datatypes::DataType
 #[derive(
@@ -53,6 +57,12 @@ pub enum LogicalType {
     // decimal (precision, scale)
     Decimal(Option<u8>, Option<u8>),
     Tuple(Vec<LogicalType>),
+    Interval,
+    Bytea,
+    /// `INTEGER[]`, a variable number of elements of the inner type
+    List(Box<LogicalType>),
+    Json,
+    Uuid,
 }
 
 impl LogicalType {
@@ -118,6 +128,9 @@ impl LogicalType {
             LogicalType::Date => Some(4),
             LogicalType::DateTime => Some(8),
             LogicalType::Time => Some(4),
+            LogicalType::Interval => Some(8),
+            LogicalType::Uuid => Some(16),
+            LogicalType::Bytea | LogicalType::List(_) | LogicalType::Json => None,
             LogicalType::Invalid | LogicalType::Tuple(_) => unreachable!(),
         }
     }
@@ -196,6 +209,11 @@ impl LogicalType {
                     return Ok(right.clone());
                 }
             }
+            (LogicalType::List(ty_0), LogicalType::List(ty_1)) => {
+                return Ok(LogicalType::List(Box::new(LogicalType::max_logical_type(
+                    ty_0, ty_1,
+                )?)));
+            }
             _ => {}
         }
         if left.is_numeric() && right.is_numeric() {
@@ -208,6 +226,18 @@ impl LogicalType {
         ) {
             return Ok(LogicalType::Date);
         }
+        if matches!(
+            (left, right),
+            (
+                LogicalType::Uuid,
+                LogicalType::Varchar(..) | LogicalType::Char(..)
+            ) | (
+                LogicalType::Varchar(..) | LogicalType::Char(..),
+                LogicalType::Uuid
+            )
+        ) {
+            return Ok(LogicalType::Uuid);
+        }
         if matches!(
             (left, right),
             (LogicalType::Date, LogicalType::DateTime) | (LogicalType::DateTime, LogicalType::Date)
@@ -231,6 +261,72 @@ impl LogicalType {
         Err(DatabaseError::Incomparable(left.clone(), right.clone()))
     }
 
+    /// The result type of `left op right` when a fixed-point decimal is involved,
+    /// integers count as decimals without fractional digits
+    ///
+    /// Tips: the scale is exact through `+ - *` and the precision grows to hold the carry,
+    /// both are capped at `MAX_DECIMAL_PRECISION` keeping the integral digits first
+    pub fn decimal_arithmetic_type(
+        op: &BinaryOperator,
+        left: &LogicalType,
+        right: &LogicalType,
+    ) -> Option<LogicalType> {
+        if !matches!(left, LogicalType::Decimal(Some(_), Some(_)))
+            && !matches!(right, LogicalType::Decimal(Some(_), Some(_)))
+        {
+            return None;
+        }
+        let (p1, s1) = left.decimal_digits()?;
+        let (p2, s2) = right.decimal_digits()?;
+
+        let (precision, scale) = match op {
+            BinaryOperator::Plus | BinaryOperator::Minus => {
+                let scale = cmp::max(s1, s2);
+                (cmp::max(p1 - s1, p2 - s2) + scale + 1, scale)
+            }
+            BinaryOperator::Multiply => (p1 + p2, s1 + s2),
+            BinaryOperator::Divide => {
+                let scale = cmp::max(6, s1 + p2 + 1);
+                (p1 - s1 + s2 + scale, scale)
+            }
+            BinaryOperator::Modulo => {
+                let scale = cmp::max(s1, s2);
+                (cmp::min(p1 - s1, p2 - s2) + scale, scale)
+            }
+            _ => return None,
+        };
+        let max = MAX_DECIMAL_PRECISION as usize;
+        let overflow = precision.saturating_sub(max);
+
+        Some(LogicalType::Decimal(
+            Some(cmp::min(precision, max) as u8),
+            Some(scale.saturating_sub(overflow) as u8),
+        ))
+    }
+
+    /// The type the operands of a binary operation are evaluated in,
+    /// decimals are computed unbounded and fitted to the declared result type afterwards
+    pub fn evaluation_type(self) -> LogicalType {
+        match self {
+            LogicalType::Decimal(_, _) => LogicalType::Decimal(None, None),
+            ty => ty,
+        }
+    }
+
+    fn decimal_digits(&self) -> Option<(usize, usize)> {
+        match self {
+            LogicalType::Decimal(Some(precision), Some(scale)) => {
+                Some((*precision as usize, *scale as usize))
+            }
+            LogicalType::Tinyint | LogicalType::UTinyint => Some((3, 0)),
+            LogicalType::Smallint | LogicalType::USmallint => Some((5, 0)),
+            LogicalType::Integer | LogicalType::UInteger => Some((10, 0)),
+            LogicalType::Bigint => Some((19, 0)),
+            LogicalType::UBigint => Some((20, 0)),
+            _ => None,
+        }
+    }
+
     fn combine_numeric_types(
         left: &LogicalType,
         right: &LogicalType,
@@ -366,7 +462,15 @@ impl LogicalType {
             LogicalType::Time => {
                 matches!(to, LogicalType::Varchar(..) | LogicalType::Char(..))
             }
-            LogicalType::Decimal(_, _) | LogicalType::Tuple(_) => false,
+            // any decimal fits in a decimal without precision and scale
+            LogicalType::Decimal(_, _) => matches!(to, LogicalType::Decimal(None, None)),
+            LogicalType::List(from) => {
+                matches!(to, LogicalType::List(to) if LogicalType::can_implicit_cast(from, to))
+            }
+            // e.g. a JSON document is read by the functions taking text
+            LogicalType::Json => matches!(to, LogicalType::Varchar(..)),
+            LogicalType::Uuid => matches!(to, LogicalType::Varchar(..) | LogicalType::Char(..)),
+            LogicalType::Tuple(_) | LogicalType::Interval | LogicalType::Bytea => false,
         }
     }
 }
@@ -445,14 +549,46 @@ impl TryFrom<sqlparser::ast::DataType> for LogicalType {
                 }
                 Ok(LogicalType::Time)
             }
-            sqlparser::ast::DataType::Decimal(info) | sqlparser::ast::DataType::Dec(info) => {
-                match info {
-                    ExactNumberInfo::None => Ok(Self::Decimal(None, None)),
-                    ExactNumberInfo::Precision(p) => Ok(Self::Decimal(Some(p as u8), None)),
-                    ExactNumberInfo::PrecisionAndScale(p, s) => {
-                        Ok(Self::Decimal(Some(p as u8), Some(s as u8)))
-                    }
+            sqlparser::ast::DataType::Timestamp(precision, info) => {
+                if precision.is_some() {
+                    return Err(DatabaseError::UnsupportedStmt(
+                        "timestamp's precision".to_string(),
+                    ));
                 }
+                if !matches!(info, TimezoneInfo::None | TimezoneInfo::WithoutTimeZone) {
+                    return Err(DatabaseError::UnsupportedStmt(
+                        "timestamp's time zone".to_string(),
+                    ));
+                }
+                Ok(LogicalType::DateTime)
+            }
+            sqlparser::ast::DataType::Decimal(info)
+            | sqlparser::ast::DataType::Dec(info)
+            | sqlparser::ast::DataType::Numeric(info) => {
+                let (precision, scale) = match info {
+                    ExactNumberInfo::None => return Ok(Self::Decimal(None, None)),
+                    ExactNumberInfo::Precision(p) => (p, None),
+                    ExactNumberInfo::PrecisionAndScale(p, s) => (p, Some(s)),
+                };
+                if precision == 0
+                    || precision > MAX_DECIMAL_PRECISION as u64
+                    || scale.is_some_and(|scale| scale > precision)
+                {
+                    return Err(DatabaseError::UnsupportedStmt(format!(
+                        "decimal precision must be between 1 and {}, and the scale at most the precision",
+                        MAX_DECIMAL_PRECISION
+                    )));
+                }
+                Ok(Self::Decimal(
+                    Some(precision as u8),
+                    scale.map(|scale| scale as u8),
+                ))
+            }
+            sqlparser::ast::DataType::Bytea => Ok(LogicalType::Bytea),
+            sqlparser::ast::DataType::JSON => Ok(LogicalType::Json),
+            sqlparser::ast::DataType::Uuid => Ok(LogicalType::Uuid),
+            sqlparser::ast::DataType::Array(Some(ty)) => {
+                Ok(LogicalType::List(Box::new(LogicalType::try_from(*ty)?)))
             }
             other => Err(DatabaseError::UnsupportedStmt(format!(
                 "unsupported data type: {other}"
@@ -482,6 +618,11 @@ impl std::fmt::Display for LogicalType {
             LogicalType::Date => write!(f, "Date")?,
             LogicalType::DateTime => write!(f, "DateTime")?,
             LogicalType::Time => write!(f, "Time")?,
+            LogicalType::Interval => write!(f, "Interval")?,
+            LogicalType::Bytea => write!(f, "Bytea")?,
+            LogicalType::List(ty) => write!(f, "List({})", ty)?,
+            LogicalType::Json => write!(f, "Json")?,
+            LogicalType::Uuid => write!(f, "Uuid")?,
             LogicalType::Decimal(precision, scale) => {
                 write!(f, "Decimal({:?}, {:?})", precision, scale)?
             }
@@ -506,6 +647,7 @@ impl std::fmt::Display for LogicalType {
 #[cfg(test)]
 pub(crate) mod test {
     use crate::errors::DatabaseError;
+    use crate::expression::BinaryOperator;
     use crate::serdes::{ReferenceSerialization, ReferenceTables};
     use crate::storage::rocksdb::RocksTransaction;
     use crate::types::LogicalType;
@@ -580,6 +722,15 @@ pub(crate) mod test {
         fn_assert(&mut cursor, &mut reference_tables, LogicalType::Date)?;
         fn_assert(&mut cursor, &mut reference_tables, LogicalType::DateTime)?;
         fn_assert(&mut cursor, &mut reference_tables, LogicalType::Time)?;
+        fn_assert(&mut cursor, &mut reference_tables, LogicalType::Interval)?;
+        fn_assert(&mut cursor, &mut reference_tables, LogicalType::Bytea)?;
+        fn_assert(&mut cursor, &mut reference_tables, LogicalType::Json)?;
+        fn_assert(&mut cursor, &mut reference_tables, LogicalType::Uuid)?;
+        fn_assert(
+            &mut cursor,
+            &mut reference_tables,
+            LogicalType::List(Box::new(LogicalType::Integer)),
+        )?;
         fn_assert(
             &mut cursor,
             &mut reference_tables,
@@ -608,4 +759,58 @@ pub(crate) mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decimal_arithmetic_type() {
+        let money = LogicalType::Decimal(Some(10), Some(2));
+        let rate = LogicalType::Decimal(Some(5), Some(4));
+        let fn_type = |op: BinaryOperator, left: &LogicalType, right: &LogicalType| {
+            LogicalType::decimal_arithmetic_type(&op, left, right)
+        };
+
+        assert_eq!(
+            fn_type(BinaryOperator::Plus, &money, &money),
+            Some(LogicalType::Decimal(Some(11), Some(2)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Minus, &money, &rate),
+            Some(LogicalType::Decimal(Some(13), Some(4)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Multiply, &money, &rate),
+            Some(LogicalType::Decimal(Some(15), Some(6)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Divide, &money, &money),
+            Some(LogicalType::Decimal(Some(23), Some(13)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Modulo, &money, &rate),
+            Some(LogicalType::Decimal(Some(5), Some(4)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Plus, &money, &LogicalType::Integer),
+            Some(LogicalType::Decimal(Some(13), Some(2)))
+        );
+        assert_eq!(
+            fn_type(
+                BinaryOperator::Multiply,
+                &LogicalType::Decimal(Some(20), Some(4)),
+                &LogicalType::Decimal(Some(20), Some(4))
+            ),
+            Some(LogicalType::Decimal(Some(28), Some(0)))
+        );
+        assert_eq!(
+            fn_type(BinaryOperator::Plus, &money, &LogicalType::Double),
+            None
+        );
+        assert_eq!(
+            fn_type(
+                BinaryOperator::Plus,
+                &LogicalType::Integer,
+                &LogicalType::Integer
+            ),
+            None
+        );
+    }
 }
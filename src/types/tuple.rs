@@ -86,7 +86,9 @@ impl Tuple {
             }
             debug_assert!(projection_i < types_len);
             if is_none(bytes[i / BITS_MAX_INDEX], i % BITS_MAX_INDEX) {
-                projection_i += 1;
+                if projections[projection_i] == i {
+                    projection_i += 1;
+                }
                 continue;
             }
             if let Some(value) =
@@ -144,6 +146,7 @@ pub fn create_table<I: ResultIter>(iter: I) -> Result<Table, DatabaseError> {
     let mut table = Table::new();
     let mut header = Vec::new();
     let schema = iter.schema().clone();
+    let float_precision = iter.settings().float_precision();
 
     for col in schema.iter() {
         header.push(Cell::new(col.full_name()));
@@ -157,7 +160,8 @@ pub fn create_table<I: ResultIter>(iter: I) -> Result<Table, DatabaseError> {
         let cells = tuple
             .values
             .iter()
-            .map(|value| Cell::new(format!("{value}")))
+            .zip(schema.iter())
+            .map(|(value, column)| Cell::new(value.format_with(column.datatype(), float_precision)))
             .collect_vec();
 
         table.add_row(cells);
@@ -320,7 +324,7 @@ mod tests {
                         unit: CharLengthUnits::Octets,
                     },
                     DataValue::Utf8 {
-                        value: "K".to_string(),
+                        value: "K         ".to_string(),
                         ty: Utf8Type::Fixed(10),
                         unit: CharLengthUnits::Octets,
                     },
@@ -378,5 +382,29 @@ mod tests {
 
             assert_eq!(tuples[1], tuple_1);
         }
+        {
+            // the NULL of a column out of the projections is skipped
+            let mut values = tuples[0].values.clone();
+            values[1] = DataValue::Null;
+            let tuple_2 = Tuple::deserialize_from(
+                &types,
+                &Arc::new(vec![0]),
+                &[0, 2, 3],
+                &Arc::new(vec![
+                    columns[0].clone(),
+                    columns[2].clone(),
+                    columns[3].clone(),
+                ]),
+                &Tuple::new(Some(Arc::new(vec![0])), values.clone())
+                    .serialize_to(&types, &arena)
+                    .unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                tuple_2.values,
+                vec![values[0].clone(), values[2].clone(), values[3].clone()]
+            );
+        }
     }
 }
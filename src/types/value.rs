@@ -1,7 +1,7 @@
 use super::LogicalType;
 use crate::errors::DatabaseError;
 use crate::storage::table_codec::{BumpBytes, BOUND_MAX_TAG, BOUND_MIN_TAG};
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
 use chrono::format::{DelayedFormat, StrftimeItems};
 use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use itertools::Itertools;
@@ -26,6 +26,7 @@ static UNIX_TIME: LazyLock<NaiveTime> = LazyLock::new(|| NaiveTime::from_hms_opt
 
 pub const DATE_FMT: &str = "%Y-%m-%d";
 pub const DATE_TIME_FMT: &str = "%Y-%m-%d %H:%M:%S";
+pub const DATE_TIME_ISO_FMT: &str = "%Y-%m-%dT%H:%M:%S";
 pub const TIME_FMT: &str = "%H:%M:%S";
 
 const ENCODE_GROUP_SIZE: usize = 8;
@@ -37,6 +38,16 @@ pub enum Utf8Type {
     Fixed(u32),
 }
 
+impl Utf8Type {
+    /// `CHAR(n)` is blank-padded, so its trailing spaces are not significant when comparing or hashing
+    pub(crate) fn unpadded<'a>(&self, value: &'a str) -> &'a str {
+        match self {
+            Utf8Type::Fixed(_) => value.trim_end_matches(' '),
+            Utf8Type::Variable(_) => value,
+        }
+    }
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataValue {
     Null,
@@ -61,9 +72,17 @@ pub enum DataValue {
     /// Date stored as a signed 64bit int timestamp since UNIX epoch 1970-01-01
     Date64(i64),
     Time(u32),
-    Decimal(Decimal),
+    Decimal(#[serde(with = "decimal_bytes")] Decimal),
     /// (values, is_upper)
     Tuple(Vec<DataValue>, bool),
+    /// Interval stored as a signed 64bit int of seconds
+    Interval(i64),
+    Bytea(Vec<u8>),
+    List(Vec<DataValue>),
+    /// the text of a valid JSON document, kept as it was written
+    Json(String),
+    /// the 16 bytes of a UUID, read as a big-endian integer so that it orders like its text
+    Uuid(u128),
 }
 
 macro_rules! generate_get_option {
@@ -126,7 +145,14 @@ impl PartialEq for DataValue {
             (UInt32(_), _) => false,
             (UInt64(v1), UInt64(v2)) => v1.eq(v2),
             (UInt64(_), _) => false,
-            (Utf8 { value: v1, .. }, Utf8 { value: v2, .. }) => v1.eq(v2),
+            (
+                Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => ty1.unpadded(v1).eq(ty2.unpadded(v2)),
             (Utf8 { .. }, _) => false,
             (Null, Null) => true,
             (Null, _) => false,
@@ -142,6 +168,16 @@ impl PartialEq for DataValue {
                 values_1.eq(values_2) && is_upper_1.eq(is_upper_2)
             }
             (Tuple(..), _) => false,
+            (Interval(v1), Interval(v2)) => v1.eq(v2),
+            (Interval(_), _) => false,
+            (Bytea(v1), Bytea(v2)) => v1.eq(v2),
+            (Bytea(_), _) => false,
+            (List(v1), List(v2)) => v1.eq(v2),
+            (List(_), _) => false,
+            (Json(v1), Json(v2)) => v1.eq(v2),
+            (Json(_), _) => false,
+            (Uuid(v1), Uuid(v2)) => v1.eq(v2),
+            (Uuid(_), _) => false,
         }
     }
 }
@@ -172,7 +208,14 @@ impl PartialOrd for DataValue {
             (UInt32(_), _) => None,
             (UInt64(v1), UInt64(v2)) => v1.partial_cmp(v2),
             (UInt64(_), _) => None,
-            (Utf8 { value: v1, .. }, Utf8 { value: v2, .. }) => v1.partial_cmp(v2),
+            (
+                Utf8 {
+                    value: v1, ty: ty1, ..
+                },
+                Utf8 {
+                    value: v2, ty: ty2, ..
+                },
+            ) => ty1.unpadded(v1).partial_cmp(ty2.unpadded(v2)),
             (Utf8 { .. }, _) => None,
             (Null, Null) => Some(Ordering::Equal),
             (Null, _) => None,
@@ -185,6 +228,16 @@ impl PartialOrd for DataValue {
             (Decimal(v1), Decimal(v2)) => v1.partial_cmp(v2),
             (Decimal(_), _) => None,
             (Tuple(..), _) => None,
+            (Interval(v1), Interval(v2)) => v1.partial_cmp(v2),
+            (Interval(_), _) => None,
+            (Bytea(v1), Bytea(v2)) => v1.partial_cmp(v2),
+            (Bytea(_), _) => None,
+            (List(v1), List(v2)) => v1.partial_cmp(v2),
+            (List(_), _) => None,
+            (Json(v1), Json(v2)) => v1.partial_cmp(v2),
+            (Json(_), _) => None,
+            (Uuid(v1), Uuid(v2)) => v1.partial_cmp(v2),
+            (Uuid(_), _) => None,
         }
     }
 }
@@ -212,7 +265,7 @@ impl Hash for DataValue {
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
-            Utf8 { value: v, .. } => v.hash(state),
+            Utf8 { value: v, ty, .. } => ty.unpadded(v).hash(state),
             Null => 1.hash(state),
             Date32(v) => v.hash(state),
             Date64(v) => v.hash(state),
@@ -222,13 +275,20 @@ impl Hash for DataValue {
                 values.hash(state);
                 is_upper.hash(state);
             }
+            Interval(v) => v.hash(state),
+            Bytea(v) => v.hash(state),
+            List(v) => v.hash(state),
+            Json(v) => v.hash(state),
+            Uuid(v) => v.hash(state),
         }
     }
 }
 macro_rules! varchar_cast {
     ($value:expr, $len:expr, $ty:expr, $unit:expr) => {{
-        let s_value = $value.to_string();
-        if let Some(len) = $len {
+        let mut s_value = $value.to_string();
+        if let Utf8Type::Fixed(len) = $ty {
+            s_value = Self::blank_pad(s_value, len, $unit)?;
+        } else if let Some(len) = $len {
             if Self::check_string_len(&s_value, *len as usize, $unit) {
                 return Err(DatabaseError::TooLong);
             }
@@ -276,6 +336,30 @@ impl DataValue {
         }
     }
 
+    pub fn uuid(&self) -> Option<u128> {
+        if let DataValue::Uuid(value) = self {
+            Some(*value)
+        } else {
+            None
+        }
+    }
+
+    pub fn json(&self) -> Option<&str> {
+        if let DataValue::Json(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn bytea(&self) -> Option<&[u8]> {
+        if let DataValue::Bytea(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
     pub fn date(&self) -> Option<NaiveDate> {
         if let DataValue::Date32(val) = self {
             NaiveDate::from_num_days_from_ce_opt(*val)
@@ -308,6 +392,28 @@ impl DataValue {
         }
     }
 
+    /// Right-pads `value` with spaces to the length of `CHAR(len)`.
+    ///
+    /// Tips: trailing spaces are not significant, so they are dropped rather than overflowing `len`
+    pub(crate) fn blank_pad(
+        mut value: String,
+        len: u32,
+        unit: CharLengthUnits,
+    ) -> Result<String, DatabaseError> {
+        value.truncate(value.trim_end_matches(' ').len());
+
+        if Self::check_string_len(&value, len as usize, unit) {
+            return Err(DatabaseError::TooLong);
+        }
+        let value_len = match unit {
+            CharLengthUnits::Characters => value.chars().count(),
+            CharLengthUnits::Octets => value.len(),
+        };
+        value.push_str(&" ".repeat(len as usize - value_len));
+
+        Ok(value)
+    }
+
     #[inline]
     pub(crate) fn check_len(&self, logic_type: &LogicalType) -> Result<(), DatabaseError> {
         let is_over_len = match (logic_type, self) {
@@ -347,9 +453,20 @@ impl DataValue {
             (LogicalType::Decimal(full_len, scale_len), DataValue::Decimal(val)) => {
                 if let Some(len) = full_len {
                     let mantissa = val.mantissa().abs();
-                    if mantissa != 0 && mantissa.ilog10() + 1 > *len as u32 {
+                    let digits = if mantissa != 0 {
+                        mantissa.ilog10() + 1
+                    } else {
+                        0
+                    };
+                    if digits > *len as u32 {
                         return Err(DatabaseError::TooLong);
                     }
+                    // the integral digits must leave room for the declared scale
+                    if let Some(scale) = scale_len {
+                        if digits.saturating_sub(val.scale()) + *scale as u32 > *len as u32 {
+                            return Err(DatabaseError::TooLong);
+                        }
+                    }
                 }
                 if let Some(len) = scale_len {
                     if val.scale() > *len as u32 {
@@ -358,6 +475,12 @@ impl DataValue {
                 }
                 false
             }
+            (LogicalType::List(ty), DataValue::List(values)) => {
+                for value in values {
+                    value.check_len(ty)?;
+                }
+                false
+            }
             _ => false,
         };
 
@@ -420,6 +543,11 @@ impl DataValue {
 
                 DataValue::Tuple(values, false)
             }
+            LogicalType::Interval => DataValue::Interval(0),
+            LogicalType::Bytea => DataValue::Bytea(Vec::new()),
+            LogicalType::List(_) => DataValue::List(Vec::new()),
+            LogicalType::Json => DataValue::Json("null".to_string()),
+            LogicalType::Uuid => DataValue::Uuid(0),
         }
     }
 
@@ -518,6 +646,35 @@ impl DataValue {
                 writer.write_all(&v.serialize())?;
                 return Ok(());
             }
+            DataValue::Interval(v) => {
+                writer.write_i64::<LittleEndian>(*v)?;
+                return Ok(());
+            }
+            DataValue::Bytea(v) => {
+                writer.write_u32::<LittleEndian>(v.len() as u32)?;
+                writer.write_all(v)?;
+                return Ok(());
+            }
+            DataValue::Uuid(v) => {
+                writer.write_u128::<BigEndian>(*v)?;
+                return Ok(());
+            }
+            DataValue::Json(v) => {
+                let bytes = v.as_bytes();
+
+                writer.write_u32::<LittleEndian>(bytes.len() as u32)?;
+                writer.write_all(bytes)?;
+                return Ok(());
+            }
+            // the count of the elements, then a flag of whether each is NULL ahead of it
+            DataValue::List(values) => {
+                writer.write_u32::<LittleEndian>(values.len() as u32)?;
+                for value in values {
+                    writer.write_u8(!value.is_null() as u8)?;
+                    value.to_raw(writer)?;
+                }
+                return Ok(());
+            }
             DataValue::Tuple(..) => unreachable!(),
         }
         Ok(())
@@ -615,7 +772,7 @@ impl DataValue {
                 DataValue::Float64(OrderedFloat(reader.read_f64::<LittleEndian>()?))
             }
             LogicalType::Char(ty_len, unit) => {
-                // https://dev.mysql.com/doc/refman/8.0/en/char.html#:~:text=If%20a%20given%20value%20is%20stored%20into%20the%20CHAR(4)%20and%20VARCHAR(4)%20columns%2C%20the%20values%20retrieved%20from%20the%20columns%20are%20not%20always%20the%20same%20because%20trailing%20spaces%20are%20removed%20from%20CHAR%20columns%20upon%20retrieval.%20The%20following%20example%20illustrates%20this%20difference%3A
+                // `CHAR(n)` is stored blank-padded and read back as is
                 let len = match unit {
                     CharLengthUnits::Characters => reader.read_u32::<LittleEndian>()?,
                     CharLengthUnits::Octets => *ty_len,
@@ -626,11 +783,6 @@ impl DataValue {
                 }
                 let mut bytes = vec![0; len];
                 reader.read_exact(&mut bytes)?;
-                let last_non_zero_index = match bytes.iter().rposition(|&x| x != b' ') {
-                    Some(index) => index + 1,
-                    None => 0,
-                };
-                bytes.truncate(last_non_zero_index);
 
                 DataValue::Utf8 {
                     value: String::from_utf8(bytes)?,
@@ -684,6 +836,62 @@ impl DataValue {
 
                 DataValue::Decimal(Decimal::deserialize(bytes))
             }
+            LogicalType::Interval => {
+                if !is_projection {
+                    reader.seek(SeekFrom::Current(8))?;
+                    return Ok(None);
+                }
+                DataValue::Interval(reader.read_i64::<LittleEndian>()?)
+            }
+            LogicalType::Bytea => {
+                let len = reader.read_u32::<LittleEndian>()? as usize;
+                if !is_projection {
+                    reader.seek(SeekFrom::Current(len as i64))?;
+                    return Ok(None);
+                }
+                let mut bytes = vec![0; len];
+                reader.read_exact(&mut bytes)?;
+
+                DataValue::Bytea(bytes)
+            }
+            LogicalType::Uuid => {
+                if !is_projection {
+                    reader.seek(SeekFrom::Current(16))?;
+                    return Ok(None);
+                }
+                DataValue::Uuid(reader.read_u128::<BigEndian>()?)
+            }
+            LogicalType::Json => {
+                let len = reader.read_u32::<LittleEndian>()? as usize;
+                if !is_projection {
+                    reader.seek(SeekFrom::Current(len as i64))?;
+                    return Ok(None);
+                }
+                let mut bytes = vec![0; len];
+                reader.read_exact(&mut bytes)?;
+
+                DataValue::Json(String::from_utf8(bytes)?)
+            }
+            LogicalType::List(ty) => {
+                let len = reader.read_u32::<LittleEndian>()? as usize;
+                let mut values = Vec::with_capacity(if is_projection { len } else { 0 });
+
+                for _ in 0..len {
+                    if reader.read_u8()? == 0 {
+                        if is_projection {
+                            values.push(DataValue::Null);
+                        }
+                        continue;
+                    }
+                    if let Some(value) = DataValue::from_raw(reader, ty, is_projection)? {
+                        values.push(value);
+                    }
+                }
+                if !is_projection {
+                    return Ok(None);
+                }
+                DataValue::List(values)
+            }
             LogicalType::Tuple(_) => unreachable!(),
         };
         Ok(Some(value))
@@ -722,6 +930,18 @@ impl DataValue {
                 let types = values.iter().map(|v| v.logical_type()).collect_vec();
                 LogicalType::Tuple(types)
             }
+            DataValue::Interval(_) => LogicalType::Interval,
+            DataValue::Bytea(_) => LogicalType::Bytea,
+            DataValue::Json(_) => LogicalType::Json,
+            DataValue::Uuid(_) => LogicalType::Uuid,
+            DataValue::List(values) => {
+                let ty = values
+                    .iter()
+                    .map(DataValue::logical_type)
+                    .find(|ty| ty != &LogicalType::SqlNull)
+                    .unwrap_or(LogicalType::SqlNull);
+                LogicalType::List(Box::new(ty))
+            }
         }
     }
 
@@ -783,14 +1003,17 @@ impl DataValue {
             DataValue::Int32(v) | DataValue::Date32(v) => {
                 encode_u!(b, *v as u32 ^ 0x80000000_u32)
             }
-            DataValue::Int64(v) | DataValue::Date64(v) => {
+            DataValue::Int64(v) | DataValue::Date64(v) | DataValue::Interval(v) => {
                 encode_u!(b, *v as u64 ^ 0x8000000000000000_u64)
             }
             DataValue::UInt8(v) => encode_u!(b, v),
             DataValue::UInt16(v) => encode_u!(b, v),
             DataValue::UInt32(v) | DataValue::Time(v) => encode_u!(b, v),
             DataValue::UInt64(v) => encode_u!(b, v),
+            DataValue::Uuid(v) => encode_u!(b, v),
             DataValue::Utf8 { value: v, .. } => Self::encode_bytes(b, v.as_bytes()),
+            DataValue::Bytea(v) => Self::encode_bytes(b, v),
+            DataValue::Json(v) => Self::encode_bytes(b, v.as_bytes()),
             DataValue::Boolean(v) => b.push(if *v { b'1' } else { b'0' }),
             DataValue::Float32(f) => {
                 let mut u = f.to_bits();
@@ -816,6 +1039,19 @@ impl DataValue {
             }
             DataValue::Null => (),
             DataValue::Decimal(v) => Self::serialize_decimal(*v, b)?,
+            // a tag ahead of each element and a terminator, so that a list orders before
+            // the lists it is a prefix of
+            DataValue::List(values) => {
+                for value in values {
+                    if value.is_null() {
+                        b.push(1);
+                    } else {
+                        b.push(2);
+                        value.memcomparable_encode(b)?;
+                    }
+                }
+                b.push(0);
+            }
             DataValue::Tuple(values, is_upper) => {
                 let last = values.len() - 1;
 
@@ -1243,7 +1479,7 @@ impl DataValue {
                 LogicalType::Boolean => numeric_to_boolean!(value),
                 _ => Err(DatabaseError::CastFail),
             },
-            DataValue::Utf8 { value, .. } => match to {
+            DataValue::Utf8 { mut value, ty, .. } => match to {
                 LogicalType::Invalid => Err(DatabaseError::CastFail),
                 LogicalType::SqlNull => Ok(DataValue::Null),
                 LogicalType::Boolean => Ok(DataValue::Boolean(bool::from_str(&value)?)),
@@ -1261,6 +1497,10 @@ impl DataValue {
                     varchar_cast!(value, Some(len), Utf8Type::Fixed(*len), *unit)
                 }
                 LogicalType::Varchar(len, unit) => {
+                    // the padding of `CHAR(n)` is not part of its value
+                    if let Utf8Type::Fixed(_) = ty {
+                        value.truncate(value.trim_end_matches(' ').len());
+                    }
                     varchar_cast!(value, len, Utf8Type::Variable(*len), *unit)
                 }
                 LogicalType::Date => {
@@ -1270,6 +1510,7 @@ impl DataValue {
                 }
                 LogicalType::DateTime => {
                     let value = NaiveDateTime::parse_from_str(&value, DATE_TIME_FMT)
+                        .or_else(|_| NaiveDateTime::parse_from_str(&value, DATE_TIME_ISO_FMT))
                         .or_else(|_| {
                             NaiveDate::parse_from_str(&value, DATE_FMT)
                                 .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
@@ -1285,6 +1526,15 @@ impl DataValue {
                     Ok(DataValue::Time(value))
                 }
                 LogicalType::Decimal(_, _) => Ok(DataValue::Decimal(Decimal::from_str(&value)?)),
+                LogicalType::Interval => Ok(DataValue::Interval(Self::parse_interval(&value)?)),
+                LogicalType::Bytea => Ok(DataValue::Bytea(value.into_bytes())),
+                LogicalType::Uuid => Ok(DataValue::Uuid(Self::parse_uuid(&value)?)),
+                LogicalType::Json => {
+                    serde_json::from_str::<serde_json::Value>(&value).map_err(|err| {
+                        DatabaseError::InvalidValue(format!("invalid json: {}", err))
+                    })?;
+                    Ok(DataValue::Json(value))
+                }
                 _ => Err(DatabaseError::CastFail),
             },
             DataValue::Date32(value) => match to {
@@ -1373,6 +1623,7 @@ impl DataValue {
                         *unit
                     )
                 }
+                LogicalType::Time => Ok(DataValue::Time(value)),
                 _ => Err(DatabaseError::CastFail),
             },
             DataValue::Decimal(value) => match to {
@@ -1383,7 +1634,11 @@ impl DataValue {
                 LogicalType::Double => Ok(DataValue::Float64(OrderedFloat(
                     value.to_f64().ok_or(DatabaseError::CastFail)?,
                 ))),
-                LogicalType::Decimal(_, _) => Ok(DataValue::Decimal(value)),
+                LogicalType::Decimal(_, option) => {
+                    let mut value = value;
+                    Self::decimal_round_f(option, &mut value);
+                    Ok(DataValue::Decimal(value))
+                }
                 LogicalType::Char(len, unit) => {
                     varchar_cast!(value, Some(len), Utf8Type::Fixed(*len), *unit)
                 }
@@ -1401,6 +1656,107 @@ impl DataValue {
                     }
                     Ok(DataValue::Tuple(values, is_upper))
                 }
+                LogicalType::List(ty) => Ok(DataValue::List(
+                    values
+                        .into_iter()
+                        .map(|value| value.cast(ty))
+                        .try_collect()?,
+                )),
+                _ => Err(DatabaseError::CastFail),
+            },
+            DataValue::Interval(value) => match to {
+                LogicalType::SqlNull => Ok(DataValue::Null),
+                LogicalType::Interval => Ok(DataValue::Interval(value)),
+                LogicalType::Char(len, unit) => {
+                    varchar_cast!(
+                        DataValue::Interval(value),
+                        Some(len),
+                        Utf8Type::Fixed(*len),
+                        *unit
+                    )
+                }
+                LogicalType::Varchar(len, unit) => {
+                    varchar_cast!(
+                        DataValue::Interval(value),
+                        len,
+                        Utf8Type::Variable(*len),
+                        *unit
+                    )
+                }
+                _ => Err(DatabaseError::CastFail),
+            },
+            DataValue::List(values) => match to {
+                LogicalType::SqlNull => Ok(DataValue::Null),
+                LogicalType::List(ty) => Ok(DataValue::List(
+                    values
+                        .into_iter()
+                        .map(|value| value.cast(ty))
+                        .try_collect()?,
+                )),
+                LogicalType::Char(len, unit) => {
+                    varchar_cast!(
+                        DataValue::List(values),
+                        Some(len),
+                        Utf8Type::Fixed(*len),
+                        *unit
+                    )
+                }
+                LogicalType::Varchar(len, unit) => {
+                    varchar_cast!(
+                        DataValue::List(values),
+                        len,
+                        Utf8Type::Variable(*len),
+                        *unit
+                    )
+                }
+                _ => Err(DatabaseError::CastFail),
+            },
+            DataValue::Uuid(value) => match to {
+                LogicalType::SqlNull => Ok(DataValue::Null),
+                LogicalType::Uuid => Ok(DataValue::Uuid(value)),
+                LogicalType::Char(len, unit) => {
+                    varchar_cast!(
+                        DataValue::Uuid(value),
+                        Some(len),
+                        Utf8Type::Fixed(*len),
+                        *unit
+                    )
+                }
+                LogicalType::Varchar(len, unit) => {
+                    varchar_cast!(DataValue::Uuid(value), len, Utf8Type::Variable(*len), *unit)
+                }
+                _ => Err(DatabaseError::CastFail),
+            },
+            DataValue::Json(value) => match to {
+                LogicalType::SqlNull => Ok(DataValue::Null),
+                LogicalType::Json => Ok(DataValue::Json(value)),
+                LogicalType::Char(len, unit) => {
+                    varchar_cast!(value, Some(len), Utf8Type::Fixed(*len), *unit)
+                }
+                LogicalType::Varchar(len, unit) => {
+                    varchar_cast!(value, len, Utf8Type::Variable(*len), *unit)
+                }
+                _ => Err(DatabaseError::CastFail),
+            },
+            DataValue::Bytea(value) => match to {
+                LogicalType::SqlNull => Ok(DataValue::Null),
+                LogicalType::Bytea => Ok(DataValue::Bytea(value)),
+                LogicalType::Char(len, unit) => {
+                    varchar_cast!(
+                        DataValue::Bytea(value),
+                        Some(len),
+                        Utf8Type::Fixed(*len),
+                        *unit
+                    )
+                }
+                LogicalType::Varchar(len, unit) => {
+                    varchar_cast!(
+                        DataValue::Bytea(value),
+                        len,
+                        Utf8Type::Variable(*len),
+                        *unit
+                    )
+                }
                 _ => Err(DatabaseError::CastFail),
             },
         }?;
@@ -1447,17 +1803,19 @@ impl DataValue {
 
     fn decimal_round_i(option: &Option<u8>, decimal: &mut Decimal) {
         if let Some(scale) = option {
-            let new_decimal = decimal.trunc_with_scale(*scale as u32);
+            let mut new_decimal = decimal.trunc_with_scale(*scale as u32);
+            new_decimal.rescale(*scale as u32);
             let _ = mem::replace(decimal, new_decimal);
         }
     }
 
     fn decimal_round_f(option: &Option<u8>, decimal: &mut Decimal) {
         if let Some(scale) = option {
-            let new_decimal = decimal.round_dp_with_strategy(
+            let mut new_decimal = decimal.round_dp_with_strategy(
                 *scale as u32,
                 rust_decimal::RoundingStrategy::MidpointAwayFromZero,
             );
+            new_decimal.rescale(*scale as u32);
             let _ = mem::replace(decimal, new_decimal);
         }
     }
@@ -1477,6 +1835,100 @@ impl DataValue {
     fn decimal_format(v: &Decimal) -> String {
         v.to_string()
     }
+
+    /// Formats the value as a result cell of type `ty`:
+    /// decimals are padded with trailing zeros to the declared scale,
+    /// and floats are rounded to `float_precision` digits after the decimal point if it is set
+    pub fn format_with(&self, ty: &LogicalType, float_precision: Option<usize>) -> String {
+        match (self, ty, float_precision) {
+            (DataValue::Decimal(value), LogicalType::Decimal(_, Some(scale)), _) => {
+                let mut value = *value;
+                value.rescale(*scale as u32);
+
+                Self::decimal_format(&value)
+            }
+            (DataValue::Float32(value), _, Some(precision)) => {
+                format!("{:.1$}", value.0, precision)
+            }
+            (DataValue::Float64(value), _, Some(precision)) => {
+                format!("{:.1$}", value.0, precision)
+            }
+            _ => self.to_string(),
+        }
+    }
+
+    /// Parses the canonical text of a UUID, e.g. `a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11`.
+    fn parse_uuid(value: &str) -> Result<u128, DatabaseError> {
+        let bytes = value.as_bytes();
+        let is_canonical = bytes.len() == 36
+            && bytes.iter().enumerate().all(|(i, byte)| match i {
+                8 | 13 | 18 | 23 => *byte == b'-',
+                _ => byte.is_ascii_hexdigit(),
+            });
+        if !is_canonical {
+            return Err(DatabaseError::InvalidValue(format!("uuid: '{}'", value)));
+        }
+        Ok(u128::from_str_radix(&value.replace('-', ""), 16)?)
+    }
+
+    /// Parses interval text such as `15 minutes`, `1 hour 30 minutes`, `-2 days` or
+    /// `1 day 02:30:00` into seconds.
+    ///
+    /// Tips: `month` and `year` are rejected, since their length in seconds is not fixed
+    fn parse_interval(value: &str) -> Result<i64, DatabaseError> {
+        let invalid = || DatabaseError::InvalidValue(format!("interval: '{}'", value));
+        let mut seconds = 0_i64;
+        let mut parts = value.split_whitespace();
+
+        if parts.clone().next().is_none() {
+            return Err(invalid());
+        }
+        while let Some(num) = parts.next() {
+            // `[-]hh:mm[:ss]`
+            if num.contains(':') {
+                let (negative, time) = num
+                    .strip_prefix('-')
+                    .map_or((false, num), |time| (true, time));
+                let fields = time
+                    .split(':')
+                    .map(u32::from_str)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| invalid())?;
+                let (hours, minutes, secs) = match fields[..] {
+                    [hours, minutes] => (hours, minutes, 0),
+                    [hours, minutes, secs] => (hours, minutes, secs),
+                    _ => return Err(invalid()),
+                };
+                if minutes >= 60 || secs >= 60 {
+                    return Err(invalid());
+                }
+                let time_seconds = hours as i64 * 3_600 + minutes as i64 * 60 + secs as i64;
+
+                seconds = seconds
+                    .checked_add(if negative {
+                        -time_seconds
+                    } else {
+                        time_seconds
+                    })
+                    .ok_or_else(invalid)?;
+                continue;
+            }
+            let num = i64::from_str(num).map_err(|_| invalid())?;
+            let unit = match parts.next().map(str::to_lowercase).as_deref() {
+                Some("second" | "seconds" | "sec" | "secs") => 1,
+                Some("minute" | "minutes" | "min" | "mins") => 60,
+                Some("hour" | "hours") => 3_600,
+                Some("day" | "days") => 86_400,
+                Some("week" | "weeks") => 604_800,
+                _ => return Err(invalid()),
+            };
+            seconds = num
+                .checked_mul(unit)
+                .and_then(|num| seconds.checked_add(num))
+                .ok_or_else(invalid)?;
+        }
+        Ok(seconds)
+    }
 }
 
 macro_rules! impl_scalar {
@@ -1687,6 +2139,55 @@ impl fmt::Display for DataValue {
                 }
                 write!(f, ")")?;
             }
+            DataValue::Interval(e) => {
+                // e.g. `1 day 02:30:00`, or `-1 days -02:30:00` when negative
+                let (days, time) = (e / 86_400, e % 86_400);
+                if days != 0 {
+                    write!(f, "{} {} ", days, if days == 1 { "day" } else { "days" })?;
+                }
+                let sign = if time < 0 { "-" } else { "" };
+                let time = time.unsigned_abs();
+                write!(
+                    f,
+                    "{}{:02}:{:02}:{:02}",
+                    sign,
+                    time / 3_600,
+                    time % 3_600 / 60,
+                    time % 60
+                )?
+            }
+            DataValue::Bytea(e) => {
+                // PostgreSQL's `hex` output format for bytea
+                write!(f, "\\x")?;
+                for byte in e {
+                    write!(f, "{:02x}", byte)?;
+                }
+            }
+            DataValue::Json(e) => write!(f, "{}", e)?,
+            DataValue::Uuid(e) => {
+                let hex = format!("{:032x}", e);
+                write!(
+                    f,
+                    "{}-{}-{}-{}-{}",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                )?
+            }
+            DataValue::List(values) => {
+                write!(f, "[")?;
+                let len = values.len();
+
+                for (i, value) in values.iter().enumerate() {
+                    value.fmt(f)?;
+                    if len != i + 1 {
+                        write!(f, ", ")?;
+                    }
+                }
+                write!(f, "]")?;
+            }
         };
         Ok(())
     }
@@ -1719,18 +2220,48 @@ impl fmt::Debug for DataValue {
                 }
                 write!(f, ")")
             }
+            DataValue::Interval(_) => write!(f, "Interval({})", self),
+            DataValue::Bytea(_) => write!(f, "Bytea({})", self),
+            DataValue::List(_) => write!(f, "List({})", self),
+            DataValue::Json(_) => write!(f, "Json({})", self),
+            DataValue::Uuid(_) => write!(f, "Uuid({})", self),
         }
     }
 }
 
+/// `Decimal` is deserialized by `deserialize_any`, which bincode does not support,
+/// so its 16 bytes are written instead.
+mod decimal_bytes {
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Decimal,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize().serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Decimal, D::Error> {
+        Ok(Decimal::deserialize(<[u8; 16]>::deserialize(deserializer)?))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::errors::DatabaseError;
     use crate::storage::table_codec::BumpBytes;
-    use crate::types::value::DataValue;
+    use crate::types::value::{DataValue, Utf8Type};
+    use crate::types::LogicalType;
     use bumpalo::Bump;
     use ordered_float::OrderedFloat;
     use rust_decimal::Decimal;
+    use sqlparser::ast::CharLengthUnits;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::io::Cursor;
 
     #[test]
     fn test_mem_comparable_null() -> Result<(), DatabaseError> {
@@ -1925,4 +2456,174 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cast_char_blank_padded() -> Result<(), DatabaseError> {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+
+        let char_value = utf8("🪁").cast(&LogicalType::Char(3, CharLengthUnits::Characters))?;
+        assert_eq!(char_value.utf8(), Some("🪁  "));
+        assert_eq!(
+            utf8("🪁")
+                .cast(&LogicalType::Char(5, CharLengthUnits::Octets))?
+                .utf8(),
+            Some("🪁 ")
+        );
+        // trailing spaces are not significant
+        assert_eq!(
+            utf8("ab    ")
+                .cast(&LogicalType::Char(2, CharLengthUnits::Characters))?
+                .utf8(),
+            Some("ab")
+        );
+        assert!(matches!(
+            utf8("abc").cast(&LogicalType::Char(2, CharLengthUnits::Characters)),
+            Err(DatabaseError::TooLong)
+        ));
+        assert_eq!(
+            char_value.cast(&LogicalType::Varchar(None, CharLengthUnits::Characters))?,
+            utf8("🪁")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_eq_and_hash() {
+        let hash = |value: &DataValue| {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let value = |value: &str, ty: Utf8Type| DataValue::Utf8 {
+            value: value.to_string(),
+            ty,
+            unit: CharLengthUnits::Characters,
+        };
+        let padded = value("ab  ", Utf8Type::Fixed(4));
+
+        for other in [
+            value("ab", Utf8Type::Fixed(2)),
+            value("ab", Utf8Type::Variable(None)),
+        ] {
+            assert_eq!(padded, other);
+            assert_eq!(hash(&padded), hash(&other));
+        }
+        let spaced = value("ab  ", Utf8Type::Variable(None));
+        assert_ne!(padded, spaced);
+        assert_eq!(padded.partial_cmp(&spaced), Some(std::cmp::Ordering::Less));
+    }
+
+    #[test]
+    fn test_cast_temporal() -> Result<(), DatabaseError> {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        let varchar = LogicalType::Varchar(None, CharLengthUnits::Characters);
+
+        for (value, ty) in [
+            ("2024-02-29", LogicalType::Date),
+            ("0001-01-01", LogicalType::Date),
+            ("9999-12-31", LogicalType::Date),
+            ("00:00:00", LogicalType::Time),
+            ("23:59:59", LogicalType::Time),
+            ("2024-02-29 12:22:33", LogicalType::DateTime),
+            ("1970-01-01 00:00:00", LogicalType::DateTime),
+            ("0001-01-01 00:00:00", LogicalType::DateTime),
+            ("9999-12-31 23:59:59", LogicalType::DateTime),
+        ] {
+            let temporal = utf8(value).cast(&ty)?;
+            assert_eq!(temporal.logical_type(), ty);
+            assert_eq!(temporal.clone().cast(&varchar)?, utf8(value));
+
+            let mut bytes = Vec::new();
+            temporal.to_raw(&mut bytes)?;
+            assert_eq!(
+                DataValue::from_raw(&mut Cursor::new(bytes), &ty, true)?,
+                Some(temporal)
+            );
+        }
+        assert_eq!(
+            utf8("2022-10-12T12:22:33").cast(&LogicalType::DateTime)?,
+            utf8("2022-10-12 12:22:33").cast(&LogicalType::DateTime)?
+        );
+        assert_eq!(
+            utf8("2022-10-12").cast(&LogicalType::DateTime)?,
+            utf8("2022-10-12 00:00:00").cast(&LogicalType::DateTime)?
+        );
+
+        for (value, ty) in [
+            ("2023-02-29", LogicalType::Date),
+            ("2022-13-01", LogicalType::Date),
+            ("2022/10/12", LogicalType::Date),
+            ("24:00:00", LogicalType::Time),
+            ("12:60:00", LogicalType::Time),
+            ("2023-02-29 12:22:33", LogicalType::DateTime),
+            ("2022-10-12 12:22", LogicalType::DateTime),
+        ] {
+            assert!(matches!(
+                utf8(value).cast(&ty),
+                Err(DatabaseError::ParseDate(_))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_uuid() -> Result<(), DatabaseError> {
+        let utf8 = |value: &str| DataValue::Utf8 {
+            value: value.to_string(),
+            ty: Utf8Type::Variable(None),
+            unit: CharLengthUnits::Characters,
+        };
+        let varchar = LogicalType::Varchar(None, CharLengthUnits::Characters);
+
+        let uuid = utf8("A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11").cast(&LogicalType::Uuid)?;
+        assert_eq!(
+            uuid,
+            DataValue::Uuid(0xa0eebc99_9c0b_4ef8_bb6d_6bb9bd380a11)
+        );
+        assert_eq!(
+            uuid.clone().cast(&varchar)?,
+            utf8("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+        );
+
+        let mut bytes = Vec::new();
+        uuid.to_raw(&mut bytes)?;
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(
+            DataValue::from_raw(&mut Cursor::new(bytes), &LogicalType::Uuid, true)?,
+            Some(uuid.clone())
+        );
+
+        let arena = Bump::new();
+        let mut key_uuid_1 = BumpBytes::new_in(&arena);
+        let mut key_uuid_2 = BumpBytes::new_in(&arena);
+        let lower = utf8("00000000-0000-0000-0000-0000000000ff").cast(&LogicalType::Uuid)?;
+        lower.memcomparable_encode(&mut key_uuid_1)?;
+        uuid.memcomparable_encode(&mut key_uuid_2)?;
+        assert!(lower < uuid);
+        assert!(key_uuid_1 < key_uuid_2);
+
+        for value in [
+            "a0eebc999c0b4ef8bb6d6bb9bd380a11",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1g",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}",
+        ] {
+            assert!(matches!(
+                utf8(value).cast(&LogicalType::Uuid),
+                Err(DatabaseError::InvalidValue(_))
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -1,11 +1,12 @@
 use crate::load::DIST_PER_WARE;
 use crate::{TpccArgs, TpccError, TpccTest, TpccTransaction};
 use chrono::Utc;
-use kite_sql::db::{DBTransaction, ResultIter, Statement};
+use kite_sql::db::{DBTransaction, PreparedStatement, ResultIter};
 use kite_sql::storage::Storage;
 use kite_sql::types::value::DataValue;
 use rand::prelude::ThreadRng;
 use rand::Rng;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub(crate) struct DeliveryArgs {
@@ -28,7 +29,7 @@ impl<S: Storage> TpccTransaction<S> for Delivery {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         let now = Utc::now().naive_utc();
 
@@ -133,11 +134,12 @@ impl<S: Storage> TpccTest<S> for DeliveryTest {
         &self,
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
-        num_ware: usize,
+        _: usize,
+        home_wares: &Range<usize>,
         _: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
-        let w_id = rng.gen_range(0..num_ware) + 1;
+        let w_id = rng.gen_range(home_wares.clone());
         let o_carrier_id = rng.gen_range(1..10);
 
         let args = DeliveryArgs::new(w_id, o_carrier_id);
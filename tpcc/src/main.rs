@@ -7,11 +7,15 @@ use crate::rt_hist::RtHist;
 use crate::slev::SlevTest;
 use crate::utils::SeqGen;
 use clap::Parser;
-use kite_sql::db::{DBTransaction, DataBaseBuilder, Statement};
+use kite_sql::db::{DBTransaction, DataBaseBuilder, Database, PreparedStatement};
 use kite_sql::errors::DatabaseError;
 use kite_sql::storage::Storage;
 use rand::prelude::ThreadRng;
 use rand::Rng;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
 use std::time::{Duration, Instant};
 
 mod delivery;
@@ -38,7 +42,7 @@ pub(crate) trait TpccTransaction<S: Storage> {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError>;
 }
 
@@ -50,8 +54,9 @@ pub(crate) trait TpccTest<S: Storage> {
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
         num_ware: usize,
+        home_wares: &Range<usize>,
         args: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError>;
 }
 
@@ -72,9 +77,10 @@ struct Args {
     measure_time: u64,
     #[clap(long, default_value = "1")]
     num_ware: usize,
+    #[clap(long, default_value = "1")]
+    threads: usize,
 }
 
-// TODO: Support multi-threaded TPCC
 fn main() -> Result<(), TpccError> {
     let args = Args::parse();
 
@@ -136,73 +142,61 @@ fn main() -> Result<(), TpccError> {
         ],
     ];
 
-    let mut rt_hist = RtHist::new();
-    let mut success = [0usize; 5];
-    let mut late = [0usize; 5];
-    let mut failure = [0usize; 5];
-    let tests = vec![
-        Box::new(NewOrdTest) as Box<dyn TpccTest<_>>,
-        Box::new(PaymentTest),
-        Box::new(OrderStatTest),
-        Box::new(DeliveryTest),
-        Box::new(SlevTest),
-    ];
     let tpcc_args = TpccArgs { joins: args.joins };
-
     let duration = Duration::new(args.measure_time, 0);
-    let mut round_count = 0;
-    let mut seq_gen = SeqGen::new(10, 10, 1, 1, 1);
+    let threads = args.threads.max(1);
+    let report = Mutex::new(TpccReport::new());
+    let is_aborted = AtomicBool::new(false);
     let tpcc_start = Instant::now();
 
-    while tpcc_start.elapsed() < duration {
-        let i = seq_gen.get();
-        let tpcc_test = &tests[i];
-        let statement = &test_statements[i];
-
-        let mut is_succeed = false;
-        for j in 0..args.max_retry + 1 {
-            let transaction_start = Instant::now();
-            let mut tx = database.new_transaction()?;
-
-            if let Err(err) =
-                tpcc_test.do_transaction(&mut rng, &mut tx, args.num_ware, &tpcc_args, &statement)
-            {
-                failure[i] += 1;
-                eprintln!(
-                    "[{}] Error while doing transaction: {}",
-                    tpcc_test.name(),
-                    err
+    let results = thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|worker| {
+                let home_wares = home_wares(worker, threads, args.num_ware);
+                let (database, test_statements, tpcc_args, args, report, is_aborted) = (
+                    &database,
+                    &test_statements,
+                    &tpcc_args,
+                    &args,
+                    &report,
+                    &is_aborted,
                 );
-            } else {
-                let rt = transaction_start.elapsed();
-                rt_hist.hist_inc(i, rt);
-                is_succeed = true;
 
-                if rt <= RT_LIMITS[i] {
-                    success[i] += 1;
-                } else {
-                    late[i] += 1;
-                }
-                tx.commit()?;
-                break;
-            }
-            if j < args.max_retry {
-                println!("[{}] Retry for the {}th time", tpcc_test.name(), j + 1);
-            }
-        }
-        if !is_succeed {
-            return Err(TpccError::MaxRetry);
-        }
-        if round_count != 0 && round_count % 100 == 0 {
-            println!(
-                "[TPCC CheckPoint {} on round {round_count}][{}]: 90th Percentile RT: {:.3}",
-                round_count / 100,
-                tpcc_test.name(),
-                rt_hist.hist_ckp(i)
-            );
-        }
-        round_count += 1;
-    }
+                scope.spawn(move || {
+                    let mut worker_report = TpccReport::new();
+                    let result = run_worker(
+                        database,
+                        test_statements,
+                        tpcc_args,
+                        args,
+                        &home_wares,
+                        tpcc_start,
+                        duration,
+                        is_aborted,
+                        &mut worker_report,
+                    );
+                    if result.is_err() {
+                        is_aborted.store(true, Ordering::SeqCst);
+                    }
+                    // the counts of a failed worker are still reported
+                    report.lock().unwrap().merge(&worker_report);
+
+                    result
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("the TPCC worker panicked"))
+            .collect::<Vec<_>>()
+    });
+    let TpccReport {
+        rt_hist,
+        success,
+        late,
+        failure,
+    } = report.into_inner().unwrap();
     let actual_tpcc_time = tpcc_start.elapsed();
     println!("---------------------------------------------------");
     // Raw Results
@@ -268,6 +262,137 @@ fn main() -> Result<(), TpccError> {
     let tpmc = ((success[0] + late[0]) as f64 / (actual_tpcc_time.as_secs_f64() / 60.0)).round();
     println!("{} Tpmc", tpmc);
 
+    results.into_iter().collect()
+}
+
+/// The counts and response times of the transactions run by one or all workers
+struct TpccReport {
+    rt_hist: RtHist,
+    success: [usize; 5],
+    late: [usize; 5],
+    failure: [usize; 5],
+}
+
+impl TpccReport {
+    fn new() -> Self {
+        TpccReport {
+            rt_hist: RtHist::new(),
+            success: [0; 5],
+            late: [0; 5],
+            failure: [0; 5],
+        }
+    }
+
+    fn merge(&mut self, other: &TpccReport) {
+        self.rt_hist.merge(&other.rt_hist);
+
+        for i in 0..5 {
+            self.success[i] += other.success[i];
+            self.late[i] += other.late[i];
+            self.failure[i] += other.failure[i];
+        }
+    }
+}
+
+/// The home warehouses of a worker, the warehouses are split evenly across the workers and
+/// shared in turn when there are more workers than warehouses
+fn home_wares(worker: usize, threads: usize, num_ware: usize) -> Range<usize> {
+    if threads >= num_ware {
+        let w_id = worker % num_ware + 1;
+
+        w_id..w_id + 1
+    } else {
+        num_ware * worker / threads + 1..num_ware * (worker + 1) / threads + 1
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_worker<S: Storage>(
+    database: &Database<S>,
+    test_statements: &[Vec<PreparedStatement>],
+    tpcc_args: &TpccArgs,
+    args: &Args,
+    home_wares: &Range<usize>,
+    tpcc_start: Instant,
+    duration: Duration,
+    is_aborted: &AtomicBool,
+    report: &mut TpccReport,
+) -> Result<(), TpccError> {
+    let mut rng = rand::thread_rng();
+    let tests = [
+        Box::new(NewOrdTest) as Box<dyn TpccTest<_>>,
+        Box::new(PaymentTest),
+        Box::new(OrderStatTest),
+        Box::new(DeliveryTest),
+        Box::new(SlevTest),
+    ];
+    let mut round_count = 0;
+    let mut seq_gen = SeqGen::new(10, 10, 1, 1, 1);
+
+    while tpcc_start.elapsed() < duration && !is_aborted.load(Ordering::SeqCst) {
+        let i = seq_gen.get();
+        let tpcc_test = &tests[i];
+        let statement = &test_statements[i];
+
+        let mut is_succeed = false;
+        for j in 0..args.max_retry + 1 {
+            let transaction_start = Instant::now();
+            let mut tx = database.new_transaction()?;
+
+            // the transactions of other workers may conflict on commit
+            if let Err(err) = tpcc_test
+                .do_transaction(
+                    &mut rng,
+                    &mut tx,
+                    args.num_ware,
+                    home_wares,
+                    tpcc_args,
+                    statement,
+                )
+                .and_then(|_| Ok(tx.commit()?))
+            {
+                report.failure[i] += 1;
+                eprintln!(
+                    "[{}] Error while doing transaction: {}",
+                    tpcc_test.name(),
+                    err
+                );
+            } else {
+                let rt = transaction_start.elapsed();
+                report.rt_hist.hist_inc(i, rt);
+                is_succeed = true;
+
+                if rt <= RT_LIMITS[i] {
+                    report.success[i] += 1;
+                } else {
+                    report.late[i] += 1;
+                }
+                break;
+            }
+            if j < args.max_retry {
+                println!("[{}] Retry for the {}th time", tpcc_test.name(), j + 1);
+            }
+        }
+        if !is_succeed {
+            eprintln!(
+                "[{}] Stop the worker of warehouses {:?}: {}",
+                tpcc_test.name(),
+                home_wares,
+                TpccError::MaxRetry
+            );
+            return Err(TpccError::MaxRetry);
+        }
+        if round_count != 0 && round_count % 100 == 0 {
+            println!(
+                "[TPCC CheckPoint {} on round {round_count}][{}]: 90th Percentile RT: {:.3}",
+                round_count / 100,
+                tpcc_test.name(),
+                report.rt_hist.hist_ckp(i)
+            );
+        }
+        round_count += 1;
+    }
+
     Ok(())
 }
 
@@ -318,6 +443,24 @@ pub enum TpccError {
     MaxRetry,
 }
 
+#[test]
+fn test_home_wares() {
+    assert_eq!(home_wares(0, 1, 1), 1..2);
+    assert_eq!(home_wares(0, 1, 3), 1..4);
+    assert_eq!(
+        (0..3)
+            .map(|worker| home_wares(worker, 3, 7))
+            .collect::<Vec<_>>(),
+        vec![1..3, 3..5, 5..8]
+    );
+    assert_eq!(
+        (0..4)
+            .map(|worker| home_wares(worker, 4, 2))
+            .collect::<Vec<_>>(),
+        vec![1..2, 2..3, 1..2, 2..3]
+    );
+}
+
 #[ignore]
 #[test]
 fn explain_tpcc() -> Result<(), DatabaseError> {
@@ -1,12 +1,13 @@
 use crate::load::{nu_rand, CUST_PER_DIST, DIST_PER_WARE, MAX_ITEMS, MAX_NUM_ITEMS};
 use crate::{other_ware, TpccArgs, TpccError, TpccTest, TpccTransaction, ALLOW_MULTI_WAREHOUSE_TX};
 use chrono::Utc;
-use kite_sql::db::{DBTransaction, ResultIter, Statement};
+use kite_sql::db::{DBTransaction, PreparedStatement, ResultIter};
 use kite_sql::storage::Storage;
 use kite_sql::types::value::DataValue;
 use rand::prelude::ThreadRng;
 use rand::Rng;
 use rust_decimal::Decimal;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub(crate) struct NewOrdArgs {
@@ -57,7 +58,7 @@ impl<S: Storage> TpccTransaction<S> for NewOrd {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         let mut price = vec![Decimal::default(); MAX_NUM_ITEMS];
         let mut iname = vec![String::new(); MAX_NUM_ITEMS];
@@ -301,8 +302,9 @@ impl<S: Storage> TpccTest<S> for NewOrdTest {
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
         num_ware: usize,
+        home_wares: &Range<usize>,
         args: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         let mut all_local = 1;
         let notfound = MAX_ITEMS + 1;
@@ -311,7 +313,7 @@ impl<S: Storage> TpccTest<S> for NewOrdTest {
         let mut supware = vec![0; MAX_NUM_ITEMS];
         let mut qty = vec![0; MAX_NUM_ITEMS];
 
-        let w_id = rng.gen_range(0..num_ware) + 1;
+        let w_id = rng.gen_range(home_wares.clone());
         let d_id = rng.gen_range(1..DIST_PER_WARE);
         let c_id = nu_rand(rng, 1023, 1, CUST_PER_DIST);
         let ol_cnt = rng.gen_range(5..15);
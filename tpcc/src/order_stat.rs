@@ -1,11 +1,12 @@
 use crate::load::{last_name, nu_rand, CUST_PER_DIST, DIST_PER_WARE};
 use crate::{TpccArgs, TpccError, TpccTest, TpccTransaction};
-use kite_sql::db::{DBTransaction, Statement};
+use kite_sql::db::{DBTransaction, PreparedStatement};
 use kite_sql::storage::Storage;
 use kite_sql::types::value::DataValue;
 use rand::prelude::ThreadRng;
 use rand::Rng;
 use rust_decimal::Decimal;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub(crate) struct OrderStatArgs {
@@ -43,7 +44,7 @@ impl<S: Storage> TpccTransaction<S> for OrderStat {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         let (c_balance, c_first, c_middle, c_last) = if args.by_name {
             // SELECT count(c_id) FROM customer WHERE c_w_id = ? AND c_d_id = ? AND c_last = ?"
@@ -158,11 +159,12 @@ impl<S: Storage> TpccTest<S> for OrderStatTest {
         &self,
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
-        num_ware: usize,
+        _: usize,
+        home_wares: &Range<usize>,
         _: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
-        let w_id = rng.gen_range(0..num_ware) + 1;
+        let w_id = rng.gen_range(home_wares.clone());
         let d_id = rng.gen_range(1..DIST_PER_WARE);
         let c_id = nu_rand(rng, 1023, 1, CUST_PER_DIST);
         let c_last = last_name(nu_rand(rng, 255, 0, 999));
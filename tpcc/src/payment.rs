@@ -1,12 +1,13 @@
 use crate::load::{last_name, nu_rand, CUST_PER_DIST, DIST_PER_WARE};
 use crate::{other_ware, TpccArgs, TpccError, TpccTest, TpccTransaction, ALLOW_MULTI_WAREHOUSE_TX};
 use chrono::Utc;
-use kite_sql::db::{DBTransaction, ResultIter, Statement};
+use kite_sql::db::{DBTransaction, PreparedStatement, ResultIter};
 use kite_sql::storage::Storage;
 use kite_sql::types::value::DataValue;
 use rand::prelude::ThreadRng;
 use rand::Rng;
 use rust_decimal::Decimal;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub(crate) struct PaymentArgs {
@@ -55,7 +56,7 @@ impl<S: Storage> TpccTransaction<S> for Payment {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         let now = Utc::now();
         // "UPDATE warehouse SET w_ytd = w_ytd + ? WHERE w_id = ?"
@@ -260,10 +261,11 @@ impl<S: Storage> TpccTest<S> for PaymentTest {
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
         num_ware: usize,
+        home_wares: &Range<usize>,
         _: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
-        let w_id = rng.gen_range(0..num_ware) + 1;
+        let w_id = rng.gen_range(home_wares.clone());
         let d_id = rng.gen_range(1..DIST_PER_WARE);
         let c_id = nu_rand(rng, 1023, 1, CUST_PER_DIST);
         let c_last = last_name(nu_rand(rng, 255, 0, 999));
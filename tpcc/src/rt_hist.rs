@@ -74,6 +74,18 @@ impl RtHist {
         line as f64 / REC_PER_SEC as f64
     }
 
+    // Add the records of another histogram, e.g. the one of another worker
+    pub fn merge(&mut self, other: &RtHist) {
+        for j in 0..NUM_TRANSACTIONS {
+            for i in 0..(MAX_REC * REC_PER_SEC) {
+                self.total_hist[j][i] += other.total_hist[j][i];
+                self.cur_hist[j][i] += other.cur_hist[j][i];
+            }
+            self.max_rt[j] = self.max_rt[j].max(other.max_rt[j]);
+            self.cur_max_rt[j] = self.cur_max_rt[j].max(other.cur_max_rt[j]);
+        }
+    }
+
     // Report histograms
     pub fn hist_report(&self) {
         let mut total = [0; NUM_TRANSACTIONS];
@@ -133,3 +145,31 @@ impl RtHist {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::rt_hist::RtHist;
+    use std::time::Duration;
+
+    #[test]
+    fn test_merge() {
+        let mut hist_1 = RtHist::new();
+        let mut hist_2 = RtHist::new();
+
+        hist_1.hist_inc(0, Duration::from_millis(10));
+        hist_1.hist_ckp(0);
+        hist_1.hist_inc(0, Duration::from_millis(30));
+        hist_2.hist_inc(0, Duration::from_millis(10));
+        hist_2.hist_inc(1, Duration::from_millis(20));
+        hist_2.hist_ckp(1);
+
+        hist_1.merge(&hist_2);
+        assert_eq!(hist_1.total_hist[0][10], 1);
+        assert_eq!(hist_1.cur_hist[0][10], 1);
+        assert_eq!(hist_1.cur_hist[0][30], 1);
+        assert_eq!(hist_1.total_hist[1][20], 1);
+        assert_eq!(hist_1.max_rt[0], 0.01);
+        assert_eq!(hist_1.max_rt[1], 0.02);
+        assert_eq!(hist_1.cur_max_rt[0], 0.03);
+    }
+}
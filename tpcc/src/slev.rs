@@ -1,10 +1,11 @@
 use crate::load::DIST_PER_WARE;
 use crate::{TpccArgs, TpccError, TpccTest, TpccTransaction};
-use kite_sql::db::{DBTransaction, Statement};
+use kite_sql::db::{DBTransaction, PreparedStatement};
 use kite_sql::storage::Storage;
 use kite_sql::types::value::DataValue;
 use rand::prelude::ThreadRng;
 use rand::Rng;
+use std::ops::Range;
 
 #[derive(Debug)]
 pub(crate) struct SlevArgs {
@@ -28,7 +29,7 @@ impl<S: Storage> TpccTransaction<S> for Slev {
     fn run(
         tx: &mut DBTransaction<S>,
         args: &Self::Args,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
         // "SELECT d_next_o_id FROM district WHERE d_id = ? AND d_w_id = ?"
         let tuple = tx
@@ -83,11 +84,12 @@ impl<S: Storage> TpccTest<S> for SlevTest {
         &self,
         rng: &mut ThreadRng,
         tx: &mut DBTransaction<S>,
-        num_ware: usize,
+        _: usize,
+        home_wares: &Range<usize>,
         _: &TpccArgs,
-        statements: &[Statement],
+        statements: &[PreparedStatement],
     ) -> Result<(), TpccError> {
-        let w_id = rng.gen_range(0..num_ware) + 1;
+        let w_id = rng.gen_range(home_wares.clone());
         let d_id = rng.gen_range(1..DIST_PER_WARE);
         let level = rng.gen_range(10..20);
 